@@ -0,0 +1,223 @@
+//! Example demonstrating A/B test assignment and exposure tracking
+//! Users are assigned to variant A or B by hashing their id together with the
+//! experiment name, so assignments are stable across sessions and independent between
+//! experiments. Rendering the assigned variant records a single exposure event.
+//! To run: cargo run --example ab_test
+
+use std::sync::{Arc, Mutex};
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Variant {
+    A,
+    B,
+}
+
+// FNV-1a, which is stable across runs and platforms unlike `DefaultHasher`
+fn fnv1a(bytes: impl Iterator<Item = u8>) -> u64 {
+    bytes.fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+// Scramble every bit of the hash (the splitmix64 finalizer); FNV alone gives a
+// lopsided split for similar ids such as `user-1`, `user-2`, ...
+fn mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+/// Deterministically assign a user to a variant of an experiment
+pub fn assign(experiment: &str, user_id: &str) -> Variant {
+    let key = format!("{}:{}", experiment, user_id);
+    if mix(fnv1a(key.bytes())) % 2 == 0 {
+        Variant::A
+    } else {
+        Variant::B
+    }
+}
+
+/// A user saw a variant
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExposureEvent {
+    pub experiment: String,
+    pub user_id: String,
+    pub variant: Variant,
+}
+
+/// Where exposure events are sent, shared across components
+pub type ExposureLog = Arc<Mutex<Vec<ExposureEvent>>>;
+
+#[derive(Clone)]
+pub struct PricingBannerProps {
+    pub experiment: String,
+    pub user_id: String,
+    pub exposures: ExposureLog,
+}
+
+/// A banner with two copy variants under test
+pub struct PricingBanner {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: PricingBannerProps,
+    // Only the first render counts as an exposure
+    exposed: Signal<bool>,
+}
+
+impl Component for PricingBanner {
+    type Props = PricingBannerProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            exposed: create_signal(&scope, false),
+            scope,
+            props,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        // A different user or experiment needs a fresh exposure
+        if props.user_id != self.props.user_id || props.experiment != self.props.experiment {
+            self.exposed.set(false).map_err(|e| {
+                ComponentError::UpdateError(format!("Failed to reset exposure: {}", e))
+            })?;
+        }
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let variant = self.variant();
+        self.record_exposure(variant);
+
+        let mut banner = Node::default();
+        banner.add_attribute("class".to_string(), "pricing-banner".to_string());
+        banner.add_attribute("data-variant".to_string(), format!("{:?}", variant));
+        let copy = match variant {
+            Variant::A => "Start your free trial",
+            Variant::B => "Try it free for 30 days - no card needed",
+        };
+        banner.add_child(Node::text(copy));
+
+        Ok(vec![banner])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl PricingBanner {
+    pub fn variant(&self) -> Variant {
+        assign(&self.props.experiment, &self.props.user_id)
+    }
+
+    fn record_exposure(&self, variant: Variant) {
+        if *self.exposed.get() {
+            return;
+        }
+        if let Ok(mut exposures) = self.props.exposures.lock() {
+            exposures.push(ExposureEvent {
+                experiment: self.props.experiment.clone(),
+                user_id: self.props.user_id.clone(),
+                variant,
+            });
+        }
+        self.exposed
+            .set(true)
+            .unwrap_or_else(|e| eprintln!("Failed to mark exposure: {}", e));
+    }
+}
+
+fn banner(user_id: &str, exposures: &ExposureLog) -> PricingBanner {
+    PricingBanner::create(
+        PricingBannerProps {
+            experiment: "pricing-copy".to_string(),
+            user_id: user_id.to_string(),
+            exposures: exposures.clone(),
+        },
+        Context::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_assignment_is_stable() {
+        for user in ["u-1", "u-2", "u-3", "u-4"] {
+            let first = assign("pricing-copy", user);
+            for _ in 0..10 {
+                assert_eq!(assign("pricing-copy", user), first);
+            }
+        }
+    }
+
+    #[test]
+    fn test_split_is_roughly_even() {
+        let total = 10_000;
+        let a = (0..total)
+            .filter(|n| assign("pricing-copy", &format!("user-{}", n)) == Variant::A)
+            .count();
+
+        assert!((4_500..=5_500).contains(&a), "{} of {} in A", a, total);
+    }
+
+    #[test]
+    fn test_exposure_is_recorded_once() {
+        let exposures = ExposureLog::default();
+        let banner = banner("u-1", &exposures);
+
+        banner.render().unwrap();
+        banner.render().unwrap();
+
+        let events = exposures.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].variant, banner.variant());
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("A/B Test Example\n");
+
+    let exposures = ExposureLog::default();
+    for user in ["alice@example.com", "erin@example.com"] {
+        let banner = banner(user, &exposures);
+        for session in 1..=3 {
+            banner.render()?;
+            println!("{} session {}: variant {:?}", user, session, banner.variant());
+        }
+    }
+
+    println!("\nExposure events:");
+    if let Ok(events) = exposures.lock() {
+        for event in events.iter() {
+            println!(
+                "  {} saw {:?} in '{}'",
+                event.user_id, event.variant, event.experiment
+            );
+        }
+    }
+
+    println!("\nA/B Test example completed!");
+    Ok(())
+}
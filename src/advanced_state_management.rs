@@ -1,23 +1,33 @@
 //! Example demonstrating advanced state management patterns in OrbitRS
-//! Shows thread-safe state with derived values, computed properties, and shared state
-//!
-//! NOTE: This example is temporarily using direct thread-safe primitives while the reactive system
-//! is being redesigned. It will be updated to use the new reactive system once it supports
-//! thread-safe operations.
+//! Shows reactive state with derived values, computed properties, and shared state.
+//! `square` and `is_even` are computed values that track `count` on their own, while
+//! the shared total stays an `Arc<Mutex<i32>>` so other components can hold it. The
+//! shared total is loaded from `shared_total.json` on startup and saved again on exit.
 
 use orbit::component::{Component, ComponentError, Context, Node};
-use std::sync::{Arc, Mutex, RwLock};
+use orbit::state::{create_computed, create_signal, ReactiveComputed, ReactiveScope, Signal};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+// File the shared total is persisted to between runs
+const TOTAL_FILE: &str = "shared_total.json";
 
 // A simple counter with advanced state management features
 struct AdvancedCounter {
     #[allow(dead_code)]
     context: Context,
-    // Base counter state using thread-safe RwLock
-    count: Arc<RwLock<i32>>,
+    // Reactive scope owning the counter's signals and computed values
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    // Base counter state as a signal
+    count: Signal<i32>,
     // Derived state for square value
-    square: Arc<RwLock<i32>>,
+    square: ReactiveComputed<i32, Box<dyn FnMut() -> i32>>,
     // Derived state for is_even
-    is_even: Arc<RwLock<bool>>,
+    is_even: ReactiveComputed<bool, Box<dyn FnMut() -> bool>>,
     // Shared state that could be accessed from other components
     shared_total: Arc<Mutex<i32>>,
 }
@@ -37,18 +47,31 @@ impl Component for AdvancedCounter {
     }
 
     fn create(props: Self::Props, context: Context) -> Self {
-        // Initialize base state with thread-safe containers
-        let count = Arc::new(RwLock::new(props.initial));
-
-        // Calculate initial square value
-        let initial_square = props.initial * props.initial;
-        let square = Arc::new(RwLock::new(initial_square));
+        let scope = ReactiveScope::new();
+
+        // Initialize base state with a signal
+        let count = create_signal(&scope, props.initial);
+
+        // Derived values recompute whenever the values they read change
+        let count_clone = count.value.clone();
+        let square = create_computed(
+            &scope,
+            Box::new(move || {
+                let count_value = *count_clone.borrow();
+                count_value * count_value
+            }) as Box<dyn FnMut() -> i32>,
+        );
 
-        // Calculate if even
-        let is_even = Arc::new(RwLock::new(initial_square % 2 == 0));
+        let square_clone = square.value.clone();
+        let is_even = create_computed(
+            &scope,
+            Box::new(move || square_clone.borrow().is_some_and(|square| square % 2 == 0))
+                as Box<dyn FnMut() -> bool>,
+        );
 
         Self {
             context,
+            scope,
             count,
             square,
             is_even,
@@ -64,42 +87,14 @@ impl Component for AdvancedCounter {
         println!("Square value: {}", self.get_square().unwrap());
         println!("Is even: {}", self.is_square_even().unwrap());
 
-        // Print state values directly since Context doesn't have lifecycle hooks in this version
-        if let Ok(count) = self.count.read() {
-            println!("Component initialized, count: {}", *count);
-            if let (Ok(square), Ok(is_even)) = (self.square.read(), self.is_even.read()) {
-                println!("Square: {}, is_even: {}", *square, *is_even);
-            }
-        }
-
         Ok(())
     }
 
     fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
-        if let Ok(mut count) = self.count.write() {
-            *count = props.initial;
-
-            // Update the square value manually
-            let square_value = props.initial * props.initial;
-            if let Ok(mut square) = self.square.write() {
-                *square = square_value;
-            } else {
-                return Err(ComponentError::UpdateError(
-                    "Failed to update square".into(),
-                ));
-            }
-
-            // Update is_even manually
-            if let Ok(mut is_even) = self.is_even.write() {
-                *is_even = square_value % 2 == 0;
-            } else {
-                return Err(ComponentError::UpdateError(
-                    "Failed to update is_even".into(),
-                ));
-            }
-        } else {
-            return Err(ComponentError::UpdateError("Failed to update count".into()));
-        }
+        // Setting the count is enough; square and is_even follow automatically
+        self.count.set(props.initial).map_err(|e| {
+            ComponentError::UpdateError(format!("Failed to update count: {}", e))
+        })?;
 
         Ok(())
     }
@@ -109,17 +104,14 @@ impl Component for AdvancedCounter {
         println!("Rendering AdvancedCounter:");
         println!("  Count: {}", self.get_count().unwrap());
 
-        // Use proper RwLock::read() method instead of non-existent get() method
-        if let Ok(square) = self.square.read() {
-            println!("  Square: {}", *square);
-        } else {
-            println!("  Square: [error reading value]");
+        match self.get_square() {
+            Ok(square) => println!("  Square: {}", square),
+            Err(_) => println!("  Square: [error reading value]"),
         }
 
-        if let Ok(is_even) = self.is_even.read() {
-            println!("  Is even: {}", *is_even);
-        } else {
-            println!("  Is even: [error reading value]");
+        match self.is_square_even() {
+            Ok(is_even) => println!("  Is even: {}", is_even),
+            Err(_) => println!("  Is even: [error reading value]"),
         }
 
         Ok(vec![])
@@ -137,79 +129,56 @@ impl Component for AdvancedCounter {
 impl AdvancedCounter {
     // Increment the counter
     pub fn increment(&mut self) {
-        if let Ok(mut count) = self.count.write() {
-            *count += 1;
-
-            // Update shared state
-            if let Ok(mut total) = self.shared_total.lock() {
-                *total += 1;
-            }
-
-            // Manually update the derived values
-            let new_square = *count * *count;
-
-            // Update square value
-            if let Ok(mut square) = self.square.write() {
-                *square = new_square;
-            }
-
-            // Update is_even value
-            if let Ok(mut is_even) = self.is_even.write() {
-                *is_even = new_square % 2 == 0;
-            }
+        self.count.update(|v| *v += 1).unwrap_or_else(|e| {
+            eprintln!("Failed to increment: {}", e);
+        });
+
+        // Update shared state
+        if let Ok(mut total) = self.shared_total.lock() {
+            *total += 1;
         }
     }
 
     // Decrement the counter
     #[allow(dead_code)]
     pub fn decrement(&mut self) {
-        if let Ok(mut count) = self.count.write() {
-            *count -= 1;
-
-            // Update shared state
-            if let Ok(mut total) = self.shared_total.lock() {
-                *total -= 1;
-            }
-
-            // Manually update the derived values
-            let new_square = *count * *count;
-
-            // Update square value
-            if let Ok(mut square) = self.square.write() {
-                *square = new_square;
-            }
-
-            // Update is_even value
-            if let Ok(mut is_even) = self.is_even.write() {
-                *is_even = new_square % 2 == 0;
-            }
+        self.count.update(|v| *v -= 1).unwrap_or_else(|e| {
+            eprintln!("Failed to decrement: {}", e);
+        });
+
+        // Update shared state
+        if let Ok(mut total) = self.shared_total.lock() {
+            *total -= 1;
         }
     }
 
+    // Set the count directly; square and is_even follow. The shared total is left
+    // alone since it counts increments and decrements, not the current value.
+    pub fn set_count(&mut self, value: i32) {
+        self.count.set(value).unwrap_or_else(|e| {
+            eprintln!("Failed to set count: {}", e);
+        });
+    }
+
     // Get the current count
     pub fn get_count(&self) -> Result<i32, &str> {
-        match self.count.read() {
-            Ok(count) => Ok(*count),
-            Err(_) => Err("Failed to read count"),
-        }
+        Ok(*self.count.get())
     }
 
-    // Get the square value directly from the RwLock
-    #[allow(dead_code)]
+    // Get the square value from the computed
     pub fn get_square(&self) -> Result<i32, &str> {
-        match self.square.read() {
-            Ok(square) => Ok(*square),
-            Err(_) => Err("Failed to read square value"),
-        }
+        self.square
+            .get()
+            .map(|square| *square)
+            .map_err(|_| "Failed to read square value")
     }
 
     // Check if the current square value is even
-    #[allow(dead_code)]
     pub fn is_square_even(&self) -> Result<bool, &str> {
-        match self.is_even.read() {
-            Ok(is_even) => Ok(*is_even),
-            Err(_) => Err("Failed to read is_even value"),
-        }
+        self.is_even
+            .get()
+            .map(|is_even| *is_even)
+            .map_err(|_| "Failed to read is_even value")
     }
 
     // Get the shared total value
@@ -222,15 +191,123 @@ impl AdvancedCounter {
     }
 }
 
+// Maximum number of undo steps kept; the oldest is dropped beyond this
+const HISTORY_LIMIT: usize = 50;
+
+/// Wraps an AdvancedCounter and records each count change so it can be undone
+struct UndoableCounter {
+    counter: AdvancedCounter,
+    // Previous counts, oldest first
+    history: Vec<i32>,
+    // Counts undone since the last change, most recent last
+    redo_stack: Vec<i32>,
+}
+
+impl UndoableCounter {
+    fn new(counter: AdvancedCounter) -> Self {
+        Self {
+            counter,
+            history: Vec::new(),
+            redo_stack: Vec::new(),
+        }
+    }
+
+    // Remember the current count before a new mutation
+    fn record(&mut self) {
+        if self.history.len() == HISTORY_LIMIT {
+            self.history.remove(0);
+        }
+        self.history.push(self.count());
+        // A new change makes the undone values unreachable
+        self.redo_stack.clear();
+    }
+
+    pub fn increment(&mut self) {
+        self.record();
+        self.counter.increment();
+    }
+
+    #[allow(dead_code)]
+    pub fn decrement(&mut self) {
+        self.record();
+        self.counter.decrement();
+    }
+
+    /// Restore the previous count; returns false when there is nothing to undo
+    pub fn undo(&mut self) -> bool {
+        let Some(previous) = self.history.pop() else {
+            return false;
+        };
+        self.redo_stack.push(self.count());
+        self.counter.set_count(previous);
+        true
+    }
+
+    /// Reapply the last undone count; returns false when there is nothing to redo
+    pub fn redo(&mut self) -> bool {
+        let Some(next) = self.redo_stack.pop() else {
+            return false;
+        };
+        self.history.push(self.count());
+        self.counter.set_count(next);
+        true
+    }
+
+    pub fn count(&self) -> i32 {
+        self.counter.get_count().unwrap_or_default()
+    }
+
+    #[allow(dead_code)]
+    pub fn history_len(&self) -> usize {
+        self.history.len()
+    }
+}
+
+// On-disk form of the shared total
+#[derive(Serialize, Deserialize)]
+struct PersistedTotal {
+    total: i32,
+}
+
+// Where the shared total's starting value came from
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TotalSource {
+    Restored,
+    Fresh,
+}
+
+// Load the total, falling back to 0 if the file is missing or malformed
+fn load_shared_total(path: &Path) -> (i32, TotalSource) {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|json| serde_json::from_str::<PersistedTotal>(&json).ok())
+        .map_or((0, TotalSource::Fresh), |persisted| {
+            (persisted.total, TotalSource::Restored)
+        })
+}
+
+fn save_shared_total(path: &Path, total: i32) -> io::Result<()> {
+    let json = serde_json::to_string(&PersistedTotal { total })?;
+    fs::write(path, json)
+}
+
+// Props for the shared state component
+#[derive(Clone)]
+struct SharedStateProps {
+    shared_total: Arc<Mutex<i32>>,
+    source: TotalSource,
+}
+
 /// A component that shares state with the counter
 struct SharedStateComponent {
     #[allow(dead_code)]
     context: Context,
     shared_total: Arc<Mutex<i32>>,
+    source: TotalSource,
 }
 
 impl Component for SharedStateComponent {
-    type Props = Arc<Mutex<i32>>;
+    type Props = SharedStateProps;
 
     fn component_id(&self) -> orbit::component::ComponentId {
         orbit::component::ComponentId::new()
@@ -239,23 +316,29 @@ impl Component for SharedStateComponent {
     fn create(props: Self::Props, context: Context) -> Self {
         Self {
             context,
-            shared_total: props,
+            shared_total: props.shared_total,
+            source: props.source,
         }
     }
 
     fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
         // Update the shared state reference
-        self.shared_total = props;
+        self.shared_total = props.shared_total;
+        self.source = props.source;
         Ok(())
     }
 
     fn render(&self) -> Result<Vec<Node>, ComponentError> {
-        let total = match self.shared_total.lock() {
-            Ok(guard) => *guard,
-            Err(_) => -1, // Error case
+        let origin = match self.source {
+            TotalSource::Restored => "restored from disk",
+            TotalSource::Fresh => "freshly initialized",
         };
 
-        println!("SharedStateComponent - Current shared total: {}", total);
+        println!(
+            "SharedStateComponent - Current shared total: {} ({})",
+            self.total(),
+            origin
+        );
         Ok(vec![])
     }
 
@@ -268,11 +351,134 @@ impl Component for SharedStateComponent {
     }
 }
 
+impl SharedStateComponent {
+    // Current total, or -1 if the lock is poisoned
+    fn total(&self) -> i32 {
+        match self.shared_total.lock() {
+            Ok(guard) => *guard,
+            Err(_) => -1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counter(initial: i32) -> AdvancedCounter {
+        AdvancedCounter::create(
+            CounterProps {
+                initial,
+                shared_total: Arc::new(Mutex::new(0)),
+            },
+            Context::new(),
+        )
+    }
+
+    #[test]
+    fn test_square_tracks_count() {
+        let mut counter = counter(5);
+
+        counter.increment();
+        counter.increment();
+
+        assert_eq!(counter.get_count(), Ok(7));
+        assert_eq!(counter.get_square(), Ok(49));
+        assert_eq!(counter.is_square_even(), Ok(false));
+        assert_eq!(counter.get_shared_total(), Ok(2));
+    }
+
+    #[test]
+    fn test_update_recomputes_derived_values() {
+        let mut counter = counter(3);
+
+        let props = CounterProps {
+            initial: 4,
+            shared_total: Arc::new(Mutex::new(0)),
+        };
+        counter.update(props).unwrap();
+
+        assert_eq!(counter.get_square(), Ok(16));
+        assert_eq!(counter.is_square_even(), Ok(true));
+    }
+
+    #[test]
+    fn test_undo_and_redo_restore_derived_values() {
+        let mut undoable = UndoableCounter::new(counter(5));
+        undoable.increment();
+        undoable.increment();
+
+        assert!(undoable.undo());
+        assert_eq!(undoable.counter.get_square(), Ok(36));
+        assert!(undoable.redo());
+        assert_eq!(undoable.counter.get_square(), Ok(49));
+        assert!(!undoable.redo());
+    }
+
+    #[test]
+    fn test_undo_with_empty_history_does_nothing() {
+        let mut undoable = UndoableCounter::new(counter(5));
+
+        assert!(!undoable.undo());
+        assert!(!undoable.redo());
+        assert_eq!(undoable.count(), 5);
+    }
+
+    #[test]
+    fn test_full_history_drops_oldest_entry() {
+        let mut undoable = UndoableCounter::new(counter(0));
+        for _ in 0..HISTORY_LIMIT + 10 {
+            undoable.increment();
+        }
+        assert_eq!(undoable.history_len(), HISTORY_LIMIT);
+
+        while undoable.undo() {}
+
+        // The first ten steps fell out of the history
+        assert_eq!(undoable.count(), 10);
+    }
+
+    fn temp_file(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("{}_{}.json", name, std::process::id()))
+    }
+
+    #[test]
+    fn test_shared_total_is_restored_from_disk() {
+        let path = temp_file("shared_total_restore");
+        save_shared_total(&path, 42).unwrap();
+
+        let (total, source) = load_shared_total(&path);
+        let component = SharedStateComponent::create(
+            SharedStateProps {
+                shared_total: Arc::new(Mutex::new(total)),
+                source,
+            },
+            Context::new(),
+        );
+
+        assert_eq!(component.total(), 42);
+        assert_eq!(component.source, TotalSource::Restored);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_malformed_file_falls_back_to_zero() {
+        let path = temp_file("shared_total_malformed");
+        fs::write(&path, "{ not json").unwrap();
+
+        assert_eq!(load_shared_total(&path), (0, TotalSource::Fresh));
+        fs::remove_file(&path).unwrap();
+        assert_eq!(load_shared_total(&path), (0, TotalSource::Fresh));
+    }
+}
+
 fn main() {
     println!("Advanced State Management Example\n");
 
-    // Create shared state
-    let shared_total = Arc::new(Mutex::new(0));
+    // Create shared state, continuing from the last run if it was saved
+    let path = Path::new(TOTAL_FILE);
+    let (initial_total, source) = load_shared_total(path);
+    let shared_total = Arc::new(Mutex::new(initial_total));
 
     // Create a context
     let context = Context::new();
@@ -287,7 +493,13 @@ fn main() {
     );
 
     // Create shared state component
-    let shared_component = SharedStateComponent::create(shared_total.clone(), context);
+    let shared_component = SharedStateComponent::create(
+        SharedStateProps {
+            shared_total: shared_total.clone(),
+            source,
+        },
+        context,
+    );
 
     // Initialize component
     counter.initialize().expect("Failed to initialize counter");
@@ -316,5 +528,32 @@ fn main() {
         .render()
         .expect("Failed to render shared component");
 
+    println!("\nUndo/redo with a history of up to {} steps:", HISTORY_LIMIT);
+    let mut undoable = UndoableCounter::new(AdvancedCounter::create(
+        CounterProps {
+            initial: 0,
+            shared_total: shared_total.clone(),
+        },
+        Context::new(),
+    ));
+    for _ in 0..3 {
+        undoable.increment();
+        println!("  increment -> count {}", undoable.count());
+    }
+    for _ in 0..2 {
+        undoable.undo();
+        println!("  undo      -> count {}", undoable.count());
+    }
+    undoable.redo();
+    println!("  redo      -> count {}", undoable.count());
+    undoable.counter.render().expect("Failed to render counter");
+
+    // Persist the total for the next run
+    let total = shared_component.total();
+    match save_shared_total(path, total) {
+        Ok(()) => println!("\nSaved shared total {} to {}", total, TOTAL_FILE),
+        Err(e) => eprintln!("\nFailed to save shared total: {}", e),
+    }
+
     println!("\nAdvanced State Management example completed!");
 }
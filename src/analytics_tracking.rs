@@ -0,0 +1,294 @@
+//! Example demonstrating analytics tracking through a higher-order component
+//! `Tracked` extends a higher-order component such as the `WithAnalytics` HOC from
+//! `advanced_patterns`, shared through a `#[path]` module: it wraps a component the
+//! way the HOC does, and also records an impression event when the component renders
+//! and an interaction event for each user action, each carrying the wrapped
+//! component's id and props. Events are buffered and handed to a pluggable sink in
+//! batches.
+//! To run: cargo run --example analytics_tracking
+
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex};
+
+use orbit::component::{
+    Component, ComponentError, ComponentId, Context, HigherOrderComponent, Node,
+};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+// Shared with the advanced_patterns example; not every item is used here
+#[allow(dead_code)]
+#[path = "advanced_patterns.rs"]
+mod advanced_patterns;
+
+use advanced_patterns::{Button, ButtonProps, WithAnalytics};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EventKind {
+    Impression,
+    Interaction(String),
+}
+
+/// A structured analytics event
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnalyticsEvent {
+    pub kind: EventKind,
+    pub component_id: ComponentId,
+    pub props: Vec<(String, String)>,
+}
+
+/// Where batches of events are delivered
+pub trait AnalyticsSink: Send + Sync {
+    fn send_batch(&self, events: Vec<AnalyticsEvent>);
+}
+
+/// A sink that keeps every batch in memory
+#[derive(Default)]
+pub struct MemorySink {
+    batches: Mutex<Vec<Vec<AnalyticsEvent>>>,
+}
+
+impl MemorySink {
+    pub fn batches(&self) -> Vec<Vec<AnalyticsEvent>> {
+        self.batches.lock().map(|b| b.clone()).unwrap_or_default()
+    }
+
+    pub fn events(&self) -> Vec<AnalyticsEvent> {
+        self.batches().into_iter().flatten().collect()
+    }
+}
+
+impl AnalyticsSink for MemorySink {
+    fn send_batch(&self, events: Vec<AnalyticsEvent>) {
+        if let Ok(mut batches) = self.batches.lock() {
+            batches.push(events);
+        }
+    }
+}
+
+/// Props a component reports with its events
+pub trait TrackedProps {
+    fn describe(&self) -> Vec<(String, String)>;
+}
+
+#[derive(Clone)]
+pub struct AnalyticsProps<P> {
+    pub inner: P,
+    pub sink: Arc<dyn AnalyticsSink>,
+    // Events buffered before the sink is called
+    pub batch_size: usize,
+}
+
+/// Wraps a component with the HOC `H` and reports its impressions and interactions
+pub struct Tracked<H, C>
+where
+    H: HigherOrderComponent<C>,
+    C: Component,
+{
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    inner: C,
+    props: AnalyticsProps<H::HOCProps>,
+    buffer: Signal<Vec<AnalyticsEvent>>,
+    hoc: PhantomData<H>,
+}
+
+/// A button tracked through the `WithAnalytics` HOC
+pub type TrackedButton = Tracked<WithAnalytics, Button>;
+
+impl<H, C> Component for Tracked<H, C>
+where
+    H: HigherOrderComponent<C, WrappedProps = C::Props> + 'static,
+    H::HOCProps: TrackedProps + Clone,
+    C: Component + 'static,
+{
+    type Props = AnalyticsProps<H::HOCProps>;
+
+    // Events are attributed to the wrapped component
+    fn component_id(&self) -> ComponentId {
+        self.inner.component_id()
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let mut inner = C::create(H::transform_props(&props.inner), context);
+        H::enhance_component(&mut inner, &props.inner)
+            .unwrap_or_else(|e| eprintln!("Failed to enhance component: {}", e));
+
+        Self {
+            inner,
+            buffer: create_signal(&scope, Vec::new()),
+            scope,
+            props,
+            hoc: PhantomData,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.inner.update(H::transform_props(&props.inner))?;
+        H::enhance_component(&mut self.inner, &props.inner)?;
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let nodes = self.inner.render()?;
+        self.record(EventKind::Impression);
+        Ok(nodes)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl<H, C> Tracked<H, C>
+where
+    H: HigherOrderComponent<C>,
+    H::HOCProps: TrackedProps,
+    C: Component,
+{
+    /// Run a user action on the wrapped component and record it
+    pub fn interact(&self, action: &str, handler: impl FnOnce(&C)) {
+        handler(&self.inner);
+        self.record(EventKind::Interaction(action.to_string()));
+    }
+
+    fn record(&self, kind: EventKind) {
+        let event = AnalyticsEvent {
+            kind,
+            component_id: self.inner.component_id(),
+            props: self.props.inner.describe(),
+        };
+        self.buffer
+            .update(|buffer| buffer.push(event))
+            .unwrap_or_else(|e| eprintln!("Failed to buffer event: {}", e));
+
+        let buffered = self.buffer.get().len();
+        if buffered >= self.props.batch_size.max(1) {
+            self.flush();
+        }
+    }
+
+    /// Send whatever is buffered to the sink
+    pub fn flush(&self) {
+        let events = self.buffer.get().clone();
+        if events.is_empty() {
+            return;
+        }
+        self.buffer
+            .set(Vec::new())
+            .unwrap_or_else(|e| eprintln!("Failed to clear buffer: {}", e));
+        self.props.sink.send_batch(events);
+    }
+
+    pub fn buffered(&self) -> usize {
+        self.buffer.get().len()
+    }
+}
+
+impl<H, C> Drop for Tracked<H, C>
+where
+    H: HigherOrderComponent<C>,
+    C: Component,
+{
+    fn drop(&mut self) {
+        // Don't lose the last partial batch
+        let events = self.buffer.get().clone();
+        if !events.is_empty() {
+            self.props.sink.send_batch(events);
+        }
+    }
+}
+
+impl TrackedProps for ButtonProps {
+    fn describe(&self) -> Vec<(String, String)> {
+        vec![
+            ("text".to_string(), self.text.clone()),
+            ("disabled".to_string(), self.disabled.to_string()),
+        ]
+    }
+}
+
+/// What a click does to the button itself; the tracker records the event
+fn click(_button: &Button) {}
+
+fn tracked_button(text: &str, sink: Arc<dyn AnalyticsSink>) -> TrackedButton {
+    Tracked::create(
+        AnalyticsProps {
+            inner: ButtonProps {
+                text: text.to_string(),
+                disabled: false,
+            },
+            sink,
+            batch_size: 3,
+        },
+        Context::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_click_reports_interaction_with_component_id() {
+        let sink = Arc::new(MemorySink::default());
+        let button = tracked_button("Buy", sink.clone());
+
+        button.interact("click", click);
+        button.flush();
+
+        let events = sink.events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, EventKind::Interaction("click".to_string()));
+        assert_eq!(events[0].component_id, button.component_id());
+        assert_eq!(events[0].props[0], ("text".to_string(), "Buy".to_string()));
+    }
+
+    #[test]
+    fn test_events_are_sent_in_batches() {
+        let sink = Arc::new(MemorySink::default());
+        let button = tracked_button("Buy", sink.clone());
+
+        button.render().unwrap();
+        button.interact("click", click);
+        assert!(sink.batches().is_empty());
+
+        button.interact("click", click);
+        assert_eq!(sink.batches().len(), 1);
+        assert_eq!(sink.batches()[0].len(), 3);
+        assert_eq!(button.buffered(), 0);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Analytics Tracking Example\n");
+
+    let sink = Arc::new(MemorySink::default());
+    {
+        let button = tracked_button("Add to cart", sink.clone());
+        button.render()?;
+        for _ in 0..3 {
+            button.interact("click", click);
+        }
+        println!(
+            "Clicked 3 times; {} event(s) still buffered",
+            button.buffered()
+        );
+        // Dropping the button flushes the partial batch
+    }
+
+    for (index, batch) in sink.batches().iter().enumerate() {
+        println!("\nBatch {}:", index + 1);
+        for event in batch {
+            println!("  {:?} from {:?} with {:?}", event.kind, event.component_id, event.props);
+        }
+    }
+
+    println!("\nAnalytics Tracking example completed!");
+    Ok(())
+}
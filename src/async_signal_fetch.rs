@@ -0,0 +1,188 @@
+//! Example demonstrating loading data on a background thread into a signal
+//! Signals aren't `Send`, so the worker thread never touches one. It sends its result
+//! over a channel instead, and the component moves whatever has arrived into its
+//! `Signal<Option<String>>` when polled. Until then it renders "loading...".
+//! To run: cargo run --example async_signal_fetch
+
+use std::sync::mpsc::{self, Receiver};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+/// Simulate a slow request on another thread
+pub fn fetch(latency: Duration) -> (JoinHandle<()>, Receiver<String>) {
+    let (sender, receiver) = mpsc::channel();
+    let worker = thread::spawn(move || {
+        thread::sleep(latency);
+        // The view may have been dropped while we were waiting
+        let _ = sender.send("loaded data".to_string());
+    });
+    (worker, receiver)
+}
+
+#[derive(Clone)]
+pub struct DataViewProps {
+    pub latency: Duration,
+}
+
+/// Shows "loading..." until the fetched data arrives
+pub struct DataView {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: DataViewProps,
+    data: Signal<Option<String>>,
+    pending: Option<Receiver<String>>,
+}
+
+impl Component for DataView {
+    type Props = DataViewProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            data: create_signal(&scope, None),
+            scope,
+            props,
+            pending: None,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut view = Node::default();
+        match self.data.get().as_ref() {
+            Some(data) => view.add_child(Node::text(data)),
+            None => {
+                view.add_attribute("aria-busy".to_string(), "true".to_string());
+                view.add_child(Node::text("loading..."));
+            }
+        }
+        Ok(vec![view])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl DataView {
+    /// Start fetching; the handle lets callers wait for the worker
+    pub fn load(&mut self) -> JoinHandle<()> {
+        self.data
+            .set(None)
+            .unwrap_or_else(|e| eprintln!("Failed to reset data: {}", e));
+        let (worker, receiver) = fetch(self.props.latency);
+        self.pending = Some(receiver);
+        worker
+    }
+
+    /// Move a result that has arrived into the signal; returns true once loaded
+    pub fn poll(&mut self) -> bool {
+        let Some(receiver) = &self.pending else {
+            return self.data.get().is_some();
+        };
+        match receiver.try_recv() {
+            Ok(data) => {
+                self.data
+                    .set(Some(data))
+                    .unwrap_or_else(|e| eprintln!("Failed to set data: {}", e));
+                self.pending = None;
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    pub fn data(&self) -> Option<String> {
+        self.data.get().clone()
+    }
+}
+
+fn data_view() -> DataView {
+    DataView::create(
+        DataViewProps {
+            latency: Duration::from_millis(50),
+        },
+        Context::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signal_holds_data_after_worker_finishes() {
+        let mut view = data_view();
+        let worker = view.load();
+        assert_eq!(view.data(), None);
+
+        worker.join().unwrap();
+        assert!(view.poll());
+
+        assert_eq!(view.data().as_deref(), Some("loaded data"));
+    }
+
+    #[test]
+    fn test_poll_before_arrival_keeps_loading() {
+        let mut view = DataView::create(
+            DataViewProps {
+                latency: Duration::from_secs(1),
+            },
+            Context::new(),
+        );
+        let _worker = view.load();
+
+        assert!(!view.poll());
+        assert_eq!(view.data(), None);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Async Signal Fetch Example\n");
+
+    let mut view = data_view();
+    let worker = view.load();
+
+    let mut frame = 0;
+    loop {
+        let loaded = view.poll();
+        view.render()?;
+        match view.data() {
+            Some(data) => println!("frame {:>2}: {}", frame, data),
+            None => println!("frame {:>2}: loading...", frame),
+        }
+        if loaded {
+            break;
+        }
+        frame += 1;
+        thread::sleep(Duration::from_millis(16));
+    }
+
+    worker
+        .join()
+        .map_err(|_| ComponentError::UpdateError("Fetch thread panicked".to_string()))?;
+
+    println!("\nAsync Signal Fetch example completed!");
+    Ok(())
+}
@@ -0,0 +1,200 @@
+//! Example demonstrating a reactive audio visualizer
+//! Frequency-bin magnitudes arrive in a `Signal<Vec<f32>>`. Each bar eases toward its
+//! bin's latest value instead of jumping, which keeps the animation smooth when the
+//! data is noisy.
+//! To run: cargo run --example audio_visualizer
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+#[derive(Clone)]
+pub struct VisualizerProps {
+    pub bar_width: f32,
+    pub max_height: f32,
+    // Fraction of the distance to the new value covered each frame, in (0, 1]
+    pub smoothing: f32,
+}
+
+/// Bars whose heights follow frequency-bin data
+pub struct AudioVisualizer {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: VisualizerProps,
+    // Latest magnitudes, each in 0.0..=1.0
+    bins: Signal<Vec<f32>>,
+    // Smoothed magnitudes actually drawn
+    levels: Signal<Vec<f32>>,
+}
+
+impl Component for AudioVisualizer {
+    type Props = VisualizerProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let bins = create_signal(&scope, Vec::new());
+        let levels = create_signal(&scope, Vec::new());
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            props,
+            bins,
+            levels,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut visualizer = Node::default();
+        visualizer.add_attribute("class".to_string(), "visualizer".to_string());
+        visualizer.add_attribute("aria-hidden".to_string(), "true".to_string());
+
+        for (index, height) in self.bar_heights().iter().enumerate() {
+            let mut bar = Node::default();
+            bar.add_attribute(
+                "x".to_string(),
+                (index as f32 * self.props.bar_width).to_string(),
+            );
+            // Bars grow up from the baseline
+            bar.add_attribute("y".to_string(), (self.props.max_height - height).to_string());
+            bar.add_attribute("width".to_string(), self.props.bar_width.to_string());
+            bar.add_attribute("height".to_string(), height.to_string());
+            visualizer.add_child(bar);
+        }
+
+        Ok(vec![visualizer])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl AudioVisualizer {
+    /// Feed a new frame of bin magnitudes and ease the bars toward it
+    pub fn push_frame(&self, bins: Vec<f32>) {
+        let smoothing = self.props.smoothing.clamp(f32::EPSILON, 1.0);
+        let targets: Vec<f32> = bins.iter().map(|value| value.clamp(0.0, 1.0)).collect();
+
+        self.levels
+            .update(|levels| {
+                // A change in bin count restarts the bars from zero
+                if levels.len() != targets.len() {
+                    *levels = vec![0.0; targets.len()];
+                }
+                for (level, target) in levels.iter_mut().zip(&targets) {
+                    *level += (target - *level) * smoothing;
+                }
+            })
+            .unwrap_or_else(|e| eprintln!("Failed to smooth levels: {}", e));
+        self.bins
+            .set(targets)
+            .unwrap_or_else(|e| eprintln!("Failed to update bins: {}", e));
+    }
+
+    /// Pixel height of each bar
+    pub fn bar_heights(&self) -> Vec<f32> {
+        self.levels
+            .get()
+            .iter()
+            .map(|level| level * self.props.max_height)
+            .collect()
+    }
+
+    pub fn bins(&self) -> Vec<f32> {
+        self.bins.get().clone()
+    }
+}
+
+fn visualizer(smoothing: f32) -> AudioVisualizer {
+    AudioVisualizer::create(
+        VisualizerProps {
+            bar_width: 8.0,
+            max_height: 100.0,
+            smoothing,
+        },
+        Context::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_smoothed_heights_fall_between_frames() {
+        let visualizer = visualizer(0.5);
+        visualizer.push_frame(vec![0.2, 0.8, 0.5]);
+        let previous = visualizer.bar_heights();
+
+        visualizer.push_frame(vec![1.0, 0.0, 0.5]);
+        let current: Vec<f32> = visualizer.bins().iter().map(|b| b * 100.0).collect();
+
+        let heights = visualizer.bar_heights();
+        for ((height, before), after) in heights.iter().zip(&previous).zip(&current) {
+            let (low, high) = (before.min(*after), before.max(*after));
+            assert!(*height >= low && *height <= high);
+        }
+        // Halfway from 10 to 100
+        assert!((heights[0] - 55.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_no_smoothing_jumps_to_value() {
+        let visualizer = visualizer(1.0);
+
+        visualizer.push_frame(vec![0.25, 1.5]);
+
+        assert_eq!(visualizer.bar_heights(), vec![25.0, 100.0]);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Audio Visualizer Example\n");
+
+    let visualizer = visualizer(0.4);
+
+    // A kick drum hits on frame 2, then decays
+    let frames = [
+        vec![0.1, 0.1, 0.1, 0.1],
+        vec![0.9, 0.6, 0.2, 0.1],
+        vec![0.5, 0.4, 0.2, 0.1],
+        vec![0.2, 0.2, 0.3, 0.4],
+        vec![0.1, 0.1, 0.1, 0.1],
+    ];
+
+    for (index, frame) in frames.into_iter().enumerate() {
+        visualizer.push_frame(frame);
+        visualizer.render()?;
+        let bars: Vec<String> = visualizer
+            .bar_heights()
+            .iter()
+            .map(|height| format!("{:>5.1}", height))
+            .collect();
+        println!(
+            "Frame {}: bins {:?} -> bars [{}]",
+            index + 1,
+            visualizer.bins(),
+            bars.join(" ")
+        );
+    }
+
+    println!("\nAudio Visualizer example completed!");
+    Ok(())
+}
@@ -0,0 +1,336 @@
+//! Example demonstrating an autocomplete input with keyboard selection
+//! Typing filters suggestions into a dropdown, ArrowUp/ArrowDown move the highlight, and
+//! Enter commits the highlighted suggestion to a value signal. Escape closes the dropdown.
+//! To run: cargo run --example autocomplete
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_computed, create_signal, ReactiveComputed, ReactiveScope, Signal};
+use winit::event::VirtualKeyCode;
+
+#[derive(Clone)]
+pub struct AutocompleteProps {
+    pub suggestions: Vec<String>,
+    pub max_results: usize,
+}
+
+/// A text input with a keyboard-navigable suggestions dropdown
+pub struct Autocomplete {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    suggestions: Signal<Vec<String>>,
+    max_results: Signal<usize>,
+    query: Signal<String>,
+    results: ReactiveComputed<Vec<String>, Box<dyn FnMut() -> Vec<String>>>,
+    // Index into the results; None until the user arrows into the list
+    highlighted: Signal<Option<usize>>,
+    open: Signal<bool>,
+    // The value the user has committed with Enter
+    value: Signal<String>,
+}
+
+impl Component for Autocomplete {
+    type Props = AutocompleteProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let suggestions = create_signal(&scope, props.suggestions);
+        let max_results = create_signal(&scope, props.max_results);
+        let query = create_signal(&scope, String::new());
+        let highlighted = create_signal(&scope, None);
+        let open = create_signal(&scope, false);
+        let value = create_signal(&scope, String::new());
+
+        let query_for_results = query.value.clone();
+        let suggestions_for_results = suggestions.value.clone();
+        let max_results_for_results = max_results.value.clone();
+        let results = create_computed(&scope, Box::new(move || {
+            let query = query_for_results.borrow().to_lowercase();
+            if query.is_empty() {
+                return Vec::new();
+            }
+            suggestions_for_results
+                .borrow()
+                .iter()
+                .filter(|suggestion| suggestion.to_lowercase().contains(&query))
+                .take(*max_results_for_results.borrow())
+                .cloned()
+                .collect()
+        }) as Box<dyn FnMut() -> Vec<String>>);
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            suggestions,
+            max_results,
+            query,
+            results,
+            highlighted,
+            open,
+            value,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.suggestions.set(props.suggestions).map_err(|e| {
+            ComponentError::UpdateError(format!("Failed to update suggestions: {}", e))
+        })?;
+        self.max_results.set(props.max_results).map_err(|e| {
+            ComponentError::UpdateError(format!("Failed to update max results: {}", e))
+        })?;
+
+        // The old highlight may point past the new results
+        self.set_highlighted(None);
+        if self.results().is_empty() {
+            self.set_open(false);
+        }
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut input = Node::default();
+        input.add_attribute("role".to_string(), "combobox".to_string());
+        input.add_attribute("value".to_string(), self.query.get().clone());
+        input.add_attribute("aria-expanded".to_string(), self.is_open().to_string());
+
+        let mut nodes = vec![input];
+        if self.is_open() {
+            let mut listbox = Node::default();
+            listbox.add_attribute("role".to_string(), "listbox".to_string());
+            for (index, result) in self.results().iter().enumerate() {
+                let mut option = Node::default();
+                option.add_attribute("role".to_string(), "option".to_string());
+                option.add_attribute(
+                    "aria-selected".to_string(),
+                    (self.highlighted() == Some(index)).to_string(),
+                );
+                option.add_child(Node::text(result));
+                listbox.add_child(option);
+            }
+            nodes.push(listbox);
+        }
+
+        Ok(nodes)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl Autocomplete {
+    /// Replace the input text, reopening the dropdown with a fresh highlight
+    pub fn type_text(&self, text: &str) {
+        self.query
+            .set(text.to_string())
+            .unwrap_or_else(|e| eprintln!("Failed to update query: {}", e));
+        self.set_highlighted(None);
+        self.set_open(!self.results().is_empty());
+    }
+
+    /// Handle a key press; returns true if the key was consumed
+    pub fn handle_key(&self, key: VirtualKeyCode) -> bool {
+        let count = self.results().len();
+        if !self.is_open() || count == 0 {
+            return false;
+        }
+
+        match key {
+            VirtualKeyCode::Down => {
+                let next = self.highlighted().map_or(0, |index| (index + 1) % count);
+                self.set_highlighted(Some(next));
+                true
+            }
+            VirtualKeyCode::Up => {
+                let prev = self
+                    .highlighted()
+                    .map_or(count - 1, |index| (index + count - 1) % count);
+                self.set_highlighted(Some(prev));
+                true
+            }
+            VirtualKeyCode::Return | VirtualKeyCode::NumpadEnter => {
+                // Enter without a highlighted suggestion leaves everything as it is
+                match self.highlighted() {
+                    Some(index) => {
+                        self.commit(self.results()[index].clone());
+                        true
+                    }
+                    None => false,
+                }
+            }
+            VirtualKeyCode::Escape => {
+                self.set_open(false);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn commit(&self, selected: String) {
+        self.query
+            .set(selected.clone())
+            .unwrap_or_else(|e| eprintln!("Failed to update query: {}", e));
+        self.value
+            .set(selected)
+            .unwrap_or_else(|e| eprintln!("Failed to commit value: {}", e));
+        self.set_highlighted(None);
+        self.set_open(false);
+    }
+
+    fn set_highlighted(&self, index: Option<usize>) {
+        self.highlighted
+            .set(index)
+            .unwrap_or_else(|e| eprintln!("Failed to update highlight: {}", e));
+    }
+
+    fn set_open(&self, open: bool) {
+        self.open
+            .set(open)
+            .unwrap_or_else(|e| eprintln!("Failed to toggle dropdown: {}", e));
+    }
+
+    pub fn results(&self) -> Vec<String> {
+        self.results
+            .get()
+            .map(|results| results.clone())
+            .unwrap_or_default()
+    }
+
+    pub fn highlighted(&self) -> Option<usize> {
+        *self.highlighted.get()
+    }
+
+    pub fn is_open(&self) -> bool {
+        *self.open.get()
+    }
+
+    pub fn value(&self) -> String {
+        self.value.get().clone()
+    }
+}
+
+fn city_picker() -> Autocomplete {
+    let suggestions = [
+        "Amsterdam",
+        "Berlin",
+        "Bern",
+        "Bergen",
+        "Boston",
+        "Copenhagen",
+    ]
+    .iter()
+    .map(|city| city.to_string())
+    .collect();
+
+    Autocomplete::create(
+        AutocompleteProps {
+            suggestions,
+            max_results: 5,
+        },
+        Context::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arrow_to_second_result_and_commit() {
+        let picker = city_picker();
+
+        picker.type_text("ber");
+        assert_eq!(picker.results(), vec!["Berlin", "Bern", "Bergen"]);
+
+        picker.handle_key(VirtualKeyCode::Down);
+        picker.handle_key(VirtualKeyCode::Down);
+        assert!(picker.handle_key(VirtualKeyCode::Return));
+
+        assert_eq!(picker.value(), "Bern");
+        assert!(!picker.is_open());
+    }
+
+    #[test]
+    fn test_enter_without_highlight_does_nothing() {
+        let picker = city_picker();
+
+        picker.type_text("ber");
+        assert!(!picker.handle_key(VirtualKeyCode::Return));
+
+        assert_eq!(picker.value(), "");
+        assert!(picker.is_open());
+    }
+
+    #[test]
+    fn test_arrow_up_wraps_to_last_result() {
+        let picker = city_picker();
+
+        picker.type_text("ber");
+        picker.handle_key(VirtualKeyCode::Up);
+
+        assert_eq!(picker.highlighted(), Some(2));
+    }
+
+    #[test]
+    fn test_update_refilters_results() {
+        let mut picker = city_picker();
+        picker.type_text("ber");
+
+        picker
+            .update(AutocompleteProps {
+                suggestions: vec!["Bernal Heights".to_string(), "Berkeley".to_string()],
+                max_results: 1,
+            })
+            .unwrap();
+
+        assert_eq!(picker.results(), vec!["Bernal Heights"]);
+        assert_eq!(picker.highlighted(), None);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Autocomplete Example\n");
+
+    let picker = city_picker();
+
+    for text in ["b", "bo", "be"] {
+        picker.type_text(text);
+        println!("Typed '{}': {:?}", text, picker.results());
+    }
+    picker.render()?;
+
+    println!("\nPressing Enter with nothing highlighted...");
+    picker.handle_key(VirtualKeyCode::Return);
+    println!("  committed value: '{}'", picker.value());
+
+    for key in [VirtualKeyCode::Down, VirtualKeyCode::Down, VirtualKeyCode::Up] {
+        picker.handle_key(key);
+        let results = picker.results();
+        let highlighted = picker.highlighted().map(|index| results[index].as_str());
+        println!("{:?} -> highlighted {:?}", key, highlighted);
+    }
+
+    println!("\nPressing Enter...");
+    picker.handle_key(VirtualKeyCode::Return);
+    picker.render()?;
+    println!("  committed value: '{}'", picker.value());
+    println!("  dropdown open: {}", picker.is_open());
+
+    picker.type_text("co");
+    picker.handle_key(VirtualKeyCode::Escape);
+    println!("\nEscape closes the dropdown: open = {}", picker.is_open());
+
+    println!("\nAutocomplete example completed!");
+    Ok(())
+}
@@ -0,0 +1,259 @@
+//! Example demonstrating a day calendar with event collision layout
+//! Events that overlap in time are split into side-by-side columns so they never
+//! cover each other. Events are grouped into clusters of transitively overlapping
+//! events, and every event in a cluster shares the cluster's column count.
+//! To run: cargo run --example calendar_scheduler
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Event {
+    pub title: String,
+    // Minutes since midnight; `end` is exclusive
+    pub start: u32,
+    pub end: u32,
+}
+
+/// Where an event is drawn in the day column
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventLayout {
+    pub title: String,
+    pub column: usize,
+    pub columns: usize,
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+/// Assign each event a column, returning (column, column count) in input order
+pub fn assign_columns(events: &[Event]) -> Vec<(usize, usize)> {
+    let mut order: Vec<usize> = (0..events.len()).collect();
+    order.sort_by_key(|&i| (events[i].start, events[i].end));
+
+    let mut result = vec![(0, 1); events.len()];
+    let mut cluster: Vec<usize> = Vec::new();
+    // End time of the last event placed in each column of the current cluster
+    let mut column_ends: Vec<u32> = Vec::new();
+    let mut cluster_end = 0;
+
+    for i in order {
+        let event = &events[i];
+
+        // Nothing in the cluster overlaps this event, so start a new cluster
+        if !cluster.is_empty() && event.start >= cluster_end {
+            close_cluster(&mut result, &mut cluster, column_ends.len());
+            column_ends.clear();
+        }
+
+        let column = match column_ends.iter().position(|&end| end <= event.start) {
+            Some(column) => column,
+            None => {
+                column_ends.push(0);
+                column_ends.len() - 1
+            }
+        };
+        column_ends[column] = event.end;
+        cluster_end = if cluster.is_empty() {
+            event.end
+        } else {
+            cluster_end.max(event.end)
+        };
+        cluster.push(i);
+        result[i].0 = column;
+    }
+    close_cluster(&mut result, &mut cluster, column_ends.len());
+
+    result
+}
+
+// Every event in a finished cluster shares the cluster's column count
+fn close_cluster(result: &mut [(usize, usize)], cluster: &mut Vec<usize>, columns: usize) {
+    for &i in cluster.iter() {
+        result[i].1 = columns;
+    }
+    cluster.clear();
+}
+
+#[derive(Clone)]
+pub struct DayViewProps {
+    pub width: f32,
+    pub hour_height: f32,
+}
+
+/// A single day's schedule
+pub struct DayView {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: DayViewProps,
+    events: Signal<Vec<Event>>,
+}
+
+impl Component for DayView {
+    type Props = DayViewProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let events = create_signal(&scope, Vec::new());
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            props,
+            events,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut day = Node::default();
+        day.add_attribute("class".to_string(), "day-view".to_string());
+
+        for layout in self.layout() {
+            let mut node = Node::default();
+            node.add_attribute("x".to_string(), layout.x.to_string());
+            node.add_attribute("y".to_string(), layout.y.to_string());
+            node.add_attribute("width".to_string(), layout.width.to_string());
+            node.add_attribute("height".to_string(), layout.height.to_string());
+            node.add_child(Node::text(&layout.title));
+            day.add_child(node);
+        }
+
+        Ok(vec![day])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl DayView {
+    pub fn add_event(&self, title: &str, start: u32, end: u32) {
+        let event = Event {
+            title: title.to_string(),
+            start,
+            end,
+        };
+        self.events
+            .update(|events| events.push(event))
+            .unwrap_or_else(|e| eprintln!("Failed to add event: {}", e));
+    }
+
+    /// Positioned boxes for every event, in insertion order
+    pub fn layout(&self) -> Vec<EventLayout> {
+        let events = self.events.get();
+        let minute_height = self.props.hour_height / 60.0;
+
+        events
+            .iter()
+            .zip(assign_columns(&events))
+            .map(|(event, (column, columns))| {
+                let width = self.props.width / columns as f32;
+                EventLayout {
+                    title: event.title.clone(),
+                    column,
+                    columns,
+                    x: column as f32 * width,
+                    y: event.start as f32 * minute_height,
+                    width,
+                    height: (event.end - event.start) as f32 * minute_height,
+                }
+            })
+            .collect()
+    }
+}
+
+fn day_view() -> DayView {
+    DayView::create(
+        DayViewProps {
+            width: 300.0,
+            hour_height: 60.0,
+        },
+        Context::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overlapping_pair_splits_and_separate_event_spans() {
+        let view = day_view();
+        view.add_event("Standup", 9 * 60, 10 * 60);
+        view.add_event("Review", 9 * 60 + 30, 11 * 60);
+        view.add_event("Lunch", 12 * 60, 13 * 60);
+
+        let layout = view.layout();
+
+        assert_eq!((layout[0].column, layout[0].columns), (0, 2));
+        assert_eq!((layout[1].column, layout[1].columns), (1, 2));
+        assert_eq!(layout[1].x, 150.0);
+        assert_eq!(layout[1].width, 150.0);
+
+        assert_eq!(layout[2].columns, 1);
+        assert_eq!(layout[2].width, 300.0);
+    }
+
+    #[test]
+    fn test_back_to_back_events_do_not_collide() {
+        let events = vec![
+            Event {
+                title: "A".to_string(),
+                start: 60,
+                end: 120,
+            },
+            Event {
+                title: "B".to_string(),
+                start: 120,
+                end: 180,
+            },
+        ];
+
+        assert_eq!(assign_columns(&events), vec![(0, 1), (0, 1)]);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Calendar Scheduler Example\n");
+
+    let view = day_view();
+    view.add_event("Planning", 9 * 60, 11 * 60);
+    view.add_event("Design sync", 9 * 60 + 30, 10 * 60 + 30);
+    view.add_event("1:1", 10 * 60, 10 * 60 + 45);
+    view.add_event("Lunch", 12 * 60, 13 * 60);
+    view.render()?;
+
+    for layout in view.layout() {
+        println!(
+            "  {:<12} column {}/{}  x={:<5} width={:<5} y={} height={}",
+            layout.title,
+            layout.column + 1,
+            layout.columns,
+            layout.x,
+            layout.width,
+            layout.y,
+            layout.height
+        );
+    }
+
+    println!("\nCalendar Scheduler example completed!");
+    Ok(())
+}
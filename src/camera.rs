@@ -0,0 +1,218 @@
+//! Camera shared by the `wgpu_*` examples
+//! Each example is its own binary and pulls this in with `mod camera;`. The camera is
+//! first-person: a position plus yaw and pitch, turned into a view-projection matrix
+//! for the shaders. `CameraController` moves it from held keys, and `OrbitController`
+//! circles it around the origin from mouse drags and the scroll wheel.
+
+use std::collections::HashSet;
+use std::f32::consts::FRAC_PI_2;
+
+use cgmath::{Deg, Matrix4, Point3, Vector3};
+use winit::event::{ElementState, VirtualKeyCode};
+
+/// cgmath builds OpenGL clip space, where depth runs -1..1; wgpu wants 0..1
+#[rustfmt::skip]
+pub const OPENGL_TO_WGPU_MATRIX: Matrix4<f32> = Matrix4::new(
+    1.0, 0.0, 0.0, 0.0,
+    0.0, 1.0, 0.0, 0.0,
+    0.0, 0.0, 0.5, 0.0,
+    0.0, 0.0, 0.5, 1.0,
+);
+
+/// Pitch stops just short of straight up or down, where yaw stops meaning anything
+const MAX_PITCH: f32 = FRAC_PI_2 - 0.01;
+
+/// Closest and furthest an orbiting camera gets to the origin
+const MIN_DISTANCE: f32 = 1.5;
+const MAX_DISTANCE: f32 = 50.0;
+
+/// Width over height for a viewport, or `None` when either side is zero, as it is
+/// for a minimized window
+pub fn aspect_ratio(width: u32, height: u32) -> Option<f32> {
+    if width == 0 || height == 0 {
+        return None;
+    }
+    Some(width as f32 / height as f32)
+}
+
+/// A first-person camera
+#[derive(Debug, Clone, PartialEq)]
+pub struct Camera {
+    pub position: [f32; 3],
+    // Radians; zero yaw looks down -Z
+    pub yaw: f32,
+    pub pitch: f32,
+    // Width over height of the viewport
+    pub aspect: f32,
+    // Vertical field of view, in degrees
+    pub fovy: f32,
+}
+
+impl Camera {
+    pub fn new(position: [f32; 3], aspect: f32) -> Self {
+        Self {
+            position,
+            yaw: 0.0,
+            pitch: 0.0,
+            aspect,
+            fovy: 45.0,
+        }
+    }
+
+    pub fn forward(&self) -> [f32; 3] {
+        [
+            self.pitch.cos() * self.yaw.sin(),
+            self.pitch.sin(),
+            -self.pitch.cos() * self.yaw.cos(),
+        ]
+    }
+
+    /// Right on the ground plane, so strafing never changes height
+    pub fn right(&self) -> [f32; 3] {
+        [self.yaw.cos(), 0.0, self.yaw.sin()]
+    }
+
+    /// World space to wgpu clip space
+    pub fn view_proj(&self) -> Matrix4<f32> {
+        let view = Matrix4::look_to_rh(
+            Point3::from(self.position),
+            Vector3::from(self.forward()),
+            Vector3::unit_y(),
+        );
+        let proj = cgmath::perspective(Deg(self.fovy), self.aspect, 0.1, 100.0);
+        OPENGL_TO_WGPU_MATRIX * proj * view
+    }
+}
+
+/// Turns held keys into camera movement
+pub struct CameraController {
+    // Units per second
+    speed: f32,
+    // Radians per second
+    turn_speed: f32,
+    pressed: HashSet<VirtualKeyCode>,
+}
+
+impl CameraController {
+    pub fn new(speed: f32, turn_speed: f32) -> Self {
+        Self {
+            speed,
+            turn_speed,
+            pressed: HashSet::new(),
+        }
+    }
+
+    /// Track a key press or release; returns true if the key controls the camera
+    pub fn process_keyboard(&mut self, key: VirtualKeyCode, state: ElementState) -> bool {
+        match key {
+            VirtualKeyCode::W
+            | VirtualKeyCode::A
+            | VirtualKeyCode::S
+            | VirtualKeyCode::D
+            | VirtualKeyCode::Up
+            | VirtualKeyCode::Down
+            | VirtualKeyCode::Left
+            | VirtualKeyCode::Right => {
+                match state {
+                    ElementState::Pressed => self.pressed.insert(key),
+                    ElementState::Released => self.pressed.remove(&key),
+                };
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Forget every held key, e.g. when the window loses focus and releases go unseen
+    pub fn clear(&mut self) {
+        self.pressed.clear();
+    }
+
+    /// Move and turn the camera for a frame lasting `dt` seconds
+    pub fn update_camera(&self, camera: &mut Camera, dt: f32) {
+        // +1, -1 or 0 depending on which of a pair of keys is held
+        let axis = |positive: VirtualKeyCode, negative: VirtualKeyCode| {
+            let held = |key: VirtualKeyCode| if self.pressed.contains(&key) { 1.0 } else { 0.0 };
+            held(positive) - held(negative)
+        };
+
+        let step = self.speed * dt;
+        let (forward, right) = (camera.forward(), camera.right());
+        let ahead = axis(VirtualKeyCode::W, VirtualKeyCode::S) * step;
+        let strafe = axis(VirtualKeyCode::D, VirtualKeyCode::A) * step;
+        for ((position, forward), right) in camera.position.iter_mut().zip(forward).zip(right) {
+            *position += forward * ahead + right * strafe;
+        }
+
+        let turn = self.turn_speed * dt;
+        camera.yaw += axis(VirtualKeyCode::Right, VirtualKeyCode::Left) * turn;
+        camera.pitch = (camera.pitch + axis(VirtualKeyCode::Up, VirtualKeyCode::Down) * turn)
+            .clamp(-MAX_PITCH, MAX_PITCH);
+    }
+}
+
+/// The point `radius` from the origin in direction `yaw`, `pitch`; zero for both is
+/// on +Z, positive yaw swings towards +X and positive pitch rises towards +Y
+pub fn spherical_to_cartesian(yaw: f32, pitch: f32, radius: f32) -> [f32; 3] {
+    [
+        radius * pitch.cos() * yaw.sin(),
+        radius * pitch.sin(),
+        radius * pitch.cos() * yaw.cos(),
+    ]
+}
+
+/// Circles the camera around the origin: left-drag rotates and scrolling zooms
+pub struct OrbitController {
+    // Spherical coordinates of the camera, see `spherical_to_cartesian`
+    pub yaw: f32,
+    pub pitch: f32,
+    pub distance: f32,
+    // Radians per pixel dragged
+    sensitivity: f32,
+    dragging: bool,
+    last_cursor: Option<(f64, f64)>,
+}
+
+impl OrbitController {
+    pub fn new(distance: f32, sensitivity: f32) -> Self {
+        Self {
+            yaw: 0.0,
+            pitch: 0.0,
+            distance: distance.clamp(MIN_DISTANCE, MAX_DISTANCE),
+            sensitivity,
+            dragging: false,
+            last_cursor: None,
+        }
+    }
+
+    pub fn set_dragging(&mut self, dragging: bool) {
+        self.dragging = dragging;
+    }
+
+    /// Track the cursor, rotating by how far it moved while dragging
+    pub fn process_cursor(&mut self, x: f64, y: f64) {
+        let last = self.last_cursor.replace((x, y));
+        if let (true, Some((last_x, last_y))) = (self.dragging, last) {
+            self.rotate((x - last_x) as f32, (y - last_y) as f32);
+        }
+    }
+
+    /// Rotate for a drag of `dx`, `dy` pixels; the scene turns with the cursor
+    pub fn rotate(&mut self, dx: f32, dy: f32) {
+        self.yaw -= dx * self.sensitivity;
+        self.pitch = (self.pitch + dy * self.sensitivity).clamp(-MAX_PITCH, MAX_PITCH);
+    }
+
+    /// Zoom by `lines` scroll-wheel lines; scrolling up moves closer
+    pub fn process_scroll(&mut self, lines: f32) {
+        self.distance = (self.distance * 0.9_f32.powf(lines)).clamp(MIN_DISTANCE, MAX_DISTANCE);
+    }
+
+    /// Place the camera on its orbit, facing the origin
+    pub fn update_camera(&self, camera: &mut Camera) {
+        camera.position = spherical_to_cartesian(self.yaw, self.pitch, self.distance);
+        // Looking back along the orbit direction
+        camera.yaw = -self.yaw;
+        camera.pitch = -self.pitch;
+    }
+}
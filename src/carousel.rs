@@ -0,0 +1,232 @@
+//! Example demonstrating a carousel/slider with snap points
+//! Slides can be changed with next/prev or by dragging. On release the track snaps to
+//! the nearest slide: dragging more than half a slide advances, less snaps back.
+//! Looping wraps around at both ends when enabled.
+//! To run: cargo run --example carousel
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+#[derive(Clone)]
+pub struct CarouselProps {
+    pub slides: Vec<String>,
+    pub slide_width: f32,
+    pub looping: bool,
+}
+
+/// A horizontally swipeable carousel
+pub struct Carousel {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: CarouselProps,
+    current: Signal<usize>,
+    // Horizontal drag distance in pixels; negative drags toward the next slide
+    drag_offset: f32,
+}
+
+impl Component for Carousel {
+    type Props = CarouselProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let current = create_signal(&scope, 0);
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            props,
+            current,
+            drag_offset: 0.0,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        // Keep the current index in range if slides were removed
+        let last = props.slides.len().saturating_sub(1);
+        if self.current() > last {
+            self.go_to(last);
+        }
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut track = Node::default();
+        track.add_attribute("class".to_string(), "carousel-track".to_string());
+        track.add_attribute("translate-x".to_string(), self.track_offset().to_string());
+
+        for (index, slide) in self.props.slides.iter().enumerate() {
+            let mut node = Node::default();
+            node.add_attribute("class".to_string(), "slide".to_string());
+            node.add_attribute(
+                "aria-hidden".to_string(),
+                (index != self.current()).to_string(),
+            );
+            node.add_child(Node::text(slide));
+            track.add_child(node);
+        }
+
+        Ok(vec![track])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl Carousel {
+    pub fn current(&self) -> usize {
+        *self.current.get()
+    }
+
+    fn go_to(&self, index: usize) {
+        self.current.set(index).unwrap_or_else(|e| {
+            eprintln!("Failed to change slide: {}", e);
+        });
+    }
+
+    // Resolve a relative move, wrapping or clamping at the ends
+    fn step(&self, delta: isize) {
+        let count = self.props.slides.len() as isize;
+        if count == 0 {
+            return;
+        }
+
+        let target = self.current() as isize + delta;
+        let index = if self.props.looping {
+            target.rem_euclid(count)
+        } else {
+            target.clamp(0, count - 1)
+        };
+        self.go_to(index as usize);
+    }
+
+    pub fn next(&self) {
+        self.step(1);
+    }
+
+    pub fn prev(&self) {
+        self.step(-1);
+    }
+
+    /// Move the track with the pointer
+    pub fn drag(&mut self, dx: f32) {
+        self.drag_offset += dx;
+    }
+
+    /// Release the drag and snap to the nearest slide
+    pub fn release(&mut self) {
+        let progress = -self.drag_offset / self.props.slide_width;
+        self.drag_offset = 0.0;
+
+        // Round to the nearest whole slide relative to the current one
+        let slides = progress.round() as isize;
+        if slides != 0 {
+            self.step(slides);
+        }
+    }
+
+    /// Current x translation of the track
+    pub fn track_offset(&self) -> f32 {
+        -(self.current() as f32) * self.props.slide_width + self.drag_offset
+    }
+}
+
+fn gallery(looping: bool) -> Carousel {
+    Carousel::create(
+        CarouselProps {
+            slides: vec![
+                "Mountains".to_string(),
+                "Ocean".to_string(),
+                "Desert".to_string(),
+            ],
+            slide_width: 300.0,
+            looping,
+        },
+        Context::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drag_past_half_snaps_forward() {
+        let mut carousel = gallery(false);
+
+        carousel.drag(-180.0); // 60% of a slide toward the next one
+        carousel.release();
+
+        assert_eq!(carousel.current(), 1);
+        assert_eq!(carousel.track_offset(), -300.0);
+    }
+
+    #[test]
+    fn test_drag_under_half_snaps_back() {
+        let mut carousel = gallery(false);
+
+        carousel.drag(-120.0); // 40%
+        carousel.release();
+
+        assert_eq!(carousel.current(), 0);
+        assert_eq!(carousel.track_offset(), 0.0);
+    }
+
+    #[test]
+    fn test_looping_wraps_and_clamping_stops() {
+        let looping = gallery(true);
+        looping.prev();
+        assert_eq!(looping.current(), 2);
+
+        let clamped = gallery(false);
+        clamped.prev();
+        assert_eq!(clamped.current(), 0);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Carousel Example\n");
+
+    let mut carousel = gallery(true);
+    carousel.render()?;
+    println!("Showing slide {}", carousel.current());
+
+    carousel.next();
+    println!("next() -> slide {}", carousel.current());
+
+    println!("\nDragging 70% toward the next slide...");
+    for _ in 0..7 {
+        carousel.drag(-30.0);
+        carousel.render()?;
+    }
+    println!("  track offset while dragging: {}", carousel.track_offset());
+    carousel.release();
+    println!("  snapped to slide {}", carousel.current());
+
+    println!("\nDragging 30% toward the next slide...");
+    carousel.drag(-90.0);
+    carousel.release();
+    println!("  snapped back to slide {}", carousel.current());
+
+    carousel.next();
+    println!("\nnext() at the end loops to slide {}", carousel.current());
+
+    carousel.prev();
+    println!("prev() -> slide {}", carousel.current());
+
+    println!("\nCarousel example completed!");
+    Ok(())
+}
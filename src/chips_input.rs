@@ -0,0 +1,241 @@
+//! Example demonstrating a multi-select chips input
+//! Selected items render as removable chips next to a text input. Typing filters the
+//! suggestions, Enter adds the best match as a chip, and Backspace in an empty input
+//! removes the last chip.
+//! To run: cargo run --example chips_input
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+use winit::event::VirtualKeyCode;
+
+#[derive(Clone)]
+pub struct ChipsInputProps {
+    pub suggestions: Vec<String>,
+}
+
+/// A text input that collects selected items as chips
+pub struct ChipsInput {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: ChipsInputProps,
+    chips: Signal<Vec<String>>,
+    input: Signal<String>,
+}
+
+impl Component for ChipsInput {
+    type Props = ChipsInputProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let chips = create_signal(&scope, Vec::new());
+        let input = create_signal(&scope, String::new());
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            props,
+            chips,
+            input,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut container = Node::default();
+        container.add_attribute("class".to_string(), "chips-input".to_string());
+
+        for chip in self.chips.get().iter() {
+            let mut node = Node::default();
+            node.add_attribute("class".to_string(), "chip".to_string());
+            node.add_child(Node::text(chip));
+
+            let mut remove = Node::default();
+            remove.add_attribute("role".to_string(), "button".to_string());
+            remove.add_attribute("aria-label".to_string(), format!("Remove {}", chip));
+            node.add_child(remove);
+
+            container.add_child(node);
+        }
+
+        let mut input = Node::default();
+        input.add_attribute("role".to_string(), "combobox".to_string());
+        input.add_attribute("value".to_string(), self.input.get().clone());
+        container.add_child(input);
+
+        let mut listbox = Node::default();
+        listbox.add_attribute("role".to_string(), "listbox".to_string());
+        for suggestion in self.suggestions() {
+            listbox.add_child(Node::text(&suggestion));
+        }
+
+        Ok(vec![container, listbox])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl ChipsInput {
+    pub fn type_text(&self, text: &str) {
+        self.input
+            .set(text.to_string())
+            .unwrap_or_else(|e| eprintln!("Failed to update input: {}", e));
+    }
+
+    /// Suggestions matching the input that haven't been selected yet
+    pub fn suggestions(&self) -> Vec<String> {
+        let query = self.input.get().to_lowercase();
+        let chips = self.chips.get();
+        self.props
+            .suggestions
+            .iter()
+            .filter(|suggestion| !chips.contains(suggestion))
+            .filter(|suggestion| suggestion.to_lowercase().contains(&query))
+            .cloned()
+            .collect()
+    }
+
+    /// Handle a key press; returns true if the key was consumed
+    pub fn handle_key(&self, key: VirtualKeyCode) -> bool {
+        match key {
+            VirtualKeyCode::Return | VirtualKeyCode::NumpadEnter => {
+                if self.input.get().is_empty() {
+                    return false;
+                }
+                // Only suggestions can be added; Enter on an unknown value is ignored
+                match self.suggestions().into_iter().next() {
+                    Some(chip) => {
+                        self.add_chip(chip);
+                        self.type_text("");
+                        true
+                    }
+                    None => false,
+                }
+            }
+            // Backspace only removes chips once the input text is gone
+            VirtualKeyCode::Back if self.input.get().is_empty() => {
+                self.chips
+                    .update(|chips| {
+                        chips.pop();
+                    })
+                    .unwrap_or_else(|e| eprintln!("Failed to remove chip: {}", e));
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn add_chip(&self, chip: String) {
+        self.chips
+            .update(|chips| chips.push(chip))
+            .unwrap_or_else(|e| eprintln!("Failed to add chip: {}", e));
+    }
+
+    /// Remove a chip via its remove button
+    pub fn remove_chip(&self, chip: &str) {
+        self.chips
+            .update(|chips| chips.retain(|c| c != chip))
+            .unwrap_or_else(|e| eprintln!("Failed to remove chip: {}", e));
+    }
+
+    pub fn chips(&self) -> Vec<String> {
+        self.chips.get().clone()
+    }
+}
+
+fn tag_picker() -> ChipsInput {
+    let suggestions = ["rust", "ruby", "python", "go", "typescript"]
+        .iter()
+        .map(|tag| tag.to_string())
+        .collect();
+
+    ChipsInput::create(ChipsInputProps { suggestions }, Context::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enter_adds_first_matching_suggestion() {
+        let picker = tag_picker();
+
+        picker.type_text("ru");
+        assert!(picker.handle_key(VirtualKeyCode::Return));
+
+        assert_eq!(picker.chips(), vec!["rust"]);
+        // Selected chips no longer show up as suggestions
+        picker.type_text("ru");
+        assert_eq!(picker.suggestions(), vec!["ruby"]);
+    }
+
+    #[test]
+    fn test_backspace_on_empty_input_removes_last_chip() {
+        let picker = tag_picker();
+        for text in ["rust", "go"] {
+            picker.type_text(text);
+            picker.handle_key(VirtualKeyCode::Return);
+        }
+
+        assert!(picker.handle_key(VirtualKeyCode::Back));
+
+        assert_eq!(picker.chips(), vec!["rust"]);
+    }
+
+    #[test]
+    fn test_backspace_with_text_keeps_chips() {
+        let picker = tag_picker();
+        picker.type_text("go");
+        picker.handle_key(VirtualKeyCode::Return);
+
+        picker.type_text("py");
+        assert!(!picker.handle_key(VirtualKeyCode::Back));
+
+        assert_eq!(picker.chips(), vec!["go"]);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Chips Input Example\n");
+
+    let picker = tag_picker();
+
+    for text in ["py", "type"] {
+        picker.type_text(text);
+        println!("Typed '{}', suggestions: {:?}", text, picker.suggestions());
+        picker.handle_key(VirtualKeyCode::Return);
+        println!("  Enter -> chips {:?}", picker.chips());
+    }
+    picker.render()?;
+
+    println!("\nBackspace in the empty input...");
+    picker.handle_key(VirtualKeyCode::Back);
+    println!("  chips {:?}", picker.chips());
+
+    picker.type_text("go");
+    picker.handle_key(VirtualKeyCode::Return);
+    println!("\nAdded 'go' -> chips {:?}", picker.chips());
+    picker.remove_chip("python");
+    picker.render()?;
+    println!("Clicked remove on 'python' -> chips {:?}", picker.chips());
+
+    println!("\nChips Input example completed!");
+    Ok(())
+}
@@ -0,0 +1,303 @@
+//! Example demonstrating imperative handles to child components (a ref API)
+//! A parent creates an empty `Ref<C>` and passes it to the child through props. When the
+//! child mounts it fills the ref with a handle exposing imperative methods such as
+//! `focus()` and `scroll_to()`, and clears it again on unmount.
+//! To run: cargo run --example component_refs
+
+use std::sync::{Arc, Mutex};
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+
+/// Components that expose an imperative handle through a `Ref`
+pub trait ImperativeHandle: Component {
+    type Handle: Clone + Send + Sync;
+}
+
+/// A slot the child fills with its handle while it is mounted
+pub struct Ref<C: ImperativeHandle> {
+    handle: Arc<Mutex<Option<C::Handle>>>,
+}
+
+impl<C: ImperativeHandle> Clone for Ref<C> {
+    fn clone(&self) -> Self {
+        Self {
+            handle: self.handle.clone(),
+        }
+    }
+}
+
+impl<C: ImperativeHandle> Default for Ref<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: ImperativeHandle> Ref<C> {
+    pub fn new() -> Self {
+        Self {
+            handle: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// The current handle, or `None` if the child isn't mounted
+    pub fn current(&self) -> Option<C::Handle> {
+        match self.handle.lock() {
+            Ok(handle) => handle.clone(),
+            Err(_) => None,
+        }
+    }
+
+    fn set(&self, handle: Option<C::Handle>) {
+        if let Ok(mut current) = self.handle.lock() {
+            *current = handle;
+        }
+    }
+}
+
+// Internal state of the input that the handle can reach
+#[derive(Debug, Default)]
+struct InputState {
+    focused: bool,
+    scroll_offset: f32,
+}
+
+/// Imperative API exposed by `TextField`
+#[derive(Clone)]
+pub struct TextFieldHandle {
+    state: Arc<Mutex<InputState>>,
+}
+
+impl TextFieldHandle {
+    pub fn focus(&self) {
+        if let Ok(mut state) = self.state.lock() {
+            state.focused = true;
+        }
+    }
+
+    pub fn scroll_to(&self, offset: f32) {
+        if let Ok(mut state) = self.state.lock() {
+            state.scroll_offset = offset.max(0.0);
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct TextFieldProps {
+    pub placeholder: String,
+    pub field_ref: Option<Ref<TextField>>,
+}
+
+/// A text field that can be focused imperatively by its parent
+pub struct TextField {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    props: TextFieldProps,
+    state: Arc<Mutex<InputState>>,
+}
+
+impl ImperativeHandle for TextField {
+    type Handle = TextFieldHandle;
+}
+
+impl Component for TextField {
+    type Props = TextFieldProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            id: ComponentId::new(),
+            context,
+            props,
+            state: Arc::new(Mutex::new(InputState::default())),
+        }
+    }
+
+    fn mount(&mut self) -> Result<(), ComponentError> {
+        // Expose the handle to whoever holds the ref
+        if let Some(field_ref) = &self.props.field_ref {
+            field_ref.set(Some(TextFieldHandle {
+                state: self.state.clone(),
+            }));
+        }
+        Ok(())
+    }
+
+    fn unmount(&mut self) -> Result<(), ComponentError> {
+        if let Some(field_ref) = &self.props.field_ref {
+            field_ref.set(None);
+        }
+        Ok(())
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let state = self
+            .state
+            .lock()
+            .map_err(|_| ComponentError::RenderError("Failed to read input state".into()))?;
+
+        let mut node = Node::default();
+        node.add_attribute("tag".to_string(), "input".to_string());
+        node.add_attribute("placeholder".to_string(), self.props.placeholder.clone());
+        node.add_attribute("data-focused".to_string(), state.focused.to_string());
+        node.add_attribute("data-scroll".to_string(), state.scroll_offset.to_string());
+        Ok(vec![node])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl TextField {
+    pub fn is_focused(&self) -> bool {
+        match self.state.lock() {
+            Ok(state) => state.focused,
+            Err(_) => false,
+        }
+    }
+}
+
+/// A search bar that focuses its input when opened
+pub struct SearchBar {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    input_ref: Ref<TextField>,
+    input: TextField,
+}
+
+impl Component for SearchBar {
+    type Props = ();
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(_props: Self::Props, context: Context) -> Self {
+        let input_ref = Ref::new();
+        let input = TextField::create(
+            TextFieldProps {
+                placeholder: "Search...".to_string(),
+                field_ref: Some(input_ref.clone()),
+            },
+            context.clone(),
+        );
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            input_ref,
+            input,
+        }
+    }
+
+    fn mount(&mut self) -> Result<(), ComponentError> {
+        self.input.mount()
+    }
+
+    fn unmount(&mut self) -> Result<(), ComponentError> {
+        self.input.unmount()
+    }
+
+    fn update(&mut self, _props: Self::Props) -> Result<(), ComponentError> {
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut bar = Node::default();
+        bar.add_attribute("class".to_string(), "search-bar".to_string());
+        for node in self.input.render()? {
+            bar.add_child(node);
+        }
+        Ok(vec![bar])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl SearchBar {
+    /// Open the search bar and move keyboard focus into the input
+    pub fn open(&self) {
+        match self.input_ref.current() {
+            Some(input) => input.focus(),
+            None => println!("Search input isn't mounted yet"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ref_focuses_child() {
+        let mut bar = SearchBar::create((), Context::new());
+        bar.mount().unwrap();
+
+        bar.open();
+
+        assert!(bar.input.is_focused());
+    }
+
+    #[test]
+    fn test_ref_is_empty_before_mount_and_after_unmount() {
+        let field_ref: Ref<TextField> = Ref::new();
+        let mut field = TextField::create(
+            TextFieldProps {
+                placeholder: String::new(),
+                field_ref: Some(field_ref.clone()),
+            },
+            Context::new(),
+        );
+
+        assert!(field_ref.current().is_none());
+        field.mount().unwrap();
+        assert!(field_ref.current().is_some());
+        field.unmount().unwrap();
+        assert!(field_ref.current().is_none());
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Component Refs Example\n");
+
+    let mut search = SearchBar::create((), Context::new());
+
+    // Before mount the ref is empty
+    search.open();
+
+    search.mount()?;
+    println!("Input focused before open: {}", search.input.is_focused());
+    search.open();
+    println!("Input focused after open: {}", search.input.is_focused());
+
+    if let Some(input) = search.input_ref.current() {
+        input.scroll_to(120.0);
+    }
+    search.render()?;
+
+    search.unmount()?;
+    println!("Ref cleared after unmount: {}", search.input_ref.current().is_none());
+
+    println!("\nComponent Refs example completed!");
+    Ok(())
+}
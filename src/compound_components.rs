@@ -0,0 +1,452 @@
+//! Example demonstrating compound components that share implicit state
+//! `Tabs`, `TabList`, `Tab` and `TabPanel` are composed declaratively. None of the
+//! children receive the selected index as a prop: `Tabs` provides a shared
+//! `TabsContext` and attaches each child to it, which is how compound-component APIs
+//! keep their internal wiring out of the consumer's way.
+//! To run: cargo run --example compound_components
+
+use std::sync::{Arc, RwLock};
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+
+/// Selection state shared between the parts of a `Tabs` compound component.
+/// `Tabs` hands this handle to its children when it attaches them.
+#[derive(Clone, Default)]
+pub struct TabsContext {
+    selected: Arc<RwLock<usize>>,
+}
+
+impl TabsContext {
+    pub fn selected(&self) -> usize {
+        match self.selected.read() {
+            Ok(selected) => *selected,
+            Err(_) => 0,
+        }
+    }
+
+    pub fn select(&self, index: usize) {
+        if let Ok(mut selected) = self.selected.write() {
+            *selected = index;
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct TabProps {
+    pub label: String,
+}
+
+/// A single tab button; its index is assigned by position in the `TabList`
+pub struct Tab {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    props: TabProps,
+    index: usize,
+    tabs: TabsContext,
+}
+
+impl Component for Tab {
+    type Props = TabProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            id: ComponentId::new(),
+            context,
+            props,
+            index: 0,
+            tabs: TabsContext::default(),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut node = Node::default();
+        node.add_attribute("role".to_string(), "tab".to_string());
+        node.add_attribute("aria-selected".to_string(), self.is_selected().to_string());
+        node.add_child(Node::text(&self.props.label));
+        Ok(vec![node])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl Tab {
+    fn attach(&mut self, tabs: TabsContext, index: usize) {
+        self.tabs = tabs;
+        self.index = index;
+    }
+
+    /// Handle a click by selecting this tab in the shared context
+    pub fn click(&self) {
+        self.tabs.select(self.index);
+    }
+
+    pub fn is_selected(&self) -> bool {
+        self.tabs.selected() == self.index
+    }
+}
+
+#[derive(Clone)]
+pub struct TabListProps {
+    pub tabs: Vec<TabProps>,
+}
+
+/// Container for the tab buttons
+pub struct TabList {
+    id: ComponentId,
+    context: Context,
+    tabs: Vec<Tab>,
+    // The handle from `Tabs`, kept so tabs rebuilt by `update` stay attached
+    state: TabsContext,
+}
+
+impl Component for TabList {
+    type Props = TabListProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let tabs = props
+            .tabs
+            .into_iter()
+            .map(|tab| Tab::create(tab, context.clone()))
+            .collect();
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            tabs,
+            state: TabsContext::default(),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.tabs = props
+            .tabs
+            .into_iter()
+            .map(|tab| Tab::create(tab, self.context.clone()))
+            .collect();
+        self.attach(self.state.clone());
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut list = Node::default();
+        list.add_attribute("role".to_string(), "tablist".to_string());
+        for tab in &self.tabs {
+            for node in tab.render()? {
+                list.add_child(node);
+            }
+        }
+        Ok(vec![list])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl TabList {
+    fn attach(&mut self, state: TabsContext) {
+        for (index, tab) in self.tabs.iter_mut().enumerate() {
+            tab.attach(state.clone(), index);
+        }
+        self.state = state;
+    }
+
+    pub fn tabs(&self) -> &[Tab] {
+        &self.tabs
+    }
+}
+
+#[derive(Clone)]
+pub struct TabPanelProps {
+    pub content: String,
+}
+
+/// Content shown when the tab at the same position is selected
+pub struct TabPanel {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    props: TabPanelProps,
+    index: usize,
+    tabs: TabsContext,
+}
+
+impl Component for TabPanel {
+    type Props = TabPanelProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            id: ComponentId::new(),
+            context,
+            props,
+            index: 0,
+            tabs: TabsContext::default(),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        // Inactive panels render nothing
+        if !self.is_active() {
+            return Ok(vec![]);
+        }
+
+        let mut panel = Node::default();
+        panel.add_attribute("role".to_string(), "tabpanel".to_string());
+        panel.add_child(Node::text(&self.props.content));
+        Ok(vec![panel])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl TabPanel {
+    fn attach(&mut self, tabs: TabsContext, index: usize) {
+        self.tabs = tabs;
+        self.index = index;
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.tabs.selected() == self.index
+    }
+}
+
+/// Declarative description of a child of `Tabs`
+#[derive(Clone)]
+pub enum TabsChild {
+    List(TabListProps),
+    Panel(TabPanelProps),
+}
+
+#[derive(Clone)]
+pub struct TabsProps {
+    pub default_index: usize,
+    pub children: Vec<TabsChild>,
+}
+
+/// Root of the compound component; owns and provides the shared selection state
+pub struct Tabs {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    state: TabsContext,
+    list: Option<TabList>,
+    panels: Vec<TabPanel>,
+}
+
+impl Component for Tabs {
+    type Props = TabsProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let state = TabsContext::default();
+        state.select(props.default_index);
+
+        let mut tabs = Self {
+            id: ComponentId::new(),
+            context,
+            state,
+            list: None,
+            panels: Vec::new(),
+        };
+        tabs.build_children(props.children);
+        tabs
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.build_children(props.children);
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut root = Node::default();
+        root.add_attribute("class".to_string(), "tabs".to_string());
+
+        if let Some(list) = &self.list {
+            for node in list.render()? {
+                root.add_child(node);
+            }
+        }
+        for panel in &self.panels {
+            for node in panel.render()? {
+                root.add_child(node);
+            }
+        }
+
+        Ok(vec![root])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl Tabs {
+    // Create the child components and attach them to the shared context
+    fn build_children(&mut self, children: Vec<TabsChild>) {
+        self.list = None;
+        self.panels.clear();
+
+        for child in children {
+            match child {
+                TabsChild::List(props) => {
+                    let mut list = TabList::create(props, self.context.clone());
+                    list.attach(self.state.clone());
+                    self.list = Some(list);
+                }
+                TabsChild::Panel(props) => {
+                    let mut panel = TabPanel::create(props, self.context.clone());
+                    panel.attach(self.state.clone(), self.panels.len());
+                    self.panels.push(panel);
+                }
+            }
+        }
+    }
+
+    pub fn tab(&self, index: usize) -> Option<&Tab> {
+        self.list.as_ref().and_then(|list| list.tabs().get(index))
+    }
+
+    pub fn panel(&self, index: usize) -> Option<&TabPanel> {
+        self.panels.get(index)
+    }
+}
+
+fn tab(label: &str) -> TabProps {
+    TabProps {
+        label: label.to_string(),
+    }
+}
+
+fn panel(content: &str) -> TabsChild {
+    TabsChild::Panel(TabPanelProps {
+        content: content.to_string(),
+    })
+}
+
+fn settings_tabs() -> TabsProps {
+    TabsProps {
+        default_index: 0,
+        children: vec![
+            TabsChild::List(TabListProps {
+                tabs: vec![tab("Profile"), tab("Security"), tab("Billing")],
+            }),
+            panel("Edit your name and avatar"),
+            panel("Change your password"),
+            panel("Manage payment methods"),
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clicking_tab_activates_matching_panel() {
+        let tabs = Tabs::create(settings_tabs(), Context::new());
+        assert!(tabs.panel(0).unwrap().is_active());
+
+        tabs.tab(2).unwrap().click();
+
+        assert!(tabs.tab(2).unwrap().is_selected());
+        assert!(tabs.panel(2).unwrap().is_active());
+        assert!(!tabs.panel(0).unwrap().is_active());
+    }
+
+    #[test]
+    fn test_only_active_panel_renders() {
+        let tabs = Tabs::create(settings_tabs(), Context::new());
+        tabs.tab(1).unwrap().click();
+
+        let nodes = tabs.render().unwrap();
+
+        // Tab list plus a single active panel
+        assert_eq!(nodes[0].children().len(), 2);
+    }
+
+    #[test]
+    fn test_updated_tab_list_stays_in_sync() {
+        let mut tabs = Tabs::create(settings_tabs(), Context::new());
+
+        tabs.list
+            .as_mut()
+            .unwrap()
+            .update(TabListProps {
+                tabs: vec![tab("Profile"), tab("Security")],
+            })
+            .unwrap();
+        tabs.tab(1).unwrap().click();
+
+        assert!(tabs.tab(1).unwrap().is_selected());
+        assert!(tabs.panel(1).unwrap().is_active());
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Compound Components Example\n");
+
+    let tabs = Tabs::create(settings_tabs(), Context::new());
+    tabs.render()?;
+    println!("Selected tab: {}", tabs.state.selected());
+
+    println!("\nClicking the 'Security' tab...");
+    if let Some(security) = tabs.tab(1) {
+        security.click();
+    }
+    tabs.render()?;
+
+    for index in 0..3 {
+        if let Some(panel) = tabs.panel(index) {
+            println!("  Panel {} active: {}", index, panel.is_active());
+        }
+    }
+
+    println!("\nCompound Components example completed!");
+    Ok(())
+}
@@ -0,0 +1,222 @@
+//! Example demonstrating a field that is only required based on another field
+//! A feedback form asks for a category, and the "other reason" field becomes required
+//! only when the category is `Other`. The requirement is a computed value over the
+//! category signal, so the field's label, validation and submit state all follow it.
+//! To run: cargo run --example conditional_required
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_computed, create_signal, ReactiveComputed, ReactiveScope, Signal};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Category {
+    Bug,
+    Feature,
+    Other,
+}
+
+#[derive(Clone)]
+pub struct FeedbackFormProps {
+    pub categories: Vec<Category>,
+}
+
+/// A feedback form with a conditionally required field
+pub struct FeedbackForm {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: FeedbackFormProps,
+    category: Signal<Option<Category>>,
+    other_reason: Signal<String>,
+    reason_required: ReactiveComputed<bool, Box<dyn FnMut() -> bool>>,
+}
+
+impl Component for FeedbackForm {
+    type Props = FeedbackFormProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let category = create_signal(&scope, None);
+        let other_reason = create_signal(&scope, String::new());
+
+        let category_clone = category.value.clone();
+        let reason_required = create_computed(
+            &scope,
+            Box::new(move || *category_clone.borrow() == Some(Category::Other))
+                as Box<dyn FnMut() -> bool>,
+        );
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            props,
+            category,
+            other_reason,
+            reason_required,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut form = Node::default();
+        form.add_attribute("role".to_string(), "form".to_string());
+
+        let selected = self.category();
+        let mut group = Node::default();
+        group.add_attribute("role".to_string(), "radiogroup".to_string());
+        group.add_attribute("aria-required".to_string(), "true".to_string());
+        for category in &self.props.categories {
+            let mut option = Node::default();
+            option.add_attribute("role".to_string(), "radio".to_string());
+            option.add_attribute(
+                "aria-checked".to_string(),
+                (selected == Some(*category)).to_string(),
+            );
+            option.add_child(Node::text(&format!("{:?}", category)));
+            group.add_child(option);
+        }
+        form.add_child(group);
+
+        let required = self.reason_required();
+        let mut reason = Node::default();
+        reason.add_attribute("id".to_string(), "other-reason".to_string());
+        reason.add_attribute("aria-required".to_string(), required.to_string());
+        let label = if required {
+            "Other reason (required)"
+        } else {
+            "Other reason (optional)"
+        };
+        reason.add_attribute("aria-label".to_string(), label.to_string());
+        if let Some(error) = self.error() {
+            reason.add_attribute("aria-invalid".to_string(), "true".to_string());
+            reason.add_attribute("aria-errormessage".to_string(), error);
+        }
+        form.add_child(reason);
+
+        let mut submit = Node::default();
+        submit.add_attribute("type".to_string(), "submit".to_string());
+        submit.add_attribute("disabled".to_string(), (!self.can_submit()).to_string());
+        form.add_child(submit);
+
+        Ok(vec![form])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl FeedbackForm {
+    pub fn select_category(&self, category: Category) {
+        self.category
+            .set(Some(category))
+            .unwrap_or_else(|e| eprintln!("Failed to select category: {}", e));
+    }
+
+    pub fn set_other_reason(&self, text: &str) {
+        self.other_reason
+            .set(text.to_string())
+            .unwrap_or_else(|e| eprintln!("Failed to set reason: {}", e));
+    }
+
+    pub fn category(&self) -> Option<Category> {
+        *self.category.get()
+    }
+
+    pub fn reason_required(&self) -> bool {
+        self.reason_required.get().map(|v| *v).unwrap_or(false)
+    }
+
+    /// Error for the reason field, if it is required and still blank
+    pub fn error(&self) -> Option<String> {
+        let blank = self.other_reason.get().trim().is_empty();
+        (self.reason_required() && blank).then(|| "Please describe your reason".to_string())
+    }
+
+    pub fn can_submit(&self) -> bool {
+        self.category().is_some() && self.error().is_none()
+    }
+}
+
+fn feedback_form() -> FeedbackForm {
+    FeedbackForm::create(
+        FeedbackFormProps {
+            categories: vec![Category::Bug, Category::Feature, Category::Other],
+        },
+        Context::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_other_makes_reason_required() {
+        let form = feedback_form();
+        assert!(!form.reason_required());
+
+        form.select_category(Category::Other);
+        assert!(form.reason_required());
+        assert!(form.error().is_some());
+        assert!(!form.can_submit());
+
+        form.select_category(Category::Bug);
+        assert!(!form.reason_required());
+        assert!(form.error().is_none());
+        assert!(form.can_submit());
+    }
+
+    #[test]
+    fn test_filled_reason_satisfies_requirement() {
+        let form = feedback_form();
+        form.select_category(Category::Other);
+
+        form.set_other_reason("Billing question");
+
+        assert!(form.can_submit());
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Conditional Required Example\n");
+
+    let form = feedback_form();
+    for category in [Category::Feature, Category::Other, Category::Bug, Category::Other] {
+        form.select_category(category);
+        form.render()?;
+        println!(
+            "Category {:?}: reason required = {}, can submit = {}",
+            category,
+            form.reason_required(),
+            form.can_submit()
+        );
+    }
+
+    println!("\nFilling in the reason...");
+    form.set_other_reason("Question about invoices");
+    form.render()?;
+    println!(
+        "Category {:?}: error = {:?}, can submit = {}",
+        form.category(),
+        form.error(),
+        form.can_submit()
+    );
+
+    println!("\nConditional Required example completed!");
+    Ok(())
+}
@@ -0,0 +1,198 @@
+//! Example demonstrating component defaults supplied by app configuration
+//! `AppConfig` is parsed once, from `ORBIT_CONFIG` if set or else the bundled defaults,
+//! and kept in a global. Button props leave size and theme optional: anything an
+//! instance sets wins, and anything it leaves out comes from the config.
+//! To run: cargo run --example config_defaults
+
+use std::sync::OnceLock;
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use serde::Deserialize;
+
+const DEFAULT_CONFIG: &str = r#"{ "button_size": "medium", "theme": "dark" }"#;
+
+static CONFIG: OnceLock<AppConfig> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ButtonSize {
+    Small,
+    Medium,
+    Large,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Theme {
+    Light,
+    Dark,
+}
+
+/// App-wide settings, including defaults for component props
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct AppConfig {
+    pub button_size: ButtonSize,
+    pub theme: Theme,
+}
+
+impl AppConfig {
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+/// The app config, loaded on first use
+pub fn config() -> &'static AppConfig {
+    CONFIG.get_or_init(|| {
+        let json = std::env::var("ORBIT_CONFIG").unwrap_or_else(|_| DEFAULT_CONFIG.to_string());
+        AppConfig::from_json(&json).unwrap_or_else(|e| {
+            eprintln!("Invalid ORBIT_CONFIG ({}), using the bundled defaults", e);
+            AppConfig::from_json(DEFAULT_CONFIG).expect("bundled config is valid")
+        })
+    })
+}
+
+/// Button props; `None` means "use the configured default"
+#[derive(Clone, Default)]
+pub struct ButtonProps {
+    pub label: String,
+    pub size: Option<ButtonSize>,
+    pub theme: Option<Theme>,
+}
+
+/// Props with every default filled in
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedButtonProps {
+    pub label: String,
+    pub size: ButtonSize,
+    pub theme: Theme,
+}
+
+impl ButtonProps {
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: label.to_string(),
+            ..Self::default()
+        }
+    }
+
+    pub fn size(mut self, size: ButtonSize) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Merge with `config`, letting this instance's own values win
+    pub fn resolve(&self, config: &AppConfig) -> ResolvedButtonProps {
+        ResolvedButtonProps {
+            label: self.label.clone(),
+            size: self.size.unwrap_or(config.button_size),
+            theme: self.theme.unwrap_or(config.theme),
+        }
+    }
+}
+
+pub struct Button {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    props: ResolvedButtonProps,
+}
+
+impl Component for Button {
+    type Props = ButtonProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            id: ComponentId::new(),
+            context,
+            props: props.resolve(config()),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props.resolve(config());
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut button = Node::default();
+        button.add_attribute("role".to_string(), "button".to_string());
+        button.add_attribute(
+            "class".to_string(),
+            format!("button {:?} {:?}", self.props.size, self.props.theme).to_lowercase(),
+        );
+        button.add_child(Node::text(&self.props.label));
+        Ok(vec![button])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl Button {
+    pub fn resolved(&self) -> &ResolvedButtonProps {
+        &self.props
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_size_comes_from_config() {
+        let button = Button::create(ButtonProps::new("Save"), Context::new());
+
+        assert_eq!(button.resolved().size, config().button_size);
+        assert_eq!(button.resolved().theme, config().theme);
+    }
+
+    #[test]
+    fn test_instance_override_wins() {
+        let config = AppConfig {
+            button_size: ButtonSize::Small,
+            theme: Theme::Light,
+        };
+
+        let resolved = ButtonProps::new("Delete").size(ButtonSize::Large).resolve(&config);
+
+        assert_eq!(resolved.size, ButtonSize::Large);
+        assert_eq!(resolved.theme, Theme::Light);
+    }
+
+    #[test]
+    fn test_bundled_config_parses() {
+        let parsed = AppConfig::from_json(DEFAULT_CONFIG).unwrap();
+
+        assert_eq!(parsed.button_size, ButtonSize::Medium);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Config Defaults Example\n");
+    println!("Config: {:?}\n", config());
+
+    let save = Button::create(ButtonProps::new("Save"), Context::new());
+    let delete = Button::create(
+        ButtonProps::new("Delete").size(ButtonSize::Large),
+        Context::new(),
+    );
+
+    for button in [&save, &delete] {
+        button.render()?;
+        let props = button.resolved();
+        println!("{:<8} size {:?}, theme {:?}", props.label, props.size, props.theme);
+    }
+
+    println!("\nConfig Defaults example completed!");
+    Ok(())
+}
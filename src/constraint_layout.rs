@@ -0,0 +1,455 @@
+//! Example demonstrating a small Cassowary-style constraint layout solver
+//! Nodes are positioned with linear constraints such as `A.right == B.left - 8`.
+//! Required constraints must all hold; weak constraints are applied afterwards only if
+//! they don't conflict. An over-constrained system of required constraints is reported
+//! as an error instead of silently producing a layout.
+//! To run: cargo run --example constraint_layout
+
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::{Add, Mul, Sub};
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+
+const EPSILON: f64 = 1e-9;
+
+/// A solver variable
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Variable(usize);
+
+/// A linear expression: sum of `coefficient * variable` terms plus a constant
+#[derive(Debug, Clone, Default)]
+pub struct Expression {
+    terms: HashMap<Variable, f64>,
+    constant: f64,
+}
+
+impl Expression {
+    pub fn constant(value: f64) -> Self {
+        Self {
+            terms: HashMap::new(),
+            constant: value,
+        }
+    }
+
+    fn add_term(&mut self, var: Variable, coefficient: f64) {
+        let entry = self.terms.entry(var).or_insert(0.0);
+        *entry += coefficient;
+        if entry.abs() < EPSILON {
+            self.terms.remove(&var);
+        }
+    }
+
+    fn scaled(mut self, factor: f64) -> Self {
+        for coefficient in self.terms.values_mut() {
+            *coefficient *= factor;
+        }
+        self.constant *= factor;
+        self
+    }
+
+    /// Build the constraint `self == rhs`
+    pub fn equals(self, rhs: impl Into<Expression>, strength: Strength) -> Constraint {
+        Constraint {
+            expression: self - rhs.into(),
+            strength,
+        }
+    }
+}
+
+impl From<Variable> for Expression {
+    fn from(var: Variable) -> Self {
+        let mut expression = Expression::default();
+        expression.add_term(var, 1.0);
+        expression
+    }
+}
+
+impl From<f64> for Expression {
+    fn from(value: f64) -> Self {
+        Expression::constant(value)
+    }
+}
+
+impl<T: Into<Expression>> Add<T> for Expression {
+    type Output = Expression;
+
+    fn add(mut self, rhs: T) -> Expression {
+        let rhs = rhs.into();
+        for (var, coefficient) in rhs.terms {
+            self.add_term(var, coefficient);
+        }
+        self.constant += rhs.constant;
+        self
+    }
+}
+
+impl<T: Into<Expression>> Sub<T> for Expression {
+    type Output = Expression;
+
+    fn sub(self, rhs: T) -> Expression {
+        self + rhs.into().scaled(-1.0)
+    }
+}
+
+impl<T: Into<Expression>> Add<T> for Variable {
+    type Output = Expression;
+
+    fn add(self, rhs: T) -> Expression {
+        Expression::from(self) + rhs
+    }
+}
+
+impl<T: Into<Expression>> Sub<T> for Variable {
+    type Output = Expression;
+
+    fn sub(self, rhs: T) -> Expression {
+        Expression::from(self) - rhs
+    }
+}
+
+impl Mul<f64> for Variable {
+    type Output = Expression;
+
+    fn mul(self, rhs: f64) -> Expression {
+        Expression::from(self).scaled(rhs)
+    }
+}
+
+/// How strongly a constraint should be enforced
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strength {
+    Required,
+    Weak,
+}
+
+/// A linear equality constraint, stored as `expression == 0`
+#[derive(Debug, Clone)]
+pub struct Constraint {
+    expression: Expression,
+    strength: Strength,
+}
+
+impl Variable {
+    /// Shorthand for `self == rhs` as a required constraint
+    pub fn equals(self, rhs: impl Into<Expression>) -> Constraint {
+        Expression::from(self).equals(rhs, Strength::Required)
+    }
+}
+
+/// Errors reported by the solver
+#[derive(Debug, Clone, PartialEq)]
+pub enum LayoutError {
+    /// A required constraint contradicts the ones added before it
+    Infeasible { constraint: usize },
+}
+
+impl fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LayoutError::Infeasible { constraint } => write!(
+                f,
+                "required constraint #{} conflicts with earlier constraints",
+                constraint
+            ),
+        }
+    }
+}
+
+impl std::error::Error for LayoutError {}
+
+/// Incremental solver keeping the accepted constraints in reduced row-echelon form
+#[derive(Debug, Default)]
+pub struct Solver {
+    variables: Vec<String>,
+    // Each row is `sum(terms) + constant == 0` with a pivot variable of coefficient 1
+    rows: Vec<(Variable, Expression)>,
+    added: usize,
+}
+
+impl Solver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn var(&mut self, name: &str) -> Variable {
+        self.variables.push(name.to_string());
+        Variable(self.variables.len() - 1)
+    }
+
+    pub fn name(&self, var: Variable) -> &str {
+        &self.variables[var.0]
+    }
+
+    /// Add a constraint. Weak constraints that conflict are skipped and return `Ok(false)`.
+    pub fn add(&mut self, constraint: Constraint) -> Result<bool, LayoutError> {
+        self.added += 1;
+        let mut row = constraint.expression;
+
+        // Substitute every existing pivot into the new row
+        for (pivot, pivot_row) in &self.rows {
+            if let Some(coefficient) = row.terms.get(pivot).copied() {
+                row = row - pivot_row.clone().scaled(coefficient);
+            }
+        }
+
+        // Choose the lowest-numbered remaining variable as the new pivot
+        let pivot = match row.terms.keys().min().copied() {
+            Some(pivot) => pivot,
+            None if row.constant.abs() < EPSILON => return Ok(true), // redundant
+            None => {
+                return match constraint.strength {
+                    Strength::Required => Err(LayoutError::Infeasible {
+                        constraint: self.added,
+                    }),
+                    Strength::Weak => Ok(false),
+                };
+            }
+        };
+
+        let row = row.clone().scaled(1.0 / row.terms[&pivot]);
+
+        // Eliminate the new pivot from the existing rows
+        for (_, existing) in self.rows.iter_mut() {
+            if let Some(coefficient) = existing.terms.get(&pivot).copied() {
+                *existing = existing.clone() - row.clone().scaled(coefficient);
+            }
+        }
+        self.rows.push((pivot, row));
+        Ok(true)
+    }
+
+    /// Current value of a variable; unconstrained variables resolve to 0
+    pub fn value(&self, var: Variable) -> f64 {
+        for (pivot, row) in &self.rows {
+            if *pivot == var {
+                // Free variables in the row are taken as 0
+                return -row.constant;
+            }
+        }
+        0.0
+    }
+}
+
+/// Horizontal box whose edges are solver variables
+#[derive(Debug, Clone, Copy)]
+pub struct LayoutBox {
+    pub left: Variable,
+    pub width: Variable,
+    pub right: Variable,
+}
+
+impl LayoutBox {
+    pub fn new(solver: &mut Solver, name: &str) -> Result<Self, LayoutError> {
+        let left = solver.var(&format!("{}.left", name));
+        let width = solver.var(&format!("{}.width", name));
+        let right = solver.var(&format!("{}.right", name));
+
+        // Every box is internally consistent: right == left + width
+        solver.add(right.equals(left + width))?;
+
+        Ok(Self { left, width, right })
+    }
+}
+
+#[derive(Clone)]
+pub struct ToolbarProps {
+    pub width: f64,
+    pub gap: f64,
+}
+
+/// A toolbar with a fixed-width logo, and a search field and actions that share the rest
+pub struct Toolbar {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    solver: Solver,
+    boxes: Vec<(String, LayoutBox)>,
+}
+
+impl Component for Toolbar {
+    type Props = ToolbarProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let mut toolbar = Self {
+            id: ComponentId::new(),
+            context,
+            solver: Solver::new(),
+            boxes: Vec::new(),
+        };
+        if let Err(e) = toolbar.build(&props) {
+            eprintln!("Toolbar layout failed: {}", e);
+        }
+        toolbar
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.solver = Solver::new();
+        self.boxes.clear();
+        self.build(&props)
+            .map_err(|e| ComponentError::UpdateError(format!("Layout failed: {}", e)))
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut toolbar = Node::default();
+        toolbar.add_attribute("class".to_string(), "toolbar".to_string());
+
+        for (name, layout) in &self.boxes {
+            let mut item = Node::default();
+            item.add_attribute("name".to_string(), name.clone());
+            item.add_attribute("x".to_string(), self.solver.value(layout.left).to_string());
+            item.add_attribute(
+                "width".to_string(),
+                self.solver.value(layout.width).to_string(),
+            );
+            toolbar.add_child(item);
+        }
+
+        Ok(vec![toolbar])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl Toolbar {
+    fn build(&mut self, props: &ToolbarProps) -> Result<(), LayoutError> {
+        let solver = &mut self.solver;
+        let logo = LayoutBox::new(solver, "logo")?;
+        let search = LayoutBox::new(solver, "search")?;
+        let actions = LayoutBox::new(solver, "actions")?;
+
+        solver.add(logo.left.equals(0.0))?;
+        solver.add(logo.width.equals(100.0))?;
+        // search.left == logo.right + gap, i.e. logo.right == search.left - gap
+        solver.add(logo.right.equals(search.left - props.gap))?;
+        solver.add(actions.left.equals(search.right + props.gap))?;
+        solver.add(actions.right.equals(props.width))?;
+        // The search field gets twice the space of the actions
+        solver.add(search.width.equals(actions.width * 2.0))?;
+        // A weak preference that loses to the required constraints above
+        solver.add(Expression::from(actions.width).equals(64.0, Strength::Weak))?;
+
+        self.boxes = vec![
+            ("logo".to_string(), logo),
+            ("search".to_string(), search),
+            ("actions".to_string(), actions),
+        ];
+        Ok(())
+    }
+
+    pub fn position(&self, name: &str) -> Option<(f64, f64)> {
+        self.boxes
+            .iter()
+            .find(|(box_name, _)| box_name == name)
+            .map(|(_, layout)| {
+                (
+                    self.solver.value(layout.left),
+                    self.solver.value(layout.width),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-6
+    }
+
+    #[test]
+    fn test_solves_simple_constraint_set() {
+        let mut solver = Solver::new();
+        let a = LayoutBox::new(&mut solver, "a").unwrap();
+        let b = LayoutBox::new(&mut solver, "b").unwrap();
+
+        solver.add(a.left.equals(10.0)).unwrap();
+        solver.add(a.width.equals(50.0)).unwrap();
+        solver.add(a.right.equals(b.left - 8.0)).unwrap();
+        solver.add(b.width.equals(a.width)).unwrap();
+
+        assert!(approx(solver.value(a.right), 60.0));
+        assert!(approx(solver.value(b.left), 68.0));
+        assert!(approx(solver.value(b.right), 118.0));
+    }
+
+    #[test]
+    fn test_over_constrained_system_reports_error() {
+        let mut solver = Solver::new();
+        let a = LayoutBox::new(&mut solver, "a").unwrap();
+
+        solver.add(a.left.equals(0.0)).unwrap();
+        solver.add(a.width.equals(100.0)).unwrap();
+        let result = solver.add(a.right.equals(120.0));
+
+        assert!(matches!(result, Err(LayoutError::Infeasible { .. })));
+    }
+
+    #[test]
+    fn test_toolbar_layout() {
+        let toolbar = Toolbar::create(
+            ToolbarProps {
+                width: 400.0,
+                gap: 8.0,
+            },
+            Context::new(),
+        );
+
+        // 400 - 100 (logo) - 2 * 8 (gaps) = 284 shared 2:1 between search and actions
+        let (search_x, search_width) = toolbar.position("search").unwrap();
+        let (actions_x, actions_width) = toolbar.position("actions").unwrap();
+        assert!(approx(search_x, 108.0));
+        assert!(approx(search_width, 284.0 * 2.0 / 3.0));
+        assert!(approx(actions_width, 284.0 / 3.0));
+        assert!(approx(actions_x + actions_width, 400.0));
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Constraint Layout Example\n");
+
+    let toolbar = Toolbar::create(
+        ToolbarProps {
+            width: 480.0,
+            gap: 8.0,
+        },
+        Context::new(),
+    );
+    toolbar.render()?;
+
+    for name in ["logo", "search", "actions"] {
+        if let Some((x, width)) = toolbar.position(name) {
+            println!("  {:8} x={:7.2} width={:7.2}", name, x, width);
+        }
+    }
+
+    println!("\nAdding a conflicting required constraint...");
+    let mut solver = Solver::new();
+    let panel = LayoutBox::new(&mut solver, "panel")
+        .map_err(|e| ComponentError::RenderError(format!("Layout failed: {}", e)))?;
+    let steps = [
+        panel.left.equals(0.0),
+        panel.width.equals(200.0),
+        panel.right.equals(180.0),
+    ];
+    for constraint in steps {
+        if let Err(e) = solver.add(constraint) {
+            println!("  Solver error: {}", e);
+        }
+    }
+    println!("  {} = {}", solver.name(panel.right), solver.value(panel.right));
+
+    println!("\nConstraint Layout example completed!");
+    Ok(())
+}
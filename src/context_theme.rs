@@ -0,0 +1,319 @@
+//! Example demonstrating a theme provided by a parent and read by descendants
+//! `ThemeProvider` provides a `Theme` to everything below it, and the `Card` and
+//! `Button` nested inside read it while rendering; neither declares a theme prop.
+//! `Provided` travels alongside `Context`: a type-keyed map that each level passes on
+//! and any level can extend. Consumers outside a provider fall back to the default.
+//! To run: cargo run --example context_theme
+
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+
+/// Values provided to a subtree, looked up by type
+#[derive(Clone, Default)]
+pub struct Provided {
+    values: Arc<HashMap<TypeId, Arc<dyn Any + Send + Sync>>>,
+}
+
+impl Provided {
+    /// A copy that also provides `value`, shadowing any outer value of its type
+    pub fn with<T: Any + Send + Sync>(&self, value: T) -> Self {
+        let mut values = (*self.values).clone();
+        values.insert(TypeId::of::<T>(), Arc::new(value));
+        Self {
+            values: Arc::new(values),
+        }
+    }
+
+    /// The nearest provided `T`, if any ancestor provided one
+    pub fn get<T: Any + Send + Sync>(&self) -> Option<&T> {
+        self.values
+            .get(&TypeId::of::<T>())
+            .and_then(|value| value.downcast_ref())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    pub primary: String,
+    pub dark: bool,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            primary: "#0070f3".to_string(),
+            dark: false,
+        }
+    }
+}
+
+/// The theme a consumer should render with: the provided one, or the default
+pub fn use_theme(provided: &Provided) -> Theme {
+    provided.get::<Theme>().cloned().unwrap_or_default()
+}
+
+/// Add the attributes every themed node carries
+fn apply_theme(node: &mut Node, theme: &Theme) {
+    let scheme = if theme.dark { "dark" } else { "light" };
+    node.add_attribute("data-theme".to_string(), scheme.to_string());
+    node.add_attribute("data-primary".to_string(), theme.primary.clone());
+}
+
+#[derive(Clone)]
+pub struct ButtonProps {
+    pub label: String,
+    pub provided: Provided,
+}
+
+/// A consumer two levels below the provider
+pub struct Button {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    props: ButtonProps,
+}
+
+impl Component for Button {
+    type Props = ButtonProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            id: ComponentId::new(),
+            context,
+            props,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut button = Node::default();
+        button.add_attribute("role".to_string(), "button".to_string());
+        apply_theme(&mut button, &use_theme(&self.props.provided));
+        button.add_child(Node::text(&self.props.label));
+        Ok(vec![button])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct CardProps {
+    pub title: String,
+    pub provided: Provided,
+}
+
+/// A consumer that passes what was provided on to its own child
+pub struct Card {
+    id: ComponentId,
+    context: Context,
+    props: CardProps,
+}
+
+impl Component for Card {
+    type Props = CardProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            id: ComponentId::new(),
+            context,
+            props,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut card = Node::default();
+        card.add_attribute("class".to_string(), "card".to_string());
+        apply_theme(&mut card, &use_theme(&self.props.provided));
+        card.add_child(Node::text(&self.props.title));
+
+        let button = Button::create(
+            ButtonProps {
+                label: "Open".to_string(),
+                provided: self.props.provided.clone(),
+            },
+            self.context.clone(),
+        );
+        for node in button.render()? {
+            card.add_child(node);
+        }
+        Ok(vec![card])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct ThemeProviderProps {
+    // None leaves whatever an outer provider set, or the default
+    pub theme: Option<Theme>,
+}
+
+/// Provides a theme to the card rendered inside it
+pub struct ThemeProvider {
+    id: ComponentId,
+    context: Context,
+    props: ThemeProviderProps,
+}
+
+impl Component for ThemeProvider {
+    type Props = ThemeProviderProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            id: ComponentId::new(),
+            context,
+            props,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let provided = match &self.props.theme {
+            Some(theme) => Provided::default().with(theme.clone()),
+            None => Provided::default(),
+        };
+        Card::create(
+            CardProps {
+                title: "Quarterly report".to_string(),
+                provided,
+            },
+            self.context.clone(),
+        )
+        .render()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+fn dark_theme() -> Theme {
+    Theme {
+        primary: "#79b8ff".to_string(),
+        dark: true,
+    }
+}
+
+/// The card's and the nested button's `data-theme` and `data-primary` attributes
+fn themes_in(nodes: &[Node]) -> Vec<(String, String)> {
+    let card = &nodes[0];
+    let button = &card.children()[1];
+    [card, button]
+        .iter()
+        .map(|node| {
+            let attributes = node.attributes();
+            (
+                attributes.get("data-theme").cloned().unwrap_or_default(),
+                attributes.get("data-primary").cloned().unwrap_or_default(),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_consumers_read_provided_theme() {
+        let provider = ThemeProvider::create(
+            ThemeProviderProps {
+                theme: Some(dark_theme()),
+            },
+            Context::new(),
+        );
+
+        let themes = themes_in(&provider.render().unwrap());
+
+        let expected = ("dark".to_string(), "#79b8ff".to_string());
+        assert_eq!(themes, [expected.clone(), expected]);
+    }
+
+    #[test]
+    fn test_missing_provider_falls_back_to_default() {
+        let provider = ThemeProvider::create(ThemeProviderProps { theme: None }, Context::new());
+
+        let themes = themes_in(&provider.render().unwrap());
+
+        assert!(themes.iter().all(|(scheme, primary)| {
+            scheme == "light" && *primary == Theme::default().primary
+        }));
+    }
+
+    #[test]
+    fn test_inner_provider_shadows_outer() {
+        let outer = Provided::default().with(Theme::default());
+        let inner = outer.with(dark_theme());
+
+        assert_eq!(use_theme(&inner), dark_theme());
+        assert_eq!(use_theme(&outer), Theme::default());
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Context Theme Example\n");
+
+    for theme in [Some(dark_theme()), None] {
+        let label = if theme.is_some() {
+            "dark theme provided"
+        } else {
+            "no theme provided"
+        };
+        let provider = ThemeProvider::create(ThemeProviderProps { theme }, Context::new());
+        let themes = themes_in(&provider.render()?);
+        println!("With {}:", label);
+        for (component, (scheme, primary)) in ["Card", "Button"].iter().zip(themes) {
+            println!(
+                "  {:<6} data-theme={} data-primary={}",
+                component, scheme, primary
+            );
+        }
+    }
+
+    println!("\nContext Theme example completed!");
+    Ok(())
+}
@@ -0,0 +1,200 @@
+//! Example demonstrating controlled and uncontrolled input modes
+//! When a `value` prop is provided the input is controlled: it always displays the
+//! parent's value and only reports edits through `on_change`. Without a `value` prop it
+//! is uncontrolled and keeps its own state in a signal seeded from `default_value`.
+//! To run: cargo run --example controlled_uncontrolled
+
+use std::sync::{Arc, RwLock};
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+/// A value owned by the parent and shared with a controlled input
+pub type SharedValue = Arc<RwLock<String>>;
+
+#[derive(Clone, Default)]
+pub struct TextInputProps {
+    // Controlled mode when present
+    pub value: Option<SharedValue>,
+    // Initial value for uncontrolled mode
+    pub default_value: String,
+    pub on_change: Option<Arc<dyn Fn(String) + Send + Sync>>,
+}
+
+/// A text input supporting both controlled and uncontrolled modes
+pub struct TextInput {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: TextInputProps,
+    // Internal state, only used in uncontrolled mode
+    internal: Signal<String>,
+}
+
+impl Component for TextInput {
+    type Props = TextInputProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let internal = create_signal(&scope, props.default_value.clone());
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            props,
+            internal,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        if self.props.value.is_some() != props.value.is_some() {
+            println!("Warning: TextInput switched between controlled and uncontrolled mode");
+        }
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut node = Node::default();
+        node.add_attribute("tag".to_string(), "input".to_string());
+        node.add_attribute("value".to_string(), self.value());
+        node.add_attribute(
+            "data-mode".to_string(),
+            if self.is_controlled() {
+                "controlled".to_string()
+            } else {
+                "uncontrolled".to_string()
+            },
+        );
+        Ok(vec![node])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl TextInput {
+    pub fn is_controlled(&self) -> bool {
+        self.props.value.is_some()
+    }
+
+    /// The value currently displayed by the input
+    pub fn value(&self) -> String {
+        match &self.props.value {
+            Some(value) => match value.read() {
+                Ok(value) => value.clone(),
+                Err(_) => String::new(),
+            },
+            None => self.internal.get().clone(),
+        }
+    }
+
+    /// Simulate the user editing the input
+    pub fn input(&self, text: &str) {
+        // Uncontrolled inputs own their value; controlled ones leave it to the parent
+        if !self.is_controlled() {
+            self.internal.set(text.to_string()).unwrap_or_else(|e| {
+                eprintln!("Failed to update input: {}", e);
+            });
+        }
+
+        if let Some(on_change) = &self.props.on_change {
+            on_change(text.to_string());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_controlled_ignores_edits_until_prop_updates() {
+        let value: SharedValue = Arc::new(RwLock::new("hello".to_string()));
+        let input = TextInput::create(
+            TextInputProps {
+                value: Some(value.clone()),
+                ..Default::default()
+            },
+            Context::new(),
+        );
+
+        input.input("hello world");
+        assert_eq!(input.value(), "hello");
+
+        *value.write().unwrap() = "hello world".to_string();
+        assert_eq!(input.value(), "hello world");
+    }
+
+    #[test]
+    fn test_uncontrolled_updates_internally() {
+        let input = TextInput::create(
+            TextInputProps {
+                default_value: "draft".to_string(),
+                ..Default::default()
+            },
+            Context::new(),
+        );
+
+        assert_eq!(input.value(), "draft");
+        input.input("final");
+        assert_eq!(input.value(), "final");
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Controlled vs Uncontrolled Input Example\n");
+
+    // Controlled: the parent owns the value and decides whether to accept edits
+    let value: SharedValue = Arc::new(RwLock::new("ORBIT".to_string()));
+    let parent_value = value.clone();
+    let controlled = TextInput::create(
+        TextInputProps {
+            value: Some(value.clone()),
+            on_change: Some(Arc::new(move |text: String| {
+                // The parent only accepts upper-case input
+                if let Ok(mut value) = parent_value.write() {
+                    *value = text.to_uppercase();
+                }
+            })),
+            ..Default::default()
+        },
+        Context::new(),
+    );
+
+    controlled.input("orbit rs");
+    controlled.render()?;
+    println!("Controlled input shows: {}", controlled.value());
+
+    // Uncontrolled: the input keeps its own state
+    let uncontrolled = TextInput::create(
+        TextInputProps {
+            default_value: "initial".to_string(),
+            on_change: Some(Arc::new(|text: String| {
+                println!("  on_change: {}", text);
+            })),
+            ..Default::default()
+        },
+        Context::new(),
+    );
+
+    println!("\nUncontrolled input starts as: {}", uncontrolled.value());
+    uncontrolled.input("edited");
+    uncontrolled.render()?;
+    println!("Uncontrolled input shows: {}", uncontrolled.value());
+
+    println!("\nControlled vs Uncontrolled example completed!");
+    Ok(())
+}
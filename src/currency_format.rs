@@ -0,0 +1,227 @@
+//! Example demonstrating a reactive, currency-aware formatted amount
+//! An amount in minor units (cents for dollars, yen for yen) and a currency are both
+//! signals, and the displayed price is computed from them: each currency supplies its
+//! symbol and where it goes, its digit grouping and decimal separators, and how many
+//! decimal places it has. Changing either signal reformats the price.
+//! To run: cargo run --example currency_format
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_computed, create_signal, ReactiveComputed, ReactiveScope, Signal};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Currency {
+    Usd,
+    Eur,
+    Jpy,
+}
+
+/// How a currency's amounts are written
+pub struct CurrencyFormat {
+    pub symbol: &'static str,
+    pub symbol_after: bool,
+    // Digits after the decimal separator; also how many minor units make a major one
+    pub decimals: u32,
+    pub group_separator: char,
+    pub decimal_separator: char,
+}
+
+impl Currency {
+    pub fn format_rules(self) -> CurrencyFormat {
+        match self {
+            Currency::Usd => CurrencyFormat {
+                symbol: "$",
+                symbol_after: false,
+                decimals: 2,
+                group_separator: ',',
+                decimal_separator: '.',
+            },
+            // As written in German-speaking countries
+            Currency::Eur => CurrencyFormat {
+                symbol: " €",
+                symbol_after: true,
+                decimals: 2,
+                group_separator: '.',
+                decimal_separator: ',',
+            },
+            Currency::Jpy => CurrencyFormat {
+                symbol: "¥",
+                symbol_after: false,
+                decimals: 0,
+                group_separator: ',',
+                decimal_separator: '.',
+            },
+        }
+    }
+}
+
+/// Format `minor` units of `currency`, e.g. 123456 USD cents as "$1,234.56"
+pub fn format_amount(minor: i64, currency: Currency) -> String {
+    let rules = currency.format_rules();
+    let scale = 10_u64.pow(rules.decimals);
+    let (major, fraction) = (minor.unsigned_abs() / scale, minor.unsigned_abs() % scale);
+
+    // Group the whole part in threes from the right
+    let digits = major.to_string();
+    let mut number = String::new();
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index) % 3 == 0 {
+            number.push(rules.group_separator);
+        }
+        number.push(digit);
+    }
+    if rules.decimals > 0 {
+        number.push(rules.decimal_separator);
+        let width = rules.decimals as usize;
+        number.push_str(&format!("{:0width$}", fraction));
+    }
+
+    let sign = if minor < 0 { "-" } else { "" };
+    if rules.symbol_after {
+        format!("{}{}{}", sign, number, rules.symbol)
+    } else {
+        format!("{}{}{}", sign, rules.symbol, number)
+    }
+}
+
+#[derive(Clone)]
+pub struct PriceProps {
+    pub minor: i64,
+    pub currency: Currency,
+}
+
+/// Displays an amount formatted for its currency
+pub struct Price {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    minor: Signal<i64>,
+    currency: Signal<Currency>,
+    formatted: ReactiveComputed<String, Box<dyn FnMut() -> String>>,
+}
+
+impl Component for Price {
+    type Props = PriceProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let minor = create_signal(&scope, props.minor);
+        let currency = create_signal(&scope, props.currency);
+
+        let minor_value = minor.value.clone();
+        let currency_value = currency.value.clone();
+        let formatted = create_computed(
+            &scope,
+            Box::new(move || format_amount(*minor_value.borrow(), *currency_value.borrow()))
+                as Box<dyn FnMut() -> String>,
+        );
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            minor,
+            currency,
+            formatted,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.minor
+            .set(props.minor)
+            .map_err(|e| ComponentError::UpdateError(format!("Failed to set amount: {}", e)))?;
+        self.currency
+            .set(props.currency)
+            .map_err(|e| ComponentError::UpdateError(format!("Failed to set currency: {}", e)))
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut price = Node::default();
+        price.add_attribute("class".to_string(), "price".to_string());
+        price.add_child(Node::text(&self.formatted()));
+        Ok(vec![price])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl Price {
+    pub fn set_currency(&self, currency: Currency) {
+        self.currency
+            .set(currency)
+            .unwrap_or_else(|e| eprintln!("Failed to set currency: {}", e));
+    }
+
+    pub fn set_minor(&self, minor: i64) {
+        self.minor
+            .set(minor)
+            .unwrap_or_else(|e| eprintln!("Failed to set amount: {}", e));
+    }
+
+    pub fn formatted(&self) -> String {
+        self.formatted.get().map(|f| f.clone()).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_usd_and_jpy_formatting() {
+        let price = Price::create(
+            PriceProps {
+                minor: 123456,
+                currency: Currency::Usd,
+            },
+            Context::new(),
+        );
+        assert_eq!(price.formatted(), "$1,234.56");
+
+        price.set_currency(Currency::Jpy);
+
+        assert_eq!(price.formatted(), "¥123,456");
+    }
+
+    #[test]
+    fn test_eur_separators_and_small_amounts() {
+        assert_eq!(format_amount(123456, Currency::Eur), "1.234,56 €");
+        assert_eq!(format_amount(5, Currency::Usd), "$0.05");
+        assert_eq!(format_amount(-100_000_000, Currency::Usd), "-$1,000,000.00");
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Currency Format Example\n");
+
+    let price = Price::create(
+        PriceProps {
+            minor: 123456,
+            currency: Currency::Usd,
+        },
+        Context::new(),
+    );
+
+    for currency in [Currency::Usd, Currency::Eur, Currency::Jpy] {
+        price.set_currency(currency);
+        price.render()?;
+        println!("{:?}: {}", currency, price.formatted());
+    }
+
+    price.set_minor(-987_654_321);
+    println!("\nAfter a refund: {}", price.formatted());
+
+    println!("\nCurrency Format example completed!");
+    Ok(())
+}
@@ -0,0 +1,344 @@
+//! Example demonstrating a dashboard composing several widgets
+//! A chart, a table and an alert badge sit in a grid. The chart and table are the
+//! `Chart` and `Table` widgets from `dynamic_component`, shared through a `#[path]`
+//! module; the badge is defined here. Each widget keeps its data in its own signal,
+//! next to a flag saying whether its last update changed that data. The dashboard owns
+//! a single refresh action that fetches new metrics and hands each widget its slice.
+//! To run: cargo run --example dashboard
+
+use std::sync::{Arc, Mutex};
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+// Shared with the dynamic_component example; not every item is used here
+#[allow(dead_code)]
+#[path = "dynamic_component.rs"]
+mod dynamic_component;
+
+use dynamic_component::{Chart, LifecycleLog, Table, WidgetProps};
+
+/// Everything the dashboard shows, fetched in one go
+#[derive(Debug, Clone, PartialEq)]
+pub struct Metrics {
+    pub daily_sales: Vec<i32>,
+    pub weekly_signups: Vec<i32>,
+    pub open_alerts: u32,
+}
+
+/// Fetches metrics for the given refresh number
+pub type MetricsSource = Arc<dyn Fn(u64) -> Metrics + Send + Sync>;
+
+#[derive(Clone)]
+pub struct AlertBadgeProps {
+    pub title: String,
+    pub count: u32,
+}
+
+/// Count badge for open alerts
+pub struct AlertBadge {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    title: String,
+    count: Signal<u32>,
+    // Whether the last update changed the count
+    updated: Signal<bool>,
+}
+
+impl Component for AlertBadge {
+    type Props = AlertBadgeProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            title: props.title,
+            count: create_signal(&scope, props.count),
+            updated: create_signal(&scope, false),
+            scope,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        let changed = *self.count.get() != props.count;
+        self.updated
+            .set(changed)
+            .map_err(|e| ComponentError::UpdateError(format!("Failed to flag update: {}", e)))?;
+        self.count
+            .set(props.count)
+            .map_err(|e| ComponentError::UpdateError(format!("Failed to set count: {}", e)))?;
+        self.title = props.title;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let count = *self.count.get();
+        let mut badge = Node::default();
+        badge.add_attribute("class".to_string(), "badge".to_string());
+        badge.add_attribute(
+            "aria-label".to_string(),
+            format!("{}: {}", self.title, count),
+        );
+        // Large counts are abbreviated to keep the badge small
+        let label = if count > 99 {
+            "99+".to_string()
+        } else {
+            count.to_string()
+        };
+        badge.add_child(Node::text(&label));
+
+        Ok(vec![badge])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl AlertBadge {
+    pub fn count(&self) -> u32 {
+        *self.count.get()
+    }
+
+    /// Whether the last update changed the count
+    pub fn updated(&self) -> bool {
+        *self.updated.get()
+    }
+}
+
+#[derive(Clone)]
+pub struct DashboardProps {
+    pub source: MetricsSource,
+    pub columns: usize,
+}
+
+/// Lays the widgets out in a grid and refreshes them together
+pub struct Dashboard {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    props: DashboardProps,
+    // Chart and Table record their mount and unmount calls here
+    log: LifecycleLog,
+    chart: Chart,
+    table: Table,
+    badge: AlertBadge,
+    // The last metrics fetched
+    metrics: Option<Metrics>,
+    refreshes: u64,
+}
+
+impl Component for Dashboard {
+    type Props = DashboardProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let log: LifecycleLog = Arc::new(Mutex::new(Vec::new()));
+        let series = WidgetProps {
+            data: Vec::new(),
+            log: log.clone(),
+        };
+
+        Self {
+            id: ComponentId::new(),
+            chart: Chart::create(series.clone(), context.clone()),
+            table: Table::create(series, context.clone()),
+            badge: AlertBadge::create(
+                AlertBadgeProps {
+                    title: "Open alerts".to_string(),
+                    count: 0,
+                },
+                context.clone(),
+            ),
+            context,
+            props,
+            log,
+            metrics: None,
+            refreshes: 0,
+        }
+    }
+
+    fn mount(&mut self) -> Result<(), ComponentError> {
+        self.chart.mount()?;
+        self.table.mount()
+    }
+
+    fn unmount(&mut self) -> Result<(), ComponentError> {
+        self.chart.unmount()?;
+        self.table.unmount()
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut grid = Node::default();
+        grid.add_attribute("class".to_string(), "dashboard-grid".to_string());
+        grid.add_attribute("columns".to_string(), self.props.columns.to_string());
+
+        let widgets: [(&str, &dyn Fn() -> Result<Vec<Node>, ComponentError>); 3] = [
+            ("Open alerts", &|| self.badge.render()),
+            ("Daily sales", &|| self.chart.render()),
+            ("Weekly signups", &|| self.table.render()),
+        ];
+        for (index, (title, render)) in widgets.iter().enumerate() {
+            let mut cell = Node::default();
+            cell.add_attribute("aria-label".to_string(), title.to_string());
+            cell.add_attribute(
+                "grid-row".to_string(),
+                (index / self.props.columns.max(1)).to_string(),
+            );
+            cell.add_attribute(
+                "grid-column".to_string(),
+                (index % self.props.columns.max(1)).to_string(),
+            );
+            for node in render()? {
+                cell.add_child(node);
+            }
+            grid.add_child(cell);
+        }
+
+        Ok(vec![grid])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl Dashboard {
+    fn series(&self, data: &[i32]) -> WidgetProps {
+        WidgetProps {
+            data: data.to_vec(),
+            log: self.log.clone(),
+        }
+    }
+
+    /// The shared refresh action: fetch once, then update every widget
+    pub fn refresh(&mut self) -> Result<(), ComponentError> {
+        self.refreshes += 1;
+        let metrics = (self.props.source)(self.refreshes);
+
+        self.chart.update(self.series(&metrics.daily_sales))?;
+        self.table.update(self.series(&metrics.weekly_signups))?;
+        self.badge.update(AlertBadgeProps {
+            title: "Open alerts".to_string(),
+            count: metrics.open_alerts,
+        })?;
+        self.metrics = Some(metrics);
+        Ok(())
+    }
+}
+
+fn fake_source() -> MetricsSource {
+    Arc::new(|refresh| {
+        let n = refresh as i32;
+        Metrics {
+            daily_sales: vec![120 + n * 10, 95 + n * 5, 140 + n * 20],
+            weekly_signups: vec![40 + n, 31 + 2 * n],
+            open_alerts: 3 * refresh as u32,
+        }
+    })
+}
+
+fn dashboard(source: MetricsSource) -> Dashboard {
+    Dashboard::create(DashboardProps { source, columns: 2 }, Context::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_refresh_updates_every_widget() {
+        let mut dashboard = dashboard(fake_source());
+        assert!(!dashboard.chart.updated());
+        assert!(!dashboard.table.updated());
+        assert!(!dashboard.badge.updated());
+
+        dashboard.refresh().unwrap();
+
+        assert!(dashboard.chart.updated());
+        assert!(dashboard.table.updated());
+        assert!(dashboard.badge.updated());
+        assert_eq!(dashboard.badge.count(), 3);
+        // The shared Chart draws one bar per daily sales figure
+        let nodes = dashboard.render().unwrap();
+        let chart = &nodes[0].children()[1].children()[0];
+        let heights: Vec<String> = chart
+            .children()
+            .iter()
+            .filter_map(|bar| bar.attributes().get("height").cloned())
+            .collect();
+        assert_eq!(heights, ["130", "100", "160"]);
+    }
+
+    #[test]
+    fn test_unchanged_data_clears_flag() {
+        let mut dashboard = dashboard(Arc::new(|_| Metrics {
+            daily_sales: vec![1, 2],
+            weekly_signups: Vec::new(),
+            open_alerts: 0,
+        }));
+
+        dashboard.refresh().unwrap();
+        assert!(dashboard.chart.updated());
+        assert!(!dashboard.table.updated());
+
+        dashboard.refresh().unwrap();
+
+        assert!(!dashboard.chart.updated());
+        assert!(!dashboard.table.updated());
+        assert!(!dashboard.badge.updated());
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Dashboard Example\n");
+
+    let mut dashboard = dashboard(fake_source());
+    dashboard.mount()?;
+
+    for _ in 0..2 {
+        dashboard.refresh()?;
+        dashboard.render()?;
+        if let Some(metrics) = &dashboard.metrics {
+            println!("Refresh {}:", dashboard.refreshes);
+            println!("  sales:   {:?}", metrics.daily_sales);
+            println!("  signups: {:?}", metrics.weekly_signups);
+            println!("  alerts:  {}", metrics.open_alerts);
+            println!(
+                "  updated: chart {}, table {}, badge {}",
+                dashboard.chart.updated(),
+                dashboard.table.updated(),
+                dashboard.badge.updated()
+            );
+        }
+    }
+
+    dashboard.unmount()?;
+    println!("\nDashboard example completed!");
+    Ok(())
+}
@@ -0,0 +1,266 @@
+//! Example demonstrating a drag-and-resize dashboard editor
+//! Widgets sit on a column grid and can be moved and resized. Every change is written
+//! back to a `Signal<Vec<WidgetLayout>>`, and widgets that end up overlapping the one
+//! being edited are pushed down until nothing collides.
+//! To run: cargo run --example dashboard_editor
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+/// A widget's position and size in grid units
+#[derive(Debug, Clone, PartialEq)]
+pub struct WidgetLayout {
+    pub id: String,
+    pub x: u32,
+    pub y: u32,
+    pub w: u32,
+    pub h: u32,
+}
+
+impl WidgetLayout {
+    fn overlaps(&self, other: &WidgetLayout) -> bool {
+        self.x < other.x + other.w
+            && other.x < self.x + self.w
+            && self.y < other.y + other.h
+            && other.y < self.y + self.h
+    }
+}
+
+/// Push widgets down until none overlap, keeping `fixed` where it is
+pub fn resolve_collisions(layouts: &mut [WidgetLayout], fixed: &str) {
+    let mut placed: Vec<WidgetLayout> = layouts
+        .iter()
+        .filter(|layout| layout.id == fixed)
+        .cloned()
+        .collect();
+
+    // Top-to-bottom order lets a pushed widget push the ones below it in turn
+    let mut others: Vec<usize> = (0..layouts.len())
+        .filter(|&i| layouts[i].id != fixed)
+        .collect();
+    others.sort_by_key(|&i| (layouts[i].y, layouts[i].x));
+
+    for index in others {
+        let widget = &mut layouts[index];
+        while let Some(blocker) = placed.iter().find(|p| p.overlaps(widget)) {
+            widget.y = blocker.y + blocker.h;
+        }
+        placed.push(widget.clone());
+    }
+}
+
+#[derive(Clone)]
+pub struct DashboardEditorProps {
+    pub columns: u32,
+    // Pixel size of one grid unit
+    pub cell_size: f32,
+}
+
+/// An editable grid of dashboard widgets
+pub struct DashboardEditor {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: DashboardEditorProps,
+    layouts: Signal<Vec<WidgetLayout>>,
+}
+
+impl Component for DashboardEditor {
+    type Props = DashboardEditorProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let layouts = create_signal(&scope, Vec::new());
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            props,
+            layouts,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut grid = Node::default();
+        grid.add_attribute("class".to_string(), "dashboard-editor".to_string());
+
+        let size = self.props.cell_size;
+        for layout in self.layouts.get().iter() {
+            let mut widget = Node::default();
+            widget.add_attribute("data-widget".to_string(), layout.id.clone());
+            widget.add_attribute("x".to_string(), (layout.x as f32 * size).to_string());
+            widget.add_attribute("y".to_string(), (layout.y as f32 * size).to_string());
+            widget.add_attribute("width".to_string(), (layout.w as f32 * size).to_string());
+            widget.add_attribute("height".to_string(), (layout.h as f32 * size).to_string());
+
+            // Resize handle in the bottom-right corner
+            let mut handle = Node::default();
+            handle.add_attribute("class".to_string(), "resize-handle".to_string());
+            widget.add_child(handle);
+
+            grid.add_child(widget);
+        }
+
+        Ok(vec![grid])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl DashboardEditor {
+    pub fn add_widget(&self, id: &str, x: u32, y: u32, w: u32, h: u32) {
+        // Keep the widget inside the grid horizontally, as moves and resizes do
+        let w = w.clamp(1, self.props.columns.max(1));
+        let layout = WidgetLayout {
+            id: id.to_string(),
+            x: x.min(self.props.columns.saturating_sub(w)),
+            y,
+            w,
+            h: h.max(1),
+        };
+        self.edit(id, |layouts| layouts.push(layout));
+    }
+
+    /// Drop a widget at a new grid position
+    pub fn move_widget(&self, id: &str, x: u32, y: u32) {
+        let columns = self.props.columns;
+        self.edit(id, |layouts| {
+            if let Some(layout) = layouts.iter_mut().find(|l| l.id == id) {
+                // Keep the widget inside the grid horizontally
+                layout.x = x.min(columns.saturating_sub(layout.w));
+                layout.y = y;
+            }
+        });
+    }
+
+    /// Resize a widget from its bottom-right handle
+    pub fn resize_widget(&self, id: &str, w: u32, h: u32) {
+        let columns = self.props.columns;
+        self.edit(id, |layouts| {
+            if let Some(layout) = layouts.iter_mut().find(|l| l.id == id) {
+                layout.w = w.clamp(1, columns.saturating_sub(layout.x).max(1));
+                layout.h = h.max(1);
+            }
+        });
+    }
+
+    // Apply a change, then push anything that now collides with `id`
+    fn edit(&self, id: &str, change: impl FnOnce(&mut Vec<WidgetLayout>)) {
+        self.layouts
+            .update(|layouts| {
+                change(layouts);
+                resolve_collisions(layouts, id);
+            })
+            .unwrap_or_else(|e| eprintln!("Failed to update layout: {}", e));
+    }
+
+    pub fn layout(&self, id: &str) -> Option<WidgetLayout> {
+        self.layouts.get().iter().find(|l| l.id == id).cloned()
+    }
+
+    pub fn layouts(&self) -> Vec<WidgetLayout> {
+        self.layouts.get().clone()
+    }
+}
+
+fn editor() -> DashboardEditor {
+    let editor = DashboardEditor::create(
+        DashboardEditorProps {
+            columns: 4,
+            cell_size: 100.0,
+        },
+        Context::new(),
+    );
+    editor.add_widget("sales", 0, 0, 2, 2);
+    editor.add_widget("alerts", 2, 0, 2, 2);
+    editor.add_widget("orders", 0, 2, 4, 1);
+    editor
+}
+
+fn print_layouts(editor: &DashboardEditor) {
+    for layout in editor.layouts() {
+        println!(
+            "  {:<7} at ({}, {}) size {}x{}",
+            layout.id, layout.x, layout.y, layout.w, layout.h
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_moving_onto_widget_pushes_it_down() {
+        let editor = editor();
+
+        editor.move_widget("alerts", 0, 0);
+
+        assert_eq!(editor.layout("alerts").unwrap().y, 0);
+        // sales is displaced below alerts, and orders below sales
+        assert_eq!(editor.layout("sales").unwrap().y, 2);
+        assert_eq!(editor.layout("orders").unwrap().y, 4);
+    }
+
+    #[test]
+    fn test_resize_is_clamped_to_grid() {
+        let editor = editor();
+
+        editor.resize_widget("alerts", 5, 3);
+
+        let alerts = editor.layout("alerts").unwrap();
+        assert_eq!((alerts.w, alerts.h), (2, 3));
+        // The taller widget now reaches row 3 and pushes orders down
+        assert_eq!(editor.layout("orders").unwrap().y, 3);
+    }
+
+    #[test]
+    fn test_widget_added_past_the_edge_is_pulled_inside() {
+        let editor = editor();
+
+        editor.add_widget("notes", 6, 5, 2, 1);
+        editor.resize_widget("notes", 3, 1);
+
+        let notes = editor.layout("notes").unwrap();
+        assert_eq!((notes.x, notes.w), (2, 2));
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Dashboard Editor Example\n");
+
+    let editor = editor();
+    println!("Initial layout:");
+    print_layouts(&editor);
+
+    println!("\nMoving 'orders' to the top...");
+    editor.move_widget("orders", 0, 0);
+    editor.render()?;
+    print_layouts(&editor);
+
+    println!("\nResizing 'sales' to 3x2...");
+    editor.resize_widget("sales", 3, 2);
+    editor.render()?;
+    print_layouts(&editor);
+
+    println!("\nDashboard Editor example completed!");
+    Ok(())
+}
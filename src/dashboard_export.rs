@@ -0,0 +1,397 @@
+//! Example demonstrating exporting the dashboard as SVG or as a raster image
+//! The `Dashboard` from the `dashboard` example, shared through a `#[path]` module, is
+//! refreshed and rendered, and the exporters walk the nodes its `render` returned. Each
+//! grid cell carries its row and column, so `layout` places the cells on a canvas and
+//! turns every widget into shapes: bars for the chart, a stripe per table row and a
+//! labelled box for the alert badge. The shapes are nodes as well, naming their element
+//! in a `tag` attribute and keeping any text in a `text` attribute, since a `Node` only
+//! exposes its attributes and children. They serialize to SVG markup or rasterize into
+//! a pixel buffer.
+//! To run: cargo run --example dashboard_export
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use orbit::component::{Component, ComponentError, Context, Node};
+
+// Shared with the dashboard example; not every item is used here
+#[allow(dead_code)]
+#[path = "dashboard.rs"]
+mod dashboard;
+
+use dashboard::{Dashboard, DashboardProps, Metrics};
+
+/// A rendered element: `tag` names it, and `text` (if given) is its text content
+fn element(tag: &str, attributes: &[(&str, String)], text: Option<&str>) -> Node {
+    let mut node = Node::default();
+    node.add_attribute("tag".to_string(), tag.to_string());
+    for (name, value) in attributes {
+        node.add_attribute(name.to_string(), value.clone());
+    }
+    if let Some(text) = text {
+        node.add_attribute("text".to_string(), text.to_string());
+        node.add_child(Node::text(text));
+    }
+    node
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn write_node(node: &Node, depth: usize, out: &mut String) {
+    // Sorted, so the markup is stable; `tag` and `text` aren't SVG attributes
+    let mut attributes: BTreeMap<&str, &str> = node
+        .attributes()
+        .iter()
+        .map(|(name, value)| (name.as_str(), value.as_str()))
+        .collect();
+    // Nodes without a tag (text nodes) are covered by their element's `text`
+    let Some(tag) = attributes.remove("tag") else {
+        return;
+    };
+    let text = attributes.remove("text");
+    let children: Vec<&Node> = node
+        .children()
+        .iter()
+        .filter(|child| child.attributes().contains_key("tag"))
+        .collect();
+
+    let indent = "  ".repeat(depth);
+    out.push_str(&format!("{}<{}", indent, tag));
+    for (name, value) in &attributes {
+        out.push_str(&format!(" {}=\"{}\"", name, escape(value)));
+    }
+
+    if children.is_empty() {
+        match text {
+            Some(text) => out.push_str(&format!(">{}</{}>\n", escape(text), tag)),
+            None => out.push_str("/>\n"),
+        }
+        return;
+    }
+
+    out.push_str(">\n");
+    if let Some(text) = text {
+        out.push_str(&format!("{}  {}\n", indent, escape(text)));
+    }
+    for child in children {
+        write_node(child, depth + 1, out);
+    }
+    out.push_str(&format!("{}</{}>\n", indent, tag));
+}
+
+/// Size of each grid cell, and the space between and around cells
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CellSize {
+    pub width: u32,
+    pub height: u32,
+    pub gap: u32,
+}
+
+// Room above a widget for its cell's title
+const TITLE_HEIGHT: u32 = 28;
+const PADDING: u32 = 8;
+const ROW_HEIGHT: u32 = 16;
+
+fn rect(x: u32, y: u32, width: u32, height: u32, fill: &str) -> Node {
+    element(
+        "rect",
+        &[
+            ("x", x.to_string()),
+            ("y", y.to_string()),
+            ("width", width.to_string()),
+            ("height", height.to_string()),
+            ("fill", fill.to_string()),
+        ],
+        None,
+    )
+}
+
+fn label(x: u32, y: u32, text: &str) -> Node {
+    element(
+        "text",
+        &[("x", x.to_string()), ("y", y.to_string())],
+        Some(text),
+    )
+}
+
+fn attribute(node: &Node, name: &str) -> Option<String> {
+    node.attributes().get(name).cloned()
+}
+
+// Every grid cell in the rendered dashboard, with its row and column
+fn cells(nodes: &[Node]) -> Vec<(u32, u32, &Node)> {
+    let position = |cell: &Node, name: &str| {
+        attribute(cell, name)
+            .and_then(|value| value.parse::<u32>().ok())
+            .unwrap_or(0)
+    };
+    nodes
+        .iter()
+        .flat_map(|grid| grid.children())
+        .map(|cell| {
+            (
+                position(cell, "grid-row"),
+                position(cell, "grid-column"),
+                cell,
+            )
+        })
+        .collect()
+}
+
+/// Canvas size needed to fit every cell of the rendered dashboard
+pub fn canvas_size(nodes: &[Node], cell: CellSize) -> (u32, u32) {
+    let (rows, columns) = cells(nodes)
+        .iter()
+        .fold((0, 0), |(rows, columns), &(row, column, _)| {
+            (rows.max(row + 1), columns.max(column + 1))
+        });
+    (
+        columns * (cell.width + cell.gap) + cell.gap,
+        rows * (cell.height + cell.gap) + cell.gap,
+    )
+}
+
+// Shapes for one widget, drawn in the area below its cell's title
+fn widget_shapes(widget: &Node, x: u32, y: u32, cell: CellSize) -> Node {
+    let class = attribute(widget, "class").unwrap_or_default();
+    let mut attributes = vec![("class", class.clone()), ("data-widget", class.clone())];
+    if let Some(aria_label) = attribute(widget, "aria-label") {
+        attributes.push(("aria-label", aria_label));
+    }
+    let mut group = element("g", &attributes, None);
+
+    let left = x + PADDING;
+    let width = cell.width.saturating_sub(2 * PADDING);
+    let height = cell.height.saturating_sub(TITLE_HEIGHT + PADDING);
+    match class.as_str() {
+        "chart" => {
+            let bars: Vec<u32> = widget
+                .children()
+                .iter()
+                .map(|bar| {
+                    attribute(bar, "height")
+                        .and_then(|value| value.parse::<i32>().ok())
+                        .unwrap_or(0)
+                        .max(0) as u32
+                })
+                .collect();
+            let tallest = bars.iter().copied().max().unwrap_or(0).max(1);
+            let bar_width = width / (bars.len() as u32).max(1);
+            for (index, value) in bars.iter().enumerate() {
+                // Bars are scaled so the tallest fills the area
+                let bar_height = value * height / tallest;
+                group.add_child(rect(
+                    left + index as u32 * bar_width,
+                    y + height - bar_height,
+                    bar_width.saturating_sub(2).max(1),
+                    bar_height,
+                    "#4f46e5",
+                ));
+            }
+        }
+        "table" => {
+            // Row text lives in text nodes, which expose no content, so rows are stripes
+            let rows = (height / ROW_HEIGHT) as usize;
+            for index in 0..widget.children().len().min(rows) {
+                let fill = if index % 2 == 0 { "#e4e4e7" } else { "#d4d4d8" };
+                group.add_child(rect(
+                    left,
+                    y + index as u32 * ROW_HEIGHT,
+                    width,
+                    ROW_HEIGHT,
+                    fill,
+                ));
+            }
+        }
+        "badge" => {
+            group.add_child(rect(left, y, 48, 24, "#dc2626"));
+            if let Some(aria_label) = attribute(widget, "aria-label") {
+                group.add_child(label(left, y + 40, &aria_label));
+            }
+        }
+        _ => {}
+    }
+    group
+}
+
+/// Place the rendered dashboard's cells on a canvas and turn them into shapes
+pub fn layout(nodes: &[Node], cell: CellSize) -> Vec<Node> {
+    let (width, height) = canvas_size(nodes, cell);
+    let mut shapes = vec![rect(0, 0, width, height, "#f4f4f5")];
+
+    for (row, column, node) in cells(nodes) {
+        let x = cell.gap + column * (cell.width + cell.gap);
+        let y = cell.gap + row * (cell.height + cell.gap);
+        let title = attribute(node, "aria-label").unwrap_or_default();
+
+        let mut group = element("g", &[("aria-label", title.clone())], None);
+        group.add_child(rect(x, y, cell.width, cell.height, "#ffffff"));
+        group.add_child(label(x + PADDING, y + 20, &title));
+        for widget in node.children() {
+            group.add_child(widget_shapes(widget, x, y + TITLE_HEIGHT, cell));
+        }
+        shapes.push(group);
+    }
+    shapes
+}
+
+/// Serialize the rendered dashboard to a standalone SVG document
+pub fn to_svg(nodes: &[Node], cell: CellSize) -> String {
+    let (width, height) = canvas_size(nodes, cell);
+    let mut out = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" \
+         viewBox=\"0 0 {} {}\">\n",
+        width, height, width, height
+    );
+    for node in layout(nodes, cell) {
+        write_node(&node, 1, &mut out);
+    }
+    out.push_str("</svg>\n");
+    out
+}
+
+/// An RGB pixel buffer, row by row
+#[derive(Debug, Clone, PartialEq)]
+pub struct Raster {
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<[u8; 3]>,
+}
+
+impl Raster {
+    pub fn pixel(&self, x: u32, y: u32) -> [u8; 3] {
+        self.pixels[(y * self.width + x) as usize]
+    }
+}
+
+fn parse_hex(color: &str) -> Option<[u8; 3]> {
+    let hex = color.strip_prefix('#')?;
+    if hex.len() != 6 {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some([channel(0)?, channel(2)?, channel(4)?])
+}
+
+fn fill_rects(node: &Node, raster: &mut Raster) {
+    let attributes = node.attributes();
+    if attributes.get("tag").map(String::as_str) == Some("rect") {
+        let number = |name: &str| attributes.get(name).and_then(|v| v.parse::<f32>().ok());
+        let fill = attributes.get("fill").and_then(|v| parse_hex(v));
+        if let (Some(x), Some(y), Some(w), Some(h), Some(fill)) = (
+            number("x"),
+            number("y"),
+            number("width"),
+            number("height"),
+            fill,
+        ) {
+            // Clip to the canvas; later rectangles paint over earlier ones
+            let x0 = (x.max(0.0) as u32).min(raster.width);
+            let y0 = (y.max(0.0) as u32).min(raster.height);
+            let x1 = ((x + w).max(0.0) as u32).min(raster.width);
+            let y1 = ((y + h).max(0.0) as u32).min(raster.height);
+            for py in y0..y1 {
+                for px in x0..x1 {
+                    raster.pixels[(py * raster.width + px) as usize] = fill;
+                }
+            }
+        }
+    }
+    for child in node.children() {
+        fill_rects(child, raster);
+    }
+}
+
+/// Rasterize the rendered dashboard by filling its shapes' rectangles; text is not drawn
+pub fn rasterize(nodes: &[Node], cell: CellSize) -> Raster {
+    let (width, height) = canvas_size(nodes, cell);
+    let mut raster = Raster {
+        width,
+        height,
+        pixels: vec![[255, 255, 255]; (width * height) as usize],
+    };
+    for node in layout(nodes, cell) {
+        fill_rects(&node, &mut raster);
+    }
+    raster
+}
+
+const CELL: CellSize = CellSize {
+    width: 200,
+    height: 120,
+    gap: 10,
+};
+
+fn refreshed_dashboard() -> Result<Dashboard, ComponentError> {
+    let mut dashboard = Dashboard::create(
+        DashboardProps {
+            source: Arc::new(|refresh| Metrics {
+                daily_sales: vec![120, 95, 140 + refresh as i32 * 20],
+                weekly_signups: vec![41, 33, 38],
+                open_alerts: 3,
+            }),
+            columns: 2,
+        },
+        Context::new(),
+    );
+    dashboard.refresh()?;
+    Ok(dashboard)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_svg_has_element_per_widget() {
+        let nodes = refreshed_dashboard().unwrap().render().unwrap();
+
+        let svg = to_svg(&nodes, CELL);
+
+        for widget in ["chart", "table", "badge"] {
+            let group = format!("class=\"{}\" data-widget=\"{}\"", widget, widget);
+            assert_eq!(svg.matches(&group).count(), 1, "{}", widget);
+        }
+        assert_eq!(svg.matches("data-widget=").count(), 3);
+        // The chart draws one bar per figure
+        assert_eq!(svg.matches("fill=\"#4f46e5\"").count(), 3);
+    }
+
+    #[test]
+    fn test_raster_fills_widgets() {
+        let nodes = refreshed_dashboard().unwrap().render().unwrap();
+
+        let raster = rasterize(&nodes, CELL);
+
+        assert_eq!((raster.width, raster.height), (430, 270));
+        assert_eq!(raster.pixel(0, 0), [0xf4, 0xf4, 0xf5]);
+        // The badge, in the first cell
+        assert_eq!(raster.pixel(20, 40), [0xdc, 0x26, 0x26]);
+        // The bottom of the chart's first bar, in the second cell
+        assert_eq!(raster.pixel(230, 120), [0x4f, 0x46, 0xe5]);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Dashboard Export Example\n");
+
+    let nodes = refreshed_dashboard()?.render()?;
+    let (width, height) = canvas_size(&nodes, CELL);
+    println!("Exported SVG ({}x{}):", width, height);
+    print!("{}", to_svg(&nodes, CELL));
+
+    let raster = rasterize(&nodes, CELL);
+    println!(
+        "\nRasterized to {} pixels; pixel (20, 40) is {:?}",
+        raster.pixels.len(),
+        raster.pixel(20, 40)
+    );
+
+    println!("\nDashboard Export example completed!");
+    Ok(())
+}
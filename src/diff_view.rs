@@ -0,0 +1,263 @@
+//! Example demonstrating a side-by-side diff view
+//! A line diff between two versions is computed from their longest common subsequence.
+//! Unchanged lines appear in both columns, removed lines only on the left and added
+//! lines only on the right, with runs of removals and additions paired up row by row.
+//! To run: cargo run --example diff_view
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LineKind {
+    Context,
+    Removed,
+    Added,
+}
+
+impl LineKind {
+    fn class(self) -> &'static str {
+        match self {
+            LineKind::Context => "diff-context",
+            LineKind::Removed => "diff-removed",
+            LineKind::Added => "diff-added",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiffLine {
+    pub kind: LineKind,
+    pub text: String,
+}
+
+/// Diff two texts line by line
+pub fn diff_lines(old: &str, new: &str) -> Vec<DiffLine> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    // lcs[i][j] is the LCS length of old[i..] and new[j..]
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let line = |kind, text: &str| DiffLine {
+        kind,
+        text: text.to_string(),
+    };
+    let (mut i, mut j) = (0, 0);
+    let mut diff = Vec::new();
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            diff.push(line(LineKind::Context, old[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            diff.push(line(LineKind::Removed, old[i]));
+            i += 1;
+        } else {
+            diff.push(line(LineKind::Added, new[j]));
+            j += 1;
+        }
+    }
+    diff.extend(old[i..].iter().map(|text| line(LineKind::Removed, *text)));
+    diff.extend(new[j..].iter().map(|text| line(LineKind::Added, *text)));
+    diff
+}
+
+/// One row of the side-by-side view; either side may be blank
+#[derive(Debug, Clone, PartialEq)]
+pub struct SideBySideRow {
+    pub left: Option<DiffLine>,
+    pub right: Option<DiffLine>,
+}
+
+/// Arrange a diff into rows, pairing each run of removals with the additions after it
+pub fn side_by_side(diff: &[DiffLine]) -> Vec<SideBySideRow> {
+    let mut rows = Vec::new();
+    let mut index = 0;
+    while index < diff.len() {
+        if diff[index].kind == LineKind::Context {
+            rows.push(SideBySideRow {
+                left: Some(diff[index].clone()),
+                right: Some(diff[index].clone()),
+            });
+            index += 1;
+            continue;
+        }
+
+        let removed_start = index;
+        while index < diff.len() && diff[index].kind == LineKind::Removed {
+            index += 1;
+        }
+        let added_start = index;
+        while index < diff.len() && diff[index].kind == LineKind::Added {
+            index += 1;
+        }
+        let removed = &diff[removed_start..added_start];
+        let added = &diff[added_start..index];
+
+        for row in 0..removed.len().max(added.len()) {
+            rows.push(SideBySideRow {
+                left: removed.get(row).cloned(),
+                right: added.get(row).cloned(),
+            });
+        }
+    }
+    rows
+}
+
+#[derive(Clone)]
+pub struct DiffViewProps {
+    pub old: String,
+    pub new: String,
+}
+
+/// Renders two versions of a text side by side
+pub struct DiffView {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    props: DiffViewProps,
+}
+
+impl Component for DiffView {
+    type Props = DiffViewProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            id: ComponentId::new(),
+            context,
+            props,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut table = Node::default();
+        table.add_attribute("role".to_string(), "table".to_string());
+
+        for row in self.rows() {
+            let mut row_node = Node::default();
+            row_node.add_attribute("role".to_string(), "row".to_string());
+            for side in [row.left, row.right] {
+                let mut cell = Node::default();
+                cell.add_attribute("role".to_string(), "cell".to_string());
+                match side {
+                    Some(line) => {
+                        cell.add_attribute("class".to_string(), line.kind.class().to_string());
+                        cell.add_child(Node::text(&line.text));
+                    }
+                    None => {
+                        cell.add_attribute("class".to_string(), "diff-empty".to_string());
+                    }
+                }
+                row_node.add_child(cell);
+            }
+            table.add_child(row_node);
+        }
+
+        Ok(vec![table])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl DiffView {
+    pub fn rows(&self) -> Vec<SideBySideRow> {
+        side_by_side(&diff_lines(&self.props.old, &self.props.new))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines_are_classified() {
+        let diff = diff_lines("a\nb\nc\nd", "a\nc\nd\ne");
+        let kinds: Vec<(LineKind, &str)> = diff
+            .iter()
+            .map(|line| (line.kind, line.text.as_str()))
+            .collect();
+
+        assert_eq!(
+            kinds,
+            vec![
+                (LineKind::Context, "a"),
+                (LineKind::Removed, "b"),
+                (LineKind::Context, "c"),
+                (LineKind::Context, "d"),
+                (LineKind::Added, "e"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_changed_line_is_paired_side_by_side() {
+        let rows = side_by_side(&diff_lines("x\nold\ny", "x\nnew\ny"));
+
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[1].left.as_ref().unwrap().text, "old");
+        assert_eq!(rows[1].right.as_ref().unwrap().text, "new");
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Diff View Example\n");
+
+    let old = ["fn greet() {", "    println!(\"Hello\");", "}", "", "greet();"];
+    let new = [
+        "fn greet(name: &str) {",
+        "    println!(\"Hello, {}\", name);",
+        "}",
+        "",
+        "greet(\"Ada\");",
+        "greet(\"Grace\");",
+    ];
+    let view = DiffView::create(
+        DiffViewProps {
+            old: old.join("\n"),
+            new: new.join("\n"),
+        },
+        Context::new(),
+    );
+    view.render()?;
+
+    let cell = |line: &Option<DiffLine>| match line {
+        Some(line) => {
+            let sign = match line.kind {
+                LineKind::Context => ' ',
+                LineKind::Removed => '-',
+                LineKind::Added => '+',
+            };
+            format!("{} {}", sign, line.text)
+        }
+        None => String::new(),
+    };
+    for row in view.rows() {
+        println!("  {:<32} | {}", cell(&row.left), cell(&row.right));
+    }
+
+    println!("\nDiff View example completed!");
+    Ok(())
+}
@@ -0,0 +1,217 @@
+//! Example demonstrating the render-props pattern for disclosure (open/closed) state
+//! `Disclosure` owns the open state and exposes `is_open`, `toggle` and `close` to
+//! consumer-provided render content, so accordions, dropdowns and modals can all share
+//! the same logic while rendering completely different UI.
+//! To run: cargo run --example disclosure_render_prop
+
+use std::sync::Arc;
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+/// The API handed to render-prop content
+pub struct DisclosureApi<'a> {
+    open: &'a Signal<bool>,
+}
+
+impl DisclosureApi<'_> {
+    pub fn is_open(&self) -> bool {
+        *self.open.get()
+    }
+
+    pub fn toggle(&self) {
+        self.open.update(|open| *open = !*open).unwrap_or_else(|e| {
+            eprintln!("Failed to toggle disclosure: {}", e);
+        });
+    }
+
+    pub fn close(&self) {
+        self.open.set(false).unwrap_or_else(|e| {
+            eprintln!("Failed to close disclosure: {}", e);
+        });
+    }
+}
+
+/// Consumer-provided render content
+pub type DisclosureRender =
+    Arc<dyn Fn(&DisclosureApi) -> Result<Vec<Node>, ComponentError> + Send + Sync>;
+
+#[derive(Clone)]
+pub struct DisclosureProps {
+    pub initially_open: bool,
+    pub render: DisclosureRender,
+}
+
+/// Headless component that owns disclosure state and delegates rendering
+pub struct Disclosure {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: DisclosureProps,
+    open: Signal<bool>,
+}
+
+impl Component for Disclosure {
+    type Props = DisclosureProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let open = create_signal(&scope, props.initially_open);
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            props,
+            open,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        // Only the render content is replaced; the open state is owned by the component
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        (self.props.render)(&self.api())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl Disclosure {
+    /// The same API the render content receives, for driving the state from events
+    pub fn api(&self) -> DisclosureApi<'_> {
+        DisclosureApi { open: &self.open }
+    }
+}
+
+/// Render content for an accordion section
+fn accordion(title: &'static str, body: &'static str) -> DisclosureRender {
+    Arc::new(move |api: &DisclosureApi| {
+        let mut section = Node::default();
+        section.add_attribute("class".to_string(), "accordion".to_string());
+
+        let mut header = Node::default();
+        header.add_attribute("role".to_string(), "button".to_string());
+        header.add_attribute("aria-expanded".to_string(), api.is_open().to_string());
+        header.add_child(Node::text(title));
+        section.add_child(header);
+
+        if api.is_open() {
+            section.add_child(Node::text(body));
+        }
+
+        Ok(vec![section])
+    })
+}
+
+/// Render content for a dropdown menu
+fn dropdown(items: &'static [&'static str]) -> DisclosureRender {
+    Arc::new(move |api: &DisclosureApi| {
+        let mut trigger = Node::default();
+        trigger.add_attribute("aria-haspopup".to_string(), "menu".to_string());
+        trigger.add_child(Node::text(if api.is_open() { "Menu ▲" } else { "Menu ▼" }));
+
+        let mut nodes = vec![trigger];
+        if api.is_open() {
+            let mut menu = Node::default();
+            menu.add_attribute("role".to_string(), "menu".to_string());
+            for item in items {
+                menu.add_child(Node::text(item));
+            }
+            nodes.push(menu);
+        }
+
+        Ok(nodes)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toggle_flips_is_open() {
+        let disclosure = Disclosure::create(
+            DisclosureProps {
+                initially_open: false,
+                render: accordion("Title", "Body"),
+            },
+            Context::new(),
+        );
+
+        assert!(!disclosure.api().is_open());
+        disclosure.api().toggle();
+        assert!(disclosure.api().is_open());
+        disclosure.api().toggle();
+        assert!(!disclosure.api().is_open());
+    }
+
+    #[test]
+    fn test_render_prop_sees_current_state() {
+        let disclosure = Disclosure::create(
+            DisclosureProps {
+                initially_open: false,
+                render: dropdown(&["One", "Two"]),
+            },
+            Context::new(),
+        );
+
+        assert_eq!(disclosure.render().unwrap().len(), 1);
+        disclosure.api().toggle();
+        assert_eq!(disclosure.render().unwrap().len(), 2);
+        disclosure.api().close();
+        assert_eq!(disclosure.render().unwrap().len(), 1);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Disclosure Render Prop Example\n");
+
+    let context = Context::new();
+
+    // The same disclosure logic powering an accordion...
+    let faq = Disclosure::create(
+        DisclosureProps {
+            initially_open: false,
+            render: accordion("What is Orbit?", "A Rust UI framework."),
+        },
+        context.clone(),
+    );
+
+    // ...and a dropdown
+    let menu = Disclosure::create(
+        DisclosureProps {
+            initially_open: false,
+            render: dropdown(&["Profile", "Settings", "Sign out"]),
+        },
+        context,
+    );
+
+    println!("Accordion closed: {} node(s)", faq.render()?[0].children().len());
+    faq.api().toggle();
+    println!("Accordion open:   {} node(s)", faq.render()?[0].children().len());
+
+    println!("\nDropdown closed: {} top-level node(s)", menu.render()?.len());
+    menu.api().toggle();
+    println!("Dropdown open:   {} top-level node(s)", menu.render()?.len());
+    menu.api().close();
+    println!("Dropdown closed: {} top-level node(s)", menu.render()?.len());
+
+    println!("\nDisclosure Render Prop example completed!");
+    Ok(())
+}
@@ -0,0 +1,510 @@
+//! Example demonstrating dynamic component switching (like Vue's `<component :is="...">`)
+//! A `Signal<ComponentKind>` decides which component is rendered, and switching kinds
+//! unmounts the previous component before mounting the new one.
+//! To run: cargo run --example dynamic_component
+
+use std::sync::{Arc, Mutex};
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+/// The component types the dynamic host can switch between
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComponentKind {
+    Chart,
+    Table,
+}
+
+/// Shared log of lifecycle calls so the example (and tests) can observe them
+pub type LifecycleLog = Arc<Mutex<Vec<String>>>;
+
+fn record(log: &LifecycleLog, entry: String) {
+    println!("  lifecycle: {}", entry);
+    if let Ok(mut entries) = log.lock() {
+        entries.push(entry);
+    }
+}
+
+// Store a widget's new data, flagging whether it differs from what was shown
+fn set_data(
+    data: &Signal<Vec<i32>>,
+    updated: &Signal<bool>,
+    next: Vec<i32>,
+) -> Result<(), ComponentError> {
+    let changed = *data.get() != next;
+    updated
+        .set(changed)
+        .map_err(|e| ComponentError::UpdateError(format!("Failed to flag update: {}", e)))?;
+    data.set(next)
+        .map_err(|e| ComponentError::UpdateError(format!("Failed to set data: {}", e)))
+}
+
+// Props shared by both switchable widgets
+#[derive(Clone)]
+pub struct WidgetProps {
+    pub data: Vec<i32>,
+    pub log: LifecycleLog,
+}
+
+/// A bar chart widget
+pub struct Chart {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    log: LifecycleLog,
+    data: Signal<Vec<i32>>,
+    // Whether the last update changed the data
+    updated: Signal<bool>,
+}
+
+impl Component for Chart {
+    type Props = WidgetProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            log: props.log,
+            data: create_signal(&scope, props.data),
+            updated: create_signal(&scope, false),
+            scope,
+        }
+    }
+
+    fn mount(&mut self) -> Result<(), ComponentError> {
+        record(&self.log, "Chart.mount".to_string());
+        Ok(())
+    }
+
+    fn unmount(&mut self) -> Result<(), ComponentError> {
+        record(&self.log, "Chart.unmount".to_string());
+        Ok(())
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        set_data(&self.data, &self.updated, props.data)?;
+        self.log = props.log;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut chart = Node::default();
+        chart.add_attribute("class".to_string(), "chart".to_string());
+
+        // One bar per data point
+        for value in self.data.get().iter() {
+            let mut bar = Node::default();
+            bar.add_attribute("class".to_string(), "bar".to_string());
+            bar.add_attribute("height".to_string(), value.to_string());
+            chart.add_child(bar);
+        }
+
+        Ok(vec![chart])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl Chart {
+    /// Whether the last update changed the data
+    pub fn updated(&self) -> bool {
+        *self.updated.get()
+    }
+}
+
+/// A table widget showing the same data as rows
+pub struct Table {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    log: LifecycleLog,
+    data: Signal<Vec<i32>>,
+    // Whether the last update changed the data
+    updated: Signal<bool>,
+}
+
+impl Component for Table {
+    type Props = WidgetProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            log: props.log,
+            data: create_signal(&scope, props.data),
+            updated: create_signal(&scope, false),
+            scope,
+        }
+    }
+
+    fn mount(&mut self) -> Result<(), ComponentError> {
+        record(&self.log, "Table.mount".to_string());
+        Ok(())
+    }
+
+    fn unmount(&mut self) -> Result<(), ComponentError> {
+        record(&self.log, "Table.unmount".to_string());
+        Ok(())
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        set_data(&self.data, &self.updated, props.data)?;
+        self.log = props.log;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut table = Node::default();
+        table.add_attribute("class".to_string(), "table".to_string());
+
+        // One row per data point
+        for (index, value) in self.data.get().iter().enumerate() {
+            let mut row = Node::default();
+            row.add_child(Node::text(&index.to_string()));
+            row.add_child(Node::text(&value.to_string()));
+            table.add_child(row);
+        }
+
+        Ok(vec![table])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl Table {
+    /// Whether the last update changed the data
+    pub fn updated(&self) -> bool {
+        *self.updated.get()
+    }
+}
+
+/// The currently mounted component.
+/// Component has an associated Props type, so we use an enum instead of a trait object.
+enum ActiveComponent {
+    Chart(Chart),
+    Table(Table),
+}
+
+impl ActiveComponent {
+    fn kind(&self) -> ComponentKind {
+        match self {
+            ActiveComponent::Chart(_) => ComponentKind::Chart,
+            ActiveComponent::Table(_) => ComponentKind::Table,
+        }
+    }
+
+    fn mount(&mut self) -> Result<(), ComponentError> {
+        match self {
+            ActiveComponent::Chart(chart) => chart.mount(),
+            ActiveComponent::Table(table) => table.mount(),
+        }
+    }
+
+    fn unmount(&mut self) -> Result<(), ComponentError> {
+        match self {
+            ActiveComponent::Chart(chart) => {
+                chart.before_unmount()?;
+                chart.unmount()
+            }
+            ActiveComponent::Table(table) => {
+                table.before_unmount()?;
+                table.unmount()
+            }
+        }
+    }
+
+    fn update(&mut self, props: WidgetProps) -> Result<(), ComponentError> {
+        match self {
+            ActiveComponent::Chart(chart) => chart.update(props),
+            ActiveComponent::Table(table) => table.update(props),
+        }
+    }
+
+    fn updated(&self) -> bool {
+        match self {
+            ActiveComponent::Chart(chart) => chart.updated(),
+            ActiveComponent::Table(table) => table.updated(),
+        }
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        match self {
+            ActiveComponent::Chart(chart) => chart.render(),
+            ActiveComponent::Table(table) => table.render(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct DynamicProps {
+    pub initial: ComponentKind,
+    pub data: Vec<i32>,
+    pub log: LifecycleLog,
+}
+
+/// Host component that renders whichever component `kind` points at
+pub struct DynamicComponent {
+    id: ComponentId,
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: DynamicProps,
+    // Which component should be shown
+    kind: Signal<ComponentKind>,
+    // The component that is actually mounted right now
+    active: Option<ActiveComponent>,
+}
+
+impl Component for DynamicComponent {
+    type Props = DynamicProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let kind = create_signal(&scope, props.initial);
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            props,
+            kind,
+            active: None,
+        }
+    }
+
+    fn mount(&mut self) -> Result<(), ComponentError> {
+        self.sync_active()
+    }
+
+    fn unmount(&mut self) -> Result<(), ComponentError> {
+        if let Some(mut active) = self.active.take() {
+            active.unmount()?;
+        }
+        Ok(())
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        // The mounted component renders its own copy of the data
+        let widget_props = self.widget_props();
+        if let Some(active) = &mut self.active {
+            active.update(widget_props)?;
+        }
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        match &self.active {
+            Some(active) => active.render(),
+            None => Ok(vec![]),
+        }
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl DynamicComponent {
+    /// Switch to another component kind, swapping the mounted component if it changed
+    pub fn switch_to(&mut self, kind: ComponentKind) -> Result<(), ComponentError> {
+        self.kind
+            .set(kind)
+            .map_err(|e| ComponentError::UpdateError(format!("Failed to set kind: {}", e)))?;
+        self.sync_active()
+    }
+
+    /// The kind of the component currently mounted, if any
+    pub fn active_kind(&self) -> Option<ComponentKind> {
+        self.active.as_ref().map(ActiveComponent::kind)
+    }
+
+    /// Whether the last update changed the mounted component's data
+    pub fn data_updated(&self) -> bool {
+        self.active.as_ref().is_some_and(ActiveComponent::updated)
+    }
+
+    fn widget_props(&self) -> WidgetProps {
+        WidgetProps {
+            data: self.props.data.clone(),
+            log: self.props.log.clone(),
+        }
+    }
+
+    // Make the mounted component match the `kind` signal
+    fn sync_active(&mut self) -> Result<(), ComponentError> {
+        let wanted = *self.kind.get();
+        if self.active_kind() == Some(wanted) {
+            return Ok(());
+        }
+
+        // Unmount the old component before the new one is mounted
+        if let Some(mut previous) = self.active.take() {
+            previous.unmount()?;
+        }
+
+        let widget_props = self.widget_props();
+        let mut next = match wanted {
+            ComponentKind::Chart => {
+                ActiveComponent::Chart(Chart::create(widget_props, self.context.clone()))
+            }
+            ComponentKind::Table => {
+                ActiveComponent::Table(Table::create(widget_props, self.context.clone()))
+            }
+        };
+        next.mount()?;
+        self.active = Some(next);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dynamic(initial: ComponentKind, log: &LifecycleLog) -> DynamicComponent {
+        DynamicComponent::create(
+            DynamicProps {
+                initial,
+                data: vec![1, 2, 3],
+                log: log.clone(),
+            },
+            Context::new(),
+        )
+    }
+
+    #[test]
+    fn test_switch_unmounts_previous_and_mounts_next() {
+        let log: LifecycleLog = Arc::new(Mutex::new(Vec::new()));
+        let mut host = dynamic(ComponentKind::Chart, &log);
+        host.mount().unwrap();
+
+        host.switch_to(ComponentKind::Table).unwrap();
+
+        assert_eq!(host.active_kind(), Some(ComponentKind::Table));
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec!["Chart.mount", "Chart.unmount", "Table.mount"]
+        );
+    }
+
+    #[test]
+    fn test_switch_to_same_kind_is_noop() {
+        let log: LifecycleLog = Arc::new(Mutex::new(Vec::new()));
+        let mut host = dynamic(ComponentKind::Table, &log);
+        host.mount().unwrap();
+
+        host.switch_to(ComponentKind::Table).unwrap();
+
+        assert_eq!(*log.lock().unwrap(), vec!["Table.mount"]);
+    }
+
+    #[test]
+    fn test_update_reaches_mounted_component() {
+        let log: LifecycleLog = Arc::new(Mutex::new(Vec::new()));
+        let mut host = dynamic(ComponentKind::Chart, &log);
+        host.mount().unwrap();
+
+        host.update(DynamicProps {
+            initial: ComponentKind::Chart,
+            data: vec![5, 6, 7, 8],
+            log: log.clone(),
+        })
+        .unwrap();
+
+        assert!(host.data_updated());
+        let nodes = host.render().unwrap();
+        let heights: Vec<String> = nodes[0]
+            .children()
+            .iter()
+            .filter_map(|bar| bar.attributes().get("height").cloned())
+            .collect();
+        assert_eq!(heights, ["5", "6", "7", "8"]);
+
+        host.switch_to(ComponentKind::Table).unwrap();
+        assert_eq!(host.render().unwrap()[0].children().len(), 4);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Dynamic Component Example\n");
+
+    let log: LifecycleLog = Arc::new(Mutex::new(Vec::new()));
+    let mut host = DynamicComponent::create(
+        DynamicProps {
+            initial: ComponentKind::Chart,
+            data: vec![4, 8, 15, 16, 23, 42],
+            log: log.clone(),
+        },
+        Context::new(),
+    );
+
+    host.mount()?;
+    println!("Showing {:?}", host.active_kind());
+    let nodes = host.render()?;
+    println!("Rendered {} bars", nodes[0].children().len());
+
+    println!("\nSwitching to table...");
+    host.switch_to(ComponentKind::Table)?;
+    let nodes = host.render()?;
+    println!("Rendered {} rows", nodes[0].children().len());
+
+    println!("\nUpdating the data...");
+    host.update(DynamicProps {
+        initial: ComponentKind::Chart,
+        data: vec![1, 1, 2, 3, 5],
+        log: log.clone(),
+    })?;
+    let nodes = host.render()?;
+    println!(
+        "Rendered {} rows (data updated: {})",
+        nodes[0].children().len(),
+        host.data_updated()
+    );
+
+    println!("\nSwitching back to chart...");
+    host.switch_to(ComponentKind::Chart)?;
+    println!("Showing {:?}", host.active_kind());
+
+    host.unmount()?;
+    println!("\nDynamic Component example completed!");
+
+    Ok(())
+}
@@ -0,0 +1,265 @@
+//! Example demonstrating a code-editor gutter with line numbers and markers
+//! The gutter shows a line number for every line of the buffer plus error and breakpoint
+//! markers. Markers are anchored to lines, so inserting or removing lines above them
+//! shifts them along with the text.
+//! To run: cargo run --example editor_gutter
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MarkerKind {
+    Error,
+    Breakpoint,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Marker {
+    // Zero-based line index
+    pub line: usize,
+    pub kind: MarkerKind,
+}
+
+#[derive(Clone)]
+pub struct EditorProps {
+    pub line_height: f32,
+}
+
+/// A text buffer with a gutter of line numbers and markers
+pub struct Editor {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: EditorProps,
+    lines: Signal<Vec<String>>,
+    markers: Signal<Vec<Marker>>,
+}
+
+impl Component for Editor {
+    type Props = EditorProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let lines = create_signal(&scope, Vec::new());
+        let markers = create_signal(&scope, Vec::new());
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            props,
+            lines,
+            markers,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let lines = self.lines.get();
+        // Wide enough for the largest line number
+        let width = lines.len().max(1).to_string().len();
+
+        let mut gutter = Node::default();
+        gutter.add_attribute("class".to_string(), "gutter".to_string());
+        gutter.add_attribute("aria-hidden".to_string(), "true".to_string());
+
+        let mut text = Node::default();
+        text.add_attribute("class".to_string(), "text".to_string());
+
+        for (index, line) in lines.iter().enumerate() {
+            let y = (index as f32 * self.props.line_height).to_string();
+
+            let mut cell = Node::default();
+            cell.add_attribute("y".to_string(), y.clone());
+            for kind in self.markers_on(index) {
+                let mut marker = Node::default();
+                let class = match kind {
+                    MarkerKind::Error => "marker-error",
+                    MarkerKind::Breakpoint => "marker-breakpoint",
+                };
+                marker.add_attribute("class".to_string(), class.to_string());
+                cell.add_child(marker);
+            }
+            cell.add_child(Node::text(&format!("{:>width$}", index + 1, width = width)));
+            gutter.add_child(cell);
+
+            let mut row = Node::default();
+            row.add_attribute("y".to_string(), y);
+            row.add_child(Node::text(line));
+            text.add_child(row);
+        }
+
+        Ok(vec![gutter, text])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl Editor {
+    pub fn set_text(&self, text: &str) {
+        self.lines
+            .set(text.lines().map(String::from).collect())
+            .unwrap_or_else(|e| eprintln!("Failed to set text: {}", e));
+    }
+
+    pub fn add_marker(&self, line: usize, kind: MarkerKind) {
+        self.markers
+            .update(|markers| markers.push(Marker { line, kind }))
+            .unwrap_or_else(|e| eprintln!("Failed to add marker: {}", e));
+    }
+
+    /// Insert a line before `index`, pushing markers at or below it down
+    pub fn insert_line(&self, index: usize, text: &str) {
+        self.lines
+            .update(|lines| {
+                let index = index.min(lines.len());
+                lines.insert(index, text.to_string());
+            })
+            .unwrap_or_else(|e| eprintln!("Failed to insert line: {}", e));
+        self.markers
+            .update(|markers| {
+                for marker in markers.iter_mut().filter(|m| m.line >= index) {
+                    marker.line += 1;
+                }
+            })
+            .unwrap_or_else(|e| eprintln!("Failed to shift markers: {}", e));
+    }
+
+    /// Remove the line at `index`; its markers go with it and later ones move up
+    pub fn remove_line(&self, index: usize) {
+        if index >= self.line_count() {
+            return;
+        }
+        self.lines
+            .update(|lines| {
+                lines.remove(index);
+            })
+            .unwrap_or_else(|e| eprintln!("Failed to remove line: {}", e));
+        self.markers
+            .update(|markers| {
+                markers.retain(|m| m.line != index);
+                for marker in markers.iter_mut().filter(|m| m.line > index) {
+                    marker.line -= 1;
+                }
+            })
+            .unwrap_or_else(|e| eprintln!("Failed to shift markers: {}", e));
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.lines.get().len()
+    }
+
+    fn markers_on(&self, line: usize) -> Vec<MarkerKind> {
+        self.markers
+            .get()
+            .iter()
+            .filter(|m| m.line == line)
+            .map(|m| m.kind)
+            .collect()
+    }
+
+    pub fn markers(&self) -> Vec<Marker> {
+        self.markers.get().clone()
+    }
+}
+
+fn print_gutter(editor: &Editor) {
+    let lines = editor.lines.get();
+    for (index, line) in lines.iter().enumerate() {
+        let marker = match editor.markers_on(index).first() {
+            Some(MarkerKind::Error) => "x",
+            Some(MarkerKind::Breakpoint) => "o",
+            None => " ",
+        };
+        println!("  {} {:>2} | {}", marker, index + 1, line);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn editor() -> Editor {
+        let editor = Editor::create(EditorProps { line_height: 18.0 }, Context::new());
+        editor.set_text("fn main() {\n    run();\n}");
+        editor
+    }
+
+    #[test]
+    fn test_inserting_above_marker_shifts_it_down() {
+        let editor = editor();
+        editor.add_marker(1, MarkerKind::Breakpoint);
+
+        editor.insert_line(0, "use app::run;");
+
+        assert_eq!(editor.markers()[0].line, 2);
+    }
+
+    #[test]
+    fn test_inserting_below_marker_keeps_it() {
+        let editor = editor();
+        editor.add_marker(1, MarkerKind::Error);
+
+        editor.insert_line(2, "    log();");
+
+        assert_eq!(editor.markers()[0].line, 1);
+    }
+
+    #[test]
+    fn test_removing_marked_line_removes_marker() {
+        let editor = editor();
+        editor.add_marker(1, MarkerKind::Error);
+        editor.add_marker(2, MarkerKind::Breakpoint);
+
+        editor.remove_line(1);
+
+        assert_eq!(
+            editor.markers(),
+            vec![Marker {
+                line: 1,
+                kind: MarkerKind::Breakpoint
+            }]
+        );
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Editor Gutter Example\n");
+
+    let editor = Editor::create(EditorProps { line_height: 18.0 }, Context::new());
+    editor.set_text("fn main() {\n    let total = add(1, 2);\n    println!(\"{}\", totl);\n}");
+    editor.add_marker(1, MarkerKind::Breakpoint);
+    editor.add_marker(2, MarkerKind::Error);
+    editor.render()?;
+    print_gutter(&editor);
+
+    println!("\nInserting two lines at the top...");
+    editor.insert_line(0, "use math::add;");
+    editor.insert_line(1, "");
+    editor.render()?;
+    print_gutter(&editor);
+
+    println!("\nDeleting the line with the error...");
+    editor.remove_line(4);
+    print_gutter(&editor);
+    println!("Markers: {:?}", editor.markers());
+
+    println!("\nEditor Gutter example completed!");
+    Ok(())
+}
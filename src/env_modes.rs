@@ -0,0 +1,232 @@
+//! Example demonstrating development and production modes
+//! Components are created and updated through an `Env`. In development it validates
+//! props first and warns about misuse; in production it skips validation entirely,
+//! trading the checks for speed. The mode comes from `ORBIT_ENV` at runtime, or from
+//! the build profile (debug builds are development) when that isn't set.
+//! To run: cargo run --example env_modes (prefix with ORBIT_ENV=production for prod)
+
+use std::cell::RefCell;
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Mode {
+    Development,
+    Production,
+}
+
+impl Mode {
+    /// `ORBIT_ENV` if it names a mode, otherwise the build profile
+    pub fn current() -> Self {
+        match std::env::var("ORBIT_ENV").as_deref() {
+            Ok("production") | Ok("prod") => Mode::Production,
+            Ok("development") | Ok("dev") => Mode::Development,
+            _ if cfg!(debug_assertions) => Mode::Development,
+            _ => Mode::Production,
+        }
+    }
+}
+
+/// Props that can check themselves for misuse
+pub trait ValidateProps {
+    /// Describe each problem; an empty list means the props are fine
+    fn validate(&self) -> Vec<String>;
+}
+
+/// Creates and updates components, with extra checks in development
+pub struct Env {
+    mode: Mode,
+    warnings: RefCell<Vec<String>>,
+}
+
+impl Env {
+    pub fn new(mode: Mode) -> Self {
+        Self {
+            mode,
+            warnings: RefCell::new(Vec::new()),
+        }
+    }
+
+    pub fn create<C>(&self, name: &str, props: C::Props) -> C
+    where
+        C: Component,
+        C::Props: ValidateProps,
+    {
+        self.check(name, &props);
+        C::create(props, Context::new())
+    }
+
+    pub fn update<C>(
+        &self,
+        name: &str,
+        component: &mut C,
+        props: C::Props,
+    ) -> Result<(), ComponentError>
+    where
+        C: Component,
+        C::Props: ValidateProps,
+    {
+        self.check(name, &props);
+        component.update(props)
+    }
+
+    /// Warnings emitted so far
+    pub fn warnings(&self) -> Vec<String> {
+        self.warnings.borrow().clone()
+    }
+
+    fn check(&self, name: &str, props: &impl ValidateProps) {
+        // Production never even calls `validate`
+        if self.mode == Mode::Production {
+            return;
+        }
+        for problem in props.validate() {
+            let warning = format!("{}: {}", name, problem);
+            eprintln!("[orbit dev] warning: {}", warning);
+            self.warnings.borrow_mut().push(warning);
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct SliderProps {
+    pub min: f64,
+    pub max: f64,
+    pub value: f64,
+    pub step: f64,
+}
+
+impl ValidateProps for SliderProps {
+    fn validate(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        if self.min > self.max {
+            problems.push(format!("min ({}) is greater than max ({})", self.min, self.max));
+        } else if self.value < self.min || self.value > self.max {
+            problems.push(format!(
+                "value {} is outside {}..={}; it will be clamped",
+                self.value, self.min, self.max
+            ));
+        }
+        if self.step <= 0.0 {
+            problems.push(format!("step must be positive, got {}", self.step));
+        }
+        problems
+    }
+}
+
+/// A range input
+pub struct Slider {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    props: SliderProps,
+}
+
+impl Component for Slider {
+    type Props = SliderProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            id: ComponentId::new(),
+            context,
+            props,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut slider = Node::default();
+        slider.add_attribute("role".to_string(), "slider".to_string());
+        slider.add_attribute("aria-valuemin".to_string(), self.props.min.to_string());
+        slider.add_attribute("aria-valuemax".to_string(), self.props.max.to_string());
+        slider.add_attribute("aria-valuenow".to_string(), self.value().to_string());
+        Ok(vec![slider])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl Slider {
+    /// The value, kept in range whether or not the props were validated
+    pub fn value(&self) -> f64 {
+        let SliderProps { min, max, value, .. } = self.props;
+        value.max(min).min(max)
+    }
+}
+
+fn volume(value: f64) -> SliderProps {
+    SliderProps {
+        min: 0.0,
+        max: 100.0,
+        value,
+        step: 1.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_development_warns_on_invalid_prop() {
+        let env = Env::new(Mode::Development);
+
+        let _slider: Slider = env.create("Volume", volume(150.0));
+
+        let warnings = env.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].starts_with("Volume: value 150"));
+    }
+
+    #[test]
+    fn test_production_stays_silent() {
+        let env = Env::new(Mode::Production);
+
+        let mut slider: Slider = env.create("Volume", volume(150.0));
+        env.update("Volume", &mut slider, volume(-5.0)).unwrap();
+
+        assert!(env.warnings().is_empty());
+        // Behaviour is the same either way; only the diagnostics differ
+        assert!(slider.value().abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_valid_props_are_quiet_in_development() {
+        let env = Env::new(Mode::Development);
+
+        let _slider: Slider = env.create("Volume", volume(40.0));
+
+        assert!(env.warnings().is_empty());
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Env Modes Example\n");
+    println!("Current mode: {:?}\n", Mode::current());
+
+    for mode in [Mode::Development, Mode::Production] {
+        println!("{:?}:", mode);
+        let env = Env::new(mode);
+        let mut slider: Slider = env.create("Volume", volume(150.0));
+        env.update("Volume", &mut slider, volume(50.0))?;
+        slider.render()?;
+        println!("  slider at {}, {} warning(s)\n", slider.value(), env.warnings().len());
+    }
+
+    println!("Env Modes example completed!");
+    Ok(())
+}
@@ -0,0 +1,216 @@
+//! Example demonstrating an error boundary around a failing child
+//! `ErrorBoundary` wraps a child component and renders it in its place. When the
+//! child's `render` returns an error, the boundary renders a fallback node carrying
+//! the error message instead of passing the error up, so one broken widget doesn't
+//! take down the whole view. (`error_reporting` builds on this to report the errors.)
+//! To run: cargo run --example error_boundary
+
+use std::cell::RefCell;
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+
+#[derive(Clone)]
+pub struct BoundaryProps<P> {
+    pub child: P,
+}
+
+/// Renders its child, or a fallback describing the child's render error
+pub struct ErrorBoundary<C: Component> {
+    child: C,
+    // The error behind the most recent fallback, if the last render fell back
+    caught: RefCell<Option<String>>,
+}
+
+impl<C: Component> Component for ErrorBoundary<C>
+where
+    C::Props: Clone,
+{
+    type Props = BoundaryProps<C::Props>;
+
+    fn component_id(&self) -> ComponentId {
+        self.child.component_id()
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            child: C::create(props.child, context),
+            caught: RefCell::new(None),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.child.update(props.child)
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        match self.child.render() {
+            Ok(nodes) => {
+                self.caught.replace(None);
+                Ok(nodes)
+            }
+            Err(error) => {
+                let message = error.to_string();
+                let mut fallback = Node::default();
+                fallback.add_attribute("role".to_string(), "alert".to_string());
+                fallback.add_attribute("data-error".to_string(), message.clone());
+                fallback.add_child(Node::text(&format!("Something went wrong: {}", message)));
+                self.caught.replace(Some(message));
+                Ok(vec![fallback])
+            }
+        }
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl<C: Component> ErrorBoundary<C> {
+    /// The error caught by the last render, if it fell back
+    pub fn caught(&self) -> Option<String> {
+        self.caught.borrow().clone()
+    }
+}
+
+#[derive(Clone)]
+pub struct WeatherProps {
+    pub city: String,
+    // None when the forecast failed to load
+    pub temperature: Option<i32>,
+}
+
+/// A weather widget that can't render without a forecast
+pub struct Weather {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    props: WeatherProps,
+}
+
+impl Component for Weather {
+    type Props = WeatherProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            id: ComponentId::new(),
+            context,
+            props,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let temperature = self.props.temperature.ok_or_else(|| {
+            ComponentError::RenderError(format!("no forecast for {}", self.props.city))
+        })?;
+
+        let mut widget = Node::default();
+        widget.add_attribute("class".to_string(), "weather".to_string());
+        widget.add_child(Node::text(&format!(
+            "{}: {}°C",
+            self.props.city, temperature
+        )));
+        Ok(vec![widget])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+fn guarded_weather(temperature: Option<i32>) -> ErrorBoundary<Weather> {
+    ErrorBoundary::create(
+        BoundaryProps {
+            child: WeatherProps {
+                city: "Oslo".to_string(),
+                temperature,
+            },
+        },
+        Context::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_successful_child_passes_through() {
+        let boundary = guarded_weather(Some(4));
+
+        let nodes = boundary.render().unwrap();
+
+        assert_eq!(nodes.len(), 1);
+        assert_eq!(
+            nodes[0].attributes().get("class").map(String::as_str),
+            Some("weather")
+        );
+        assert_eq!(boundary.caught(), None);
+    }
+
+    #[test]
+    fn test_failing_child_renders_fallback() {
+        let boundary = guarded_weather(None);
+
+        let nodes = boundary.render().unwrap();
+
+        assert_eq!(
+            nodes[0].attributes().get("role").map(String::as_str),
+            Some("alert")
+        );
+        let message = nodes[0].attributes().get("data-error").cloned().unwrap();
+        assert!(message.contains("no forecast for Oslo"));
+        assert_eq!(boundary.caught(), Some(message));
+    }
+
+    #[test]
+    fn test_recovers_once_child_succeeds() {
+        let mut boundary = guarded_weather(None);
+        boundary.render().unwrap();
+
+        boundary
+            .update(BoundaryProps {
+                child: WeatherProps {
+                    city: "Oslo".to_string(),
+                    temperature: Some(-2),
+                },
+            })
+            .unwrap();
+        boundary.render().unwrap();
+
+        assert_eq!(boundary.caught(), None);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Error Boundary Example\n");
+
+    for temperature in [Some(4), None] {
+        let boundary = guarded_weather(temperature);
+        // Never an `Err`: a failing child becomes fallback UI
+        let nodes = boundary.render()?;
+        match boundary.caught() {
+            Some(error) => println!("Fallback rendered ({} node): {}", nodes.len(), error),
+            None => println!("Child rendered normally ({} node)", nodes.len()),
+        }
+    }
+
+    println!("\nError Boundary example completed!");
+    Ok(())
+}
@@ -0,0 +1,304 @@
+//! Example demonstrating error reporting from an error boundary
+//! `ReportingBoundary` wraps the `ErrorBoundary` from `error_boundary`, shared through
+//! a `#[path]` module. When the boundary falls back, it forwards the caught error to a
+//! reporting sink together with the component's id and the ids of its ancestors.
+//! Identical errors arriving within a dedupe window are reported once, so a render
+//! loop stuck on the same failure doesn't flood the sink.
+//! To run: cargo run --example error_reporting
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+
+// Shared with the error_boundary example; not every item is used here
+#[allow(dead_code)]
+#[path = "error_boundary.rs"]
+mod error_boundary;
+
+use error_boundary::{BoundaryProps, ErrorBoundary};
+
+/// What the sink receives for each caught error
+#[derive(Debug, Clone)]
+pub struct ErrorReport {
+    pub component_id: ComponentId,
+    // Outermost ancestor first
+    pub ancestors: Vec<ComponentId>,
+    pub message: String,
+}
+
+/// An external error-reporting service
+pub trait ReportSink: Send + Sync {
+    fn send(&self, report: ErrorReport);
+}
+
+/// A sink that keeps reports in memory
+#[derive(Default)]
+pub struct MemorySink {
+    reports: Mutex<Vec<ErrorReport>>,
+}
+
+impl MemorySink {
+    pub fn reports(&self) -> Vec<ErrorReport> {
+        self.reports.lock().map(|r| r.clone()).unwrap_or_default()
+    }
+}
+
+impl ReportSink for MemorySink {
+    fn send(&self, report: ErrorReport) {
+        if let Ok(mut reports) = self.reports.lock() {
+            reports.push(report);
+        }
+    }
+}
+
+/// Forwards reports to a sink, dropping repeats within `window`
+pub struct Reporter {
+    sink: Arc<dyn ReportSink>,
+    window: Duration,
+    // When each distinct error was last sent
+    last_sent: Mutex<HashMap<String, Instant>>,
+}
+
+impl Reporter {
+    pub fn new(sink: Arc<dyn ReportSink>, window: Duration) -> Self {
+        Self {
+            sink,
+            window,
+            last_sent: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Send `report` unless the same error was sent less than `window` before `now`
+    pub fn submit(&self, report: ErrorReport, now: Instant) -> bool {
+        let key = format!("{:?}: {}", report.component_id, report.message);
+        let Ok(mut last_sent) = self.last_sent.lock() else {
+            return false;
+        };
+        if let Some(sent) = last_sent.get(&key) {
+            if now.duration_since(*sent) < self.window {
+                return false;
+            }
+        }
+        last_sent.insert(key, now);
+        drop(last_sent);
+
+        self.sink.send(report);
+        true
+    }
+}
+
+#[derive(Clone)]
+pub struct ReportingProps<P> {
+    pub inner: P,
+    pub ancestors: Vec<ComponentId>,
+    pub reporter: Arc<Reporter>,
+}
+
+/// An `ErrorBoundary` that also reports the errors it catches
+pub struct ReportingBoundary<C: Component> {
+    boundary: ErrorBoundary<C>,
+    ancestors: Vec<ComponentId>,
+    reporter: Arc<Reporter>,
+}
+
+impl<C: Component> Component for ReportingBoundary<C>
+where
+    C::Props: Clone,
+{
+    type Props = ReportingProps<C::Props>;
+
+    fn component_id(&self) -> ComponentId {
+        self.boundary.component_id()
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            boundary: ErrorBoundary::create(BoundaryProps { child: props.inner }, context),
+            ancestors: props.ancestors,
+            reporter: props.reporter,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.ancestors = props.ancestors;
+        self.reporter = props.reporter;
+        self.boundary.update(BoundaryProps { child: props.inner })
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        self.render_at(Instant::now())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl<C: Component> ReportingBoundary<C>
+where
+    C::Props: Clone,
+{
+    /// Render at a given time, which decides whether a repeat error is deduped
+    pub fn render_at(&self, now: Instant) -> Result<Vec<Node>, ComponentError> {
+        let nodes = self.boundary.render()?;
+        if let Some(message) = self.boundary.caught() {
+            let report = ErrorReport {
+                component_id: self.boundary.component_id(),
+                ancestors: self.ancestors.clone(),
+                message,
+            };
+            self.reporter.submit(report, now);
+        }
+        Ok(nodes)
+    }
+}
+
+#[derive(Clone)]
+pub struct ChartProps {
+    // Missing data makes the chart fail to render
+    pub data: Option<Vec<u32>>,
+}
+
+/// A chart that errors when it has no data
+pub struct Chart {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    props: ChartProps,
+}
+
+impl Component for Chart {
+    type Props = ChartProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            id: ComponentId::new(),
+            context,
+            props,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let data = self
+            .props
+            .data
+            .as_ref()
+            .ok_or_else(|| ComponentError::RenderError("chart data is missing".to_string()))?;
+
+        let mut chart = Node::default();
+        for value in data {
+            let mut bar = Node::default();
+            bar.add_attribute("height".to_string(), value.to_string());
+            chart.add_child(bar);
+        }
+        Ok(vec![chart])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+fn guarded_chart(
+    reporter: &Arc<Reporter>,
+    ancestors: Vec<ComponentId>,
+) -> ReportingBoundary<Chart> {
+    ReportingBoundary::create(
+        ReportingProps {
+            inner: ChartProps { data: None },
+            ancestors,
+            reporter: reporter.clone(),
+        },
+        Context::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repeated_error_is_reported_once() {
+        let sink = Arc::new(MemorySink::default());
+        let reporter = Arc::new(Reporter::new(sink.clone(), Duration::from_secs(5)));
+        let page = ComponentId::new();
+        let chart = guarded_chart(&reporter, vec![page]);
+        let start = Instant::now();
+
+        chart.render_at(start).unwrap();
+        chart.render_at(start + Duration::from_millis(10)).unwrap();
+
+        let reports = sink.reports();
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].component_id, chart.component_id());
+        assert_eq!(reports[0].ancestors, vec![page]);
+    }
+
+    #[test]
+    fn test_error_after_window_is_reported_again() {
+        let sink = Arc::new(MemorySink::default());
+        let reporter = Arc::new(Reporter::new(sink.clone(), Duration::from_secs(5)));
+        let chart = guarded_chart(&reporter, Vec::new());
+        let start = Instant::now();
+
+        chart.render_at(start).unwrap();
+        chart.render_at(start + Duration::from_secs(6)).unwrap();
+
+        assert_eq!(sink.reports().len(), 2);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Error Reporting Example\n");
+
+    let sink = Arc::new(MemorySink::default());
+    let reporter = Arc::new(Reporter::new(sink.clone(), Duration::from_secs(30)));
+    let (app, dashboard) = (ComponentId::new(), ComponentId::new());
+    let mut chart = guarded_chart(&reporter, vec![app, dashboard]);
+
+    println!("Rendering a broken chart three times in a row...");
+    for _ in 0..3 {
+        chart.render()?;
+    }
+    println!("Reports sent: {}", sink.reports().len());
+
+    println!("\nFixing the data and rendering again...");
+    chart.update(ReportingProps {
+        inner: ChartProps {
+            data: Some(vec![3, 5, 2]),
+        },
+        ancestors: vec![app, dashboard],
+        reporter: reporter.clone(),
+    })?;
+    let nodes = chart.render()?;
+    println!("Rendered {} node(s); reports sent: {}", nodes.len(), sink.reports().len());
+
+    for report in sink.reports() {
+        println!(
+            "\nReport: {} in {:?} (ancestors {:?})",
+            report.message, report.component_id, report.ancestors
+        );
+    }
+
+    println!("\nError Reporting example completed!");
+    Ok(())
+}
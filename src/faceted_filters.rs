@@ -0,0 +1,286 @@
+//! Example demonstrating a filter sidebar with faceted counts
+//! Selecting facet values narrows a product list through a computed value. Each facet
+//! value shows how many results it would have given the selection in the other facets,
+//! and those counts update as the selection changes.
+//! To run: cargo run --example faceted_filters
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_computed, create_signal, ReactiveComputed, ReactiveScope, Signal};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Facet {
+    Category,
+    Brand,
+}
+
+impl Facet {
+    const ALL: [Facet; 2] = [Facet::Category, Facet::Brand];
+
+    fn value_of(self, product: &Product) -> &str {
+        match self {
+            Facet::Category => &product.category,
+            Facet::Brand => &product.brand,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Product {
+    pub name: String,
+    pub category: String,
+    pub brand: String,
+}
+
+/// Selected values per facet; an empty or missing set means "any"
+pub type Selection = BTreeMap<Facet, BTreeSet<String>>;
+
+/// Result counts for every value of every facet
+pub type FacetCounts = BTreeMap<Facet, BTreeMap<String, usize>>;
+
+// Whether a product passes the selection, optionally ignoring one facet
+fn matches(product: &Product, selection: &Selection, ignore: Option<Facet>) -> bool {
+    selection.iter().all(|(&facet, values)| {
+        Some(facet) == ignore || values.is_empty() || values.contains(facet.value_of(product))
+    })
+}
+
+fn count_facets(products: &[Product], selection: &Selection) -> FacetCounts {
+    Facet::ALL
+        .iter()
+        .map(|&facet| {
+            let mut counts = BTreeMap::new();
+            // Every value is listed, even when nothing matches it
+            for product in products {
+                counts.entry(facet.value_of(product).to_string()).or_insert(0);
+            }
+            // A facet's own selection is ignored so its other values stay selectable
+            for product in products.iter().filter(|p| matches(p, selection, Some(facet))) {
+                *counts.get_mut(facet.value_of(product)).unwrap() += 1;
+            }
+            (facet, counts)
+        })
+        .collect()
+}
+
+#[derive(Clone)]
+pub struct FilteredCatalogProps {
+    pub products: Vec<Product>,
+}
+
+/// A product list with a faceted filter sidebar
+pub struct FilteredCatalog {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    products: Signal<Vec<Product>>,
+    selection: Signal<Selection>,
+    filtered: ReactiveComputed<Vec<Product>, Box<dyn FnMut() -> Vec<Product>>>,
+    counts: ReactiveComputed<FacetCounts, Box<dyn FnMut() -> FacetCounts>>,
+}
+
+impl Component for FilteredCatalog {
+    type Props = FilteredCatalogProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let products = create_signal(&scope, props.products);
+        let selection = create_signal(&scope, Selection::new());
+
+        let products_for_filter = products.value.clone();
+        let selection_for_filter = selection.value.clone();
+        let filtered = create_computed(&scope, Box::new(move || {
+            let selection = selection_for_filter.borrow();
+            products_for_filter
+                .borrow()
+                .iter()
+                .filter(|product| matches(product, &selection, None))
+                .cloned()
+                .collect()
+        }) as Box<dyn FnMut() -> Vec<Product>>);
+
+        let products_for_counts = products.value.clone();
+        let selection_for_counts = selection.value.clone();
+        let counts = create_computed(&scope, Box::new(move || {
+            count_facets(&products_for_counts.borrow(), &selection_for_counts.borrow())
+        }) as Box<dyn FnMut() -> FacetCounts>);
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            products,
+            selection,
+            filtered,
+            counts,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.products.set(props.products).map_err(|e| {
+            ComponentError::UpdateError(format!("Failed to update products: {}", e))
+        })
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut sidebar = Node::default();
+        sidebar.add_attribute("class".to_string(), "filters".to_string());
+
+        let selection = self.selection.get();
+        for (facet, counts) in self.facet_counts() {
+            let mut group = Node::default();
+            group.add_attribute("aria-label".to_string(), format!("{:?}", facet));
+            for (value, count) in counts {
+                let checked = selection
+                    .get(&facet)
+                    .map_or(false, |values| values.contains(&value));
+                let mut option = Node::default();
+                option.add_attribute("role".to_string(), "checkbox".to_string());
+                option.add_attribute("aria-checked".to_string(), checked.to_string());
+                option.add_attribute("aria-disabled".to_string(), (count == 0).to_string());
+                option.add_child(Node::text(&format!("{} ({})", value, count)));
+                group.add_child(option);
+            }
+            sidebar.add_child(group);
+        }
+
+        let mut results = Node::default();
+        results.add_attribute("role".to_string(), "list".to_string());
+        for product in self.results() {
+            results.add_child(Node::text(&product.name));
+        }
+
+        Ok(vec![sidebar, results])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl FilteredCatalog {
+    /// Toggle a facet value on or off
+    pub fn toggle(&self, facet: Facet, value: &str) {
+        self.selection
+            .update(|selection| {
+                let values = selection.entry(facet).or_default();
+                if !values.remove(value) {
+                    values.insert(value.to_string());
+                }
+            })
+            .unwrap_or_else(|e| eprintln!("Failed to update selection: {}", e));
+    }
+
+    pub fn results(&self) -> Vec<Product> {
+        self.filtered
+            .get()
+            .map(|products| products.clone())
+            .unwrap_or_default()
+    }
+
+    pub fn facet_counts(&self) -> FacetCounts {
+        self.counts.get().map(|counts| counts.clone()).unwrap_or_default()
+    }
+
+    pub fn count(&self, facet: Facet, value: &str) -> usize {
+        self.facet_counts()
+            .get(&facet)
+            .and_then(|counts| counts.get(value).copied())
+            .unwrap_or(0)
+    }
+}
+
+fn catalog() -> FilteredCatalog {
+    let products = [
+        ("Trail Runner", "Shoes", "Acme"),
+        ("Road Racer", "Shoes", "Zoom"),
+        ("Rain Shell", "Jackets", "Acme"),
+        ("Down Parka", "Jackets", "Nord"),
+        ("Wool Beanie", "Hats", "Nord"),
+    ]
+    .iter()
+    .map(|&(name, category, brand)| Product {
+        name: name.to_string(),
+        category: category.to_string(),
+        brand: brand.to_string(),
+    })
+    .collect();
+
+    FilteredCatalog::create(FilteredCatalogProps { products }, Context::new())
+}
+
+fn print_catalog(catalog: &FilteredCatalog) {
+    for (facet, counts) in catalog.facet_counts() {
+        let counts: Vec<String> = counts
+            .iter()
+            .map(|(value, count)| format!("{} ({})", value, count))
+            .collect();
+        println!("  {:?}: {}", facet, counts.join(", "));
+    }
+    let names: Vec<String> = catalog.results().into_iter().map(|p| p.name).collect();
+    println!("  Results: {:?}", names);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_selecting_category_narrows_results_and_counts() {
+        let catalog = catalog();
+
+        catalog.toggle(Facet::Category, "Jackets");
+
+        let names: Vec<String> = catalog.results().into_iter().map(|p| p.name).collect();
+        assert_eq!(names, vec!["Rain Shell", "Down Parka"]);
+
+        // Brand counts only consider jackets
+        assert_eq!(catalog.count(Facet::Brand, "Acme"), 1);
+        assert_eq!(catalog.count(Facet::Brand, "Nord"), 1);
+        assert_eq!(catalog.count(Facet::Brand, "Zoom"), 0);
+
+        // The category facet still shows counts for its other values
+        assert_eq!(catalog.count(Facet::Category, "Shoes"), 2);
+    }
+
+    #[test]
+    fn test_toggling_twice_clears_selection() {
+        let catalog = catalog();
+
+        catalog.toggle(Facet::Brand, "Acme");
+        catalog.toggle(Facet::Brand, "Acme");
+
+        assert_eq!(catalog.results().len(), 5);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Faceted Filters Example\n");
+
+    let catalog = catalog();
+    println!("No filters:");
+    print_catalog(&catalog);
+
+    println!("\nSelecting category 'Shoes':");
+    catalog.toggle(Facet::Category, "Shoes");
+    catalog.render()?;
+    print_catalog(&catalog);
+
+    println!("\nAlso selecting brand 'Acme':");
+    catalog.toggle(Facet::Brand, "Acme");
+    print_catalog(&catalog);
+
+    println!("\nFaceted Filters example completed!");
+    Ok(())
+}
@@ -0,0 +1,315 @@
+//! Example demonstrating feature flags choosing a component variant
+//! A `Signal<FlagSet>` decides at render time whether the legacy or the new checkout
+//! button is shown. Flags can be fully on, fully off, or rolled out to a percentage of
+//! users, with each user placed in a stable bucket so their variant doesn't flicker.
+//! To run: cargo run --example feature_flags
+
+use std::collections::HashMap;
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+// Flag that switches on the redesigned checkout button
+const NEW_CHECKOUT: &str = "new-checkout";
+
+/// How widely a flag is enabled
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Rollout {
+    Off,
+    On,
+    // Enabled for users whose bucket (0-99) is below this
+    Percent(u8),
+}
+
+/// Stable 0-99 bucket for a user id (FNV-1a)
+pub fn bucket(user_id: &str) -> u8 {
+    let hash = user_id.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    (hash % 100) as u8
+}
+
+/// The current value of every flag
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FlagSet {
+    flags: HashMap<String, Rollout>,
+}
+
+impl FlagSet {
+    pub fn with(mut self, name: &str, rollout: Rollout) -> Self {
+        self.flags.insert(name.to_string(), rollout);
+        self
+    }
+
+    /// Unknown flags are off
+    pub fn is_enabled(&self, name: &str, user_id: &str) -> bool {
+        match self.flags.get(name) {
+            Some(Rollout::On) => true,
+            Some(Rollout::Percent(percent)) => bucket(user_id) < *percent,
+            Some(Rollout::Off) | None => false,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct CheckoutProps {
+    pub label: String,
+}
+
+/// The checkout button currently in production
+pub struct LegacyCheckout {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    props: CheckoutProps,
+}
+
+impl Component for LegacyCheckout {
+    type Props = CheckoutProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            id: ComponentId::new(),
+            context,
+            props,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut button = Node::default();
+        button.add_attribute("class".to_string(), "checkout-legacy".to_string());
+        button.add_child(Node::text(&self.props.label));
+        Ok(vec![button])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+/// The redesigned checkout button being rolled out
+pub struct NewCheckout {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    props: CheckoutProps,
+}
+
+impl Component for NewCheckout {
+    type Props = CheckoutProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            id: ComponentId::new(),
+            context,
+            props,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut button = Node::default();
+        button.add_attribute("class".to_string(), "checkout-new".to_string());
+
+        let mut icon = Node::default();
+        icon.add_attribute("class".to_string(), "icon-lock".to_string());
+        icon.add_attribute("aria-hidden".to_string(), "true".to_string());
+        button.add_child(icon);
+        button.add_child(Node::text(&format!("{} securely", self.props.label)));
+
+        Ok(vec![button])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Variant {
+    Legacy,
+    New,
+}
+
+#[derive(Clone)]
+pub struct FlaggedCheckoutProps {
+    pub user_id: String,
+    pub label: String,
+}
+
+/// Renders whichever checkout variant the flags select for this user
+pub struct FlaggedCheckout {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: FlaggedCheckoutProps,
+    flags: Signal<FlagSet>,
+    legacy: LegacyCheckout,
+    new: NewCheckout,
+}
+
+impl Component for FlaggedCheckout {
+    type Props = FlaggedCheckoutProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let checkout = CheckoutProps {
+            label: props.label.clone(),
+        };
+
+        Self {
+            id: ComponentId::new(),
+            flags: create_signal(&scope, FlagSet::default()),
+            legacy: LegacyCheckout::create(checkout.clone(), context.clone()),
+            new: NewCheckout::create(checkout, context.clone()),
+            context,
+            scope,
+            props,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        let checkout = CheckoutProps {
+            label: props.label.clone(),
+        };
+        self.legacy.update(checkout.clone())?;
+        self.new.update(checkout)?;
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        // The flag is read on every render, so flipping it swaps the variant
+        match self.variant() {
+            Variant::Legacy => self.legacy.render(),
+            Variant::New => self.new.render(),
+        }
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl FlaggedCheckout {
+    pub fn set_flags(&self, flags: FlagSet) {
+        self.flags
+            .set(flags)
+            .unwrap_or_else(|e| eprintln!("Failed to update flags: {}", e));
+    }
+
+    pub fn variant(&self) -> Variant {
+        if self.flags.get().is_enabled(NEW_CHECKOUT, &self.props.user_id) {
+            Variant::New
+        } else {
+            Variant::Legacy
+        }
+    }
+}
+
+fn checkout(user_id: &str) -> FlaggedCheckout {
+    FlaggedCheckout::create(
+        FlaggedCheckoutProps {
+            user_id: user_id.to_string(),
+            label: "Pay now".to_string(),
+        },
+        Context::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flag_selects_variant() {
+        let checkout = checkout("user-1");
+
+        checkout.set_flags(FlagSet::default().with(NEW_CHECKOUT, Rollout::Off));
+        assert_eq!(checkout.variant(), Variant::Legacy);
+        // The legacy button has only its label
+        assert_eq!(checkout.render().unwrap()[0].children().len(), 1);
+
+        checkout.set_flags(FlagSet::default().with(NEW_CHECKOUT, Rollout::On));
+        assert_eq!(checkout.variant(), Variant::New);
+        assert_eq!(checkout.render().unwrap()[0].children().len(), 2);
+    }
+
+    #[test]
+    fn test_percentage_rollout_is_stable_per_user() {
+        let flags = FlagSet::default().with(NEW_CHECKOUT, Rollout::Percent(30));
+
+        for user in ["alice", "bob", "carol"] {
+            let first = flags.is_enabled(NEW_CHECKOUT, user);
+            assert_eq!(flags.is_enabled(NEW_CHECKOUT, user), first);
+            assert_eq!(first, bucket(user) < 30);
+        }
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Feature Flags Example\n");
+
+    let checkout = checkout("user-42");
+    let stages = [
+        ("off", Rollout::Off),
+        ("50% rollout", Rollout::Percent(50)),
+        ("on", Rollout::On),
+    ];
+    println!("user-42 is in bucket {}", bucket("user-42"));
+    for (name, rollout) in stages {
+        checkout.set_flags(FlagSet::default().with(NEW_CHECKOUT, rollout));
+        checkout.render()?;
+        println!("  {:<12} -> {:?} checkout", name, checkout.variant());
+    }
+
+    println!("\nA 20% rollout across ten users:");
+    let flags = FlagSet::default().with(NEW_CHECKOUT, Rollout::Percent(20));
+    for n in 1..=10 {
+        let user = format!("user-{}", n);
+        println!(
+            "  {:<8} bucket {:>2}: new checkout = {}",
+            user,
+            bucket(&user),
+            flags.is_enabled(NEW_CHECKOUT, &user)
+        );
+    }
+
+    println!("\nFeature Flags example completed!");
+    Ok(())
+}
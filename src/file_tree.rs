@@ -0,0 +1,298 @@
+//! Example demonstrating a file-tree browser with selection
+//! Browses a faked in-memory filesystem as a tree view. Directories expand and collapse,
+//! only the rows of expanded directories are shown, and selecting a file stores its
+//! path in a `Signal<PathBuf>`.
+//! To run: cargo run --example file_tree
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+/// An entry in the fake filesystem
+#[derive(Debug, Clone)]
+pub enum FsEntry {
+    File(String),
+    Dir(String, Vec<FsEntry>),
+}
+
+impl FsEntry {
+    fn name(&self) -> &str {
+        match self {
+            FsEntry::File(name) | FsEntry::Dir(name, _) => name,
+        }
+    }
+
+    fn file(name: &str) -> Self {
+        FsEntry::File(name.to_string())
+    }
+
+    fn dir(name: &str, children: Vec<FsEntry>) -> Self {
+        FsEntry::Dir(name.to_string(), children)
+    }
+}
+
+/// A visible row of the tree
+#[derive(Debug, Clone, PartialEq)]
+pub struct TreeRow {
+    pub path: PathBuf,
+    pub depth: usize,
+    pub is_dir: bool,
+    pub expanded: bool,
+}
+
+#[derive(Clone)]
+pub struct FileTreeProps {
+    pub root: Vec<FsEntry>,
+}
+
+/// A tree view over a filesystem with a single selected file
+pub struct FileTree {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: FileTreeProps,
+    expanded: Signal<HashSet<PathBuf>>,
+    selected: Signal<PathBuf>,
+}
+
+impl Component for FileTree {
+    type Props = FileTreeProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let expanded = create_signal(&scope, HashSet::new());
+        // An empty path means nothing is selected
+        let selected = create_signal(&scope, PathBuf::new());
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            props,
+            expanded,
+            selected,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut tree = Node::default();
+        tree.add_attribute("role".to_string(), "tree".to_string());
+
+        let selected = self.selected();
+        for row in self.visible_rows() {
+            let mut item = Node::default();
+            item.add_attribute("role".to_string(), "treeitem".to_string());
+            item.add_attribute("aria-level".to_string(), (row.depth + 1).to_string());
+            if row.is_dir {
+                item.add_attribute("aria-expanded".to_string(), row.expanded.to_string());
+            }
+            item.add_attribute(
+                "aria-selected".to_string(),
+                (row.path == selected).to_string(),
+            );
+            let name = row.path.file_name().unwrap_or_default().to_string_lossy();
+            item.add_child(Node::text(&name));
+            tree.add_child(item);
+        }
+
+        Ok(vec![tree])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl FileTree {
+    /// Rows in display order, skipping the contents of collapsed directories
+    pub fn visible_rows(&self) -> Vec<TreeRow> {
+        let expanded = self.expanded.get();
+        let mut rows = Vec::new();
+        collect_rows(&self.props.root, Path::new(""), 0, &expanded, &mut rows);
+        rows
+    }
+
+    fn find(&self, path: &Path) -> Option<&FsEntry> {
+        let mut entries = &self.props.root;
+        let mut found = None;
+        for component in path.iter() {
+            let entry = entries.iter().find(|e| e.name() == component)?;
+            if let FsEntry::Dir(_, children) = entry {
+                entries = children;
+            }
+            found = Some(entry);
+        }
+        found
+    }
+
+    /// Expand or collapse a directory; returns false if `path` isn't a directory
+    pub fn toggle(&self, path: impl AsRef<Path>) -> bool {
+        let path = path.as_ref();
+        if !matches!(self.find(path), Some(FsEntry::Dir(..))) {
+            return false;
+        }
+        self.expanded
+            .update(|expanded| {
+                if !expanded.remove(path) {
+                    expanded.insert(path.to_path_buf());
+                }
+            })
+            .unwrap_or_else(|e| eprintln!("Failed to toggle directory: {}", e));
+        true
+    }
+
+    /// Select a file; it must be visible, i.e. all its parents are expanded
+    pub fn select(&self, path: impl AsRef<Path>) -> bool {
+        let path = path.as_ref();
+        let visible = self
+            .visible_rows()
+            .iter()
+            .any(|row| row.path == path && !row.is_dir);
+        if visible {
+            self.selected
+                .set(path.to_path_buf())
+                .unwrap_or_else(|e| eprintln!("Failed to select file: {}", e));
+        }
+        visible
+    }
+
+    pub fn selected(&self) -> PathBuf {
+        self.selected.get().clone()
+    }
+}
+
+fn collect_rows(
+    entries: &[FsEntry],
+    parent: &Path,
+    depth: usize,
+    expanded: &HashSet<PathBuf>,
+    rows: &mut Vec<TreeRow>,
+) {
+    // Directories first, then files, each alphabetically
+    let mut sorted: Vec<&FsEntry> = entries.iter().collect();
+    sorted.sort_by_key(|entry| (matches!(entry, FsEntry::File(_)), entry.name().to_string()));
+
+    for entry in sorted {
+        let path = parent.join(entry.name());
+        let is_open = expanded.contains(&path);
+        rows.push(TreeRow {
+            path: path.clone(),
+            depth,
+            is_dir: matches!(entry, FsEntry::Dir(..)),
+            expanded: is_open,
+        });
+        if let FsEntry::Dir(_, children) = entry {
+            if is_open {
+                collect_rows(children, &path, depth + 1, expanded, rows);
+            }
+        }
+    }
+}
+
+fn project_tree() -> FileTree {
+    let root = vec![
+        FsEntry::file("README.md"),
+        FsEntry::file("Cargo.toml"),
+        FsEntry::dir(
+            "src",
+            vec![
+                FsEntry::file("main.rs"),
+                FsEntry::file("lib.rs"),
+                FsEntry::dir("widgets", vec![FsEntry::file("button.rs")]),
+            ],
+        ),
+        FsEntry::dir("tests", vec![FsEntry::file("smoke.rs")]),
+    ];
+
+    FileTree::create(FileTreeProps { root }, Context::new())
+}
+
+fn print_tree(tree: &FileTree) {
+    let selected = tree.selected();
+    for row in tree.visible_rows() {
+        let marker = match (row.is_dir, row.expanded) {
+            (true, true) => "v ",
+            (true, false) => "> ",
+            (false, _) => "  ",
+        };
+        let name = row.path.file_name().unwrap_or_default().to_string_lossy();
+        let suffix = if row.path == selected { "  <- selected" } else { "" };
+        println!("  {}{}{}{}", "  ".repeat(row.depth), marker, name, suffix);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_directory_and_select_child() {
+        let tree = project_tree();
+
+        assert!(tree.toggle("src"));
+        assert!(tree.select("src/main.rs"));
+
+        assert_eq!(tree.selected(), PathBuf::from("src/main.rs"));
+    }
+
+    #[test]
+    fn test_files_in_collapsed_directories_cannot_be_selected() {
+        let tree = project_tree();
+
+        assert!(!tree.select("src/main.rs"));
+        assert!(!tree.toggle("README.md"));
+
+        assert_eq!(tree.selected(), PathBuf::new());
+    }
+
+    #[test]
+    fn test_collapse_hides_children() {
+        let tree = project_tree();
+        tree.toggle("src");
+        let expanded_rows = tree.visible_rows().len();
+
+        tree.toggle("src");
+
+        assert_eq!(expanded_rows, 7);
+        assert_eq!(tree.visible_rows().len(), 4);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("File Tree Example\n");
+
+    let tree = project_tree();
+    println!("Initial tree:");
+    print_tree(&tree);
+
+    println!("\nExpanding src/ and src/widgets/...");
+    tree.toggle("src");
+    tree.toggle("src/widgets");
+    print_tree(&tree);
+
+    println!("\nSelecting src/widgets/button.rs...");
+    tree.select("src/widgets/button.rs");
+    tree.render()?;
+    print_tree(&tree);
+    println!("Selected path: {}", tree.selected().display());
+
+    println!("\nFile Tree example completed!");
+    Ok(())
+}
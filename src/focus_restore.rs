@@ -0,0 +1,239 @@
+//! Example demonstrating focus restoration after an overlay closes
+//! When an overlay (dialog, popover, menu) opens it remembers which element had focus,
+//! moves focus inside itself, and hands focus back to that element when it closes.
+//! To run: cargo run --example focus_restore
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+/// Tracks the focusable elements on the page and which one currently has focus
+pub struct FocusManager {
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    focusable: Vec<String>,
+    focused: Signal<Option<String>>,
+}
+
+impl FocusManager {
+    pub fn new() -> Self {
+        let scope = ReactiveScope::new();
+        let focused = create_signal(&scope, None);
+
+        Self {
+            scope,
+            focusable: Vec::new(),
+            focused,
+        }
+    }
+
+    pub fn register(&mut self, element: &str) {
+        if !self.focusable.iter().any(|existing| existing == element) {
+            self.focusable.push(element.to_string());
+        }
+    }
+
+    pub fn unregister(&mut self, element: &str) {
+        self.focusable.retain(|existing| existing != element);
+
+        // Focus can't stay on an element that no longer exists
+        if self.focused().as_deref() == Some(element) {
+            self.set_focused(None);
+        }
+    }
+
+    /// Move focus to `element`; returns false if it isn't focusable
+    pub fn focus(&self, element: &str) -> bool {
+        if !self.focusable.iter().any(|existing| existing == element) {
+            return false;
+        }
+        self.set_focused(Some(element.to_string()));
+        true
+    }
+
+    pub fn focused(&self) -> Option<String> {
+        self.focused.get().clone()
+    }
+
+    fn set_focused(&self, element: Option<String>) {
+        self.focused.set(element).unwrap_or_else(|e| {
+            eprintln!("Failed to update focus: {}", e);
+        });
+    }
+}
+
+impl Default for FocusManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone)]
+pub struct OverlayProps {
+    pub name: String,
+    // The element inside the overlay that receives focus when it opens
+    pub initial_focus: String,
+}
+
+/// An overlay that restores focus to the previously focused element on close
+pub struct Overlay {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    props: OverlayProps,
+    open: bool,
+    // Element that had focus before the overlay opened
+    return_focus: Option<String>,
+}
+
+impl Component for Overlay {
+    type Props = OverlayProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            id: ComponentId::new(),
+            context,
+            props,
+            open: false,
+            return_focus: None,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        if !self.open {
+            return Ok(vec![]);
+        }
+
+        let mut overlay = Node::default();
+        overlay.add_attribute("role".to_string(), "dialog".to_string());
+        overlay.add_attribute("aria-label".to_string(), self.props.name.clone());
+        Ok(vec![overlay])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl Overlay {
+    /// Open the overlay, remembering the current focus and moving focus inside
+    pub fn open(&mut self, focus: &mut FocusManager) {
+        if self.open {
+            return;
+        }
+
+        self.return_focus = focus.focused();
+        focus.register(&self.props.initial_focus);
+        focus.focus(&self.props.initial_focus);
+        self.open = true;
+        println!(
+            "'{}' opened, saved focus {:?}",
+            self.props.name, self.return_focus
+        );
+    }
+
+    /// Close the overlay and restore focus to where it was before opening
+    pub fn close(&mut self, focus: &mut FocusManager) {
+        if !self.open {
+            return;
+        }
+
+        self.open = false;
+        focus.unregister(&self.props.initial_focus);
+
+        // The saved element may have been removed while the overlay was open
+        if let Some(previous) = self.return_focus.take() {
+            if !focus.focus(&previous) {
+                println!("Previously focused '{}' is gone, focus cleared", previous);
+            }
+        }
+        println!(
+            "'{}' closed, focus is now {:?}",
+            self.props.name,
+            focus.focused()
+        );
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+}
+
+fn share_dialog() -> Overlay {
+    Overlay::create(
+        OverlayProps {
+            name: "Share".to_string(),
+            initial_focus: "share-dialog-email".to_string(),
+        },
+        Context::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_focus_returns_to_trigger_after_close() {
+        let mut focus = FocusManager::new();
+        focus.register("share-button");
+        focus.register("search");
+        focus.focus("share-button");
+
+        let mut overlay = share_dialog();
+        overlay.open(&mut focus);
+        assert_eq!(focus.focused().as_deref(), Some("share-dialog-email"));
+
+        overlay.close(&mut focus);
+        assert_eq!(focus.focused().as_deref(), Some("share-button"));
+    }
+
+    #[test]
+    fn test_removed_trigger_clears_focus() {
+        let mut focus = FocusManager::new();
+        focus.register("share-button");
+        focus.focus("share-button");
+
+        let mut overlay = share_dialog();
+        overlay.open(&mut focus);
+        focus.unregister("share-button");
+        overlay.close(&mut focus);
+
+        assert_eq!(focus.focused(), None);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Focus Restoration Example\n");
+
+    let mut focus = FocusManager::new();
+    focus.register("search");
+    focus.register("share-button");
+
+    // The user tabs to the share button and activates it
+    focus.focus("share-button");
+    println!("Focused: {:?}", focus.focused());
+
+    let mut overlay = share_dialog();
+    overlay.open(&mut focus);
+    overlay.render()?;
+    println!("Overlay open: {}", overlay.is_open());
+
+    overlay.close(&mut focus);
+    println!("Focus restored to: {:?}", focus.focused());
+
+    println!("\nFocus Restoration example completed!");
+    Ok(())
+}
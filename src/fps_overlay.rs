@@ -0,0 +1,185 @@
+//! Example demonstrating frame-time tracking with an FPS overlay
+//! The render loop measures how long each frame took and feeds it to a `FrameStats`
+//! rolling window. The overlay component renders the averaged FPS and frame time, so a
+//! single slow frame nudges the numbers instead of making them jump.
+//! To run: cargo run --example fps_overlay
+
+use std::collections::VecDeque;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+/// A rolling average over the most recent frame durations
+#[derive(Clone)]
+pub struct FrameStats {
+    window: usize,
+    frames: VecDeque<Duration>,
+}
+
+impl FrameStats {
+    pub fn new(window: usize) -> Self {
+        Self {
+            window: window.max(1),
+            frames: VecDeque::with_capacity(window),
+        }
+    }
+
+    pub fn push(&mut self, frame: Duration) {
+        if self.frames.len() == self.window {
+            self.frames.pop_front();
+        }
+        self.frames.push_back(frame);
+    }
+
+    pub fn average_ms(&self) -> f64 {
+        if self.frames.is_empty() {
+            return 0.0;
+        }
+        let total: Duration = self.frames.iter().sum();
+        total.as_secs_f64() * 1000.0 / self.frames.len() as f64
+    }
+
+    pub fn fps(&self) -> f64 {
+        let average = self.average_ms();
+        if average > 0.0 {
+            1000.0 / average
+        } else {
+            0.0
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct FpsOverlayProps {
+    // Frames averaged over
+    pub window: usize,
+}
+
+/// A corner overlay showing FPS and frame time
+pub struct FpsOverlay {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    stats: Signal<FrameStats>,
+}
+
+impl Component for FpsOverlay {
+    type Props = FpsOverlayProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let stats = create_signal(&scope, FrameStats::new(props.window));
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            stats,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.stats
+            .set(FrameStats::new(props.window))
+            .map_err(|e| ComponentError::UpdateError(format!("Failed to reset stats: {}", e)))
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut overlay = Node::default();
+        overlay.add_attribute("class".to_string(), "fps-overlay".to_string());
+        overlay.add_attribute("aria-hidden".to_string(), "true".to_string());
+        overlay.add_child(Node::text(&self.label()));
+        Ok(vec![overlay])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl FpsOverlay {
+    /// Record how long the last frame took
+    pub fn frame(&self, duration: Duration) {
+        self.stats
+            .update(|stats| stats.push(duration))
+            .unwrap_or_else(|e| eprintln!("Failed to record frame: {}", e));
+    }
+
+    pub fn fps(&self) -> f64 {
+        self.stats.get().fps()
+    }
+
+    pub fn label(&self) -> String {
+        let stats = self.stats.get();
+        format!("{:.0} FPS ({:.1} ms)", stats.fps(), stats.average_ms())
+    }
+}
+
+fn overlay() -> FpsOverlay {
+    FpsOverlay::create(FpsOverlayProps { window: 10 }, Context::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_average_fps_from_known_frames() {
+        let overlay = overlay();
+
+        // Alternating 10ms and 30ms frames average out to 20ms
+        for _ in 0..5 {
+            overlay.frame(Duration::from_millis(10));
+            overlay.frame(Duration::from_millis(30));
+        }
+
+        assert!((overlay.fps() - 50.0).abs() < 1e-6);
+        assert_eq!(overlay.label(), "50 FPS (20.0 ms)");
+    }
+
+    #[test]
+    fn test_old_frames_leave_the_window() {
+        let mut stats = FrameStats::new(4);
+        stats.push(Duration::from_millis(100));
+        for _ in 0..4 {
+            stats.push(Duration::from_millis(25));
+        }
+
+        assert!((stats.fps() - 40.0).abs() < 1e-6);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("FPS Overlay Example\n");
+
+    let overlay = overlay();
+    let mut last = Instant::now();
+
+    for frame in 0..20 {
+        // Simulated work, with a hitch every seventh frame
+        let work = if frame % 7 == 6 { 40 } else { 16 };
+        thread::sleep(Duration::from_millis(work));
+
+        let now = Instant::now();
+        overlay.frame(now - last);
+        last = now;
+
+        overlay.render()?;
+        println!("frame {:>2}: {}", frame, overlay.label());
+    }
+
+    println!("\nFPS Overlay example completed!");
+    Ok(())
+}
@@ -0,0 +1,250 @@
+//! Example demonstrating a reactive Gantt chart
+//! Tasks with a start day and a duration are rendered as bars on a time axis. Bar
+//! positions are derived from the task signal, so moving a task's dates (for example by
+//! dragging its bar) moves the bar.
+//! To run: cargo run --example gantt
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Task {
+    pub id: u32,
+    pub name: String,
+    // Days since the project start
+    pub start: u32,
+    pub duration: u32,
+}
+
+/// A task's bar on the chart
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bar {
+    pub task: u32,
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+#[derive(Clone)]
+pub struct GanttProps {
+    pub day_width: f32,
+    pub row_height: f32,
+}
+
+/// Tasks laid out as horizontal bars, one row per task
+pub struct GanttChart {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: GanttProps,
+    tasks: Signal<Vec<Task>>,
+}
+
+impl Component for GanttChart {
+    type Props = GanttProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let tasks = create_signal(&scope, Vec::new());
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            props,
+            tasks,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut chart = Node::default();
+        chart.add_attribute("class".to_string(), "gantt".to_string());
+
+        // Day ticks along the time axis
+        let mut axis = Node::default();
+        axis.add_attribute("class".to_string(), "time-axis".to_string());
+        for day in 0..self.end_day() {
+            let mut tick = Node::default();
+            tick.add_attribute(
+                "x".to_string(),
+                (day as f32 * self.props.day_width).to_string(),
+            );
+            tick.add_child(Node::text(&format!("Day {}", day + 1)));
+            axis.add_child(tick);
+        }
+        chart.add_child(axis);
+
+        for (task, bar) in self.tasks.get().iter().zip(self.bars()) {
+            let mut node = Node::default();
+            node.add_attribute("x".to_string(), bar.x.to_string());
+            node.add_attribute("y".to_string(), bar.y.to_string());
+            node.add_attribute("width".to_string(), bar.width.to_string());
+            node.add_attribute("height".to_string(), bar.height.to_string());
+            node.add_child(Node::text(&task.name));
+            chart.add_child(node);
+        }
+
+        Ok(vec![chart])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl GanttChart {
+    pub fn set_tasks(&self, tasks: Vec<Task>) {
+        self.tasks
+            .set(tasks)
+            .unwrap_or_else(|e| eprintln!("Failed to set tasks: {}", e));
+    }
+
+    /// Bars for the current tasks
+    pub fn bars(&self) -> Vec<Bar> {
+        self.tasks
+            .get()
+            .iter()
+            .enumerate()
+            .map(|(row, task)| Bar {
+                task: task.id,
+                x: task.start as f32 * self.props.day_width,
+                y: row as f32 * self.props.row_height,
+                width: task.duration as f32 * self.props.day_width,
+                height: self.props.row_height,
+            })
+            .collect()
+    }
+
+    pub fn bar(&self, task: u32) -> Option<Bar> {
+        self.bars().into_iter().find(|bar| bar.task == task)
+    }
+
+    /// The day after the last task ends
+    pub fn end_day(&self) -> u32 {
+        self.tasks
+            .get()
+            .iter()
+            .map(|task| task.start + task.duration)
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn set_start(&self, task: u32, start: u32) {
+        self.tasks
+            .update(|tasks| {
+                if let Some(entry) = tasks.iter_mut().find(|t| t.id == task) {
+                    entry.start = start;
+                }
+            })
+            .unwrap_or_else(|e| eprintln!("Failed to move task: {}", e));
+    }
+
+    /// Drag a bar horizontally by `dx` pixels, snapping to whole days
+    pub fn drag(&self, task: u32, dx: f32) {
+        let current = match self.tasks.get().iter().find(|t| t.id == task) {
+            Some(task) => task.start as i64,
+            None => return,
+        };
+        let days = (dx / self.props.day_width).round() as i64;
+        // Tasks can't be dragged before the project start
+        self.set_start(task, (current + days).max(0) as u32);
+    }
+}
+
+fn project() -> GanttChart {
+    let chart = GanttChart::create(
+        GanttProps {
+            day_width: 40.0,
+            row_height: 24.0,
+        },
+        Context::new(),
+    );
+    chart.set_tasks(vec![
+        Task {
+            id: 1,
+            name: "Research".to_string(),
+            start: 0,
+            duration: 3,
+        },
+        Task {
+            id: 2,
+            name: "Build".to_string(),
+            start: 3,
+            duration: 5,
+        },
+        Task {
+            id: 3,
+            name: "Launch".to_string(),
+            start: 8,
+            duration: 1,
+        },
+    ]);
+    chart
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_changing_start_moves_bar_proportionally() {
+        let chart = project();
+        assert_eq!(chart.bar(2).unwrap().x, 120.0);
+
+        chart.set_start(2, 5);
+
+        let bar = chart.bar(2).unwrap();
+        assert_eq!(bar.x, 200.0);
+        assert_eq!(bar.width, 200.0);
+    }
+
+    #[test]
+    fn test_drag_snaps_to_days_and_stops_at_project_start() {
+        let chart = project();
+
+        chart.drag(3, -55.0); // about 1.4 days
+        assert_eq!(chart.bar(3).unwrap().x, 7.0 * 40.0);
+
+        chart.drag(1, -200.0);
+        assert_eq!(chart.bar(1).unwrap().x, 0.0);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Gantt Chart Example\n");
+
+    let chart = project();
+    chart.render()?;
+
+    let print_bars = |chart: &GanttChart| {
+        for bar in chart.bars() {
+            println!("  task {} -> x={} width={}", bar.task, bar.x, bar.width);
+        }
+    };
+    print_bars(&chart);
+
+    println!("\nDragging 'Launch' 2 days later...");
+    chart.drag(3, 80.0);
+    chart.render()?;
+    print_bars(&chart);
+    println!("Project now ends on day {}", chart.end_day());
+
+    println!("\nGantt Chart example completed!");
+    Ok(())
+}
@@ -0,0 +1,364 @@
+//! WGPU setup shared by the `wgpu_*` examples
+//! Each example is its own binary and pulls this in with `mod gpu;`. `Gpu` owns the
+//! device, the window's surface and a matching depth buffer; the rest are the small
+//! pieces every mesh example needs: buffers for a `Mesh`, a uniform block holding the
+//! camera and model matrices, and a render pipeline for `Vertex` data.
+
+use std::fmt;
+use std::iter;
+
+use bytemuck::{Pod, Zeroable};
+use wgpu::util::DeviceExt;
+use winit::dpi::PhysicalSize;
+use winit::window::Window;
+
+use crate::camera::Camera;
+use crate::mesh::{Mesh, Vertex};
+
+pub const DEPTH_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth32Float;
+
+#[derive(Debug)]
+pub enum GpuError {
+    NoAdapter,
+    Device(wgpu::RequestDeviceError),
+}
+
+impl fmt::Display for GpuError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GpuError::NoAdapter => write!(f, "no suitable graphics adapter found"),
+            GpuError::Device(e) => write!(f, "failed to open graphics device: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for GpuError {}
+
+/// A device drawing into a window
+pub struct Gpu {
+    pub adapter: wgpu::Adapter,
+    pub device: wgpu::Device,
+    pub queue: wgpu::Queue,
+    pub surface: wgpu::Surface,
+    pub config: wgpu::SurfaceConfiguration,
+    pub depth_view: wgpu::TextureView,
+}
+
+impl Gpu {
+    pub fn new(window: &Window) -> Result<Self, GpuError> {
+        Self::with_features(window, wgpu::Features::empty())
+    }
+
+    /// Open the device with any of `optional` features the adapter supports
+    pub fn with_features(window: &Window, optional: wgpu::Features) -> Result<Self, GpuError> {
+        pollster::block_on(Self::request(window, optional))
+    }
+
+    async fn request(window: &Window, optional: wgpu::Features) -> Result<Self, GpuError> {
+        let instance = wgpu::Instance::new(wgpu::Backends::all());
+        // Safety: the window outlives the surface in every example's event loop
+        let surface = unsafe { instance.create_surface(window) };
+        let adapter = instance
+            .request_adapter(&wgpu::RequestAdapterOptions {
+                power_preference: wgpu::PowerPreference::default(),
+                compatible_surface: Some(&surface),
+                force_fallback_adapter: false,
+            })
+            .await
+            .ok_or(GpuError::NoAdapter)?;
+        let (device, queue) = adapter
+            .request_device(
+                &wgpu::DeviceDescriptor {
+                    label: Some("device"),
+                    features: adapter.features() & optional,
+                    limits: wgpu::Limits::default(),
+                },
+                None,
+            )
+            .await
+            .map_err(GpuError::Device)?;
+
+        let size = window.inner_size();
+        let config = wgpu::SurfaceConfiguration {
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            format: surface.get_supported_formats(&adapter)[0],
+            width: size.width.max(1),
+            height: size.height.max(1),
+            present_mode: wgpu::PresentMode::Fifo,
+            alpha_mode: wgpu::CompositeAlphaMode::Auto,
+        };
+        surface.configure(&device, &config);
+        let depth_view = create_depth_view(&device, config.width, config.height);
+
+        Ok(Self {
+            adapter,
+            device,
+            queue,
+            surface,
+            config,
+            depth_view,
+        })
+    }
+
+    /// Match the surface to a new window size; returns false for a zero-sized
+    /// (minimized) window, which can't be configured
+    pub fn resize(&mut self, width: u32, height: u32) -> bool {
+        if width == 0 || height == 0 {
+            return false;
+        }
+        self.config.width = width;
+        self.config.height = height;
+        self.surface.configure(&self.device, &self.config);
+        self.depth_view = create_depth_view(&self.device, width, height);
+        true
+    }
+
+    /// Handle a window's `Resized` event, keeping `camera`'s aspect ratio in step
+    pub fn fit_window(&mut self, size: PhysicalSize<u32>, camera: &mut Camera) {
+        if self.resize(size.width, size.height) {
+            camera.aspect = size.width as f32 / size.height as f32;
+        }
+    }
+
+    pub fn begin_frame(&self) -> Result<Frame, wgpu::SurfaceError> {
+        let output = self.surface.get_current_texture()?;
+        let view = output.texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let encoder = self.device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("frame encoder"),
+        });
+        Ok(Frame {
+            output,
+            view,
+            encoder,
+        })
+    }
+
+    pub fn end_frame(&self, frame: Frame) {
+        self.queue.submit(iter::once(frame.encoder.finish()));
+        frame.output.present();
+    }
+
+    /// Build a pipeline drawing `Vertex` data with `shader`'s `vs_main` and `fs_main`
+    pub fn mesh_pipeline(
+        &self,
+        shader: &wgpu::ShaderModule,
+        bind_group_layouts: &[&wgpu::BindGroupLayout],
+        polygon_mode: wgpu::PolygonMode,
+    ) -> wgpu::RenderPipeline {
+        mesh_pipeline(
+            &self.device,
+            self.config.format,
+            shader,
+            bind_group_layouts,
+            &[Vertex::layout()],
+            polygon_mode,
+        )
+    }
+}
+
+/// One frame's surface texture and the commands drawn into it
+pub struct Frame {
+    output: wgpu::SurfaceTexture,
+    view: wgpu::TextureView,
+    pub encoder: wgpu::CommandEncoder,
+}
+
+impl Frame {
+    /// Start a pass that clears the frame to `clear` and the depth buffer to far
+    pub fn pass<'a>(
+        &'a mut self,
+        depth_view: &'a wgpu::TextureView,
+        clear: wgpu::Color,
+    ) -> wgpu::RenderPass<'a> {
+        begin_pass(&mut self.encoder, &self.view, depth_view, clear)
+    }
+}
+
+pub fn begin_pass<'a>(
+    encoder: &'a mut wgpu::CommandEncoder,
+    view: &'a wgpu::TextureView,
+    depth_view: &'a wgpu::TextureView,
+    clear: wgpu::Color,
+) -> wgpu::RenderPass<'a> {
+    encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+        label: Some("mesh pass"),
+        color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+            view,
+            resolve_target: None,
+            ops: wgpu::Operations {
+                load: wgpu::LoadOp::Clear(clear),
+                store: true,
+            },
+        })],
+        depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+            view: depth_view,
+            depth_ops: Some(wgpu::Operations {
+                load: wgpu::LoadOp::Clear(1.0),
+                store: true,
+            }),
+            stencil_ops: None,
+        }),
+    })
+}
+
+pub fn create_depth_view(device: &wgpu::Device, width: u32, height: u32) -> wgpu::TextureView {
+    device
+        .create_texture(&wgpu::TextureDescriptor {
+            label: Some("depth"),
+            size: wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: DEPTH_FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+        })
+        .create_view(&wgpu::TextureViewDescriptor::default())
+}
+
+/// A depth-tested pipeline for triangle lists, culling back faces
+pub fn mesh_pipeline(
+    device: &wgpu::Device,
+    format: wgpu::TextureFormat,
+    shader: &wgpu::ShaderModule,
+    bind_group_layouts: &[&wgpu::BindGroupLayout],
+    vertex_buffers: &[wgpu::VertexBufferLayout],
+    polygon_mode: wgpu::PolygonMode,
+) -> wgpu::RenderPipeline {
+    let layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+        label: Some("mesh pipeline layout"),
+        bind_group_layouts,
+        push_constant_ranges: &[],
+    });
+    device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        label: Some("mesh pipeline"),
+        layout: Some(&layout),
+        vertex: wgpu::VertexState {
+            module: shader,
+            entry_point: "vs_main",
+            buffers: vertex_buffers,
+        },
+        fragment: Some(wgpu::FragmentState {
+            module: shader,
+            entry_point: "fs_main",
+            targets: &[Some(wgpu::ColorTargetState {
+                format,
+                blend: Some(wgpu::BlendState::REPLACE),
+                write_mask: wgpu::ColorWrites::ALL,
+            })],
+        }),
+        primitive: wgpu::PrimitiveState {
+            topology: wgpu::PrimitiveTopology::TriangleList,
+            strip_index_format: None,
+            front_face: wgpu::FrontFace::Ccw,
+            cull_mode: Some(wgpu::Face::Back),
+            unclipped_depth: false,
+            polygon_mode,
+            conservative: false,
+        },
+        depth_stencil: Some(wgpu::DepthStencilState {
+            format: DEPTH_FORMAT,
+            depth_write_enabled: true,
+            depth_compare: wgpu::CompareFunction::Less,
+            stencil: wgpu::StencilState::default(),
+            bias: wgpu::DepthBiasState::default(),
+        }),
+        multisample: wgpu::MultisampleState::default(),
+        multiview: None,
+    })
+}
+
+/// A mesh uploaded to the GPU
+pub struct MeshBuffers {
+    vertices: wgpu::Buffer,
+    indices: wgpu::Buffer,
+    index_count: u32,
+}
+
+impl MeshBuffers {
+    pub fn new(device: &wgpu::Device, mesh: &Mesh) -> Self {
+        let vertices = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("vertices"),
+            contents: bytemuck::cast_slice(&mesh.vertices),
+            usage: wgpu::BufferUsages::VERTEX,
+        });
+        let indices = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("indices"),
+            contents: bytemuck::cast_slice(&mesh.indices),
+            usage: wgpu::BufferUsages::INDEX,
+        });
+        Self {
+            vertices,
+            indices,
+            index_count: mesh.indices.len() as u32,
+        }
+    }
+
+    pub fn draw<'a>(&'a self, pass: &mut wgpu::RenderPass<'a>) {
+        self.draw_instanced(pass, 1);
+    }
+
+    pub fn draw_instanced<'a>(&'a self, pass: &mut wgpu::RenderPass<'a>, instances: u32) {
+        pass.set_vertex_buffer(0, self.vertices.slice(..));
+        pass.set_index_buffer(self.indices.slice(..), wgpu::IndexFormat::Uint32);
+        pass.draw_indexed(0..self.index_count, 0, 0..instances);
+    }
+}
+
+/// Camera and model matrices, matching `Uniforms` in `shaders/mesh.wgsl`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+pub struct Uniforms {
+    pub view_proj: [[f32; 4]; 4],
+    pub model: [[f32; 4]; 4],
+}
+
+/// A uniform buffer alone in a bind group, at binding 0
+pub struct UniformBinding {
+    buffer: wgpu::Buffer,
+    pub layout: wgpu::BindGroupLayout,
+    pub bind_group: wgpu::BindGroup,
+}
+
+impl UniformBinding {
+    pub fn new<T: Pod>(device: &wgpu::Device, uniforms: &T) -> Self {
+        let buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("uniforms"),
+            contents: bytemuck::bytes_of(uniforms),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+        let layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("uniform layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("uniform bind group"),
+            layout: &layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: buffer.as_entire_binding(),
+            }],
+        });
+        Self {
+            buffer,
+            layout,
+            bind_group,
+        }
+    }
+
+    pub fn write<T: Pod>(&self, queue: &wgpu::Queue, uniforms: &T) {
+        queue.write_buffer(&self.buffer, 0, bytemuck::bytes_of(uniforms));
+    }
+}
@@ -0,0 +1,406 @@
+//! Example demonstrating a graceful shutdown that flushes pending work
+//! On shutdown every mounted component first gets `before_unmount`, while the app is
+//! still whole, and may queue last-minute work there. Pending autosaves and the
+//! analytics batch are then flushed, and only after that are components unmounted,
+//! last-mounted first. An error from one component doesn't stop the others.
+//! To run: cargo run --example graceful_shutdown
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+/// Everything that happened during shutdown, in order
+pub type Journal = Rc<RefCell<Vec<String>>>;
+
+fn note(journal: &Journal, entry: String) {
+    println!("  {}", entry);
+    journal.borrow_mut().push(entry);
+}
+
+/// Document saves waiting for their debounce to expire
+pub struct AutosaveQueue {
+    pending: RefCell<Vec<(String, String)>>,
+    saved: RefCell<Vec<(String, String)>>,
+    journal: Journal,
+}
+
+impl AutosaveQueue {
+    pub fn new(journal: Journal) -> Self {
+        Self {
+            pending: RefCell::new(Vec::new()),
+            saved: RefCell::new(Vec::new()),
+            journal,
+        }
+    }
+
+    /// Queue `contents` for `document`, replacing any older pending save of it
+    pub fn queue(&self, document: &str, contents: &str) {
+        let mut pending = self.pending.borrow_mut();
+        pending.retain(|(queued, _)| queued != document);
+        pending.push((document.to_string(), contents.to_string()));
+    }
+
+    /// Save everything pending now; returns how many documents were written
+    pub fn flush(&self) -> usize {
+        let pending: Vec<_> = self.pending.borrow_mut().drain(..).collect();
+        for (document, contents) in &pending {
+            note(&self.journal, format!("autosave {}: {:?}", document, contents));
+        }
+        let count = pending.len();
+        self.saved.borrow_mut().extend(pending);
+        count
+    }
+
+    pub fn pending(&self) -> usize {
+        self.pending.borrow().len()
+    }
+
+    pub fn saved(&self) -> Vec<(String, String)> {
+        self.saved.borrow().clone()
+    }
+}
+
+/// Analytics events buffered until the batch is sent
+pub struct AnalyticsBatch {
+    events: RefCell<Vec<String>>,
+    journal: Journal,
+}
+
+impl AnalyticsBatch {
+    pub fn new(journal: Journal) -> Self {
+        Self {
+            events: RefCell::new(Vec::new()),
+            journal,
+        }
+    }
+
+    pub fn track(&self, event: &str) {
+        self.events.borrow_mut().push(event.to_string());
+    }
+
+    pub fn flush(&self) {
+        let events: Vec<_> = self.events.borrow_mut().drain(..).collect();
+        if !events.is_empty() {
+            note(&self.journal, format!("analytics sent [{}]", events.join(", ")));
+        }
+    }
+}
+
+/// The unmount half of the lifecycle, for components of any type
+pub trait Mounted {
+    fn before_unmount(&mut self) -> Result<(), ComponentError>;
+    fn unmount(&mut self) -> Result<(), ComponentError>;
+}
+
+impl<C: Component> Mounted for C {
+    fn before_unmount(&mut self) -> Result<(), ComponentError> {
+        Component::before_unmount(self)
+    }
+
+    fn unmount(&mut self) -> Result<(), ComponentError> {
+        Component::unmount(self)
+    }
+}
+
+/// Mounted components plus the work they leave pending
+pub struct App {
+    pub autosaves: Rc<AutosaveQueue>,
+    pub analytics: Rc<AnalyticsBatch>,
+    pub journal: Journal,
+    components: Vec<Box<dyn Mounted>>,
+}
+
+impl App {
+    pub fn new() -> Self {
+        let journal = Journal::default();
+        Self {
+            autosaves: Rc::new(AutosaveQueue::new(journal.clone())),
+            analytics: Rc::new(AnalyticsBatch::new(journal.clone())),
+            journal,
+            components: Vec::new(),
+        }
+    }
+
+    pub fn mount<C>(&mut self, mut component: C) -> Result<(), ComponentError>
+    where
+        C: Component + 'static,
+    {
+        component.mount()?;
+        self.components.push(Box::new(component));
+        Ok(())
+    }
+
+    /// Tear the app down, reporting the first error once everything has been attempted
+    pub fn shutdown(mut self) -> Result<(), ComponentError> {
+        let mut first_error = None;
+        let mut keep_first = |result: Result<(), ComponentError>| {
+            if let Err(e) = result {
+                eprintln!("Shutdown step failed: {}", e);
+                first_error.get_or_insert(e);
+            }
+        };
+
+        // Children are mounted after their parents, so go newest first
+        for component in self.components.iter_mut().rev() {
+            keep_first(component.before_unmount());
+        }
+
+        // Flush while nothing has been unmounted yet, including work queued just above
+        self.autosaves.flush();
+        self.analytics.flush();
+
+        for mut component in self.components.drain(..).rev() {
+            keep_first(component.unmount());
+        }
+
+        match first_error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Default for App {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone)]
+pub struct EditorProps {
+    pub document: String,
+    pub autosaves: Rc<AutosaveQueue>,
+    pub journal: Journal,
+}
+
+/// A text editor that autosaves after edits
+pub struct Editor {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: EditorProps,
+    text: Signal<String>,
+}
+
+impl Component for Editor {
+    type Props = EditorProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let text = create_signal(&scope, String::new());
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            props,
+            text,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn before_unmount(&mut self) -> Result<(), ComponentError> {
+        note(&self.props.journal, format!("editor {} before_unmount", self.props.document));
+        Ok(())
+    }
+
+    fn unmount(&mut self) -> Result<(), ComponentError> {
+        note(&self.props.journal, format!("editor {} unmount", self.props.document));
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut editor = Node::default();
+        editor.add_attribute("role".to_string(), "textbox".to_string());
+        editor.add_attribute("aria-label".to_string(), self.props.document.clone());
+        editor.add_child(Node::text(&self.text.get().clone()));
+        Ok(vec![editor])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl Editor {
+    /// Append typed text; the save waits in the queue rather than hitting storage per key
+    pub fn type_text(&self, typed: &str) {
+        let text = self.text.get().clone() + typed;
+        self.props.autosaves.queue(&self.props.document, &text);
+        self.text
+            .set(text)
+            .unwrap_or_else(|e| eprintln!("Failed to update text: {}", e));
+    }
+}
+
+#[derive(Clone)]
+pub struct SessionProps {
+    pub analytics: Rc<AnalyticsBatch>,
+    pub journal: Journal,
+}
+
+/// Tracks the session, reporting its end as the app closes
+pub struct Session {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    props: SessionProps,
+}
+
+impl Component for Session {
+    type Props = SessionProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            id: ComponentId::new(),
+            context,
+            props,
+        }
+    }
+
+    fn mount(&mut self) -> Result<(), ComponentError> {
+        self.props.analytics.track("session_start");
+        Ok(())
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn before_unmount(&mut self) -> Result<(), ComponentError> {
+        // Queued here and still delivered, since the batch is flushed afterwards
+        self.props.analytics.track("session_end");
+        note(&self.props.journal, "session before_unmount".to_string());
+        Ok(())
+    }
+
+    fn unmount(&mut self) -> Result<(), ComponentError> {
+        note(&self.props.journal, "session unmount".to_string());
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        Ok(Vec::new())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+fn editor(app: &App, document: &str) -> Editor {
+    Editor::create(
+        EditorProps {
+            document: document.to_string(),
+            autosaves: app.autosaves.clone(),
+            journal: app.journal.clone(),
+        },
+        Context::new(),
+    )
+}
+
+fn session(app: &App) -> Session {
+    Session::create(
+        SessionProps {
+            analytics: app.analytics.clone(),
+            journal: app.journal.clone(),
+        },
+        Context::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(journal: &Journal, prefix: &str) -> usize {
+        journal
+            .borrow()
+            .iter()
+            .position(|entry| entry.starts_with(prefix))
+            .unwrap_or_else(|| panic!("no journal entry starting with {:?}", prefix))
+    }
+
+    #[test]
+    fn test_queued_autosave_flushed_before_unmount() {
+        let mut app = App::new();
+        let notes = editor(&app, "notes.txt");
+        notes.type_text("draft");
+        app.mount(notes).unwrap();
+        let (autosaves, journal) = (app.autosaves.clone(), app.journal.clone());
+        assert_eq!(autosaves.pending(), 1);
+
+        app.shutdown().unwrap();
+
+        assert_eq!(autosaves.pending(), 0);
+        assert_eq!(autosaves.saved(), vec![("notes.txt".to_string(), "draft".to_string())]);
+        let saved_at = position(&journal, "autosave notes.txt");
+        assert!(saved_at < position(&journal, "editor notes.txt unmount"));
+    }
+
+    #[test]
+    fn test_unmounts_newest_first_after_all_before_unmounts() {
+        let mut app = App::new();
+        app.mount(session(&app)).unwrap();
+        app.mount(editor(&app, "a.txt")).unwrap();
+        let journal = app.journal.clone();
+
+        app.shutdown().unwrap();
+
+        assert_eq!(
+            *journal.borrow(),
+            vec![
+                "editor a.txt before_unmount",
+                "session before_unmount",
+                "analytics sent [session_start, session_end]",
+                "editor a.txt unmount",
+                "session unmount",
+            ]
+        );
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Graceful Shutdown Example\n");
+
+    let mut app = App::new();
+    app.mount(session(&app))?;
+    let notes = editor(&app, "notes.txt");
+    notes.type_text("Buy milk");
+    notes.type_text(", eggs");
+    notes.render()?;
+    app.mount(notes)?;
+    app.mount(editor(&app, "todo.txt"))?;
+
+    println!("Pending autosaves: {}", app.autosaves.pending());
+    println!("Shutting down:");
+    app.shutdown()?;
+
+    println!("\nGraceful Shutdown example completed!");
+    Ok(())
+}
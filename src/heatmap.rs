@@ -0,0 +1,194 @@
+//! Example demonstrating a heatmap rendered from a 2D array
+//! Each value is normalized between the array's minimum and maximum and mapped onto a
+//! linear color scale, producing one colored cell node per value. A uniform array has
+//! no range to normalize over, so every cell gets the middle of the scale.
+//! To run: cargo run --example heatmap
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+
+    fn lerp(self, other: Rgb, t: f32) -> Rgb {
+        let channel = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+        Rgb(
+            channel(self.0, other.0),
+            channel(self.1, other.1),
+            channel(self.2, other.2),
+        )
+    }
+}
+
+/// A two-stop color scale
+#[derive(Debug, Clone, Copy)]
+pub struct ColorScale {
+    pub low: Rgb,
+    pub high: Rgb,
+}
+
+impl ColorScale {
+    /// Color for `value` given the data range
+    pub fn color(&self, value: f32, min: f32, max: f32) -> Rgb {
+        let t = if max > min {
+            ((value - min) / (max - min)).clamp(0.0, 1.0)
+        } else {
+            // min == max: avoid dividing by zero
+            0.5
+        };
+        self.low.lerp(self.high, t)
+    }
+}
+
+#[derive(Clone)]
+pub struct HeatmapProps {
+    pub values: Vec<Vec<f32>>,
+    pub cell_size: f32,
+    pub scale: ColorScale,
+}
+
+/// A grid of cells colored by value
+pub struct Heatmap {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    props: HeatmapProps,
+}
+
+impl Component for Heatmap {
+    type Props = HeatmapProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            id: ComponentId::new(),
+            context,
+            props,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut grid = Node::default();
+        grid.add_attribute("role".to_string(), "img".to_string());
+        grid.add_attribute("aria-label".to_string(), "Heatmap".to_string());
+
+        let size = self.props.cell_size;
+        for (row, values) in self.props.values.iter().enumerate() {
+            for (col, value) in values.iter().enumerate() {
+                let mut cell = Node::default();
+                cell.add_attribute("x".to_string(), (col as f32 * size).to_string());
+                cell.add_attribute("y".to_string(), (row as f32 * size).to_string());
+                cell.add_attribute("width".to_string(), size.to_string());
+                cell.add_attribute("height".to_string(), size.to_string());
+                cell.add_attribute("fill".to_string(), self.cell_color(row, col).hex());
+                cell.add_attribute("title".to_string(), value.to_string());
+                grid.add_child(cell);
+            }
+        }
+
+        Ok(vec![grid])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl Heatmap {
+    /// Smallest and largest values, ignoring NaN
+    pub fn range(&self) -> (f32, f32) {
+        self.props
+            .values
+            .iter()
+            .flatten()
+            .filter(|value| !value.is_nan())
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), &value| {
+                (min.min(value), max.max(value))
+            })
+    }
+
+    pub fn cell_color(&self, row: usize, col: usize) -> Rgb {
+        let (min, max) = self.range();
+        self.props.scale.color(self.props.values[row][col], min, max)
+    }
+}
+
+fn heatmap(values: Vec<Vec<f32>>) -> Heatmap {
+    Heatmap::create(
+        HeatmapProps {
+            values,
+            cell_size: 20.0,
+            scale: ColorScale {
+                low: Rgb(0, 0, 255),
+                high: Rgb(255, 0, 0),
+            },
+        },
+        Context::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cell_color_for_known_value() {
+        let map = heatmap(vec![vec![0.0, 5.0], vec![7.5, 10.0]]);
+
+        assert_eq!(map.cell_color(0, 0), Rgb(0, 0, 255));
+        assert_eq!(map.cell_color(0, 1), Rgb(128, 0, 128));
+        assert_eq!(map.cell_color(1, 0).hex(), "#bf0040");
+        assert_eq!(map.cell_color(1, 1), Rgb(255, 0, 0));
+    }
+
+    #[test]
+    fn test_uniform_values_do_not_divide_by_zero() {
+        let map = heatmap(vec![vec![3.0, 3.0], vec![3.0, 3.0]]);
+
+        assert_eq!(map.cell_color(1, 1), Rgb(128, 0, 128));
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Heatmap Example\n");
+
+    // Requests per hour for three days
+    let map = heatmap(vec![
+        vec![2.0, 5.0, 14.0, 20.0, 9.0],
+        vec![1.0, 7.0, 18.0, 25.0, 11.0],
+        vec![0.0, 4.0, 12.0, 16.0, 6.0],
+    ]);
+    map.render()?;
+
+    let (min, max) = map.range();
+    println!("Range: {} to {}", min, max);
+    for (row, values) in map.props.values.iter().enumerate() {
+        let colors: Vec<String> = (0..values.len())
+            .map(|col| map.cell_color(row, col).hex())
+            .collect();
+        println!("  {}", colors.join(" "));
+    }
+
+    let flat = heatmap(vec![vec![4.0; 3]; 2]);
+    flat.render()?;
+    println!("\nUniform array cell color: {}", flat.cell_color(0, 0).hex());
+
+    println!("\nHeatmap example completed!");
+    Ok(())
+}
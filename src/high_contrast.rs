@@ -0,0 +1,257 @@
+//! Example demonstrating high-contrast theme support
+//! The theme exposes a high-contrast variant whose colors meet WCAG AAA contrast
+//! (at least 7:1). A signal toggles between the regular and high-contrast palettes.
+//! To run: cargo run --example high_contrast
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+/// WCAG AAA contrast ratio for normal text
+pub const AAA_CONTRAST: f32 = 7.0;
+
+/// An sRGB color
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    pub fn to_hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+
+    /// Relative luminance as defined by WCAG 2.x
+    pub fn relative_luminance(self) -> f32 {
+        fn channel(value: u8) -> f32 {
+            let c = value as f32 / 255.0;
+            if c <= 0.03928 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        }
+
+        0.2126 * channel(self.r) + 0.7152 * channel(self.g) + 0.0722 * channel(self.b)
+    }
+}
+
+/// Contrast ratio between two colors, from 1:1 up to 21:1
+pub fn contrast_ratio(a: Color, b: Color) -> f32 {
+    let (la, lb) = (a.relative_luminance(), b.relative_luminance());
+    let (lighter, darker) = if la > lb { (la, lb) } else { (lb, la) };
+    (lighter + 0.05) / (darker + 0.05)
+}
+
+/// Colors used by the button
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Palette {
+    pub button_background: Color,
+    pub button_foreground: Color,
+    pub focus_ring: Color,
+}
+
+impl Palette {
+    pub fn standard() -> Self {
+        Self {
+            button_background: Color::rgb(0x00, 0x70, 0xf3),
+            button_foreground: Color::rgb(0xff, 0xff, 0xff),
+            focus_ring: Color::rgb(0x79, 0xb8, 0xff),
+        }
+    }
+
+    /// Accessible palette overriding the standard colors
+    pub fn high_contrast() -> Self {
+        Self {
+            button_background: Color::rgb(0x00, 0x00, 0x00),
+            button_foreground: Color::rgb(0xff, 0xff, 0x00),
+            focus_ring: Color::rgb(0xff, 0xff, 0xff),
+        }
+    }
+}
+
+/// Theme that switches palettes based on a high-contrast signal
+pub struct Theme {
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    high_contrast: Signal<bool>,
+}
+
+impl Theme {
+    pub fn new() -> Self {
+        let scope = ReactiveScope::new();
+        let high_contrast = create_signal(&scope, false);
+        Self {
+            scope,
+            high_contrast,
+        }
+    }
+
+    pub fn set_high_contrast(&self, enabled: bool) {
+        self.high_contrast.set(enabled).unwrap_or_else(|e| {
+            eprintln!("Failed to toggle high contrast: {}", e);
+        });
+    }
+
+    pub fn is_high_contrast(&self) -> bool {
+        *self.high_contrast.get()
+    }
+
+    pub fn palette(&self) -> Palette {
+        if self.is_high_contrast() {
+            Palette::high_contrast()
+        } else {
+            Palette::standard()
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone)]
+pub struct ButtonProps {
+    pub label: String,
+}
+
+/// A button that renders with the theme's current palette
+pub struct Button {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    props: ButtonProps,
+    palette: Palette,
+}
+
+impl Component for Button {
+    type Props = ButtonProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            id: ComponentId::new(),
+            context,
+            props,
+            palette: Palette::standard(),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut node = Node::default();
+        node.add_attribute("label".to_string(), self.props.label.clone());
+        node.add_attribute(
+            "background".to_string(),
+            self.palette.button_background.to_hex(),
+        );
+        node.add_attribute("color".to_string(), self.palette.button_foreground.to_hex());
+        node.add_attribute(
+            "outline-color".to_string(),
+            self.palette.focus_ring.to_hex(),
+        );
+        Ok(vec![node])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl Button {
+    /// Pick up the theme's current palette
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        self.palette = theme.palette();
+    }
+
+    pub fn contrast(&self) -> f32 {
+        contrast_ratio(
+            self.palette.button_foreground,
+            self.palette.button_background,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_high_contrast_exceeds_aaa_threshold() {
+        let theme = Theme::new();
+        let mut button = Button::create(
+            ButtonProps {
+                label: "Submit".to_string(),
+            },
+            Context::new(),
+        );
+
+        theme.set_high_contrast(true);
+        button.apply_theme(&theme);
+
+        assert!(button.contrast() >= AAA_CONTRAST);
+    }
+
+    #[test]
+    fn test_contrast_ratio_bounds() {
+        let black = Color::rgb(0, 0, 0);
+        let white = Color::rgb(255, 255, 255);
+
+        assert!((contrast_ratio(black, white) - 21.0).abs() < 0.01);
+        assert!((contrast_ratio(white, white) - 1.0).abs() < 0.01);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("High Contrast Theme Example\n");
+
+    let theme = Theme::new();
+    let mut button = Button::create(
+        ButtonProps {
+            label: "Submit".to_string(),
+        },
+        Context::new(),
+    );
+
+    button.apply_theme(&theme);
+    button.render()?;
+    println!(
+        "Standard palette: {} on {} (contrast {:.2}:1)",
+        theme.palette().button_foreground.to_hex(),
+        theme.palette().button_background.to_hex(),
+        button.contrast()
+    );
+
+    println!("\nEnabling high contrast...");
+    theme.set_high_contrast(true);
+    button.apply_theme(&theme);
+    button.render()?;
+    println!(
+        "High-contrast palette: {} on {} (contrast {:.2}:1, AAA: {})",
+        theme.palette().button_foreground.to_hex(),
+        theme.palette().button_background.to_hex(),
+        button.contrast(),
+        button.contrast() >= AAA_CONTRAST
+    );
+
+    println!("\nHigh Contrast example completed!");
+    Ok(())
+}
@@ -0,0 +1,261 @@
+//! Example demonstrating lazily loaded translation catalogs
+//! Only the English catalog ships with the app. The first switch to any other locale
+//! starts loading its catalog on a background thread; until it arrives, messages
+//! render as their keys. Loaded catalogs are cached, so switching away and back
+//! never loads the same locale twice.
+//! To run: cargo run --example i18n_lazy_load
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Locale {
+    English,
+    German,
+    French,
+}
+
+/// Message id to translated text
+pub type Catalog = HashMap<&'static str, &'static str>;
+
+/// The catalog a translation server would return for `locale`
+fn catalog_for(locale: Locale) -> Catalog {
+    let messages: &[(&str, &str)] = match locale {
+        Locale::English => &[("greeting", "Hello!"), ("farewell", "Goodbye!")],
+        Locale::German => &[("greeting", "Hallo!"), ("farewell", "Auf Wiedersehen!")],
+        Locale::French => &[("greeting", "Bonjour !"), ("farewell", "Au revoir !")],
+    };
+    messages.iter().copied().collect()
+}
+
+/// Fetches catalogs on a background thread, counting how often it's asked to
+#[derive(Clone)]
+pub struct CatalogLoader {
+    latency: Duration,
+    loads: Arc<AtomicUsize>,
+}
+
+impl CatalogLoader {
+    pub fn new(latency: Duration) -> Self {
+        Self {
+            latency,
+            loads: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    pub fn load(&self, locale: Locale) -> (JoinHandle<()>, Receiver<Catalog>) {
+        self.loads.fetch_add(1, Ordering::SeqCst);
+        let (sender, receiver) = mpsc::channel();
+        let latency = self.latency;
+        let worker = thread::spawn(move || {
+            thread::sleep(latency);
+            // The translator may have been dropped while we were waiting
+            let _ = sender.send(catalog_for(locale));
+        });
+        (worker, receiver)
+    }
+
+    /// How many catalogs have been requested so far
+    pub fn loads(&self) -> usize {
+        self.loads.load(Ordering::SeqCst)
+    }
+}
+
+#[derive(Clone)]
+pub struct TranslatorProps {
+    pub loader: CatalogLoader,
+}
+
+/// Renders messages in the current locale, loading its catalog on first use
+pub struct Translator {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    loader: CatalogLoader,
+    locale: Signal<Locale>,
+    catalogs: HashMap<Locale, Catalog>,
+    pending: HashMap<Locale, Receiver<Catalog>>,
+}
+
+impl Component for Translator {
+    type Props = TranslatorProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            locale: create_signal(&scope, Locale::English),
+            scope,
+            loader: props.loader,
+            catalogs: HashMap::from([(Locale::English, catalog_for(Locale::English))]),
+            pending: HashMap::new(),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.loader = props.loader;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut view = Node::default();
+        if !self.is_loaded() {
+            view.add_attribute("aria-busy".to_string(), "true".to_string());
+        }
+        view.add_child(Node::text(&self.t("greeting")));
+        Ok(vec![view])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl Translator {
+    /// Switch locale, starting a load unless its catalog is cached or already on
+    /// its way; the handle lets callers wait for the worker
+    pub fn set_locale(&mut self, locale: Locale) -> Option<JoinHandle<()>> {
+        self.locale
+            .set(locale)
+            .unwrap_or_else(|e| eprintln!("Failed to set locale: {}", e));
+        if self.catalogs.contains_key(&locale) || self.pending.contains_key(&locale) {
+            return None;
+        }
+        let (worker, receiver) = self.loader.load(locale);
+        self.pending.insert(locale, receiver);
+        Some(worker)
+    }
+
+    /// Cache any catalogs that have arrived; returns true once the current
+    /// locale's catalog is available
+    pub fn poll(&mut self) -> bool {
+        let arrived: Vec<(Locale, Catalog)> = self
+            .pending
+            .iter()
+            .filter_map(|(&locale, receiver)| Some((locale, receiver.try_recv().ok()?)))
+            .collect();
+        for (locale, catalog) in arrived {
+            self.pending.remove(&locale);
+            self.catalogs.insert(locale, catalog);
+        }
+        self.is_loaded()
+    }
+
+    pub fn is_loaded(&self) -> bool {
+        self.catalogs.contains_key(&self.locale())
+    }
+
+    pub fn locale(&self) -> Locale {
+        *self.locale.get()
+    }
+
+    /// Translate `key`, or fall back to the key while its catalog loads
+    pub fn t(&self, key: &str) -> String {
+        self.catalogs
+            .get(&self.locale())
+            .and_then(|catalog| catalog.get(key))
+            .map_or_else(|| key.to_string(), |text| text.to_string())
+    }
+}
+
+fn translator(latency: Duration) -> Translator {
+    Translator::create(
+        TranslatorProps {
+            loader: CatalogLoader::new(latency),
+        },
+        Context::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catalog_loads_once_across_switches() {
+        let mut translator = translator(Duration::ZERO);
+
+        let worker = translator.set_locale(Locale::German).unwrap();
+        worker.join().unwrap();
+        assert!(translator.poll());
+
+        translator.set_locale(Locale::English);
+        assert!(translator.set_locale(Locale::German).is_none());
+
+        assert_eq!(translator.loader.loads(), 1);
+        assert_eq!(translator.t("greeting"), "Hallo!");
+    }
+
+    #[test]
+    fn test_keys_shown_while_loading() {
+        let mut translator = translator(Duration::from_secs(1));
+
+        let _worker = translator.set_locale(Locale::French);
+
+        assert!(!translator.poll());
+        assert_eq!(translator.t("greeting"), "greeting");
+    }
+
+    #[test]
+    fn test_bundled_locale_needs_no_load() {
+        let mut translator = translator(Duration::ZERO);
+
+        assert!(translator.set_locale(Locale::English).is_none());
+        assert_eq!(translator.t("farewell"), "Goodbye!");
+        assert_eq!(translator.loader.loads(), 0);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("I18n Lazy Load Example\n");
+
+    let mut translator = translator(Duration::from_millis(40));
+    let switches = [
+        Locale::German,
+        Locale::English,
+        Locale::German,
+        Locale::French,
+    ];
+
+    for locale in switches {
+        match translator.set_locale(locale) {
+            Some(_worker) => println!("Switched to {:?}, loading its catalog", locale),
+            None => println!("Switched to {:?}", locale),
+        }
+
+        let mut frame = 0;
+        loop {
+            let loaded = translator.poll();
+            translator.render()?;
+            println!("  frame {}: {}", frame, translator.t("greeting"));
+            if loaded {
+                break;
+            }
+            frame += 1;
+            thread::sleep(Duration::from_millis(16));
+        }
+    }
+
+    println!("\nCatalogs loaded: {}", translator.loader.loads());
+    println!("\nI18n Lazy Load example completed!");
+    Ok(())
+}
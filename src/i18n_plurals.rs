@@ -0,0 +1,269 @@
+//! Example demonstrating reactive i18n with CLDR-style plural rules
+//! Orbit has no i18n module yet, so this example brings a small one: each locale
+//! picks a plural category (one, few, many, other) for a count, and the message
+//! catalog holds one template per category. English only needs "one" and "other";
+//! Polish also distinguishes "few" from "many". The cart label is a computed value,
+//! so changing either the locale or the count re-renders it in the right form.
+//! To run: cargo run --example i18n_plurals
+
+use std::collections::HashMap;
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_computed, create_signal, ReactiveComputed, ReactiveScope, Signal};
+
+/// The CLDR plural categories these locales need (CLDR also has zero and two)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PluralCategory {
+    One,
+    Few,
+    Many,
+    Other,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Locale {
+    English,
+    Polish,
+}
+
+impl Locale {
+    /// The plural category for a whole-number count, following the CLDR rules
+    pub fn plural_category(self, count: u64) -> PluralCategory {
+        match self {
+            Locale::English => match count {
+                1 => PluralCategory::One,
+                _ => PluralCategory::Other,
+            },
+            // 1 produkt, 2-4 produkty (but 12-14 produktów), everything else produktów
+            Locale::Polish => match (count % 10, count % 100) {
+                _ if count == 1 => PluralCategory::One,
+                (2..=4, n) if !(12..=14).contains(&n) => PluralCategory::Few,
+                _ => PluralCategory::Many,
+            },
+        }
+    }
+}
+
+/// Message templates keyed by locale and message id, one per plural category
+#[derive(Default)]
+pub struct Catalog {
+    messages: HashMap<(Locale, &'static str), HashMap<PluralCategory, &'static str>>,
+}
+
+impl Catalog {
+    pub fn add(
+        &mut self,
+        locale: Locale,
+        key: &'static str,
+        forms: &[(PluralCategory, &'static str)],
+    ) {
+        self.messages
+            .insert((locale, key), forms.iter().copied().collect());
+    }
+
+    /// Format `key` for `count`, falling back to the "other" form when the
+    /// locale's category has no template, and to the key itself when nothing does
+    pub fn plural(&self, locale: Locale, key: &str, count: u64) -> String {
+        let category = locale.plural_category(count);
+        let template = self.messages.get(&(locale, key)).and_then(|forms| {
+            forms
+                .get(&category)
+                .or_else(|| forms.get(&PluralCategory::Other))
+        });
+        match template {
+            Some(template) => template.replace("{count}", &count.to_string()),
+            None => key.to_string(),
+        }
+    }
+}
+
+pub fn catalog() -> Catalog {
+    use PluralCategory::*;
+
+    let mut catalog = Catalog::default();
+    catalog.add(
+        Locale::English,
+        "cart.items",
+        &[(One, "{count} item"), (Other, "{count} items")],
+    );
+    catalog.add(
+        Locale::Polish,
+        "cart.items",
+        &[
+            (One, "{count} produkt"),
+            (Few, "{count} produkty"),
+            (Many, "{count} produktów"),
+        ],
+    );
+    catalog
+}
+
+#[derive(Clone)]
+pub struct CartSummaryProps {
+    pub locale: Locale,
+    pub count: u64,
+}
+
+/// Shows how many items are in the cart, in the current locale
+pub struct CartSummary {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    locale: Signal<Locale>,
+    count: Signal<u64>,
+    label: ReactiveComputed<String, Box<dyn FnMut() -> String>>,
+}
+
+impl Component for CartSummary {
+    type Props = CartSummaryProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let locale = create_signal(&scope, props.locale);
+        let count = create_signal(&scope, props.count);
+
+        let catalog = catalog();
+        let locale_value = locale.value.clone();
+        let count_value = count.value.clone();
+        let label = create_computed(
+            &scope,
+            Box::new(move || {
+                catalog.plural(*locale_value.borrow(), "cart.items", *count_value.borrow())
+            }) as Box<dyn FnMut() -> String>,
+        );
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            locale,
+            count,
+            label,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.locale
+            .set(props.locale)
+            .map_err(|e| ComponentError::UpdateError(format!("Failed to set locale: {}", e)))?;
+        self.count
+            .set(props.count)
+            .map_err(|e| ComponentError::UpdateError(format!("Failed to set count: {}", e)))
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut summary = Node::default();
+        summary.add_attribute("role".to_string(), "status".to_string());
+        summary.add_child(Node::text(&self.label()));
+        Ok(vec![summary])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl CartSummary {
+    pub fn set_locale(&self, locale: Locale) {
+        self.locale
+            .set(locale)
+            .unwrap_or_else(|e| eprintln!("Failed to set locale: {}", e));
+    }
+
+    pub fn set_count(&self, count: u64) {
+        self.count
+            .set(count)
+            .unwrap_or_else(|e| eprintln!("Failed to set count: {}", e));
+    }
+
+    pub fn label(&self) -> String {
+        self.label.get().map(|l| l.clone()).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(locale: Locale) -> Vec<String> {
+        let cart = CartSummary::create(CartSummaryProps { locale, count: 0 }, Context::new());
+        [0, 1, 5]
+            .into_iter()
+            .map(|count| {
+                cart.set_count(count);
+                cart.label()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_english_plural_forms() {
+        assert_eq!(labels(Locale::English), ["0 items", "1 item", "5 items"]);
+    }
+
+    #[test]
+    fn test_polish_plural_forms() {
+        assert_eq!(
+            labels(Locale::Polish),
+            ["0 produktów", "1 produkt", "5 produktów"]
+        );
+    }
+
+    #[test]
+    fn test_polish_few_skips_the_teens() {
+        assert_eq!(Locale::Polish.plural_category(2), PluralCategory::Few);
+        assert_eq!(Locale::Polish.plural_category(12), PluralCategory::Many);
+        assert_eq!(Locale::Polish.plural_category(22), PluralCategory::Few);
+    }
+
+    #[test]
+    fn test_locale_switch_updates_label() {
+        let cart = CartSummary::create(
+            CartSummaryProps {
+                locale: Locale::English,
+                count: 3,
+            },
+            Context::new(),
+        );
+
+        cart.set_locale(Locale::Polish);
+
+        assert_eq!(cart.label(), "3 produkty");
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("I18n Plurals Example\n");
+
+    let cart = CartSummary::create(
+        CartSummaryProps {
+            locale: Locale::English,
+            count: 0,
+        },
+        Context::new(),
+    );
+
+    for locale in [Locale::English, Locale::Polish] {
+        cart.set_locale(locale);
+        println!("{:?}:", locale);
+        for count in [0, 1, 2, 5, 12, 22] {
+            cart.set_count(count);
+            cart.render()?;
+            println!("  {:>2} -> {}", count, cart.label());
+        }
+        println!();
+    }
+
+    println!("I18n Plurals example completed!");
+    Ok(())
+}
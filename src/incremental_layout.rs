@@ -0,0 +1,287 @@
+//! Example demonstrating incremental layout recomputation with dirty flags
+//! Resizing a node marks it and its ancestors dirty. The next layout pass only
+//! recomputes dirty nodes; clean subtrees keep their cached sizes and child positions
+//! and are merely shifted by their parent when an earlier sibling changes size.
+//! To run: cargo run --example incremental_layout
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+
+/// Identifier of a node in the layout tree
+pub type LayoutId = usize;
+
+#[derive(Debug)]
+struct LayoutNode {
+    name: String,
+    parent: Option<LayoutId>,
+    children: Vec<LayoutId>,
+    // Height requested by a leaf; containers derive theirs from their children
+    intrinsic_height: f32,
+    // Cached layout results
+    height: f32,
+    // Offset relative to the parent's top edge
+    y: f32,
+    dirty: bool,
+    // How many times this node's layout was recomputed
+    layout_runs: usize,
+}
+
+/// A vertical-stack layout tree with per-node dirty flags
+#[derive(Debug, Default)]
+pub struct LayoutTree {
+    nodes: Vec<LayoutNode>,
+    gap: f32,
+}
+
+impl LayoutTree {
+    pub fn new(gap: f32) -> Self {
+        Self {
+            nodes: Vec::new(),
+            gap,
+        }
+    }
+
+    /// Add a node under `parent` (or as the root)
+    pub fn add(&mut self, parent: Option<LayoutId>, name: &str, height: f32) -> LayoutId {
+        let id = self.nodes.len();
+        self.nodes.push(LayoutNode {
+            name: name.to_string(),
+            parent,
+            children: Vec::new(),
+            intrinsic_height: height,
+            height: 0.0,
+            y: 0.0,
+            dirty: true,
+            layout_runs: 0,
+        });
+
+        if let Some(parent) = parent {
+            self.nodes[parent].children.push(id);
+            self.mark_dirty(parent);
+        }
+        id
+    }
+
+    /// Change a leaf's height, invalidating it and its ancestors
+    pub fn resize(&mut self, id: LayoutId, height: f32) {
+        if self.nodes[id].intrinsic_height != height {
+            self.nodes[id].intrinsic_height = height;
+            self.mark_dirty(id);
+        }
+    }
+
+    fn mark_dirty(&mut self, id: LayoutId) {
+        let mut current = Some(id);
+        while let Some(id) = current {
+            self.nodes[id].dirty = true;
+            current = self.nodes[id].parent;
+        }
+    }
+
+    /// Lay out the tree from the root, recomputing only dirty nodes
+    pub fn layout(&mut self) {
+        if !self.nodes.is_empty() {
+            self.layout_node(0);
+        }
+    }
+
+    fn layout_node(&mut self, id: LayoutId) -> f32 {
+        if !self.nodes[id].dirty {
+            return self.nodes[id].height;
+        }
+
+        self.nodes[id].layout_runs += 1;
+
+        let children = self.nodes[id].children.clone();
+        let height = if children.is_empty() {
+            self.nodes[id].intrinsic_height
+        } else {
+            let mut y = 0.0;
+            for (index, child) in children.iter().enumerate() {
+                if index > 0 {
+                    y += self.gap;
+                }
+                // Clean children are only repositioned, not laid out again
+                self.nodes[*child].y = y;
+                y += self.layout_node(*child);
+            }
+            y
+        };
+
+        let node = &mut self.nodes[id];
+        node.height = height;
+        node.dirty = false;
+        height
+    }
+
+    /// Absolute y position of a node
+    pub fn absolute_y(&self, id: LayoutId) -> f32 {
+        let mut y = 0.0;
+        let mut current = Some(id);
+        while let Some(id) = current {
+            y += self.nodes[id].y;
+            current = self.nodes[id].parent;
+        }
+        y
+    }
+
+    pub fn local_y(&self, id: LayoutId) -> f32 {
+        self.nodes[id].y
+    }
+
+    pub fn height(&self, id: LayoutId) -> f32 {
+        self.nodes[id].height
+    }
+
+    pub fn layout_runs(&self, id: LayoutId) -> usize {
+        self.nodes[id].layout_runs
+    }
+
+    fn to_node(&self, id: LayoutId) -> Node {
+        let layout = &self.nodes[id];
+        let mut node = Node::default();
+        node.add_attribute("name".to_string(), layout.name.clone());
+        node.add_attribute("y".to_string(), self.absolute_y(id).to_string());
+        node.add_attribute("height".to_string(), layout.height.to_string());
+        for child in &layout.children {
+            node.add_child(self.to_node(*child));
+        }
+        node
+    }
+}
+
+/// A settings page whose sections are laid out incrementally
+pub struct SettingsPage {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    tree: LayoutTree,
+    // Node ids used by the demo
+    account_email: LayoutId,
+    notifications: LayoutId,
+    notifications_email: LayoutId,
+}
+
+impl Component for SettingsPage {
+    type Props = ();
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(_props: Self::Props, context: Context) -> Self {
+        let mut tree = LayoutTree::new(8.0);
+        let root = tree.add(None, "page", 0.0);
+
+        let account = tree.add(Some(root), "account", 0.0);
+        tree.add(Some(account), "account.name", 40.0);
+        let account_email = tree.add(Some(account), "account.email", 40.0);
+
+        let notifications = tree.add(Some(root), "notifications", 0.0);
+        let notifications_email = tree.add(Some(notifications), "notifications.email", 32.0);
+        tree.add(Some(notifications), "notifications.push", 32.0);
+
+        tree.layout();
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            tree,
+            account_email,
+            notifications,
+            notifications_email,
+        }
+    }
+
+    fn update(&mut self, _props: Self::Props) -> Result<(), ComponentError> {
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        Ok(vec![self.tree.to_node(0)])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl SettingsPage {
+    /// Show a validation message under the account email field, growing it
+    pub fn show_email_error(&mut self, visible: bool) {
+        let height = if visible { 64.0 } else { 40.0 };
+        self.tree.resize(self.account_email, height);
+        self.tree.layout();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resizing_leaf_keeps_sibling_subtree_cached() {
+        let mut page = SettingsPage::create((), Context::new());
+        let email = page.notifications_email;
+        let runs_before = page.tree.layout_runs(email);
+        let local_before = page.tree.local_y(email);
+        let section_y_before = page.tree.absolute_y(page.notifications);
+
+        page.show_email_error(true);
+
+        // The sibling subtree wasn't laid out again and kept its internal positions
+        assert_eq!(page.tree.layout_runs(email), runs_before);
+        assert_eq!(page.tree.layout_runs(page.notifications), 1);
+        assert_eq!(page.tree.local_y(email), local_before);
+
+        // It was only shifted down by the growth of the section above
+        assert_eq!(
+            page.tree.absolute_y(page.notifications),
+            section_y_before + 24.0
+        );
+    }
+
+    #[test]
+    fn test_resized_path_is_recomputed() {
+        let mut page = SettingsPage::create((), Context::new());
+
+        page.show_email_error(true);
+
+        assert_eq!(page.tree.layout_runs(page.account_email), 2);
+        assert_eq!(page.tree.layout_runs(0), 2);
+        assert_eq!(page.tree.height(page.account_email), 64.0);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Incremental Layout Example\n");
+
+    let mut page = SettingsPage::create((), Context::new());
+    page.render()?;
+    println!(
+        "Initial: page height {}, notifications at y={}",
+        page.tree.height(0),
+        page.tree.absolute_y(page.notifications)
+    );
+
+    println!("\nShowing an error under the account email field...");
+    page.show_email_error(true);
+    page.render()?;
+    println!(
+        "After resize: page height {}, notifications at y={}",
+        page.tree.height(0),
+        page.tree.absolute_y(page.notifications)
+    );
+    println!(
+        "Layout runs: account.email={}, notifications={}, notifications.email={}",
+        page.tree.layout_runs(page.account_email),
+        page.tree.layout_runs(page.notifications),
+        page.tree.layout_runs(page.notifications_email)
+    );
+
+    println!("\nIncremental Layout example completed!");
+    Ok(())
+}
@@ -0,0 +1,266 @@
+//! Example demonstrating a kanban board with drag between columns
+//! Each column's cards live in their own signal. Dragging a card removes it from the
+//! source column and inserts it into the target column at the drop index, keeping the
+//! order of everything else.
+//! To run: cargo run --example kanban
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Card {
+    pub id: u32,
+    pub title: String,
+}
+
+pub struct Column {
+    pub title: String,
+    pub cards: Signal<Vec<Card>>,
+}
+
+/// The card currently being dragged
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct DragSource {
+    column: usize,
+    index: usize,
+}
+
+#[derive(Clone)]
+pub struct KanbanProps {
+    pub columns: Vec<(String, Vec<Card>)>,
+}
+
+/// A board of columns whose cards can be dragged around
+pub struct KanbanBoard {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    columns: Vec<Column>,
+    dragging: Option<DragSource>,
+}
+
+impl Component for KanbanBoard {
+    type Props = KanbanProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let columns = props
+            .columns
+            .into_iter()
+            .map(|(title, cards)| Column {
+                title,
+                cards: create_signal(&scope, cards),
+            })
+            .collect();
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            columns,
+            dragging: None,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        if props.columns.len() != self.columns.len() {
+            return Err(ComponentError::UpdateError(
+                "Kanban columns cannot be added or removed".to_string(),
+            ));
+        }
+        for (column, (title, cards)) in self.columns.iter_mut().zip(props.columns) {
+            column.title = title;
+            column.cards.set(cards).map_err(|e| {
+                ComponentError::UpdateError(format!("Failed to update cards: {}", e))
+            })?;
+        }
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut board = Node::default();
+        board.add_attribute("class".to_string(), "kanban".to_string());
+
+        for (column_index, column) in self.columns.iter().enumerate() {
+            let mut list = Node::default();
+            list.add_attribute("aria-label".to_string(), column.title.clone());
+
+            for (index, card) in column.cards.get().iter().enumerate() {
+                let dragged = self.dragging
+                    == Some(DragSource {
+                        column: column_index,
+                        index,
+                    });
+                let mut node = Node::default();
+                node.add_attribute("draggable".to_string(), "true".to_string());
+                node.add_attribute("aria-grabbed".to_string(), dragged.to_string());
+                node.add_child(Node::text(&card.title));
+                list.add_child(node);
+            }
+
+            board.add_child(list);
+        }
+
+        Ok(vec![board])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl KanbanBoard {
+    /// Pick up the card at `index` in `column`
+    pub fn start_drag(&mut self, column: usize, index: usize) {
+        let exists = self
+            .columns
+            .get(column)
+            .map_or(false, |c| index < c.cards.get().len());
+        self.dragging = exists.then_some(DragSource { column, index });
+    }
+
+    /// Drop the dragged card into `column` at `index`; returns false if nothing moved
+    pub fn drop_at(&mut self, column: usize, index: usize) -> bool {
+        let source = match self.dragging.take() {
+            Some(source) => source,
+            None => return false,
+        };
+        if column >= self.columns.len() {
+            return false;
+        }
+
+        let mut card = None;
+        self.columns[source.column]
+            .cards
+            .update(|cards| card = Some(cards.remove(source.index)))
+            .unwrap_or_else(|e| eprintln!("Failed to remove card: {}", e));
+        let card = match card {
+            Some(card) => card,
+            None => return false,
+        };
+
+        self.columns[column]
+            .cards
+            .update(|cards| {
+                // Past-the-end drops append to the column
+                let index = index.min(cards.len());
+                cards.insert(index, card);
+            })
+            .unwrap_or_else(|e| eprintln!("Failed to insert card: {}", e));
+        true
+    }
+
+    /// Card titles of a column, in order
+    pub fn titles(&self, column: usize) -> Vec<String> {
+        self.columns[column]
+            .cards
+            .get()
+            .iter()
+            .map(|card| card.title.clone())
+            .collect()
+    }
+
+    pub fn columns(&self) -> &[Column] {
+        &self.columns
+    }
+}
+
+fn board() -> KanbanBoard {
+    let cards = |titles: &[(u32, &str)]| {
+        titles
+            .iter()
+            .map(|&(id, title)| Card {
+                id,
+                title: title.to_string(),
+            })
+            .collect()
+    };
+
+    KanbanBoard::create(
+        KanbanProps {
+            columns: vec![
+                ("To Do".to_string(), cards(&[(1, "Design"), (2, "Docs")])),
+                ("Doing".to_string(), cards(&[(3, "API"), (4, "Tests")])),
+                ("Done".to_string(), cards(&[(5, "Setup")])),
+            ],
+        },
+        Context::new(),
+    )
+}
+
+fn print_board(board: &KanbanBoard) {
+    for (index, column) in board.columns().iter().enumerate() {
+        println!("  {:<6} {:?}", column.title, board.titles(index));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drag_between_columns_preserves_order() {
+        let mut board = board();
+
+        board.start_drag(0, 0);
+        assert!(board.drop_at(1, 1));
+
+        assert_eq!(board.titles(0), vec!["Docs"]);
+        assert_eq!(board.titles(1), vec!["API", "Design", "Tests"]);
+    }
+
+    #[test]
+    fn test_drop_without_drag_does_nothing() {
+        let mut board = board();
+
+        assert!(!board.drop_at(2, 0));
+
+        assert_eq!(board.titles(2), vec!["Setup"]);
+    }
+
+    #[test]
+    fn test_reorder_within_column() {
+        let mut board = board();
+
+        board.start_drag(1, 0);
+        board.drop_at(1, 1);
+
+        assert_eq!(board.titles(1), vec!["Tests", "API"]);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Kanban Example\n");
+
+    let mut board = board();
+    println!("Initial board:");
+    print_board(&board);
+
+    println!("\nDragging 'API' from Doing to the end of Done...");
+    board.start_drag(1, 0);
+    board.render()?;
+    board.drop_at(2, usize::MAX);
+    print_board(&board);
+
+    println!("\nDragging 'Design' from To Do to the top of Doing...");
+    board.start_drag(0, 0);
+    board.drop_at(1, 0);
+    board.render()?;
+    print_board(&board);
+
+    let ids: Vec<u32> = board.columns()[1].cards.get().iter().map(|c| c.id).collect();
+    println!("\nDoing card ids: {:?}", ids);
+
+    println!("\nKanban example completed!");
+    Ok(())
+}
@@ -0,0 +1,303 @@
+//! Example making the props_and_events form fully keyboard operable
+//! Tab / Shift+Tab move between the fields and the submit button, Enter submits from
+//! any field, and Escape resets the form to its initial values.
+//! To run: cargo run --example keyboard_form
+
+use std::sync::Arc;
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+use winit::event::VirtualKeyCode;
+
+// Define a KeyboardEvent for the example, mirroring the MouseEvent in props_and_events
+#[derive(Debug, Clone, Copy)]
+pub struct KeyboardEvent {
+    pub key: VirtualKeyCode,
+    pub shift: bool,
+}
+
+impl KeyboardEvent {
+    pub fn key(key: VirtualKeyCode) -> Self {
+        Self { key, shift: false }
+    }
+
+    pub fn shift(key: VirtualKeyCode) -> Self {
+        Self { key, shift: true }
+    }
+}
+
+/// Submitted form values, in field order
+pub type FormValues = Vec<(String, String)>;
+
+#[derive(Clone)]
+pub struct KeyboardFormProps {
+    pub fields: Vec<String>,
+    pub on_submit: Option<Arc<dyn Fn(FormValues) + Send + Sync>>,
+}
+
+/// A form whose fields and submit button are reachable and operable from the keyboard
+pub struct KeyboardForm {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: KeyboardFormProps,
+    values: Signal<Vec<String>>,
+    // Index into the focus order; the submit button comes after the fields
+    focus_index: Signal<usize>,
+}
+
+impl Component for KeyboardForm {
+    type Props = KeyboardFormProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let values = create_signal(&scope, vec![String::new(); props.fields.len()]);
+        let focus_index = create_signal(&scope, 0);
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            props,
+            values,
+            focus_index,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        // Keep what was typed into surviving fields, one value per field
+        let fields = props.fields.len();
+        self.props = props;
+        self.values
+            .update(|values| values.resize(fields, String::new()))
+            .map_err(|e| ComponentError::UpdateError(format!("Failed to resize fields: {}", e)))?;
+        // The submit button sits right after the last field
+        self.focus_index
+            .update(|index| *index = (*index).min(fields))
+            .map_err(|e| ComponentError::UpdateError(format!("Failed to clamp focus: {}", e)))?;
+
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut form = Node::default();
+        form.add_attribute("tag".to_string(), "form".to_string());
+
+        let focused = *self.focus_index.get();
+        let values = self.values.get();
+
+        for (index, field) in self.props.fields.iter().enumerate() {
+            let mut input = Node::default();
+            input.add_attribute("tag".to_string(), "input".to_string());
+            input.add_attribute("name".to_string(), field.clone());
+            input.add_attribute("value".to_string(), values[index].clone());
+            input.add_attribute("tabindex".to_string(), "0".to_string());
+            if index == focused {
+                input.add_attribute("data-focused".to_string(), "true".to_string());
+            }
+            form.add_child(input);
+        }
+
+        let mut submit = Node::default();
+        submit.add_attribute("tag".to_string(), "button".to_string());
+        submit.add_attribute("type".to_string(), "submit".to_string());
+        if focused == self.props.fields.len() {
+            submit.add_attribute("data-focused".to_string(), "true".to_string());
+        }
+        submit.add_child(Node::text("Submit"));
+        form.add_child(submit);
+
+        Ok(vec![form])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl KeyboardForm {
+    // Number of focus stops: every field plus the submit button
+    fn focus_stops(&self) -> usize {
+        self.props.fields.len() + 1
+    }
+
+    /// Handle a key press on the form
+    pub fn handle_key(&self, event: KeyboardEvent) {
+        match event.key {
+            VirtualKeyCode::Tab => {
+                let stops = self.focus_stops();
+                self.focus_index
+                    .update(|index| {
+                        *index = if event.shift {
+                            (*index + stops - 1) % stops
+                        } else {
+                            (*index + 1) % stops
+                        };
+                    })
+                    .unwrap_or_else(|e| eprintln!("Failed to move focus: {}", e));
+            }
+            VirtualKeyCode::Return | VirtualKeyCode::NumpadEnter => self.submit(),
+            VirtualKeyCode::Escape => self.reset(),
+            _ => {}
+        }
+    }
+
+    /// Type text into the focused field; ignored when the submit button has focus
+    pub fn type_text(&self, text: &str) {
+        let focused = *self.focus_index.get();
+        if focused >= self.props.fields.len() {
+            return;
+        }
+
+        self.values
+            .update(|values| values[focused].push_str(text))
+            .unwrap_or_else(|e| eprintln!("Failed to update field: {}", e));
+    }
+
+    pub fn focused_field(&self) -> Option<String> {
+        self.props.fields.get(*self.focus_index.get()).cloned()
+    }
+
+    fn submit(&self) {
+        let values: FormValues = self
+            .props
+            .fields
+            .iter()
+            .cloned()
+            .zip(self.values.get().iter().cloned())
+            .collect();
+
+        println!("Form submitted via keyboard: {:?}", values);
+        if let Some(on_submit) = &self.props.on_submit {
+            on_submit(values);
+        }
+    }
+
+    fn reset(&self) {
+        println!("Form reset");
+        let empty = vec![String::new(); self.props.fields.len()];
+        self.values
+            .set(empty)
+            .unwrap_or_else(|e| eprintln!("Failed to reset form: {}", e));
+        self.focus_index
+            .set(0)
+            .unwrap_or_else(|e| eprintln!("Failed to reset focus: {}", e));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    fn form_with_sink(sink: Arc<Mutex<Option<FormValues>>>) -> KeyboardForm {
+        KeyboardForm::create(
+            KeyboardFormProps {
+                fields: vec!["name".to_string(), "email".to_string()],
+                on_submit: Some(Arc::new(move |values| {
+                    *sink.lock().unwrap() = Some(values);
+                })),
+            },
+            Context::new(),
+        )
+    }
+
+    #[test]
+    fn test_tab_navigation_and_enter_submit() {
+        let submitted = Arc::new(Mutex::new(None));
+        let form = form_with_sink(submitted.clone());
+
+        form.type_text("Ada");
+        form.handle_key(KeyboardEvent::key(VirtualKeyCode::Tab));
+        assert_eq!(form.focused_field().as_deref(), Some("email"));
+        form.type_text("ada@example.com");
+        form.handle_key(KeyboardEvent::key(VirtualKeyCode::Return));
+
+        let values = submitted.lock().unwrap().clone().expect("submit callback fired");
+        assert_eq!(values[0].1, "Ada");
+        assert_eq!(values[1].1, "ada@example.com");
+    }
+
+    #[test]
+    fn test_shift_tab_wraps_and_escape_resets() {
+        let form = form_with_sink(Arc::new(Mutex::new(None)));
+
+        form.type_text("draft");
+        form.handle_key(KeyboardEvent::shift(VirtualKeyCode::Tab));
+        assert_eq!(form.focused_field(), None);
+
+        form.handle_key(KeyboardEvent::key(VirtualKeyCode::Escape));
+        assert_eq!(form.focused_field().as_deref(), Some("name"));
+        assert!(form.values.get().iter().all(String::is_empty));
+    }
+
+    #[test]
+    fn test_update_resizes_fields_and_clamps_focus() {
+        let mut form = form_with_sink(Arc::new(Mutex::new(None)));
+        form.type_text("Ada");
+        form.handle_key(KeyboardEvent::shift(VirtualKeyCode::Tab));
+
+        form.update(KeyboardFormProps {
+            fields: vec!["name".to_string()],
+            on_submit: None,
+        })
+        .unwrap();
+        // Focus was on the old submit button, past the only remaining field
+        assert_eq!(form.focused_field(), None);
+        assert_eq!(*form.focus_index.get(), 1);
+
+        form.update(KeyboardFormProps {
+            fields: vec!["name".to_string(), "email".to_string(), "phone".to_string()],
+            on_submit: None,
+        })
+        .unwrap();
+        // Focus stayed at index 1, now the email field
+        form.handle_key(KeyboardEvent::key(VirtualKeyCode::Tab));
+        form.type_text("555-0100");
+
+        assert!(form.render().is_ok());
+        assert_eq!(*form.values.get(), ["Ada", "", "555-0100"]);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Keyboard-Operable Form Example\n");
+
+    let form = KeyboardForm::create(
+        KeyboardFormProps {
+            fields: vec!["name".to_string(), "email".to_string()],
+            on_submit: Some(Arc::new(|values: FormValues| {
+                println!("on_submit callback received {} field(s)", values.len());
+            })),
+        },
+        Context::new(),
+    );
+
+    println!("Focused: {:?}", form.focused_field());
+    form.type_text("Grace Hopper");
+
+    form.handle_key(KeyboardEvent::key(VirtualKeyCode::Tab));
+    println!("Tab -> focused: {:?}", form.focused_field());
+    form.type_text("grace@example.com");
+    form.render()?;
+
+    // Pressing Enter in the last field submits the form
+    form.handle_key(KeyboardEvent::key(VirtualKeyCode::Return));
+
+    // Escape clears everything
+    form.handle_key(KeyboardEvent::key(VirtualKeyCode::Escape));
+    form.render()?;
+
+    println!("\nKeyboard Form example completed!");
+    Ok(())
+}
@@ -0,0 +1,294 @@
+//! Example demonstrating cached text measurement to avoid layout thrash
+//! Measuring text is expensive, and layout often runs several passes over the same
+//! content. Measurements are cached by content and width constraint so repeated passes
+//! reuse them; changing a block's content makes the next pass measure it again.
+//! To run: cargo run --example layout_measure_cache
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+
+// Approximate glyph metrics for the fake text shaper
+const CHAR_WIDTH: f32 = 8.0;
+const LINE_HEIGHT: f32 = 18.0;
+
+/// Measured size of a block of text
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Size {
+    pub width: f32,
+    pub height: f32,
+}
+
+/// Cache key: the content plus the width constraint it was measured against
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct MeasureKey {
+    content: String,
+    // f32 isn't hashable, so the constraint is stored as its bit pattern
+    max_width_bits: u32,
+}
+
+/// Text measurer with a cache in front of the expensive measure function
+#[derive(Default)]
+pub struct MeasureCache {
+    entries: RefCell<HashMap<MeasureKey, Size>>,
+    // How many times the real measure function ran
+    measure_calls: Cell<usize>,
+    hits: Cell<usize>,
+}
+
+impl MeasureCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Measure `content` wrapped to `max_width`, using the cache when possible
+    pub fn measure(&self, content: &str, max_width: f32) -> Size {
+        let key = MeasureKey {
+            content: content.to_string(),
+            max_width_bits: max_width.to_bits(),
+        };
+
+        if let Some(size) = self.entries.borrow().get(&key) {
+            self.hits.set(self.hits.get() + 1);
+            return *size;
+        }
+
+        let size = self.measure_uncached(content, max_width);
+        self.entries.borrow_mut().insert(key, size);
+        size
+    }
+
+    /// Drop every cached measurement of `content`
+    pub fn invalidate(&self, content: &str) {
+        self.entries
+            .borrow_mut()
+            .retain(|key, _| key.content != content);
+    }
+
+    pub fn measure_calls(&self) -> usize {
+        self.measure_calls.get()
+    }
+
+    pub fn hits(&self) -> usize {
+        self.hits.get()
+    }
+
+    // Stand-in for real text shaping: greedy word wrap with fixed-width glyphs
+    fn measure_uncached(&self, content: &str, max_width: f32) -> Size {
+        self.measure_calls.set(self.measure_calls.get() + 1);
+
+        let max_chars = ((max_width / CHAR_WIDTH).floor() as usize).max(1);
+        let mut lines = 0;
+        let mut widest = 0;
+        let mut current = 0;
+
+        for word in content.split_whitespace() {
+            let len = word.chars().count();
+            let needed = if current == 0 { len } else { current + 1 + len };
+            if needed > max_chars && current > 0 {
+                widest = widest.max(current);
+                lines += 1;
+                current = len;
+            } else {
+                current = needed;
+            }
+        }
+        if current > 0 || lines == 0 {
+            widest = widest.max(current);
+            lines += 1;
+        }
+
+        Size {
+            width: (widest as f32 * CHAR_WIDTH).min(max_width),
+            height: lines as f32 * LINE_HEIGHT,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ArticleProps {
+    pub paragraphs: Vec<String>,
+    pub width: f32,
+}
+
+/// An article that stacks paragraphs vertically using cached measurements
+pub struct Article {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    props: ArticleProps,
+    cache: MeasureCache,
+}
+
+impl Component for Article {
+    type Props = ArticleProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            id: ComponentId::new(),
+            context,
+            props,
+            cache: MeasureCache::new(),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        // Old content won't be laid out again, so its measurements can go
+        for old in &self.props.paragraphs {
+            if !props.paragraphs.contains(old) {
+                self.cache.invalidate(old);
+            }
+        }
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut article = Node::default();
+        article.add_attribute("tag".to_string(), "article".to_string());
+
+        for (text, y) in self.props.paragraphs.iter().zip(self.layout()) {
+            let mut paragraph = Node::default();
+            paragraph.add_attribute("y".to_string(), y.to_string());
+            paragraph.add_child(Node::text(text));
+            article.add_child(paragraph);
+        }
+
+        Ok(vec![article])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl Article {
+    /// Run a layout pass, returning the y offset of each paragraph
+    pub fn layout(&self) -> Vec<f32> {
+        let mut y = 0.0;
+        self.props
+            .paragraphs
+            .iter()
+            .map(|text| {
+                let top = y;
+                y += self.cache.measure(text, self.props.width).height;
+                top
+            })
+            .collect()
+    }
+
+    pub fn cache(&self) -> &MeasureCache {
+        &self.cache
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repeated_layout_measures_once() {
+        let article = Article::create(
+            ArticleProps {
+                paragraphs: vec!["The quick brown fox jumps over the lazy dog".to_string()],
+                width: 120.0,
+            },
+            Context::new(),
+        );
+
+        let first = article.layout();
+        let second = article.layout();
+
+        assert_eq!(first, second);
+        assert_eq!(article.cache().measure_calls(), 1);
+        assert_eq!(article.cache().hits(), 1);
+    }
+
+    #[test]
+    fn test_changed_content_is_measured_again() {
+        let mut article = Article::create(
+            ArticleProps {
+                paragraphs: vec!["Hello".to_string()],
+                width: 120.0,
+            },
+            Context::new(),
+        );
+        article.layout();
+
+        article
+            .update(ArticleProps {
+                paragraphs: vec!["Hello, world".to_string()],
+                width: 120.0,
+            })
+            .unwrap();
+        article.layout();
+
+        assert_eq!(article.cache().measure_calls(), 2);
+    }
+
+    #[test]
+    fn test_different_constraint_is_a_separate_entry() {
+        let cache = MeasureCache::new();
+
+        let wide = cache.measure("one two three four", 400.0);
+        let narrow = cache.measure("one two three four", 40.0);
+
+        assert!(narrow.height > wide.height);
+        assert_eq!(cache.measure_calls(), 2);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Layout Measurement Cache Example\n");
+
+    let mut article = Article::create(
+        ArticleProps {
+            paragraphs: vec![
+                "Orbit lays out text by measuring each paragraph.".to_string(),
+                "Measuring is slow, so results are cached.".to_string(),
+                "Repeated layout passes reuse cached sizes.".to_string(),
+            ],
+            width: 160.0,
+        },
+        Context::new(),
+    );
+
+    // Several layout passes, as happens when parents re-run layout
+    for pass in 1..=3 {
+        article.render()?;
+        println!(
+            "Pass {}: measure calls = {}, cache hits = {}",
+            pass,
+            article.cache().measure_calls(),
+            article.cache().hits()
+        );
+    }
+
+    println!("\nEditing the second paragraph...");
+    article.update(ArticleProps {
+        paragraphs: vec![
+            "Orbit lays out text by measuring each paragraph.".to_string(),
+            "Edited content must be measured again.".to_string(),
+            "Repeated layout passes reuse cached sizes.".to_string(),
+        ],
+        width: 160.0,
+    })?;
+    article.render()?;
+    println!(
+        "After edit: measure calls = {}, cache hits = {}",
+        article.cache().measure_calls(),
+        article.cache().hits()
+    );
+
+    println!("\nLayout Measurement Cache example completed!");
+    Ok(())
+}
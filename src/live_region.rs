@@ -0,0 +1,218 @@
+//! Example demonstrating accessible live-region announcements
+//! Status messages are pushed into a queue and shown one at a time inside an
+//! `aria-live` region so screen readers announce them. Each message stays in the
+//! region for an announce window and is then cleared so the next one can be read.
+//! To run: cargo run --example live_region
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+/// How urgently assistive technology should announce a message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Politeness {
+    Polite,
+    Assertive,
+}
+
+impl Politeness {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Politeness::Polite => "polite",
+            Politeness::Assertive => "assertive",
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct LiveRegionProps {
+    pub politeness: Politeness,
+    // How long a message stays in the region before it is cleared
+    pub announce_window: Duration,
+}
+
+/// The live region; renders the message currently being announced
+pub struct LiveRegion {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: LiveRegionProps,
+    // Message in the region and when it was placed there
+    current: Signal<Option<(String, Instant)>>,
+    pending: VecDeque<String>,
+}
+
+impl Component for LiveRegion {
+    type Props = LiveRegionProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let current = create_signal(&scope, None);
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            props,
+            current,
+            pending: VecDeque::new(),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        // The region itself must always be present so screen readers observe changes
+        let mut region = Node::default();
+        region.add_attribute("role".to_string(), "status".to_string());
+        region.add_attribute(
+            "aria-live".to_string(),
+            self.props.politeness.as_str().to_string(),
+        );
+        region.add_attribute("aria-atomic".to_string(), "true".to_string());
+        region.add_attribute("class".to_string(), "visually-hidden".to_string());
+
+        if let Some(message) = self.current_message() {
+            region.add_child(Node::text(&message));
+        }
+
+        Ok(vec![region])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl LiveRegion {
+    /// Queue a message for announcement
+    pub fn announce(&mut self, message: &str, now: Instant) {
+        self.pending.push_back(message.to_string());
+        self.tick(now);
+    }
+
+    /// Advance the queue: clear an expired message and show the next pending one
+    pub fn tick(&mut self, now: Instant) {
+        let expired = match &*self.current.get() {
+            Some((_, shown_at)) => {
+                now.saturating_duration_since(*shown_at) >= self.props.announce_window
+            }
+            None => true,
+        };
+        if !expired {
+            return;
+        }
+
+        let next = self.pending.pop_front().map(|message| {
+            println!("Announcing: {}", message);
+            (message, now)
+        });
+        self.current.set(next).unwrap_or_else(|e| {
+            eprintln!("Failed to update live region: {}", e);
+        });
+    }
+
+    /// The message currently in the region
+    pub fn current_message(&self) -> Option<String> {
+        self.current.get().as_ref().map(|(message, _)| message.clone())
+    }
+
+    pub fn pending_count(&self) -> usize {
+        self.pending.len()
+    }
+}
+
+fn status_region() -> LiveRegion {
+    LiveRegion::create(
+        LiveRegionProps {
+            politeness: Politeness::Polite,
+            announce_window: Duration::from_millis(500),
+        },
+        Context::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_message_is_announced_then_cleared() {
+        let mut region = status_region();
+        let start = Instant::now();
+
+        region.announce("Saved", start);
+        assert_eq!(region.current_message().as_deref(), Some("Saved"));
+        assert_eq!(region.render().unwrap()[0].children().len(), 1);
+
+        region.tick(start + Duration::from_millis(500));
+        assert_eq!(region.current_message(), None);
+        assert!(region.render().unwrap()[0].children().is_empty());
+    }
+
+    #[test]
+    fn test_queued_messages_wait_for_window() {
+        let mut region = status_region();
+        let start = Instant::now();
+
+        region.announce("Saved", start);
+        region.announce("Synced", start + Duration::from_millis(100));
+        assert_eq!(region.current_message().as_deref(), Some("Saved"));
+        assert_eq!(region.pending_count(), 1);
+
+        region.tick(start + Duration::from_millis(600));
+        assert_eq!(region.current_message().as_deref(), Some("Synced"));
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Live Region Example\n");
+
+    let mut region = status_region();
+    let start = Instant::now();
+
+    region.announce("Saved", start);
+    region.announce("3 new messages", start);
+    region.render()?;
+    println!(
+        "Region shows {:?}, {} pending",
+        region.current_message(),
+        region.pending_count()
+    );
+
+    // Simulate time passing in announce-window steps
+    for step in 1..=3 {
+        region.tick(start + Duration::from_millis(500 * step));
+        region.render()?;
+        println!("After {}ms: {:?}", 500 * step, region.current_message());
+    }
+
+    // Errors go to a separate assertive region so they interrupt the user
+    let mut alerts = LiveRegion::create(
+        LiveRegionProps {
+            politeness: Politeness::Assertive,
+            announce_window: Duration::from_secs(1),
+        },
+        Context::new(),
+    );
+    alerts.announce("Upload failed", Instant::now());
+    alerts.render()?;
+
+    println!("\nLive Region example completed!");
+    Ok(())
+}
@@ -0,0 +1,310 @@
+//! Example demonstrating a log viewer with live tailing and filtering
+//! Entries are appended to a bounded `Signal<VecDeque<LogEntry>>`. A level filter and a
+//! text filter narrow what is shown, and the view keeps following new entries unless
+//! the user has scrolled up to read older ones.
+//! To run: cargo run --example log_viewer
+
+use std::collections::VecDeque;
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogEntry {
+    pub level: Level,
+    pub message: String,
+}
+
+#[derive(Clone)]
+pub struct LogViewerProps {
+    // Oldest entries are dropped beyond this many
+    pub capacity: usize,
+    // Number of rows visible at once
+    pub viewport_rows: usize,
+}
+
+/// A tailing, filterable view of log entries
+pub struct LogViewer {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: LogViewerProps,
+    entries: Signal<VecDeque<LogEntry>>,
+    min_level: Signal<Level>,
+    text_filter: Signal<String>,
+    // Index of the first visible row among the filtered entries
+    scroll_top: Signal<usize>,
+    following: Signal<bool>,
+}
+
+impl Component for LogViewer {
+    type Props = LogViewerProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            entries: create_signal(&scope, VecDeque::new()),
+            min_level: create_signal(&scope, Level::Debug),
+            text_filter: create_signal(&scope, String::new()),
+            scroll_top: create_signal(&scope, 0),
+            following: create_signal(&scope, true),
+            scope,
+            props,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut log = Node::default();
+        log.add_attribute("role".to_string(), "log".to_string());
+        log.add_attribute("aria-live".to_string(), "polite".to_string());
+
+        for entry in self.visible_rows() {
+            let mut row = Node::default();
+            row.add_attribute(
+                "class".to_string(),
+                format!("log-{:?}", entry.level).to_lowercase(),
+            );
+            row.add_child(Node::text(&format!("[{:?}] {}", entry.level, entry.message)));
+            log.add_child(row);
+        }
+
+        Ok(vec![log])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl LogViewer {
+    /// Append an entry, keeping the view pinned to the bottom while following
+    pub fn append(&self, level: Level, message: &str) {
+        let capacity = self.props.capacity.max(1);
+        self.entries
+            .update(|entries| {
+                entries.push_back(LogEntry {
+                    level,
+                    message: message.to_string(),
+                });
+                while entries.len() > capacity {
+                    entries.pop_front();
+                }
+            })
+            .unwrap_or_else(|e| eprintln!("Failed to append log entry: {}", e));
+
+        if self.is_following() {
+            self.scroll_to_bottom();
+        }
+    }
+
+    pub fn set_min_level(&self, level: Level) {
+        self.min_level
+            .set(level)
+            .unwrap_or_else(|e| eprintln!("Failed to set level filter: {}", e));
+        self.refilter();
+    }
+
+    pub fn set_text_filter(&self, text: &str) {
+        self.text_filter
+            .set(text.to_lowercase())
+            .unwrap_or_else(|e| eprintln!("Failed to set text filter: {}", e));
+        self.refilter();
+    }
+
+    // Filters change the row count, so re-pin or clamp the scroll position
+    fn refilter(&self) {
+        if self.is_following() {
+            self.scroll_to_bottom();
+        } else {
+            let top = *self.scroll_top.get();
+            self.scroll_to(top);
+        }
+    }
+
+    /// Entries passing both filters, oldest first
+    pub fn filtered(&self) -> Vec<LogEntry> {
+        let min_level = *self.min_level.get();
+        let text = self.text_filter.get();
+        self.entries
+            .get()
+            .iter()
+            .filter(|entry| entry.level >= min_level)
+            .filter(|entry| text.is_empty() || entry.message.to_lowercase().contains(&*text))
+            .cloned()
+            .collect()
+    }
+
+    fn max_scroll(&self) -> usize {
+        self.filtered()
+            .len()
+            .saturating_sub(self.props.viewport_rows)
+    }
+
+    /// Scroll to a row; scrolling up stops following, reaching the bottom resumes it
+    pub fn scroll_to(&self, row: usize) {
+        let max = self.max_scroll();
+        let row = row.min(max);
+        self.scroll_top
+            .set(row)
+            .unwrap_or_else(|e| eprintln!("Failed to scroll: {}", e));
+        self.following
+            .set(row == max)
+            .unwrap_or_else(|e| eprintln!("Failed to update follow mode: {}", e));
+    }
+
+    pub fn scroll_to_bottom(&self) {
+        self.scroll_to(usize::MAX);
+    }
+
+    pub fn is_following(&self) -> bool {
+        *self.following.get()
+    }
+
+    /// The filtered entries currently in the viewport
+    pub fn visible_rows(&self) -> Vec<LogEntry> {
+        self.filtered()
+            .into_iter()
+            .skip(*self.scroll_top.get())
+            .take(self.props.viewport_rows)
+            .collect()
+    }
+}
+
+fn viewer() -> LogViewer {
+    LogViewer::create(
+        LogViewerProps {
+            capacity: 100,
+            viewport_rows: 3,
+        },
+        Context::new(),
+    )
+}
+
+fn print_rows(viewer: &LogViewer) {
+    for entry in viewer.visible_rows() {
+        println!("  [{:?}] {}", entry.level, entry.message);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mixed_logs() -> LogViewer {
+        let viewer = viewer();
+        viewer.append(Level::Info, "server started");
+        viewer.append(Level::Error, "db connection lost");
+        viewer.append(Level::Warn, "retrying");
+        viewer.append(Level::Debug, "pool size 4");
+        viewer.append(Level::Error, "request failed");
+        viewer
+    }
+
+    #[test]
+    fn test_error_filter_shows_only_errors() {
+        let viewer = mixed_logs();
+
+        viewer.set_min_level(Level::Error);
+
+        let messages: Vec<String> = viewer.filtered().into_iter().map(|e| e.message).collect();
+        assert_eq!(messages, vec!["db connection lost", "request failed"]);
+    }
+
+    #[test]
+    fn test_text_filter_is_case_insensitive() {
+        let viewer = mixed_logs();
+
+        viewer.set_text_filter("DB");
+
+        assert_eq!(viewer.filtered().len(), 1);
+    }
+
+    #[test]
+    fn test_scrolled_up_view_does_not_follow() {
+        let viewer = mixed_logs();
+        viewer.scroll_to(0);
+        assert!(!viewer.is_following());
+
+        viewer.append(Level::Info, "new entry");
+
+        assert_eq!(viewer.visible_rows()[0].message, "server started");
+    }
+
+    #[test]
+    fn test_following_view_tails_new_entries() {
+        let viewer = mixed_logs();
+
+        viewer.append(Level::Info, "new entry");
+
+        assert_eq!(viewer.visible_rows().last().unwrap().message, "new entry");
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Log Viewer Example\n");
+
+    let viewer = viewer();
+    let logs = [
+        (Level::Info, "listening on :8080"),
+        (Level::Debug, "loaded 12 routes"),
+        (Level::Warn, "slow query (1.2s)"),
+        (Level::Error, "upstream timeout"),
+        (Level::Info, "GET /health 200"),
+        (Level::Error, "panic in worker 3"),
+    ];
+    for (level, message) in logs {
+        viewer.append(level, message);
+    }
+
+    println!("Tailing the last 3 rows:");
+    print_rows(&viewer);
+
+    println!("\nErrors only:");
+    viewer.set_min_level(Level::Error);
+    viewer.render()?;
+    print_rows(&viewer);
+
+    println!("\nAll levels, scrolled to the top, then a new entry arrives:");
+    viewer.set_min_level(Level::Debug);
+    viewer.scroll_to(0);
+    viewer.append(Level::Info, "GET /users 200");
+    print_rows(&viewer);
+    println!("  following: {}", viewer.is_following());
+
+    println!("\nScrolling back to the bottom resumes tailing:");
+    viewer.scroll_to_bottom();
+    viewer.append(Level::Warn, "cache miss ratio 40%");
+    print_rows(&viewer);
+
+    viewer.set_text_filter("GET");
+    println!("\nText filter 'GET': {} entries", viewer.filtered().len());
+
+    println!("\nLog Viewer example completed!");
+    Ok(())
+}
@@ -0,0 +1,198 @@
+//! Example demonstrating a masonry (Pinterest-style) layout
+//! Items of varying height are placed one by one into whichever of the N columns is
+//! currently shortest, which keeps the column heights balanced.
+//! To run: cargo run --example masonry_layout
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+
+/// Where an item ended up
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Placement {
+    pub column: usize,
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+/// Place items with the given heights into `columns` columns
+pub fn masonry(heights: &[f32], columns: usize, width: f32, gap: f32) -> Vec<Placement> {
+    let columns = columns.max(1);
+    let column_width = (width - gap * (columns - 1) as f32) / columns as f32;
+    let mut column_heights = vec![0.0_f32; columns];
+
+    heights
+        .iter()
+        .map(|&height| {
+            // Shortest column wins; ties go to the leftmost column
+            let column = column_heights
+                .iter()
+                .enumerate()
+                .fold(0, |best, (index, &h)| {
+                    if h < column_heights[best] {
+                        index
+                    } else {
+                        best
+                    }
+                });
+
+            let y = column_heights[column];
+            column_heights[column] += height + gap;
+
+            Placement {
+                column,
+                x: column as f32 * (column_width + gap),
+                y,
+                width: column_width,
+                height,
+            }
+        })
+        .collect()
+}
+
+#[derive(Clone)]
+pub struct Pin {
+    pub title: String,
+    pub height: f32,
+}
+
+#[derive(Clone)]
+pub struct MasonryGridProps {
+    pub pins: Vec<Pin>,
+    pub columns: usize,
+    pub width: f32,
+    pub gap: f32,
+}
+
+/// A grid of pins laid out in masonry columns
+pub struct MasonryGrid {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    props: MasonryGridProps,
+}
+
+impl Component for MasonryGrid {
+    type Props = MasonryGridProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            id: ComponentId::new(),
+            context,
+            props,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut grid = Node::default();
+        grid.add_attribute("class".to_string(), "masonry".to_string());
+
+        for (pin, placement) in self.props.pins.iter().zip(self.placements()) {
+            let mut card = Node::default();
+            card.add_attribute("x".to_string(), placement.x.to_string());
+            card.add_attribute("y".to_string(), placement.y.to_string());
+            card.add_attribute("width".to_string(), placement.width.to_string());
+            card.add_attribute("height".to_string(), placement.height.to_string());
+            card.add_child(Node::text(&pin.title));
+            grid.add_child(card);
+        }
+
+        Ok(vec![grid])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl MasonryGrid {
+    pub fn placements(&self) -> Vec<Placement> {
+        let heights: Vec<f32> = self.props.pins.iter().map(|pin| pin.height).collect();
+        masonry(&heights, self.props.columns, self.props.width, self.props.gap)
+    }
+
+    /// Total height of each column, including gaps
+    pub fn column_heights(&self) -> Vec<f32> {
+        let mut heights = vec![0.0; self.props.columns.max(1)];
+        for placement in self.placements() {
+            heights[placement.column] = placement.y + placement.height;
+        }
+        heights
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_items_land_in_shortest_column() {
+        let placements = masonry(&[100.0, 50.0, 80.0, 30.0, 60.0], 3, 320.0, 10.0);
+        let columns: Vec<usize> = placements.iter().map(|p| p.column).collect();
+
+        // Column heights (with gaps) after the first three items are 110, 60 and 90,
+        // so 30 goes under the 50 and then 60 goes under the 80
+        assert_eq!(columns, vec![0, 1, 2, 1, 2]);
+        assert_eq!(placements[3].y, 60.0);
+        assert_eq!(placements[4].y, 90.0);
+    }
+
+    #[test]
+    fn test_column_geometry() {
+        let placements = masonry(&[10.0, 10.0], 2, 210.0, 10.0);
+
+        assert_eq!(placements[0].width, 100.0);
+        assert_eq!(placements[1].x, 110.0);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Masonry Layout Example\n");
+
+    let heights = [220.0, 140.0, 300.0, 180.0, 120.0, 260.0, 90.0, 200.0];
+    let pins = heights
+        .iter()
+        .enumerate()
+        .map(|(index, &height)| Pin {
+            title: format!("Pin {}", index + 1),
+            height,
+        })
+        .collect();
+
+    let grid = MasonryGrid::create(
+        MasonryGridProps {
+            pins,
+            columns: 3,
+            width: 720.0,
+            gap: 12.0,
+        },
+        Context::new(),
+    );
+    grid.render()?;
+
+    for (index, placement) in grid.placements().iter().enumerate() {
+        println!(
+            "  Pin {} -> column {} at y={}",
+            index + 1,
+            placement.column,
+            placement.y
+        );
+    }
+    println!("Column heights: {:?}", grid.column_heights());
+
+    println!("\nMasonry Layout example completed!");
+    Ok(())
+}
@@ -0,0 +1,199 @@
+//! Example demonstrating per-component memory sampling
+//! Each sampled render is timed through `PerformanceRegistry`, and the retained node
+//! tree is walked to count its nodes and estimate how much memory it holds. The
+//! registry only tracks render times, so the memory samples are kept alongside it,
+//! keyed by the same component ids, and ranked to surface the heaviest components.
+//! To run: cargo run --example memory_sampling
+
+use std::cell::RefCell;
+use std::mem;
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node, PerformanceRegistry};
+
+/// The latest memory sample for one component
+#[derive(Debug, Clone)]
+pub struct MemorySample {
+    pub id: ComponentId,
+    pub name: String,
+    pub nodes: usize,
+    // Node structs only; heap data such as text and attributes isn't counted
+    pub approx_bytes: usize,
+}
+
+/// Count `nodes` and everything below them
+pub fn count_nodes(nodes: &[Node]) -> usize {
+    nodes.iter().map(|node| 1 + count_nodes(node.children())).sum()
+}
+
+/// Render timings plus retained-tree size per component
+pub struct MemorySampler {
+    registry: PerformanceRegistry,
+    samples: RefCell<Vec<MemorySample>>,
+}
+
+impl MemorySampler {
+    pub fn new() -> Self {
+        Self {
+            registry: PerformanceRegistry::new(),
+            samples: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Render `component` and record the size of the tree it returned
+    pub fn sample<C: Component>(&self, name: &str, component: &C) -> Result<(), ComponentError> {
+        let id = component.component_id();
+        let monitor = self.registry.monitor();
+        let timer = monitor.start_render_timing(id);
+        let nodes = component.render()?;
+        drop(timer);
+
+        let count = count_nodes(&nodes);
+        let sample = MemorySample {
+            id,
+            name: name.to_string(),
+            nodes: count,
+            approx_bytes: count * mem::size_of::<Node>(),
+        };
+
+        // Keep only the latest sample per component
+        let mut samples = self.samples.borrow_mut();
+        match samples.iter_mut().find(|s| s.id == id) {
+            Some(existing) => *existing = sample,
+            None => samples.push(sample),
+        }
+        Ok(())
+    }
+
+    /// The `limit` components retaining the most nodes, heaviest first
+    pub fn heaviest(&self, limit: usize) -> Vec<MemorySample> {
+        let mut samples = self.samples.borrow().clone();
+        samples.sort_by(|a, b| b.nodes.cmp(&a.nodes));
+        samples.truncate(limit);
+        samples
+    }
+}
+
+impl Default for MemorySampler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone)]
+pub struct TableProps {
+    pub rows: usize,
+    pub columns: usize,
+}
+
+/// A table rendering one node per row and per cell
+pub struct Table {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    props: TableProps,
+}
+
+impl Component for Table {
+    type Props = TableProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            id: ComponentId::new(),
+            context,
+            props,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut table = Node::default();
+        table.add_attribute("role".to_string(), "table".to_string());
+        for row in 0..self.props.rows {
+            let mut tr = Node::default();
+            tr.add_attribute("role".to_string(), "row".to_string());
+            for column in 0..self.props.columns {
+                tr.add_child(Node::text(&format!("{},{}", row, column)));
+            }
+            table.add_child(tr);
+        }
+        Ok(vec![table])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+fn table(rows: usize, columns: usize) -> Table {
+    Table::create(TableProps { rows, columns }, Context::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_heaviest_component_is_reported_first() {
+        let sampler = MemorySampler::new();
+        sampler.sample("small", &table(2, 2)).unwrap();
+        sampler.sample("large", &table(40, 5)).unwrap();
+        sampler.sample("medium", &table(10, 3)).unwrap();
+
+        let heaviest = sampler.heaviest(3);
+
+        let names: Vec<_> = heaviest.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["large", "medium", "small"]);
+        // Table, 40 rows and 200 cells
+        assert_eq!(heaviest[0].nodes, 241);
+    }
+
+    #[test]
+    fn test_resampling_replaces_previous_sample() {
+        let sampler = MemorySampler::new();
+        let mut grid = table(10, 10);
+        sampler.sample("grid", &grid).unwrap();
+
+        grid.update(TableProps { rows: 1, columns: 1 }).unwrap();
+        sampler.sample("grid", &grid).unwrap();
+
+        let heaviest = sampler.heaviest(5);
+        assert_eq!(heaviest.len(), 1);
+        assert_eq!(heaviest[0].nodes, 3);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Memory Sampling Example\n");
+
+    let sampler = MemorySampler::new();
+    sampler.sample("Toolbar", &table(1, 6))?;
+    sampler.sample("Sidebar", &table(12, 1))?;
+    sampler.sample("Orders grid", &table(500, 8))?;
+    sampler.sample("Footer", &table(1, 3))?;
+
+    println!("Heaviest components:");
+    for (rank, sample) in sampler.heaviest(3).iter().enumerate() {
+        println!(
+            "  {}. {:<12} {:>5} nodes, ~{} KiB",
+            rank + 1,
+            sample.name,
+            sample.nodes,
+            sample.approx_bytes / 1024
+        );
+    }
+
+    println!("\nMemory Sampling example completed!");
+    Ok(())
+}
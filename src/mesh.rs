@@ -0,0 +1,132 @@
+//! Mesh data shared by the `wgpu_*` examples
+//! Each example is its own binary and pulls this in with `mod mesh;`. A `Mesh` is a
+//! list of vertices plus triangle indices into it, laid out the way the vertex shaders
+//! expect, and `MeshPrimitives` builds the stock shapes.
+
+use std::f32::consts::PI;
+
+use bytemuck::{Pod, Zeroable};
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Pod, Zeroable)]
+pub struct Vertex {
+    pub position: [f32; 3],
+    pub normal: [f32; 3],
+    pub uv: [f32; 2],
+}
+
+impl Vertex {
+    const ATTRIBUTES: [wgpu::VertexAttribute; 3] =
+        wgpu::vertex_attr_array![0 => Float32x3, 1 => Float32x3, 2 => Float32x2];
+
+    pub fn layout() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<Vertex>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Vertex,
+            attributes: &Self::ATTRIBUTES,
+        }
+    }
+}
+
+/// Indexed triangle geometry
+#[derive(Debug, Clone, Default)]
+pub struct Mesh {
+    pub vertices: Vec<Vertex>,
+    // Three per triangle, counter-clockwise when seen from outside
+    pub indices: Vec<u32>,
+}
+
+impl Mesh {
+    pub fn triangle_count(&self) -> usize {
+        self.indices.len() / 3
+    }
+}
+
+/// Builders for simple shapes centred on the origin
+pub struct MeshPrimitives;
+
+impl MeshPrimitives {
+    /// A cube with sides of length `size`; each face has its own vertices so its
+    /// normals stay flat
+    pub fn cube(size: f32) -> Mesh {
+        let h = size / 2.0;
+        // Normal, then the face's right and up directions
+        let faces: [([f32; 3], [f32; 3], [f32; 3]); 6] = [
+            ([0.0, 0.0, 1.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]),
+            ([0.0, 0.0, -1.0], [-1.0, 0.0, 0.0], [0.0, 1.0, 0.0]),
+            ([1.0, 0.0, 0.0], [0.0, 0.0, -1.0], [0.0, 1.0, 0.0]),
+            ([-1.0, 0.0, 0.0], [0.0, 0.0, 1.0], [0.0, 1.0, 0.0]),
+            ([0.0, 1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, -1.0]),
+            ([0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]),
+        ];
+
+        let mut mesh = Mesh::default();
+        for (normal, right, up) in faces {
+            let base = mesh.vertices.len() as u32;
+            for (u, v) in [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)] {
+                let (sx, sy) = (u * 2.0 - 1.0, v * 2.0 - 1.0);
+                let position = [0, 1, 2].map(|i| (normal[i] + right[i] * sx + up[i] * sy) * h);
+                mesh.vertices.push(Vertex {
+                    position,
+                    normal,
+                    // Image rows run downwards
+                    uv: [u, 1.0 - v],
+                });
+            }
+            mesh.indices.extend([base, base + 1, base + 2, base, base + 2, base + 3]);
+        }
+        mesh
+    }
+
+    /// A flat square of side `size` in the XZ plane, facing up
+    pub fn plane(size: f32) -> Mesh {
+        let h = size / 2.0;
+        let corners = [(-h, h, 0.0, 1.0), (h, h, 1.0, 1.0), (h, -h, 1.0, 0.0), (-h, -h, 0.0, 0.0)];
+        Mesh {
+            vertices: corners
+                .iter()
+                .map(|&(x, z, u, v)| Vertex {
+                    position: [x, 0.0, z],
+                    normal: [0.0, 1.0, 0.0],
+                    uv: [u, v],
+                })
+                .collect(),
+            indices: vec![0, 1, 2, 0, 2, 3],
+        }
+    }
+
+    /// A UV sphere; more `segments` (around) and `rings` (top to bottom) make it rounder
+    pub fn sphere(radius: f32, segments: u32, rings: u32) -> Mesh {
+        let (segments, rings) = (segments.max(3), rings.max(2));
+        let mut mesh = Mesh::default();
+
+        for ring in 0..=rings {
+            let v = ring as f32 / rings as f32;
+            let polar = v * PI;
+            for segment in 0..=segments {
+                let u = segment as f32 / segments as f32;
+                let azimuth = u * 2.0 * PI;
+                let normal = [
+                    polar.sin() * azimuth.cos(),
+                    polar.cos(),
+                    -polar.sin() * azimuth.sin(),
+                ];
+                mesh.vertices.push(Vertex {
+                    position: normal.map(|n| n * radius),
+                    normal,
+                    uv: [u, v],
+                });
+            }
+        }
+
+        let row = segments + 1;
+        for ring in 0..rings {
+            for segment in 0..segments {
+                let top = ring * row + segment;
+                let bottom = top + row;
+                mesh.indices.extend([top, bottom, top + 1, top + 1, bottom, bottom + 1]);
+            }
+        }
+        mesh
+    }
+}
@@ -0,0 +1,291 @@
+//! Example demonstrating render statistics exported for Prometheus
+//! Renders are timed through `PerformanceRegistry`, and `export` turns the registry's
+//! averages, plus render counts and worst cases kept alongside it, into the Prometheus
+//! text exposition format. Run with `--serve` to answer scrapes on
+//! http://127.0.0.1:9898/metrics until interrupted.
+//! To run: cargo run --example metrics_endpoint [-- --serve]
+
+use std::cell::RefCell;
+use std::fmt::Write as _;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::time::{Duration, Instant};
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node, PerformanceRegistry};
+
+const ADDRESS: &str = "127.0.0.1:9898";
+
+/// What the registry doesn't track for a component
+struct RenderCounts {
+    id: ComponentId,
+    name: String,
+    renders: u64,
+    slowest: Duration,
+}
+
+/// Render timings for a set of components, exportable as metrics
+pub struct RenderMetrics {
+    registry: PerformanceRegistry,
+    counts: RefCell<Vec<RenderCounts>>,
+}
+
+impl RenderMetrics {
+    pub fn new() -> Self {
+        Self {
+            registry: PerformanceRegistry::new(),
+            counts: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Render `component`, recording how long it took under `name`
+    pub fn render<C: Component>(
+        &self,
+        name: &str,
+        component: &C,
+    ) -> Result<Vec<Node>, ComponentError> {
+        let id = component.component_id();
+        let monitor = self.registry.monitor();
+        let timer = monitor.start_render_timing(id);
+        let started = Instant::now();
+
+        let nodes = component.render()?;
+
+        let elapsed = started.elapsed();
+        drop(timer);
+
+        let mut counts = self.counts.borrow_mut();
+        match counts.iter_mut().find(|c| c.id == id) {
+            Some(entry) => {
+                entry.renders += 1;
+                entry.slowest = entry.slowest.max(elapsed);
+            }
+            None => counts.push(RenderCounts {
+                id,
+                name: name.to_string(),
+                renders: 1,
+                slowest: elapsed,
+            }),
+        }
+        Ok(nodes)
+    }
+
+    /// Every component's statistics in the Prometheus text exposition format
+    pub fn export(&self) -> String {
+        let counts = self.counts.borrow();
+        let monitor = self.registry.monitor();
+        let mut out = String::new();
+
+        write_family(
+            &mut out,
+            &counts,
+            ("component_render_seconds", "gauge"),
+            "Average time spent rendering the component.",
+            |entry| {
+                let average = monitor.get_average_render_time(entry.id).unwrap_or_default();
+                average.as_secs_f64().to_string()
+            },
+        );
+        write_family(
+            &mut out,
+            &counts,
+            ("component_render_seconds_max", "gauge"),
+            "Slowest render of the component.",
+            |entry| entry.slowest.as_secs_f64().to_string(),
+        );
+        write_family(
+            &mut out,
+            &counts,
+            ("component_renders_total", "counter"),
+            "Number of times the component rendered.",
+            |entry| entry.renders.to_string(),
+        );
+        out
+    }
+}
+
+impl Default for RenderMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// One metric family: its help and type comments, then a sample per component
+fn write_family(
+    out: &mut String,
+    counts: &[RenderCounts],
+    (name, kind): (&str, &str),
+    help: &str,
+    value: impl Fn(&RenderCounts) -> String,
+) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} {}", name, kind);
+    for entry in counts {
+        let _ = writeln!(out, "{}{} {}", name, labels(entry), value(entry));
+    }
+}
+
+fn labels(entry: &RenderCounts) -> String {
+    format!(
+        "{{component=\"{}\",id=\"{}\"}}",
+        escape_label(&entry.name),
+        escape_label(&format!("{:?}", entry.id))
+    )
+}
+
+/// Label values may not contain raw backslashes, quotes or newlines
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Answer one HTTP request with `body`, whatever path was asked for
+fn respond(stream: TcpStream, body: &str) -> std::io::Result<()> {
+    let mut reader = BufReader::new(&stream);
+    let mut line = String::new();
+    // Skip the request line and headers
+    while reader.read_line(&mut line)? > 2 {
+        line.clear();
+    }
+
+    let mut stream = &stream;
+    write!(
+        stream,
+        "HTTP/1.1 200 OK\r\n\
+         Content-Type: text/plain; version=0.0.4\r\n\
+         Content-Length: {}\r\n\r\n{}",
+        body.len(),
+        body
+    )
+}
+
+#[derive(Clone)]
+pub struct ListProps {
+    pub items: usize,
+}
+
+/// A list rendering one text node per item
+pub struct List {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    props: ListProps,
+}
+
+impl Component for List {
+    type Props = ListProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            id: ComponentId::new(),
+            context,
+            props,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut list = Node::default();
+        list.add_attribute("role".to_string(), "list".to_string());
+        for item in 0..self.props.items {
+            list.add_child(Node::text(&format!("Item {}", item + 1)));
+        }
+        Ok(vec![list])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+fn list(items: usize) -> List {
+    List::create(ListProps { items }, Context::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_seconds_line_has_component_labels() {
+        let metrics = RenderMetrics::new();
+        let inbox = list(3);
+        metrics.render("inbox", &inbox).unwrap();
+
+        let prefix = format!(
+            "component_render_seconds{{component=\"inbox\",id=\"{:?}\"}} ",
+            inbox.component_id()
+        );
+        let line = metrics
+            .export()
+            .lines()
+            .find(|line| line.starts_with(&prefix))
+            .map(str::to_string)
+            .expect("missing component_render_seconds line");
+        let seconds: f64 = line[prefix.len()..].parse().unwrap();
+        assert!(seconds >= 0.0);
+    }
+
+    #[test]
+    fn test_render_count_per_component() {
+        let metrics = RenderMetrics::new();
+        let (header, feed) = (list(1), list(20));
+        metrics.render("header", &header).unwrap();
+        for _ in 0..3 {
+            metrics.render("feed", &feed).unwrap();
+        }
+
+        let export = metrics.export();
+        let count = |name: &str, id: ComponentId| {
+            format!("component_renders_total{{component=\"{}\",id=\"{:?}\"}}", name, id)
+        };
+        assert!(export.contains(&format!("{} 1\n", count("header", header.component_id()))));
+        assert!(export.contains(&format!("{} 3\n", count("feed", feed.component_id()))));
+    }
+
+    #[test]
+    fn test_label_values_are_escaped() {
+        assert_eq!(escape_label("say \"hi\"\\\n"), "say \\\"hi\\\"\\\\\\n");
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("Metrics Endpoint Example\n");
+
+    let metrics = RenderMetrics::new();
+    let components = [("header", list(1)), ("sidebar", list(12)), ("feed", list(400))];
+    for _ in 0..5 {
+        for (name, component) in &components {
+            metrics.render(name, component)?;
+        }
+    }
+    print!("{}", metrics.export());
+
+    if std::env::args().any(|arg| arg == "--serve") {
+        let listener = TcpListener::bind(ADDRESS)?;
+        println!("\nServing metrics on http://{}/metrics", ADDRESS);
+        for stream in listener.incoming() {
+            // Render again so each scrape sees fresh numbers
+            for (name, component) in &components {
+                metrics.render(name, component)?;
+            }
+            respond(stream?, &metrics.export())?;
+        }
+    }
+
+    println!("\nMetrics Endpoint example completed!");
+    Ok(())
+}
@@ -0,0 +1,228 @@
+//! Example demonstrating deterministic timing with a controllable clock
+//! Components read the time through a `Clock` instead of calling `Instant::now()`, so
+//! the real `SystemClock` can be swapped for a `MockClock` that only moves when
+//! `advance` is called. The countdown below finishes in tests without a single sleep.
+//! To run: cargo run --example mock_clock
+
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+/// Source of the current time
+pub trait Clock: Send + Sync {
+    fn now(&self) -> Instant;
+}
+
+/// The real clock
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// A clock that only moves when told to
+pub struct MockClock {
+    now: Mutex<Instant>,
+}
+
+impl MockClock {
+    pub fn new() -> Self {
+        Self {
+            now: Mutex::new(Instant::now()),
+        }
+    }
+
+    pub fn advance(&self, by: Duration) {
+        if let Ok(mut now) = self.now.lock() {
+            *now += by;
+        }
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        self.now.lock().map(|now| *now).unwrap_or_else(|_| Instant::now())
+    }
+}
+
+#[derive(Clone)]
+pub struct CountdownProps {
+    pub duration: Duration,
+    pub clock: Arc<dyn Clock>,
+}
+
+/// Counts down from `duration` once started
+pub struct Countdown {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: CountdownProps,
+    remaining: Signal<Duration>,
+    started_at: Option<Instant>,
+}
+
+impl Component for Countdown {
+    type Props = CountdownProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let remaining = create_signal(&scope, props.duration);
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            props,
+            remaining,
+            started_at: None,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut timer = Node::default();
+        timer.add_attribute("role".to_string(), "timer".to_string());
+        let label = if self.is_finished() {
+            "Time's up!".to_string()
+        } else {
+            format!("{}s left", self.remaining().as_secs_f32().ceil())
+        };
+        timer.add_child(Node::text(&label));
+        Ok(vec![timer])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl Countdown {
+    pub fn start(&mut self) {
+        if self.started_at.is_none() {
+            self.started_at = Some(self.props.clock.now());
+        }
+    }
+
+    /// Refresh the remaining time from the clock; call once per frame
+    pub fn tick(&self) {
+        if let Some(started_at) = self.started_at {
+            let elapsed = self.props.clock.now() - started_at;
+            self.remaining
+                .set(self.props.duration.saturating_sub(elapsed))
+                .unwrap_or_else(|e| eprintln!("Failed to update remaining time: {}", e));
+        }
+    }
+
+    pub fn remaining(&self) -> Duration {
+        *self.remaining.get()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.remaining().is_zero()
+    }
+}
+
+fn countdown(seconds: u64, clock: Arc<dyn Clock>) -> Countdown {
+    Countdown::create(
+        CountdownProps {
+            duration: Duration::from_secs(seconds),
+            clock,
+        },
+        Context::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_advancing_mock_clock_counts_down() {
+        let clock = Arc::new(MockClock::new());
+        let mut countdown = countdown(10, clock.clone());
+        countdown.start();
+
+        clock.advance(Duration::from_millis(3_500));
+        countdown.tick();
+
+        assert_eq!(countdown.remaining(), Duration::from_millis(6_500));
+        assert!(!countdown.is_finished());
+    }
+
+    #[test]
+    fn test_finishes_and_stays_at_zero() {
+        let clock = Arc::new(MockClock::new());
+        let mut countdown = countdown(5, clock.clone());
+        countdown.start();
+
+        clock.advance(Duration::from_secs(60));
+        countdown.tick();
+
+        assert_eq!(countdown.remaining(), Duration::ZERO);
+        assert!(countdown.is_finished());
+    }
+
+    #[test]
+    fn test_not_started_ignores_time() {
+        let clock = Arc::new(MockClock::new());
+        let countdown = countdown(5, clock.clone());
+
+        clock.advance(Duration::from_secs(3));
+        countdown.tick();
+
+        assert_eq!(countdown.remaining(), Duration::from_secs(5));
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Mock Clock Example\n");
+
+    println!("Mock clock, one simulated second per step:");
+    let clock = Arc::new(MockClock::new());
+    let mut mocked = countdown(3, clock.clone());
+    mocked.start();
+    while !mocked.is_finished() {
+        clock.advance(Duration::from_secs(1));
+        mocked.tick();
+        mocked.render()?;
+        println!("  {:?} remaining", mocked.remaining());
+    }
+
+    println!("\nSystem clock, sleeping for real:");
+    let mut real = countdown(1, Arc::new(SystemClock));
+    real.start();
+    while !real.is_finished() {
+        thread::sleep(Duration::from_millis(250));
+        real.tick();
+        println!("  {:.2?} remaining", real.remaining());
+    }
+    real.render()?;
+
+    println!("\nMock Clock example completed!");
+    Ok(())
+}
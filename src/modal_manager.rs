@@ -0,0 +1,267 @@
+//! Example demonstrating a modal manager that stacks multiple modals
+//! Modals are rendered into a single overlay layer (like a portal target) above the
+//! page. The manager assigns z-indices in stacking order, and both backdrop clicks and
+//! the Escape key only ever dismiss the topmost modal.
+//! To run: cargo run --example modal_manager
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+// z-index of the lowest modal; each stacked modal sits above the previous one
+const BASE_Z_INDEX: u32 = 1000;
+const Z_INDEX_STEP: u32 = 10;
+
+/// A modal that has been opened through the manager
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModalEntry {
+    pub key: String,
+    pub title: String,
+    pub body: String,
+    // Whether clicking the backdrop closes this modal
+    pub dismiss_on_backdrop: bool,
+}
+
+impl ModalEntry {
+    pub fn new(key: &str, title: &str, body: &str) -> Self {
+        Self {
+            key: key.to_string(),
+            title: title.to_string(),
+            body: body.to_string(),
+            dismiss_on_backdrop: true,
+        }
+    }
+}
+
+/// Keyboard keys the manager reacts to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    Escape,
+    Enter,
+}
+
+/// Owns the stack of open modals and renders them into the overlay layer
+pub struct ModalManager {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    // Open modals, bottom first
+    stack: Signal<Vec<ModalEntry>>,
+}
+
+impl Component for ModalManager {
+    type Props = ();
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(_props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let stack = create_signal(&scope, Vec::new());
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            stack,
+        }
+    }
+
+    fn update(&mut self, _props: Self::Props) -> Result<(), ComponentError> {
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let stack = self.stack.get();
+        if stack.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut layer = Node::default();
+        layer.add_attribute("class".to_string(), "modal-layer".to_string());
+
+        for (depth, modal) in stack.iter().enumerate() {
+            let z_index = Self::z_index_for(depth);
+
+            // Each modal gets its own backdrop just below it
+            let mut backdrop = Node::default();
+            backdrop.add_attribute("class".to_string(), "modal-backdrop".to_string());
+            backdrop.add_attribute("z-index".to_string(), (z_index - 1).to_string());
+            layer.add_child(backdrop);
+
+            let mut dialog = Node::default();
+            dialog.add_attribute("role".to_string(), "dialog".to_string());
+            dialog.add_attribute("aria-modal".to_string(), "true".to_string());
+            dialog.add_attribute("data-key".to_string(), modal.key.clone());
+            dialog.add_attribute("z-index".to_string(), z_index.to_string());
+            dialog.add_child(Node::text(&modal.title));
+            dialog.add_child(Node::text(&modal.body));
+            layer.add_child(dialog);
+        }
+
+        Ok(vec![layer])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl ModalManager {
+    fn z_index_for(depth: usize) -> u32 {
+        BASE_Z_INDEX + depth as u32 * Z_INDEX_STEP
+    }
+
+    /// Push a modal on top of the stack
+    pub fn open(&self, modal: ModalEntry) {
+        println!("Opening modal '{}'", modal.key);
+        self.stack
+            .update(|stack| {
+                // Re-opening an existing modal moves it to the top
+                stack.retain(|open| open.key != modal.key);
+                stack.push(modal);
+            })
+            .unwrap_or_else(|e| eprintln!("Failed to open modal: {}", e));
+    }
+
+    /// Close the topmost modal, returning it
+    pub fn close_top(&self) -> Option<ModalEntry> {
+        let mut closed = None;
+        self.stack
+            .update(|stack| closed = stack.pop())
+            .unwrap_or_else(|e| eprintln!("Failed to close modal: {}", e));
+
+        if let Some(modal) = &closed {
+            println!("Closed modal '{}'", modal.key);
+        }
+        closed
+    }
+
+    /// Handle a click on the backdrop belonging to the modal with `key`
+    pub fn click_backdrop(&self, key: &str) -> Option<ModalEntry> {
+        let top = self.top()?;
+
+        // Backdrops of modals underneath the top one are covered and can't be clicked
+        if top.key != key || !top.dismiss_on_backdrop {
+            return None;
+        }
+        self.close_top()
+    }
+
+    /// Handle a key press while any modal is open
+    pub fn handle_key(&self, key: Key) -> Option<ModalEntry> {
+        match key {
+            Key::Escape => self.close_top(),
+            _ => None,
+        }
+    }
+
+    /// The modal currently on top
+    pub fn top(&self) -> Option<ModalEntry> {
+        self.stack.get().last().cloned()
+    }
+
+    /// Keys of the open modals, bottom first
+    pub fn open_keys(&self) -> Vec<String> {
+        self.stack.get().iter().map(|modal| modal.key.clone()).collect()
+    }
+
+    /// z-index assigned to the modal with `key`, if it is open
+    pub fn z_index_of(&self, key: &str) -> Option<u32> {
+        self.stack
+            .get()
+            .iter()
+            .position(|modal| modal.key == key)
+            .map(Self::z_index_for)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manager_with_two() -> ModalManager {
+        let manager = ModalManager::create((), Context::new());
+        manager.open(ModalEntry::new("settings", "Settings", "..."));
+        manager.open(ModalEntry::new("confirm", "Discard changes?", "..."));
+        manager
+    }
+
+    #[test]
+    fn test_escape_closes_only_topmost() {
+        let manager = manager_with_two();
+
+        let closed = manager.handle_key(Key::Escape);
+
+        assert_eq!(closed.map(|modal| modal.key), Some("confirm".to_string()));
+        assert_eq!(manager.open_keys(), vec!["settings"]);
+
+        manager.handle_key(Key::Escape);
+        assert!(manager.open_keys().is_empty());
+    }
+
+    #[test]
+    fn test_backdrop_click_ignores_covered_modal() {
+        let manager = manager_with_two();
+
+        assert!(manager.click_backdrop("settings").is_none());
+        assert_eq!(manager.open_keys(), vec!["settings", "confirm"]);
+
+        assert!(manager.click_backdrop("confirm").is_some());
+        assert_eq!(manager.open_keys(), vec!["settings"]);
+    }
+
+    #[test]
+    fn test_stacked_modals_have_increasing_z_index() {
+        let manager = manager_with_two();
+
+        assert!(manager.z_index_of("confirm") > manager.z_index_of("settings"));
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Modal Manager Example\n");
+
+    let manager = ModalManager::create((), Context::new());
+
+    manager.open(ModalEntry::new(
+        "settings",
+        "Settings",
+        "Change your preferences",
+    ));
+    manager.open(ModalEntry::new(
+        "confirm",
+        "Discard changes?",
+        "Your edits will be lost",
+    ));
+
+    manager.render()?;
+    for key in manager.open_keys() {
+        println!("  '{}' at z-index {:?}", key, manager.z_index_of(&key));
+    }
+
+    println!("\nClicking the covered settings backdrop does nothing...");
+    manager.click_backdrop("settings");
+    println!("Open modals: {:?}", manager.open_keys());
+
+    println!("\nPressing Enter leaves the stack alone...");
+    manager.handle_key(Key::Enter);
+    println!("Open modals: {:?}", manager.open_keys());
+
+    println!("\nPressing Escape...");
+    manager.handle_key(Key::Escape);
+    println!("Open modals: {:?}", manager.open_keys());
+
+    println!("\nClicking the settings backdrop...");
+    manager.click_backdrop("settings");
+    println!("Open modals: {:?}", manager.open_keys());
+
+    println!("\nModal Manager example completed!");
+    Ok(())
+}
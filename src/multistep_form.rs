@@ -0,0 +1,354 @@
+//! Example demonstrating a multi-step form that keeps each step's data
+//! The wizard validates the current step before moving forward, while going back is
+//! always allowed. Values live in one signal per step, so earlier input survives
+//! navigation, and only the final step submits the whole form at once.
+//! To run: cargo run --example multistep_form
+
+use std::collections::BTreeMap;
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+/// One page of the wizard
+#[derive(Debug, Clone)]
+pub struct StepSchema {
+    pub title: String,
+    // Field names that must be filled before leaving the step
+    pub required: Vec<String>,
+}
+
+#[derive(Clone)]
+pub struct MultiStepFormProps {
+    pub steps: Vec<StepSchema>,
+}
+
+/// A wizard that preserves per-step values and submits at the end
+pub struct MultiStepForm {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: MultiStepFormProps,
+    current: Signal<usize>,
+    // Values entered on each step, keyed by field name
+    values: Vec<Signal<BTreeMap<String, String>>>,
+    errors: Signal<Vec<String>>,
+    submitted: Signal<Option<BTreeMap<String, String>>>,
+}
+
+impl Component for MultiStepForm {
+    type Props = MultiStepFormProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let values = props
+            .steps
+            .iter()
+            .map(|_| create_signal(&scope, BTreeMap::new()))
+            .collect();
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            current: create_signal(&scope, 0),
+            values,
+            errors: create_signal(&scope, Vec::new()),
+            submitted: create_signal(&scope, None),
+            scope,
+            props,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        if props.steps.len() != self.props.steps.len() {
+            return Err(ComponentError::UpdateError(
+                "The number of steps cannot change".to_string(),
+            ));
+        }
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let current = self.current_step();
+        let step = &self.props.steps[current];
+
+        let mut wizard = Node::default();
+        wizard.add_attribute("role".to_string(), "form".to_string());
+        wizard.add_attribute(
+            "aria-label".to_string(),
+            format!(
+                "Step {} of {}: {}",
+                current + 1,
+                self.props.steps.len(),
+                step.title
+            ),
+        );
+
+        for field in &step.required {
+            let mut input = Node::default();
+            input.add_attribute("name".to_string(), field.clone());
+            input.add_attribute("value".to_string(), self.value(current, field));
+            input.add_attribute("aria-required".to_string(), "true".to_string());
+            wizard.add_child(input);
+        }
+
+        for error in self.errors() {
+            let mut message = Node::default();
+            message.add_attribute("role".to_string(), "alert".to_string());
+            message.add_child(Node::text(&error));
+            wizard.add_child(message);
+        }
+
+        let mut back = Node::default();
+        back.add_attribute("disabled".to_string(), (current == 0).to_string());
+        back.add_child(Node::text("Back"));
+        wizard.add_child(back);
+
+        let mut forward = Node::default();
+        let label = if self.is_last_step() { "Submit" } else { "Next" };
+        forward.add_child(Node::text(label));
+        wizard.add_child(forward);
+
+        Ok(vec![wizard])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl MultiStepForm {
+    /// Set a field on the current step
+    pub fn set_value(&self, field: &str, value: &str) {
+        self.values[self.current_step()]
+            .update(|values| {
+                values.insert(field.to_string(), value.to_string());
+            })
+            .unwrap_or_else(|e| eprintln!("Failed to set {}: {}", field, e));
+    }
+
+    pub fn value(&self, step: usize, field: &str) -> String {
+        self.values[step]
+            .get()
+            .get(field)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    // Messages for required fields left blank on a step
+    fn validate_step(&self, step: usize) -> Vec<String> {
+        self.props.steps[step]
+            .required
+            .iter()
+            .filter(|field| self.value(step, field).trim().is_empty())
+            .map(|field| format!("{} is required", field))
+            .collect()
+    }
+
+    fn set_errors(&self, errors: Vec<String>) {
+        self.errors
+            .set(errors)
+            .unwrap_or_else(|e| eprintln!("Failed to set errors: {}", e));
+    }
+
+    fn go_to(&self, step: usize) {
+        self.current
+            .set(step)
+            .unwrap_or_else(|e| eprintln!("Failed to change step: {}", e));
+    }
+
+    /// Advance if the current step is valid; returns whether it moved
+    pub fn next(&self) -> bool {
+        let current = self.current_step();
+        let errors = self.validate_step(current);
+        let valid = errors.is_empty();
+        self.set_errors(errors);
+        if valid && !self.is_last_step() {
+            self.go_to(current + 1);
+            return true;
+        }
+        false
+    }
+
+    /// Go back one step without validating; entered values are kept
+    pub fn back(&self) -> bool {
+        let current = self.current_step();
+        self.set_errors(Vec::new());
+        if current == 0 {
+            return false;
+        }
+        self.go_to(current - 1);
+        true
+    }
+
+    /// Validate every step and submit all values together from the last step
+    pub fn submit(&self) -> bool {
+        if !self.is_last_step() {
+            return false;
+        }
+        // Jump back to the first step that has a problem
+        for step in 0..self.props.steps.len() {
+            let errors = self.validate_step(step);
+            if !errors.is_empty() {
+                self.go_to(step);
+                self.set_errors(errors);
+                return false;
+            }
+        }
+
+        let all: BTreeMap<String, String> = self
+            .values
+            .iter()
+            .flat_map(|values| values.get().clone())
+            .collect();
+        self.submitted
+            .set(Some(all))
+            .unwrap_or_else(|e| eprintln!("Failed to submit: {}", e));
+        true
+    }
+
+    pub fn current_step(&self) -> usize {
+        *self.current.get()
+    }
+
+    pub fn is_last_step(&self) -> bool {
+        self.current_step() + 1 == self.props.steps.len()
+    }
+
+    pub fn errors(&self) -> Vec<String> {
+        self.errors.get().clone()
+    }
+
+    pub fn submitted(&self) -> Option<BTreeMap<String, String>> {
+        self.submitted.get().clone()
+    }
+}
+
+fn signup_wizard() -> MultiStepForm {
+    let step = |title: &str, required: &[&str]| StepSchema {
+        title: title.to_string(),
+        required: required.iter().map(|field| field.to_string()).collect(),
+    };
+    MultiStepForm::create(
+        MultiStepFormProps {
+            steps: vec![
+                step("Account", &["email", "password"]),
+                step("Profile", &["name", "city"]),
+                step("Confirm", &[]),
+            ],
+        },
+        Context::new(),
+    )
+}
+
+fn print_step(form: &MultiStepForm) {
+    let current = form.current_step();
+    let step = &form.props.steps[current];
+    let fields: Vec<String> = step
+        .required
+        .iter()
+        .map(|field| format!("{}={:?}", field, form.value(current, field)))
+        .collect();
+    println!("  on step {} ({}): {}", current + 1, step.title, fields.join(", "));
+    for error in form.errors() {
+        println!("    ! {}", error);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_going_back_keeps_step_one_data() {
+        let form = signup_wizard();
+        form.set_value("email", "ada@example.com");
+        form.set_value("password", "hunter22");
+        assert!(form.next());
+
+        form.set_value("name", "Ada");
+        assert!(form.back());
+
+        assert_eq!(form.current_step(), 0);
+        assert_eq!(form.value(0, "email"), "ada@example.com");
+        assert_eq!(form.value(0, "password"), "hunter22");
+        assert_eq!(form.value(1, "name"), "Ada");
+    }
+
+    #[test]
+    fn test_next_blocks_on_invalid_step() {
+        let form = signup_wizard();
+        form.set_value("email", "ada@example.com");
+
+        assert!(!form.next());
+        assert_eq!(form.errors(), vec!["password is required"]);
+        assert_eq!(form.current_step(), 0);
+    }
+
+    #[test]
+    fn test_only_last_step_submits_everything() {
+        let form = signup_wizard();
+        assert!(!form.submit());
+
+        form.set_value("email", "ada@example.com");
+        form.set_value("password", "hunter22");
+        form.next();
+        form.set_value("name", "Ada");
+        form.set_value("city", "London");
+        form.next();
+
+        assert!(form.submit());
+        assert_eq!(form.submitted().unwrap().len(), 4);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Multi-step Form Example\n");
+
+    let form = signup_wizard();
+    form.render()?;
+    print_step(&form);
+
+    println!("\nTrying to continue with only an email...");
+    form.set_value("email", "ada@example.com");
+    form.next();
+    print_step(&form);
+
+    println!("\nAdding a password and continuing...");
+    form.set_value("password", "hunter22");
+    form.next();
+    form.set_value("name", "Ada");
+    print_step(&form);
+
+    println!("\nGoing back to step 1...");
+    form.back();
+    form.render()?;
+    print_step(&form);
+
+    println!("\nForward again, finishing the profile...");
+    form.next();
+    print_step(&form);
+    form.set_value("city", "London");
+    form.next();
+    print_step(&form);
+
+    println!("\nSubmitting: {}", form.submit());
+    if let Some(values) = form.submitted() {
+        for (field, value) in values {
+            println!("  {} = {}", field, value);
+        }
+    }
+
+    println!("\nMulti-step Form example completed!");
+    Ok(())
+}
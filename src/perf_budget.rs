@@ -0,0 +1,241 @@
+//! Example demonstrating a per-component render-time budget
+//! Renders are timed through `PerformanceRegistry`, and each component also keeps a
+//! rolling window of its most recent render times. A component whose average over that
+//! window exceeds the budget is flagged, and a warning is logged when it first crosses
+//! the line.
+//! To run: cargo run --example perf_budget
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node, PerformanceRegistry};
+
+/// Render times kept per component
+const WINDOW: usize = 5;
+
+struct Tracked {
+    id: ComponentId,
+    name: String,
+    samples: VecDeque<Duration>,
+    flagged: bool,
+}
+
+impl Tracked {
+    fn average(&self) -> Duration {
+        if self.samples.is_empty() {
+            return Duration::ZERO;
+        }
+        self.samples.iter().sum::<Duration>() / self.samples.len() as u32
+    }
+}
+
+/// Flags components whose recent renders run over budget
+pub struct RenderBudget {
+    budget: Duration,
+    registry: PerformanceRegistry,
+    tracked: RefCell<Vec<Tracked>>,
+}
+
+impl RenderBudget {
+    pub fn new(budget: Duration) -> Self {
+        Self {
+            budget,
+            registry: PerformanceRegistry::new(),
+            tracked: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Render `component`, timing it against the budget
+    pub fn render<C: Component>(
+        &self,
+        name: &str,
+        component: &C,
+    ) -> Result<Vec<Node>, ComponentError> {
+        let id = component.component_id();
+        let monitor = self.registry.monitor();
+        let timer = monitor.start_render_timing(id);
+        let started = Instant::now();
+
+        let nodes = component.render()?;
+
+        let elapsed = started.elapsed();
+        drop(timer);
+        self.record(name, id, elapsed);
+        Ok(nodes)
+    }
+
+    /// Add one render time to the component's window
+    pub fn record(&self, name: &str, id: ComponentId, elapsed: Duration) {
+        let mut tracked = self.tracked.borrow_mut();
+        let index = match tracked.iter().position(|t| t.id == id) {
+            Some(index) => index,
+            None => {
+                tracked.push(Tracked {
+                    id,
+                    name: name.to_string(),
+                    samples: VecDeque::with_capacity(WINDOW),
+                    flagged: false,
+                });
+                tracked.len() - 1
+            }
+        };
+
+        let entry = &mut tracked[index];
+        if entry.samples.len() == WINDOW {
+            entry.samples.pop_front();
+        }
+        entry.samples.push_back(elapsed);
+
+        let over = entry.average() > self.budget;
+        if over && !entry.flagged {
+            println!(
+                "  ⚠ {} is over budget: averaging {:?} against {:?}",
+                entry.name,
+                entry.average(),
+                self.budget
+            );
+        }
+        entry.flagged = over;
+    }
+
+    pub fn is_flagged(&self, id: ComponentId) -> bool {
+        self.tracked.borrow().iter().any(|t| t.id == id && t.flagged)
+    }
+
+    /// Names of every component currently over budget
+    pub fn flagged(&self) -> Vec<String> {
+        self.tracked
+            .borrow()
+            .iter()
+            .filter(|t| t.flagged)
+            .map(|t| t.name.clone())
+            .collect()
+    }
+
+    pub fn average(&self, id: ComponentId) -> Option<Duration> {
+        self.registry.monitor().get_average_render_time(id)
+    }
+}
+
+#[derive(Clone)]
+pub struct WorkProps {
+    pub label: String,
+    // Simulated work done on every render
+    pub cost: Duration,
+}
+
+/// A component whose render takes a configurable amount of time
+pub struct Widget {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    props: WorkProps,
+}
+
+impl Component for Widget {
+    type Props = WorkProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            id: ComponentId::new(),
+            context,
+            props,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        thread::sleep(self.props.cost);
+        Ok(vec![Node::text(&self.props.label)])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+fn widget(label: &str, cost: Duration) -> Widget {
+    Widget::create(
+        WorkProps {
+            label: label.to_string(),
+            cost,
+        },
+        Context::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_over_budget_component_is_flagged() {
+        let budget = RenderBudget::new(Duration::from_millis(8));
+        let (fast, slow) = (ComponentId::new(), ComponentId::new());
+
+        for _ in 0..WINDOW {
+            budget.record("fast", fast, Duration::from_millis(2));
+            budget.record("slow", slow, Duration::from_millis(12));
+        }
+
+        assert!(!budget.is_flagged(fast));
+        assert!(budget.is_flagged(slow));
+        assert_eq!(budget.flagged(), vec!["slow"]);
+    }
+
+    #[test]
+    fn test_flag_clears_once_window_recovers() {
+        let budget = RenderBudget::new(Duration::from_millis(8));
+        let id = ComponentId::new();
+
+        budget.record("chart", id, Duration::from_millis(30));
+        assert!(budget.is_flagged(id));
+
+        for _ in 0..WINDOW {
+            budget.record("chart", id, Duration::from_millis(1));
+        }
+        assert!(!budget.is_flagged(id));
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Perf Budget Example\n");
+
+    let budget = RenderBudget::new(Duration::from_millis(8));
+    let header = widget("Header", Duration::from_millis(1));
+    let chart = widget("Chart", Duration::from_millis(15));
+
+    println!("Rendering {} frames with an {:?} budget:", WINDOW, Duration::from_millis(8));
+    for _ in 0..WINDOW {
+        budget.render("Header", &header)?;
+        budget.render("Chart", &chart)?;
+    }
+
+    for (name, component) in [("Header", &header), ("Chart", &chart)] {
+        let id = component.component_id();
+        println!(
+            "\n{}: registry average {:?}, flagged: {}",
+            name,
+            budget.average(id),
+            budget.is_flagged(id)
+        );
+    }
+    println!("\nOver budget: {:?}", budget.flagged());
+
+    println!("\nPerf Budget example completed!");
+    Ok(())
+}
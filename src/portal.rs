@@ -0,0 +1,319 @@
+//! Example demonstrating a portal that renders its child somewhere else in the tree
+//! `Portal` renders the component it wraps, but instead of returning the nodes inline
+//! it files them under a named target in a shared registry. Whoever owns that target
+//! (here the app root's "overlay" layer) takes the nodes after rendering the tree and
+//! places them there, so a tooltip declared next to its button ends up on top of
+//! everything instead of being clipped by the button's container.
+//! To run: cargo run --example portal
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+
+/// Nodes waiting to be placed, keyed by portal target name
+pub type PortalTargets = Arc<Mutex<HashMap<String, Vec<Node>>>>;
+
+/// Take everything portalled into `target`, leaving it empty for the next render
+pub fn take_target(targets: &PortalTargets, target: &str) -> Result<Vec<Node>, ComponentError> {
+    let mut targets = targets
+        .lock()
+        .map_err(|e| ComponentError::RenderError(format!("Portal targets poisoned: {}", e)))?;
+    Ok(targets.remove(target).unwrap_or_default())
+}
+
+#[derive(Clone)]
+pub struct PortalProps<P> {
+    pub target: String,
+    pub targets: PortalTargets,
+    pub child: P,
+}
+
+/// Renders its child into the named target rather than in its own place
+pub struct Portal<C: Component> {
+    child: C,
+    target: String,
+    targets: PortalTargets,
+}
+
+impl<C: Component> Component for Portal<C>
+where
+    C::Props: Clone,
+{
+    type Props = PortalProps<C::Props>;
+
+    fn component_id(&self) -> ComponentId {
+        self.child.component_id()
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            child: C::create(props.child, context),
+            target: props.target,
+            targets: props.targets,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.target = props.target;
+        self.targets = props.targets;
+        self.child.update(props.child)
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let nodes = self.child.render()?;
+        self.targets
+            .lock()
+            .map_err(|e| ComponentError::RenderError(format!("Portal targets poisoned: {}", e)))?
+            .entry(self.target.clone())
+            .or_default()
+            .extend(nodes);
+        // Nothing stays inline
+        Ok(Vec::new())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct TooltipProps {
+    pub text: String,
+}
+
+pub struct Tooltip {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    props: TooltipProps,
+}
+
+impl Component for Tooltip {
+    type Props = TooltipProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            id: ComponentId::new(),
+            context,
+            props,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut tooltip = Node::default();
+        tooltip.add_attribute("role".to_string(), "tooltip".to_string());
+        tooltip.add_child(Node::text(&self.props.text));
+        Ok(vec![tooltip])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+fn tooltip_props() -> TooltipProps {
+    TooltipProps {
+        text: "Save your changes".to_string(),
+    }
+}
+
+#[derive(Clone)]
+pub struct AppProps {
+    pub targets: PortalTargets,
+}
+
+/// A toolbar whose button's tooltip is portalled to the app's overlay layer
+pub struct App {
+    id: ComponentId,
+    props: AppProps,
+    tooltip: Portal<Tooltip>,
+}
+
+impl Component for App {
+    type Props = AppProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let tooltip = Portal::create(
+            PortalProps {
+                target: "overlay".to_string(),
+                targets: props.targets.clone(),
+                child: tooltip_props(),
+            },
+            context,
+        );
+
+        Self {
+            id: ComponentId::new(),
+            props,
+            tooltip,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        // The tooltip must file its nodes where this app will look for them
+        self.tooltip.update(PortalProps {
+            target: "overlay".to_string(),
+            targets: props.targets.clone(),
+            child: tooltip_props(),
+        })?;
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut button = Node::default();
+        button.add_attribute("role".to_string(), "button".to_string());
+        button.add_child(Node::text("Save"));
+        // Logically the tooltip belongs to the button
+        for node in self.tooltip.render()? {
+            button.add_child(node);
+        }
+
+        let mut toolbar = Node::default();
+        toolbar.add_attribute("class".to_string(), "toolbar".to_string());
+        toolbar.add_child(button);
+
+        let mut overlay = Node::default();
+        overlay.add_attribute("class".to_string(), "overlay".to_string());
+        for node in take_target(&self.props.targets, "overlay")? {
+            overlay.add_child(node);
+        }
+
+        Ok(vec![toolbar, overlay])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+/// Print a node tree, one node per line, showing each node's attributes
+fn print_tree(node: &Node, depth: usize) {
+    let attributes: Vec<String> = node
+        .attributes()
+        .iter()
+        .map(|(name, value)| format!("{}={:?}", name, value))
+        .collect();
+    println!("{}<{}>", "  ".repeat(depth), attributes.join(" "));
+    for child in node.children() {
+        print_tree(child, depth + 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Whether `node` or anything below it has the given role
+    fn contains_role(node: &Node, role: &str) -> bool {
+        node.attributes().get("role").map(String::as_str) == Some(role)
+            || node
+                .children()
+                .iter()
+                .any(|child| contains_role(child, role))
+    }
+
+    #[test]
+    fn test_children_land_in_named_target() {
+        let targets = PortalTargets::default();
+        let portal = Portal::<Tooltip>::create(
+            PortalProps {
+                target: "overlay".to_string(),
+                targets: targets.clone(),
+                child: TooltipProps {
+                    text: "Hint".to_string(),
+                },
+            },
+            Context::new(),
+        );
+
+        let inline = portal.render().unwrap();
+
+        assert!(inline.is_empty());
+        let placed = take_target(&targets, "overlay").unwrap();
+        assert_eq!(placed.len(), 1);
+        assert!(contains_role(&placed[0], "tooltip"));
+        assert!(take_target(&targets, "overlay").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_app_places_tooltip_in_overlay() {
+        let app = App::create(
+            AppProps {
+                targets: PortalTargets::default(),
+            },
+            Context::new(),
+        );
+
+        let nodes = app.render().unwrap();
+
+        assert!(!contains_role(&nodes[0], "tooltip"));
+        assert!(contains_role(&nodes[1], "tooltip"));
+        // Rendering again doesn't leave a duplicate behind
+        let nodes = app.render().unwrap();
+        assert_eq!(nodes[1].children().len(), 1);
+    }
+
+    #[test]
+    fn test_updated_targets_still_receive_tooltip() {
+        let mut app = App::create(
+            AppProps {
+                targets: PortalTargets::default(),
+            },
+            Context::new(),
+        );
+
+        app.update(AppProps {
+            targets: PortalTargets::default(),
+        })
+        .unwrap();
+        let nodes = app.render().unwrap();
+
+        assert!(!contains_role(&nodes[0], "tooltip"));
+        assert!(contains_role(&nodes[1], "tooltip"));
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Portal Example\n");
+
+    let app = App::create(
+        AppProps {
+            targets: PortalTargets::default(),
+        },
+        Context::new(),
+    );
+
+    for node in app.render()? {
+        print_tree(&node, 0);
+    }
+
+    println!("\nPortal example completed!");
+    Ok(())
+}
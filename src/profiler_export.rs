@@ -0,0 +1,236 @@
+//! Example demonstrating render profiling exported as folded stacks
+//! Each component opens a span when it renders, and spans opened while another is
+//! still running become its children. When a span closes, its self time (its own
+//! duration minus its children's) is recorded against the full parent;child path.
+//! The export is the folded-stack format that `flamegraph.pl` and `inferno` read.
+//! To run: cargo run --example profiler_export
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+
+struct OpenSpan {
+    name: String,
+    started: Instant,
+    // Total time spent in spans nested inside this one
+    children: Duration,
+}
+
+#[derive(Default)]
+struct ProfilerState {
+    open: Vec<OpenSpan>,
+    // Self time per stack, in microseconds
+    folded: BTreeMap<String, u128>,
+}
+
+/// Records nested render spans
+#[derive(Default)]
+pub struct Profiler {
+    state: Mutex<ProfilerState>,
+}
+
+/// Closes its span when dropped
+pub struct Span<'a> {
+    profiler: &'a Profiler,
+}
+
+impl Profiler {
+    pub fn span(&self, name: &str) -> Span<'_> {
+        if let Ok(mut state) = self.state.lock() {
+            state.open.push(OpenSpan {
+                name: name.to_string(),
+                started: Instant::now(),
+                children: Duration::ZERO,
+            });
+        }
+        Span { profiler: self }
+    }
+
+    fn close(&self) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        let Some(span) = state.open.pop() else {
+            return;
+        };
+        let elapsed = span.started.elapsed();
+
+        let mut path: Vec<&str> = state.open.iter().map(|s| s.name.as_str()).collect();
+        path.push(&span.name);
+        let stack = path.join(";");
+
+        if let Some(parent) = state.open.last_mut() {
+            parent.children += elapsed;
+        }
+        let self_time = elapsed.saturating_sub(span.children).as_micros();
+        *state.folded.entry(stack).or_insert(0) += self_time;
+    }
+
+    /// One `stack microseconds` line per distinct stack
+    pub fn folded(&self) -> String {
+        let Ok(state) = self.state.lock() else {
+            return String::new();
+        };
+        state
+            .folded
+            .iter()
+            .map(|(stack, micros)| format!("{} {}\n", stack, micros))
+            .collect()
+    }
+}
+
+impl Drop for Span<'_> {
+    fn drop(&mut self) {
+        self.profiler.close();
+    }
+}
+
+#[derive(Clone)]
+pub struct PanelProps {
+    pub name: String,
+    // Simulated render work
+    pub work: Duration,
+    pub children: Vec<PanelProps>,
+    pub profiler: Arc<Profiler>,
+}
+
+/// A panel that renders its child panels inside its own span
+pub struct Panel {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    props: PanelProps,
+    children: Vec<Panel>,
+}
+
+impl Component for Panel {
+    type Props = PanelProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let children = props
+            .children
+            .iter()
+            .map(|child| Panel::create(child.clone(), context.clone()))
+            .collect();
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            props,
+            children,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let _span = self.props.profiler.span(&self.props.name);
+        thread::sleep(self.props.work);
+
+        let mut panel = Node::default();
+        panel.add_attribute("aria-label".to_string(), self.props.name.clone());
+        for child in &self.children {
+            for node in child.render()? {
+                panel.add_child(node);
+            }
+        }
+        Ok(vec![panel])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+fn panel(
+    profiler: &Arc<Profiler>,
+    name: &str,
+    work_ms: u64,
+    children: Vec<PanelProps>,
+) -> PanelProps {
+    PanelProps {
+        name: name.to_string(),
+        work: Duration::from_millis(work_ms),
+        children,
+        profiler: profiler.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_folded_output_has_parent_child_stack() {
+        let profiler = Arc::new(Profiler::default());
+        let tree = panel(&profiler, "App", 0, vec![panel(&profiler, "Sidebar", 2, Vec::new())]);
+        Panel::create(tree, Context::new()).render().unwrap();
+
+        let folded = profiler.folded();
+        let line = folded
+            .lines()
+            .find(|line| line.starts_with("App;Sidebar "))
+            .expect("missing App;Sidebar stack");
+        let micros: u128 = line.rsplit(' ').next().unwrap().parse().unwrap();
+
+        assert!(micros >= 2_000);
+        assert!(folded.lines().any(|line| line.starts_with("App ")));
+    }
+
+    #[test]
+    fn test_repeated_renders_accumulate() {
+        let profiler = Arc::new(Profiler::default());
+        let app = Panel::create(panel(&profiler, "App", 1, Vec::new()), Context::new());
+
+        app.render().unwrap();
+        app.render().unwrap();
+
+        assert_eq!(profiler.folded().lines().count(), 1);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Profiler Export Example\n");
+
+    let profiler = Arc::new(Profiler::default());
+    let tree = panel(
+        &profiler,
+        "App",
+        1,
+        vec![
+            panel(&profiler, "Header", 2, Vec::new()),
+            panel(
+                &profiler,
+                "Main",
+                1,
+                vec![
+                    panel(&profiler, "Chart", 6, Vec::new()),
+                    panel(&profiler, "Table", 3, Vec::new()),
+                ],
+            ),
+        ],
+    );
+
+    let app = Panel::create(tree, Context::new());
+    app.render()?;
+
+    println!("Folded stacks (pipe into flamegraph.pl or inferno-flamegraph):\n");
+    print!("{}", profiler.folded());
+
+    println!("\nProfiler Export example completed!");
+    Ok(())
+}
@@ -0,0 +1,290 @@
+//! Example demonstrating a pull-to-refresh interaction
+//! Dragging down while the list is scrolled to the top shows an indicator whose
+//! progress follows the drag distance. Releasing past the threshold starts a refresh on
+//! a background thread; releasing earlier cancels the pull.
+//! To run: cargo run --example pull_to_refresh
+
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+/// Loads fresh list items; runs on a background thread
+pub type Loader = Arc<dyn Fn() -> Vec<String> + Send + Sync>;
+
+#[derive(Clone)]
+pub struct PullToRefreshProps {
+    // Pull distance in pixels needed to trigger a refresh
+    pub threshold: f32,
+    pub loader: Loader,
+}
+
+/// A list that refreshes when pulled down from the top
+pub struct PullToRefreshList {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: PullToRefreshProps,
+    items: Signal<Vec<String>>,
+    pull_distance: Signal<f32>,
+    refreshing: Signal<bool>,
+    scroll_top: f32,
+    // Receives the loader's result while a refresh is in flight
+    pending: Option<Receiver<Vec<String>>>,
+}
+
+impl Component for PullToRefreshList {
+    type Props = PullToRefreshProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let items = create_signal(&scope, Vec::new());
+        let pull_distance = create_signal(&scope, 0.0);
+        let refreshing = create_signal(&scope, false);
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            props,
+            items,
+            pull_distance,
+            refreshing,
+            scroll_top: 0.0,
+            pending: None,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut list = Node::default();
+        list.add_attribute("class".to_string(), "pull-to-refresh".to_string());
+
+        if self.is_refreshing() || self.progress() > 0.0 {
+            let mut indicator = Node::default();
+            indicator.add_attribute("role".to_string(), "progressbar".to_string());
+            indicator.add_attribute(
+                "value".to_string(),
+                format!("{:.2}", if self.is_refreshing() { 1.0 } else { self.progress() }),
+            );
+            indicator.add_attribute("spinning".to_string(), self.is_refreshing().to_string());
+            list.add_child(indicator);
+        }
+
+        for item in self.items.get().iter() {
+            list.add_child(Node::text(item));
+        }
+
+        Ok(vec![list])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl PullToRefreshList {
+    pub fn scroll_to(&mut self, top: f32) {
+        self.scroll_top = top.max(0.0);
+    }
+
+    /// Drag the list down by `dy` pixels
+    pub fn pull(&self, dy: f32) {
+        // Pulling only works at the top of the list and while no refresh is running
+        if self.scroll_top > 0.0 || self.is_refreshing() {
+            return;
+        }
+        self.pull_distance
+            .update(|distance| *distance = (*distance + dy).max(0.0))
+            .unwrap_or_else(|e| eprintln!("Failed to update pull: {}", e));
+    }
+
+    /// Indicator progress from 0.0 to 1.0
+    pub fn progress(&self) -> f32 {
+        (*self.pull_distance.get() / self.props.threshold).min(1.0)
+    }
+
+    pub fn is_refreshing(&self) -> bool {
+        *self.refreshing.get()
+    }
+
+    /// Release the drag; returns true if a refresh was started
+    pub fn release(&mut self) -> bool {
+        let triggered = *self.pull_distance.get() >= self.props.threshold;
+        self.pull_distance
+            .set(0.0)
+            .unwrap_or_else(|e| eprintln!("Failed to reset pull: {}", e));
+
+        if !triggered || self.is_refreshing() {
+            return false;
+        }
+
+        let (sender, receiver) = mpsc::channel();
+        let loader = self.props.loader.clone();
+        thread::spawn(move || {
+            // The receiver may be gone if the list was dropped; nothing to do then
+            let _ = sender.send(loader());
+        });
+
+        self.pending = Some(receiver);
+        self.refreshing
+            .set(true)
+            .unwrap_or_else(|e| eprintln!("Failed to start refresh: {}", e));
+        true
+    }
+
+    /// Apply the refresh result if it has arrived; returns true when finished
+    pub fn poll(&mut self) -> bool {
+        let result = match &self.pending {
+            Some(receiver) => match receiver.try_recv() {
+                Ok(items) => Some(items),
+                Err(TryRecvError::Empty) => return false,
+                // The loader thread panicked; stop refreshing and keep the old items
+                Err(TryRecvError::Disconnected) => None,
+            },
+            None => return false,
+        };
+
+        if let Some(items) = result {
+            self.items
+                .set(items)
+                .unwrap_or_else(|e| eprintln!("Failed to update items: {}", e));
+        }
+        self.pending = None;
+        self.refreshing
+            .set(false)
+            .unwrap_or_else(|e| eprintln!("Failed to finish refresh: {}", e));
+        true
+    }
+
+    pub fn items(&self) -> Vec<String> {
+        self.items.get().clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn list_with_counter(calls: Arc<AtomicUsize>) -> PullToRefreshList {
+        PullToRefreshList::create(
+            PullToRefreshProps {
+                threshold: 80.0,
+                loader: Arc::new(move || {
+                    calls.fetch_add(1, Ordering::SeqCst);
+                    vec!["fresh".to_string()]
+                }),
+            },
+            Context::new(),
+        )
+    }
+
+    fn wait_for_refresh(list: &mut PullToRefreshList) {
+        for _ in 0..100 {
+            if list.poll() {
+                return;
+            }
+            thread::sleep(Duration::from_millis(5));
+        }
+        panic!("refresh did not finish");
+    }
+
+    #[test]
+    fn test_release_past_threshold_refreshes() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut list = list_with_counter(calls.clone());
+
+        list.pull(50.0);
+        list.pull(40.0);
+        assert_eq!(list.progress(), 1.0);
+        assert!(list.release());
+        wait_for_refresh(&mut list);
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(list.items(), vec!["fresh"]);
+    }
+
+    #[test]
+    fn test_release_under_threshold_cancels() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut list = list_with_counter(calls.clone());
+
+        list.pull(40.0);
+        assert_eq!(list.progress(), 0.5);
+        assert!(!list.release());
+
+        assert!(!list.is_refreshing());
+        assert_eq!(list.progress(), 0.0);
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_pull_ignored_when_scrolled() {
+        let mut list = list_with_counter(Arc::new(AtomicUsize::new(0)));
+        list.scroll_to(120.0);
+
+        list.pull(200.0);
+
+        assert_eq!(list.progress(), 0.0);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Pull to Refresh Example\n");
+
+    let mut list = PullToRefreshList::create(
+        PullToRefreshProps {
+            threshold: 80.0,
+            loader: Arc::new(|| {
+                // Simulate a network request
+                thread::sleep(Duration::from_millis(100));
+                vec![
+                    "New message from Ada".to_string(),
+                    "New message from Grace".to_string(),
+                ]
+            }),
+        },
+        Context::new(),
+    );
+
+    println!("Pulling 40px and letting go...");
+    list.pull(40.0);
+    println!("  progress {:.0}%", list.progress() * 100.0);
+    println!("  refresh started: {}", list.release());
+
+    println!("\nPulling past the threshold...");
+    for _ in 0..5 {
+        list.pull(20.0);
+        list.render()?;
+        println!("  progress {:.0}%", list.progress() * 100.0);
+    }
+    println!("  refresh started: {}", list.release());
+
+    while !list.poll() {
+        println!("  refreshing...");
+        thread::sleep(Duration::from_millis(30));
+    }
+    list.render()?;
+    println!("Refreshed items: {:?}", list.items());
+
+    println!("\nPull to Refresh example completed!");
+    Ok(())
+}
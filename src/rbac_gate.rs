@@ -0,0 +1,324 @@
+//! Example demonstrating a role-based rendering gate
+//! `Can` renders its children only when the current user's roles grant a permission,
+//! and a fallback otherwise. The roles live in a `Signal<Roles>` owned by the app; each
+//! gate reads the signal's current value at render time, so switching users re-gates
+//! every part of the UI without touching the gates themselves.
+//! To run: cargo run --example rbac_gate
+
+use std::cell::RefCell;
+use std::collections::BTreeSet;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Permission {
+    ViewReports,
+    EditContent,
+    ManageUsers,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Role {
+    Viewer,
+    Editor,
+    Admin,
+}
+
+impl Role {
+    fn permissions(self) -> &'static [Permission] {
+        match self {
+            Role::Viewer => &[Permission::ViewReports],
+            Role::Editor => &[Permission::ViewReports, Permission::EditContent],
+            Role::Admin => &[
+                Permission::ViewReports,
+                Permission::EditContent,
+                Permission::ManageUsers,
+            ],
+        }
+    }
+}
+
+/// The roles held by the current user
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Roles(pub BTreeSet<Role>);
+
+impl Roles {
+    pub fn of(roles: &[Role]) -> Self {
+        Roles(roles.iter().copied().collect())
+    }
+
+    pub fn grants(&self, permission: Permission) -> bool {
+        self.0
+            .iter()
+            .any(|role| role.permissions().contains(&permission))
+    }
+}
+
+/// Content rendered by a gate
+pub type RenderFn = Arc<dyn Fn() -> Result<Vec<Node>, ComponentError> + Send + Sync>;
+
+#[derive(Clone)]
+pub struct CanProps {
+    pub permission: Permission,
+    pub children: RenderFn,
+    pub fallback: RenderFn,
+}
+
+/// Renders `children` if the current roles grant `permission`, else `fallback`
+pub struct Can {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    props: CanProps,
+    // The app's roles signal value, shared so the gate always sees the current user
+    roles: Rc<RefCell<Roles>>,
+}
+
+impl Component for Can {
+    type Props = CanProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            id: ComponentId::new(),
+            context,
+            props,
+            // Denies everything until bound to a roles signal
+            roles: Rc::new(RefCell::new(Roles::default())),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        if self.allowed() {
+            (self.props.children)()
+        } else {
+            (self.props.fallback)()
+        }
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl Can {
+    /// Read roles from `roles` from now on
+    pub fn bind_roles(mut self, roles: &Signal<Roles>) -> Self {
+        self.roles = roles.value.clone();
+        self
+    }
+
+    pub fn allowed(&self) -> bool {
+        self.roles.borrow().grants(self.props.permission)
+    }
+}
+
+#[derive(Clone)]
+pub struct AdminToolbarProps {
+    pub label: String,
+    pub initial_roles: Roles,
+}
+
+/// A toolbar with an admin-only button
+pub struct AdminToolbar {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: AdminToolbarProps,
+    roles: Signal<Roles>,
+    manage_users: Can,
+}
+
+fn button(label: &str) -> RenderFn {
+    let label = label.to_string();
+    Arc::new(move || {
+        let mut button = Node::default();
+        button.add_attribute("role".to_string(), "button".to_string());
+        button.add_child(Node::text(&label));
+        Ok(vec![button])
+    })
+}
+
+fn nothing() -> RenderFn {
+    Arc::new(|| Ok(Vec::new()))
+}
+
+impl Component for AdminToolbar {
+    type Props = AdminToolbarProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let roles = create_signal(&scope, props.initial_roles.clone());
+        let manage_users = Can::create(
+            CanProps {
+                permission: Permission::ManageUsers,
+                children: button("Manage users"),
+                fallback: nothing(),
+            },
+            context.clone(),
+        )
+        .bind_roles(&roles);
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            props,
+            roles,
+            manage_users,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut toolbar = Node::default();
+        toolbar.add_attribute("role".to_string(), "toolbar".to_string());
+        toolbar.add_attribute("aria-label".to_string(), self.props.label.clone());
+
+        for node in (button("Refresh"))()? {
+            toolbar.add_child(node);
+        }
+        for node in self.manage_users.render()? {
+            toolbar.add_child(node);
+        }
+
+        Ok(vec![toolbar])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl AdminToolbar {
+    /// Switch to another user's roles
+    pub fn set_roles(&self, roles: Roles) {
+        self.roles
+            .set(roles)
+            .unwrap_or_else(|e| eprintln!("Failed to set roles: {}", e));
+    }
+
+    pub fn shows_admin_button(&self) -> bool {
+        self.manage_users.allowed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    // Render function that counts how often it is used
+    fn counting(counter: &Arc<AtomicUsize>) -> RenderFn {
+        let counter = counter.clone();
+        Arc::new(move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+            Ok(vec![Node::default()])
+        })
+    }
+
+    #[test]
+    fn test_children_for_admin_and_fallback_otherwise() {
+        let scope = ReactiveScope::new();
+        let roles = create_signal(&scope, Roles::of(&[Role::Admin]));
+        let children = Arc::new(AtomicUsize::new(0));
+        let fallback = Arc::new(AtomicUsize::new(0));
+        let gate = Can::create(
+            CanProps {
+                permission: Permission::ManageUsers,
+                children: counting(&children),
+                fallback: counting(&fallback),
+            },
+            Context::new(),
+        )
+        .bind_roles(&roles);
+
+        gate.render().unwrap();
+        assert_eq!(children.load(Ordering::SeqCst), 1);
+        assert_eq!(fallback.load(Ordering::SeqCst), 0);
+
+        roles.set(Roles::of(&[Role::Editor])).unwrap();
+        gate.render().unwrap();
+        assert_eq!(children.load(Ordering::SeqCst), 1);
+        assert_eq!(fallback.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_toolbar_hides_admin_button_for_regular_user() {
+        let toolbar = AdminToolbar::create(
+            AdminToolbarProps {
+                label: "Reports".to_string(),
+                initial_roles: Roles::of(&[Role::Viewer]),
+            },
+            Context::new(),
+        );
+        assert!(!toolbar.shows_admin_button());
+
+        toolbar.set_roles(Roles::of(&[Role::Viewer, Role::Admin]));
+
+        assert!(toolbar.shows_admin_button());
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("RBAC Gate Example\n");
+
+    let toolbar = AdminToolbar::create(
+        AdminToolbarProps {
+            label: "Reports".to_string(),
+            initial_roles: Roles::of(&[Role::Viewer]),
+        },
+        Context::new(),
+    );
+
+    let users = [
+        ("viewer", Roles::of(&[Role::Viewer])),
+        ("editor", Roles::of(&[Role::Editor])),
+        ("admin", Roles::of(&[Role::Admin])),
+    ];
+    for (name, roles) in users {
+        let can_edit = roles.grants(Permission::EditContent);
+        toolbar.set_roles(roles);
+        let buttons = toolbar.render()?[0].children().len();
+        println!(
+            "Signed in as {:<6}: can edit content = {:<5}, toolbar buttons = {}, \
+             'Manage users' shown = {}",
+            name,
+            can_edit,
+            buttons,
+            toolbar.shows_admin_button()
+        );
+    }
+
+    println!("\nRBAC Gate example completed!");
+    Ok(())
+}
@@ -0,0 +1,241 @@
+//! Example demonstrating batching several signal writes into one recompute
+//! Three signals feed one computed sum. Written one at a time with `Signal::set`, the
+//! sum re-evaluates after every write. Inside `batch`, writes go through the `Batch`
+//! handle straight into each signal's storage without notifying anyone. When the block
+//! ends, the batch sets the last written signal to its current value, once. Setting a
+//! signal recomputes the computeds in its scope, so the sum runs a single time, and
+//! every computed there sees all of the new values, whichever signals it reads.
+//! To run: cargo run --example reactive_batching
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_computed, create_signal, ReactiveComputed, ReactiveScope, Signal};
+
+/// Collects writes made inside a `batch` block
+pub struct Batch<'a> {
+    // Re-sets the last written signal; one set recomputes the whole scope's computeds
+    notify: RefCell<Option<Box<dyn Fn() + 'a>>>,
+}
+
+impl<'a> Batch<'a> {
+    /// Store `value` without notifying dependents yet
+    pub fn set<T: Clone + 'a>(&self, signal: &'a Signal<T>, value: T) {
+        *signal.value.borrow_mut() = value;
+        *self.notify.borrow_mut() = Some(Box::new(move || {
+            let current = signal.get().clone();
+            signal
+                .set(current)
+                .unwrap_or_else(|e| eprintln!("Failed to flush batch: {}", e));
+        }));
+    }
+}
+
+/// Run `writes`, then recompute dependents once for all of them
+pub fn batch<'a>(writes: impl FnOnce(&Batch<'a>)) {
+    let batch = Batch {
+        notify: RefCell::new(None),
+    };
+    writes(&batch);
+
+    let notify = batch.notify.borrow_mut().take();
+    if let Some(notify) = notify {
+        notify();
+    }
+}
+
+#[derive(Clone)]
+pub struct TotalsProps {
+    pub label: String,
+}
+
+/// Three amounts and their sum
+pub struct Totals {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: TotalsProps,
+    subtotal: Signal<i32>,
+    shipping: Signal<i32>,
+    tax: Signal<i32>,
+    sum: ReactiveComputed<i32, Box<dyn FnMut() -> i32>>,
+    evaluations: Rc<Cell<usize>>,
+}
+
+impl Component for Totals {
+    type Props = TotalsProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let subtotal = create_signal(&scope, 0);
+        let shipping = create_signal(&scope, 0);
+        let tax = create_signal(&scope, 0);
+
+        let evaluations = Rc::new(Cell::new(0));
+        let evaluations_clone = evaluations.clone();
+        let values = [
+            subtotal.value.clone(),
+            shipping.value.clone(),
+            tax.value.clone(),
+        ];
+        let sum = create_computed(
+            &scope,
+            Box::new(move || {
+                evaluations_clone.set(evaluations_clone.get() + 1);
+                values.iter().map(|value| *value.borrow()).sum()
+            }) as Box<dyn FnMut() -> i32>,
+        );
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            props,
+            subtotal,
+            shipping,
+            tax,
+            sum,
+            evaluations,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut total = Node::default();
+        total.add_attribute("aria-label".to_string(), self.props.label.clone());
+        total.add_child(Node::text(&self.sum().to_string()));
+        Ok(vec![total])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl Totals {
+    pub fn sum(&self) -> i32 {
+        self.sum.get().map(|v| *v).unwrap_or(0)
+    }
+
+    /// How many times the sum has been computed
+    pub fn evaluations(&self) -> usize {
+        self.evaluations.get()
+    }
+}
+
+fn set(signal: &Signal<i32>, value: i32) {
+    signal
+        .set(value)
+        .unwrap_or_else(|e| eprintln!("Failed to set value: {}", e));
+}
+
+fn totals() -> Totals {
+    Totals::create(
+        TotalsProps {
+            label: "Order total".to_string(),
+        },
+        Context::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_batch_of_three_writes_evaluates_once() {
+        let totals = totals();
+        totals.sum();
+        let before = totals.evaluations();
+
+        batch(|b| {
+            b.set(&totals.subtotal, 100);
+            b.set(&totals.shipping, 5);
+            b.set(&totals.tax, 20);
+        });
+
+        assert_eq!(totals.sum(), 125);
+        assert_eq!(totals.evaluations(), before + 1);
+    }
+
+    #[test]
+    fn test_empty_batch_does_not_evaluate() {
+        let totals = totals();
+        totals.sum();
+        let before = totals.evaluations();
+
+        batch(|_| {});
+
+        assert_eq!(totals.evaluations(), before);
+    }
+
+    #[test]
+    fn test_computed_reading_only_first_signal_is_recomputed() {
+        let scope = ReactiveScope::new();
+        let first = create_signal(&scope, 0);
+        let second = create_signal(&scope, 0);
+        let first_value = first.value.clone();
+        let doubled = create_computed(
+            &scope,
+            Box::new(move || *first_value.borrow() * 2) as Box<dyn FnMut() -> i32>,
+        );
+
+        batch(|b| {
+            b.set(&first, 21);
+            b.set(&second, 1);
+        });
+
+        assert_eq!(doubled.get().map(|v| *v), Some(42));
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Reactive Batching Example\n");
+
+    let totals = totals();
+    println!("Initial sum: {} ({} evaluations)", totals.sum(), totals.evaluations());
+
+    let before = totals.evaluations();
+    set(&totals.subtotal, 40);
+    set(&totals.shipping, 4);
+    set(&totals.tax, 8);
+    totals.render()?;
+    println!(
+        "\nThree separate writes: sum = {}, evaluations {} -> {}",
+        totals.sum(),
+        before,
+        totals.evaluations()
+    );
+
+    let before = totals.evaluations();
+    batch(|b| {
+        b.set(&totals.subtotal, 100);
+        b.set(&totals.shipping, 5);
+        b.set(&totals.tax, 20);
+    });
+    totals.render()?;
+    println!(
+        "Three writes in one batch: sum = {}, evaluations {} -> {}",
+        totals.sum(),
+        before,
+        totals.evaluations()
+    );
+
+    println!("\nReactive Batching example completed!");
+    Ok(())
+}
@@ -1,13 +1,15 @@
 //! Example demonstrating the new scope-based reactive system in OrbitRS
 //! Shows how to use ReactiveScope, Signal, Effect, and ReactiveComputed
 
-use orbit::component::{Component, ComponentError, Context, Node};
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
 use orbit::state::{
     create_computed, create_effect, create_signal, ReactiveComputed, ReactiveScope, Signal,
 };
 
 // A simple counter using the new scope-based reactive system
 struct ReactiveCounter {
+    id: ComponentId,
+    #[allow(dead_code)]
     context: Context,
     // Reactive scope for this component
     scope: ReactiveScope,
@@ -17,6 +19,10 @@ struct ReactiveCounter {
     square: ReactiveComputed<i32, Box<dyn FnMut() -> i32>>,
     // Derived state for is_even
     is_even: ReactiveComputed<bool, Box<dyn FnMut() -> bool>>,
+    // Second base signal: how far each projected step moves the count
+    step: Signal<i32>,
+    // Derived from both count and step
+    projected: ReactiveComputed<i32, Box<dyn FnMut() -> i32>>,
 }
 
 // Props for our counter
@@ -28,6 +34,10 @@ struct CounterProps {
 impl Component for ReactiveCounter {
     type Props = CounterProps;
 
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
     fn create(props: Self::Props, context: Context) -> Self {
         // Create reactive scope
         let scope = ReactiveScope::new();
@@ -52,12 +62,23 @@ impl Component for ReactiveCounter {
             }
         }) as Box<dyn FnMut() -> bool>);
 
+        // A computed can depend on several signals; it reruns when any of them changes
+        let step = create_signal(&scope, 1);
+        let count_for_projection = count.value.clone();
+        let step_clone = step.value.clone();
+        let projected = create_computed(&scope, Box::new(move || {
+            *count_for_projection.borrow() + *step_clone.borrow() * 3
+        }) as Box<dyn FnMut() -> i32>);
+
         Self {
+            id: ComponentId::new(),
             context,
             scope,
             count,
             square,
             is_even,
+            step,
+            projected,
         }
     }
 
@@ -124,6 +145,12 @@ impl Component for ReactiveCounter {
         };
         println!("  Is even: {}", is_even);
 
+        println!(
+            "  Projected (count + step * 3, step = {}): {}",
+            *self.step.get(),
+            self.get_projected()
+        );
+
         Ok(vec![])
     }
 
@@ -151,6 +178,46 @@ impl ReactiveCounter {
             eprintln!("Failed to decrement: {}", e);
         });
     }
+
+    // Change the step used by the projection
+    pub fn set_step(&mut self, v: i32) {
+        self.step.set(v).unwrap_or_else(|e| {
+            eprintln!("Failed to set step: {}", e);
+        });
+    }
+
+    // Get the projected count
+    pub fn get_projected(&self) -> i32 {
+        match self.projected.get() {
+            Ok(val) => *val,
+            Err(_) => -1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_projected_recomputes_when_only_step_changes() {
+        let mut counter = ReactiveCounter::create(CounterProps { initial: 5 }, Context::new());
+        assert_eq!(counter.get_projected(), 8);
+
+        counter.set_step(4);
+
+        assert_eq!(*counter.count.get(), 5);
+        assert_eq!(counter.get_projected(), 17);
+    }
+
+    #[test]
+    fn test_projected_follows_count() {
+        let mut counter = ReactiveCounter::create(CounterProps { initial: 5 }, Context::new());
+
+        counter.increment();
+
+        assert_eq!(counter.get_projected(), 9);
+    }
 }
 
 fn main() {
@@ -183,5 +250,11 @@ fn main() {
     // Render final state
     counter.render().expect("Failed to render counter");
 
+    println!("\nChanging only the step to 10...");
+    counter.set_step(10);
+
+    // Projected follows step even though count did not change
+    counter.render().expect("Failed to render counter");
+
     println!("\nReactive System Example completed!");
 }
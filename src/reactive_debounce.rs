@@ -0,0 +1,278 @@
+//! Example demonstrating a debounced search input
+//! Every keystroke updates the query signal immediately, but the query is only passed
+//! on once it has been stable for the debounce delay. A background thread does the
+//! timing, receiving keystrokes and sending settled values back over channels, and it
+//! exits as soon as its owner is dropped, even in the middle of a delay.
+//! To run: cargo run --example reactive_debounce
+
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+/// Forwards a value once no newer one has arrived for `delay`
+pub struct Debouncer {
+    input: Option<Sender<String>>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl Debouncer {
+    /// Start the timer thread; settled values arrive on the returned receiver
+    pub fn new(delay: Duration) -> (Self, Receiver<String>) {
+        let (input, inbox) = mpsc::channel::<String>();
+        let (outbox, settled) = mpsc::channel();
+
+        let worker = thread::spawn(move || {
+            // Block until there is something to debounce
+            while let Ok(mut pending) = inbox.recv() {
+                loop {
+                    match inbox.recv_timeout(delay) {
+                        // A newer value restarts the delay
+                        Ok(newer) => pending = newer,
+                        Err(RecvTimeoutError::Timeout) => {
+                            // Nobody listening any more is not an error here
+                            let _ = outbox.send(pending);
+                            break;
+                        }
+                        // The owner was dropped mid-delay: exit without sending
+                        Err(RecvTimeoutError::Disconnected) => return,
+                    }
+                }
+            }
+        });
+
+        (
+            Self {
+                input: Some(input),
+                worker: Some(worker),
+            },
+            settled,
+        )
+    }
+
+    pub fn push(&self, value: &str) {
+        if let Some(input) = &self.input {
+            input
+                .send(value.to_string())
+                .unwrap_or_else(|e| eprintln!("Debounce thread has stopped: {}", e));
+        }
+    }
+}
+
+impl Drop for Debouncer {
+    fn drop(&mut self) {
+        // Closing the channel wakes the thread, which then returns
+        self.input.take();
+        if let Some(worker) = self.worker.take() {
+            worker.join().unwrap_or_else(|_| eprintln!("Debounce thread panicked"));
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct SearchBoxProps {
+    pub delay: Duration,
+}
+
+/// A search box whose downstream query only fires once typing pauses
+pub struct SearchBox {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    #[allow(dead_code)]
+    props: SearchBoxProps,
+    // What is in the box right now
+    query: Signal<String>,
+    // The last value that settled
+    debounced: Signal<String>,
+    // Every query sent downstream, in order
+    fired: Signal<Vec<String>>,
+    debouncer: Debouncer,
+    settled: Receiver<String>,
+}
+
+impl Component for SearchBox {
+    type Props = SearchBoxProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let (debouncer, settled) = Debouncer::new(props.delay);
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            query: create_signal(&scope, String::new()),
+            debounced: create_signal(&scope, String::new()),
+            fired: create_signal(&scope, Vec::new()),
+            scope,
+            props,
+            debouncer,
+            settled,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        // The thread was started with the old delay, so restart it
+        let (debouncer, settled) = Debouncer::new(props.delay);
+        self.debouncer = debouncer;
+        self.settled = settled;
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut search = Node::default();
+        search.add_attribute("role".to_string(), "searchbox".to_string());
+        search.add_attribute("value".to_string(), self.query.get().clone());
+
+        let mut status = Node::default();
+        status.add_attribute("aria-live".to_string(), "polite".to_string());
+        status.add_child(Node::text(&format!(
+            "Results for \"{}\"",
+            self.debounced.get()
+        )));
+
+        Ok(vec![search, status])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl SearchBox {
+    pub fn type_text(&self, text: &str) {
+        self.query
+            .set(text.to_string())
+            .unwrap_or_else(|e| eprintln!("Failed to set query: {}", e));
+        self.debouncer.push(text);
+    }
+
+    /// Apply any values that have settled; returns how many arrived
+    ///
+    /// Signals live on this thread, so the timer thread only sends values back.
+    pub fn poll(&self) -> usize {
+        let mut arrived = 0;
+        while let Ok(query) = self.settled.try_recv() {
+            arrived += 1;
+            self.debounced
+                .set(query.clone())
+                .unwrap_or_else(|e| eprintln!("Failed to set debounced query: {}", e));
+            // Downstream effect: run the search
+            println!("  query fired: {:?}", query);
+            self.fired
+                .update(|fired| fired.push(query))
+                .unwrap_or_else(|e| eprintln!("Failed to record query: {}", e));
+        }
+        arrived
+    }
+
+    /// Poll until something settles or `timeout` passes
+    pub fn wait(&self, timeout: Duration) -> usize {
+        let step = Duration::from_millis(5);
+        let mut waited = Duration::ZERO;
+        loop {
+            let arrived = self.poll();
+            if arrived > 0 || waited >= timeout {
+                return arrived;
+            }
+            thread::sleep(step);
+            waited += step;
+        }
+    }
+
+    pub fn fired(&self) -> Vec<String> {
+        self.fired.get().clone()
+    }
+}
+
+fn search_box() -> SearchBox {
+    SearchBox::create(
+        SearchBoxProps {
+            delay: Duration::from_millis(150),
+        },
+        Context::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    #[test]
+    fn test_only_final_keystroke_fires() {
+        let search = search_box();
+
+        // Keystrokes well inside the delay of each other
+        for text in ["r", "ru", "rus", "rust"] {
+            search.type_text(text);
+            thread::sleep(Duration::from_millis(10));
+        }
+        assert!(search.fired().is_empty());
+
+        // Generous timeout so a slow machine doesn't fail the test
+        search.wait(Duration::from_secs(2));
+
+        assert_eq!(search.fired(), vec!["rust"]);
+    }
+
+    #[test]
+    fn test_drop_mid_debounce_stops_thread() {
+        let (debouncer, settled) = Debouncer::new(Duration::from_millis(150));
+        debouncer.push("ru");
+
+        let started = Instant::now();
+        drop(debouncer);
+
+        // The thread exited without waiting out the delay or sending anything
+        assert!(started.elapsed() < Duration::from_millis(150));
+        assert_eq!(
+            settled.recv_timeout(Duration::from_millis(300)),
+            Err(RecvTimeoutError::Disconnected)
+        );
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Reactive Debounce Example\n");
+
+    let search = search_box();
+    println!("Typing quickly:");
+    for text in ["r", "ru", "rus", "rust"] {
+        search.type_text(text);
+        search.render()?;
+        println!("  typed {:?}", text);
+        thread::sleep(Duration::from_millis(30));
+    }
+    search.wait(Duration::from_secs(1));
+
+    println!("\nTyping, pausing, then typing again:");
+    search.type_text("rust g");
+    search.wait(Duration::from_secs(1));
+    search.type_text("rust gui");
+    search.wait(Duration::from_secs(1));
+    search.render()?;
+    println!("Queries fired: {:?}", search.fired());
+
+    println!("\nDropping a search box mid-debounce...");
+    let abandoned = search_box();
+    abandoned.type_text("orb");
+    drop(abandoned);
+    println!("  timer thread exited cleanly");
+
+    println!("\nReactive Debounce example completed!");
+    Ok(())
+}
@@ -0,0 +1,217 @@
+//! Example demonstrating a diamond of computed dependencies
+//! Signal `a` feeds computeds `b = a + 1` and `c = a * 2`, which both feed
+//! `d = b + c`. Every node counts its evaluations, showing whether `d` recomputes once
+//! per change to `a` or once for each path that reaches it.
+//! To run: cargo run --example reactive_diamond
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_computed, create_signal, ReactiveComputed, ReactiveScope, Signal};
+
+/// Evaluation counts for each computed node
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Evaluations {
+    pub b: usize,
+    pub c: usize,
+    pub d: usize,
+}
+
+impl Evaluations {
+    fn since(self, earlier: Evaluations) -> Evaluations {
+        Evaluations {
+            b: self.b - earlier.b,
+            c: self.c - earlier.c,
+            d: self.d - earlier.d,
+        }
+    }
+}
+
+type Computed = ReactiveComputed<i32, Box<dyn FnMut() -> i32>>;
+
+// Wrap a computation so each run bumps `counter`
+fn counted(
+    counter: &Rc<Cell<usize>>,
+    mut compute: impl FnMut() -> i32 + 'static,
+) -> Box<dyn FnMut() -> i32> {
+    let counter = counter.clone();
+    Box::new(move || {
+        counter.set(counter.get() + 1);
+        compute()
+    })
+}
+
+#[derive(Clone)]
+pub struct DiamondProps {
+    pub initial: i32,
+}
+
+/// The four-node diamond `a -> (b, c) -> d`
+pub struct Diamond {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    a: Signal<i32>,
+    b: Computed,
+    c: Computed,
+    d: Computed,
+    counts: [Rc<Cell<usize>>; 3],
+}
+
+impl Component for Diamond {
+    type Props = DiamondProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let counts = [
+            Rc::new(Cell::new(0)),
+            Rc::new(Cell::new(0)),
+            Rc::new(Cell::new(0)),
+        ];
+
+        let a = create_signal(&scope, props.initial);
+
+        let a_for_b = a.value.clone();
+        let b = create_computed(&scope, counted(&counts[0], move || *a_for_b.borrow() + 1));
+
+        let a_for_c = a.value.clone();
+        let c = create_computed(&scope, counted(&counts[1], move || *a_for_c.borrow() * 2));
+
+        // Both inputs of `d` change whenever `a` does
+        let (b_value, c_value) = (b.value.clone(), c.value.clone());
+        let d = create_computed(
+            &scope,
+            counted(&counts[2], move || {
+                b_value.borrow().unwrap_or(0) + c_value.borrow().unwrap_or(0)
+            }),
+        );
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            a,
+            b,
+            c,
+            d,
+            counts,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.a
+            .set(props.initial)
+            .map_err(|e| ComponentError::UpdateError(format!("Failed to set a: {}", e)))
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut graph = Node::default();
+        graph.add_attribute("class".to_string(), "diamond".to_string());
+
+        let values = [
+            ("a", *self.a.get()),
+            ("b", Self::read(&self.b)),
+            ("c", Self::read(&self.c)),
+            ("d", Self::read(&self.d)),
+        ];
+        for (name, value) in values {
+            let mut node = Node::default();
+            node.add_attribute("data-node".to_string(), name.to_string());
+            node.add_child(Node::text(&value.to_string()));
+            graph.add_child(node);
+        }
+
+        Ok(vec![graph])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl Diamond {
+    fn read(computed: &Computed) -> i32 {
+        computed.get().map(|v| *v).unwrap_or(0)
+    }
+
+    pub fn set_a(&self, value: i32) {
+        self.a
+            .set(value)
+            .unwrap_or_else(|e| eprintln!("Failed to set a: {}", e));
+    }
+
+    pub fn d(&self) -> i32 {
+        Self::read(&self.d)
+    }
+
+    pub fn evaluations(&self) -> Evaluations {
+        Evaluations {
+            b: self.counts[0].get(),
+            c: self.counts[1].get(),
+            d: self.counts[2].get(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_each_node_recomputes_once_per_change() {
+        let diamond = Diamond::create(DiamondProps { initial: 0 }, Context::new());
+        diamond.d();
+        let before = diamond.evaluations();
+
+        for value in 1..=5 {
+            diamond.set_a(value);
+            assert_eq!(diamond.d(), (value + 1) + value * 2);
+        }
+
+        let delta = diamond.evaluations().since(before);
+        assert_eq!(delta, Evaluations { b: 5, c: 5, d: 5 });
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Reactive Diamond Example\n");
+
+    let diamond = Diamond::create(DiamondProps { initial: 1 }, Context::new());
+    diamond.render()?;
+    println!("a = 1: d = {}, evaluations {:?}", diamond.d(), diamond.evaluations());
+
+    for value in [2, 3, 10] {
+        let before = diamond.evaluations();
+        diamond.set_a(value);
+        diamond.render()?;
+        let delta = diamond.evaluations().since(before);
+        println!(
+            "a = {}: d = {}, recomputed b {}x, c {}x, d {}x",
+            value,
+            diamond.d(),
+            delta.b,
+            delta.c,
+            delta.d
+        );
+    }
+
+    let total = diamond.evaluations();
+    println!(
+        "\nTotal evaluations: b = {}, c = {}, d = {} (d once per change means no glitches)",
+        total.b, total.c, total.d
+    );
+
+    println!("\nReactive Diamond example completed!");
+    Ok(())
+}
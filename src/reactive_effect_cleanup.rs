@@ -0,0 +1,193 @@
+//! Example demonstrating effect cleanup and disposal
+//! An effect's setup returns a cleanup closure, which runs exactly once: either when the
+//! effect's handle is disposed or when the scope that owns it is dropped, whichever comes
+//! first. orbit's `create_effect` takes a closure with no return value, so the cleanup
+//! bookkeeping is layered on top of it here.
+//! To run: cargo run --example reactive_effect_cleanup
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use orbit::state::{create_effect, ReactiveScope};
+
+/// Teardown returned by an effect's setup
+pub type Cleanup = Box<dyn FnOnce()>;
+
+/// Handle that can dispose an effect before its scope is dropped
+#[derive(Clone)]
+pub struct EffectHandle {
+    cleanup: Rc<RefCell<Option<Cleanup>>>,
+    disposed: Rc<Cell<bool>>,
+}
+
+impl EffectHandle {
+    /// Run the pending cleanup and stop the effect; later calls do nothing
+    pub fn dispose(&self) {
+        if self.disposed.replace(true) {
+            return;
+        }
+        let cleanup = self.cleanup.borrow_mut().take();
+        if let Some(cleanup) = cleanup {
+            cleanup();
+        }
+    }
+
+    pub fn is_disposed(&self) -> bool {
+        self.disposed.get()
+    }
+}
+
+/// Register an effect whose setup returns a cleanup closure
+///
+/// When the effect re-runs, the previous cleanup runs before the new setup.
+pub fn create_effect_with_cleanup<F>(scope: &ReactiveScope, mut setup: F) -> EffectHandle
+where
+    F: FnMut() -> Cleanup + 'static,
+{
+    let handle = EffectHandle {
+        cleanup: Rc::new(RefCell::new(None)),
+        disposed: Rc::new(Cell::new(false)),
+    };
+
+    let cleanup = handle.cleanup.clone();
+    let disposed = handle.disposed.clone();
+    create_effect(scope, move || {
+        if disposed.get() {
+            return;
+        }
+        let previous = cleanup.borrow_mut().take();
+        if let Some(previous) = previous {
+            previous();
+        }
+        *cleanup.borrow_mut() = Some(setup());
+    });
+
+    handle
+}
+
+/// A reactive scope that disposes its effects when dropped
+pub struct EffectScope {
+    scope: ReactiveScope,
+    effects: Vec<EffectHandle>,
+}
+
+impl EffectScope {
+    pub fn new() -> Self {
+        Self {
+            scope: ReactiveScope::new(),
+            effects: Vec::new(),
+        }
+    }
+
+    pub fn effect<F>(&mut self, setup: F) -> EffectHandle
+    where
+        F: FnMut() -> Cleanup + 'static,
+    {
+        let handle = create_effect_with_cleanup(&self.scope, setup);
+        self.effects.push(handle.clone());
+        handle
+    }
+}
+
+impl Default for EffectScope {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for EffectScope {
+    fn drop(&mut self) {
+        // Already-disposed handles skip their cleanup, so each one runs once
+        for effect in &self.effects {
+            effect.dispose();
+        }
+    }
+}
+
+// An effect that "subscribes" to a channel and unsubscribes on cleanup
+fn subscribe(channel: &str, on_cleanup: impl Fn() + 'static) -> impl FnMut() -> Cleanup {
+    let channel = channel.to_string();
+    let on_cleanup = Rc::new(on_cleanup);
+    move || {
+        println!("  subscribed to {}", channel);
+        let channel = channel.clone();
+        let on_cleanup = on_cleanup.clone();
+        Box::new(move || {
+            println!("  cleaned up {}", channel);
+            on_cleanup();
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    fn counted(cleanups: &Arc<AtomicUsize>) -> impl Fn() + 'static {
+        let cleanups = cleanups.clone();
+        move || {
+            cleanups.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn test_dropping_scopes_runs_each_cleanup_once() {
+        let cleanups = Arc::new(AtomicUsize::new(0));
+
+        for round in 0..3 {
+            let mut scope = EffectScope::new();
+            scope.effect(subscribe("prices", counted(&cleanups)));
+            scope.effect(subscribe("news", counted(&cleanups)));
+            assert_eq!(cleanups.load(Ordering::SeqCst), round * 2);
+        }
+
+        assert_eq!(cleanups.load(Ordering::SeqCst), 6);
+    }
+
+    #[test]
+    fn test_disposed_effect_is_not_cleaned_up_again() {
+        let cleanups = Arc::new(AtomicUsize::new(0));
+        let mut scope = EffectScope::new();
+        let handle = scope.effect(subscribe("prices", counted(&cleanups)));
+
+        handle.dispose();
+        handle.dispose();
+        assert_eq!(cleanups.load(Ordering::SeqCst), 1);
+
+        drop(scope);
+        assert_eq!(cleanups.load(Ordering::SeqCst), 1);
+    }
+}
+
+fn main() {
+    println!("Reactive Effect Cleanup Example\n");
+
+    let cleanups = Rc::new(Cell::new(0));
+    let counter = |cleanups: &Rc<Cell<usize>>| {
+        let cleanups = cleanups.clone();
+        move || cleanups.set(cleanups.get() + 1)
+    };
+
+    for page in ["home", "settings", "profile"] {
+        println!("Opening the {} page:", page);
+        let mut scope = EffectScope::new();
+        scope.effect(subscribe(&format!("{}/updates", page), counter(&cleanups)));
+        scope.effect(subscribe(&format!("{}/presence", page), counter(&cleanups)));
+        println!("Leaving the {} page:", page);
+        // `scope` is dropped here, cleaning up both effects
+    }
+
+    println!("\nDisposing one effect early:");
+    let mut scope = EffectScope::new();
+    let chat = scope.effect(subscribe("chat", counter(&cleanups)));
+    scope.effect(subscribe("typing", counter(&cleanups)));
+    chat.dispose();
+    println!("  chat disposed: {}", chat.is_disposed());
+    println!("Dropping the scope:");
+    drop(scope);
+
+    println!("\nCleanups run: {}", cleanups.get());
+    println!("\nReactive Effect Cleanup example completed!");
+}
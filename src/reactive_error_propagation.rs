@@ -0,0 +1,210 @@
+//! Example demonstrating errors flowing through a chain of computeds
+//! `per_person` divides a bill between a number of people and returns a `Result`, so a
+//! head count of zero becomes an error rather than a panic or a made-up default. The
+//! downstream `with_tip` computed passes that error on unchanged, and the caller sees
+//! exactly why there is no value instead of a silently masked one.
+//! To run: cargo run --example reactive_error_propagation
+
+use std::fmt;
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_computed, create_signal, ReactiveComputed, ReactiveScope, Signal};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SplitError {
+    DivideByZero,
+    // The upstream computed hasn't produced a value yet
+    NotComputed,
+}
+
+impl fmt::Display for SplitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SplitError::DivideByZero => write!(f, "cannot split a bill between zero people"),
+            SplitError::NotComputed => write!(f, "value has not been computed yet"),
+        }
+    }
+}
+
+type Amount = Result<i64, SplitError>;
+
+#[derive(Clone)]
+pub struct BillSplitProps {
+    // In cents
+    pub total: i64,
+    pub people: i64,
+    // Percent added on top of each share
+    pub tip_percent: i64,
+}
+
+/// Splits a bill and adds a tip, surfacing division errors
+pub struct BillSplit {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    total: Signal<i64>,
+    people: Signal<i64>,
+    #[allow(dead_code)]
+    per_person: ReactiveComputed<Amount, Box<dyn FnMut() -> Amount>>,
+    with_tip: ReactiveComputed<Amount, Box<dyn FnMut() -> Amount>>,
+}
+
+impl Component for BillSplit {
+    type Props = BillSplitProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let total = create_signal(&scope, props.total);
+        let people = create_signal(&scope, props.people);
+
+        let total_clone = total.value.clone();
+        let people_clone = people.value.clone();
+        let per_person = create_computed(
+            &scope,
+            Box::new(move || {
+                let people = *people_clone.borrow();
+                if people == 0 {
+                    return Err(SplitError::DivideByZero);
+                }
+                Ok(*total_clone.borrow() / people)
+            }) as Box<dyn FnMut() -> Amount>,
+        );
+
+        // Propagate the upstream error instead of defaulting it away
+        let per_person_clone = per_person.value.clone();
+        let tip_percent = props.tip_percent;
+        let with_tip = create_computed(
+            &scope,
+            Box::new(move || {
+                let share = per_person_clone
+                    .borrow()
+                    .clone()
+                    .unwrap_or(Err(SplitError::NotComputed))?;
+                Ok(share + share * tip_percent / 100)
+            }) as Box<dyn FnMut() -> Amount>,
+        );
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            total,
+            people,
+            per_person,
+            with_tip,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.total
+            .set(props.total)
+            .map_err(|e| ComponentError::UpdateError(format!("Failed to set total: {}", e)))?;
+        self.people
+            .set(props.people)
+            .map_err(|e| ComponentError::UpdateError(format!("Failed to set people: {}", e)))
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut share = Node::default();
+        match self.with_tip() {
+            Ok(cents) => {
+                share.add_child(Node::text(&format!("${:.2} each", cents as f64 / 100.0)));
+            }
+            Err(error) => {
+                share.add_attribute("role".to_string(), "alert".to_string());
+                share.add_child(Node::text(&error.to_string()));
+            }
+        }
+        Ok(vec![share])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl BillSplit {
+    pub fn set_people(&self, people: i64) {
+        self.people
+            .set(people)
+            .unwrap_or_else(|e| eprintln!("Failed to set people: {}", e));
+    }
+
+    /// Each person's share including tip, or why it can't be computed
+    pub fn with_tip(&self) -> Amount {
+        self.with_tip
+            .get()
+            .map(|amount| amount.clone())
+            .unwrap_or(Err(SplitError::NotComputed))
+    }
+}
+
+fn bill_split(people: i64) -> BillSplit {
+    BillSplit::create(
+        BillSplitProps {
+            total: 12_000,
+            people,
+            tip_percent: 15,
+        },
+        Context::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_share_with_tip() {
+        let split = bill_split(4);
+
+        // 12000 / 4 = 3000, plus 15%
+        assert_eq!(split.with_tip(), Ok(3450));
+    }
+
+    #[test]
+    fn test_zero_people_error_reaches_caller() {
+        let split = bill_split(4);
+
+        split.set_people(0);
+
+        assert_eq!(split.with_tip(), Err(SplitError::DivideByZero));
+    }
+
+    #[test]
+    fn test_recovers_when_divisor_is_fixed() {
+        let split = bill_split(0);
+        assert_eq!(split.with_tip(), Err(SplitError::DivideByZero));
+
+        split.set_people(3);
+
+        assert_eq!(split.with_tip(), Ok(4600));
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Reactive Error Propagation Example\n");
+
+    let split = bill_split(4);
+    for people in [4, 3, 0, 2] {
+        split.set_people(people);
+        split.render()?;
+        match split.with_tip() {
+            Ok(cents) => println!("{} people: ${:.2} each", people, cents as f64 / 100.0),
+            Err(error) => println!("{} people: error: {}", people, error),
+        }
+    }
+
+    println!("\nReactive Error Propagation example completed!");
+    Ok(())
+}
@@ -0,0 +1,169 @@
+//! Example demonstrating rendering a list held in a signal
+//! Todo items live in a `Signal<Vec<String>>`, and `render` emits one text node per
+//! item. Adding, removing and clearing items all go through the signal, and removing
+//! an index that doesn't exist is ignored rather than panicking.
+//! To run: cargo run --example reactive_list
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+#[derive(Clone)]
+pub struct TodoListProps {
+    pub initial: Vec<String>,
+}
+
+/// A list of todo items
+pub struct TodoList {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    items: Signal<Vec<String>>,
+}
+
+impl Component for TodoList {
+    type Props = TodoListProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let items = create_signal(&scope, props.initial);
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            items,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.items
+            .set(props.initial)
+            .map_err(|e| ComponentError::UpdateError(format!("Failed to set items: {}", e)))
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        Ok(self.items.get().iter().map(|item| Node::text(item)).collect())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl TodoList {
+    pub fn add_item(&self, item: &str) {
+        self.items
+            .update(|items| items.push(item.to_string()))
+            .unwrap_or_else(|e| eprintln!("Failed to add item: {}", e));
+    }
+
+    /// Remove the item at `index`; returns false if there is no such item
+    pub fn remove_item(&self, index: usize) -> bool {
+        if index >= self.items.get().len() {
+            return false;
+        }
+        self.items
+            .update(|items| {
+                items.remove(index);
+            })
+            .unwrap_or_else(|e| eprintln!("Failed to remove item: {}", e));
+        true
+    }
+
+    pub fn clear(&self) {
+        self.items
+            .set(Vec::new())
+            .unwrap_or_else(|e| eprintln!("Failed to clear items: {}", e));
+    }
+
+    pub fn items(&self) -> Vec<String> {
+        self.items.get().clone()
+    }
+}
+
+fn todo_list() -> TodoList {
+    TodoList::create(
+        TodoListProps {
+            initial: vec!["Write docs".to_string()],
+        },
+        Context::new(),
+    )
+}
+
+fn report(list: &TodoList, action: &str) -> Result<(), ComponentError> {
+    let nodes = list.render()?;
+    println!("{:<28} -> {} node(s) {:?}", action, nodes.len(), list.items());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_then_remove() {
+        let list = todo_list();
+        list.add_item("Fix bug");
+        list.add_item("Ship release");
+
+        assert!(list.remove_item(0));
+
+        assert_eq!(list.items(), vec!["Fix bug", "Ship release"]);
+        assert_eq!(list.render().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_remove_out_of_range_is_noop() {
+        let list = todo_list();
+
+        assert!(!list.remove_item(5));
+
+        assert_eq!(list.items(), vec!["Write docs"]);
+    }
+
+    #[test]
+    fn test_clear_renders_nothing() {
+        let list = todo_list();
+        list.add_item("Fix bug");
+
+        list.clear();
+
+        assert!(list.render().unwrap().is_empty());
+        assert!(!list.remove_item(0));
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Reactive List Example\n");
+
+    let list = todo_list();
+    report(&list, "initial")?;
+
+    list.add_item("Review PR");
+    report(&list, "add \"Review PR\"")?;
+
+    list.add_item("Update changelog");
+    report(&list, "add \"Update changelog\"")?;
+
+    list.remove_item(0);
+    report(&list, "remove index 0")?;
+
+    let removed = list.remove_item(10);
+    report(&list, &format!("remove index 10 ({})", removed))?;
+
+    list.clear();
+    report(&list, "clear")?;
+
+    println!("\nReactive List example completed!");
+    Ok(())
+}
@@ -0,0 +1,162 @@
+//! Example demonstrating a computed that skips work when its input doesn't change
+//! `Signal::set` notifies dependents on every write, even when the new value equals
+//! the old one (`reactive_batching` relies on this to flush). Writing through
+//! `set_if_changed` compares first, so setting the same value twice in a row only
+//! recomputes the square once.
+//! To run: cargo run --example reactive_memo_compute
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_computed, create_signal, ReactiveComputed, ReactiveScope, Signal};
+
+/// Set `signal` only if `value` differs; returns whether it was written
+pub fn set_if_changed<T: PartialEq + Clone + 'static>(signal: &Signal<T>, value: T) -> bool {
+    if *signal.get() == value {
+        return false;
+    }
+    signal
+        .set(value)
+        .unwrap_or_else(|e| eprintln!("Failed to set value: {}", e));
+    true
+}
+
+#[derive(Clone)]
+pub struct SquareProps {
+    pub initial: i32,
+}
+
+/// Shows a number and its square
+pub struct Square {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    value: Signal<i32>,
+    square: ReactiveComputed<i32, Box<dyn FnMut() -> i32>>,
+    evaluations: Rc<Cell<usize>>,
+}
+
+impl Component for Square {
+    type Props = SquareProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let value = create_signal(&scope, props.initial);
+
+        let evaluations = Rc::new(Cell::new(0));
+        let evaluations_clone = evaluations.clone();
+        let value_clone = value.value.clone();
+        let square = create_computed(
+            &scope,
+            Box::new(move || {
+                evaluations_clone.set(evaluations_clone.get() + 1);
+                let value = *value_clone.borrow();
+                value * value
+            }) as Box<dyn FnMut() -> i32>,
+        );
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            value,
+            square,
+            evaluations,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.set(props.initial);
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        Ok(vec![Node::text(&format!("{}² = {}", self.value.get(), self.square()))])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl Square {
+    /// Write the value, skipping the recompute if it's unchanged
+    pub fn set(&self, value: i32) -> bool {
+        set_if_changed(&self.value, value)
+    }
+
+    pub fn square(&self) -> i32 {
+        self.square.get().map(|v| *v).unwrap_or(0)
+    }
+
+    /// How many times the square has been computed
+    pub fn evaluations(&self) -> usize {
+        self.evaluations.get()
+    }
+}
+
+fn square() -> Square {
+    Square::create(SquareProps { initial: 0 }, Context::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redundant_set_does_not_recompute() {
+        let square = square();
+        let before = square.evaluations();
+
+        assert!(square.set(5));
+        assert!(!square.set(5));
+
+        assert_eq!(square.square(), 25);
+        assert_eq!(square.evaluations(), before + 1);
+    }
+
+    #[test]
+    fn test_changed_value_recomputes() {
+        let square = square();
+        square.set(5);
+        let before = square.evaluations();
+
+        square.set(6);
+
+        assert_eq!(square.square(), 36);
+        assert_eq!(square.evaluations(), before + 1);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Reactive Memo Compute Example\n");
+
+    let square = square();
+    println!("Initial: {} evaluation(s)", square.evaluations());
+
+    for value in [5, 5, 5, 6, 6] {
+        let written = square.set(value);
+        square.render()?;
+        println!(
+            "set({}) -> {} (square = {}, evaluations = {})",
+            value,
+            if written { "recomputed" } else { "skipped" },
+            square.square(),
+            square.evaluations()
+        );
+    }
+
+    println!("\nReactive Memo Compute example completed!");
+    Ok(())
+}
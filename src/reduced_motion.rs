@@ -0,0 +1,224 @@
+//! Example demonstrating reduced-motion awareness for animations
+//! Animation helpers read a `prefers-reduced-motion` signal. When it is set, tweens
+//! skip straight to their end state instead of animating.
+//! To run: cargo run --example reduced_motion
+
+use std::time::Duration;
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+/// Linear tween between two values
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tween {
+    pub from: f32,
+    pub to: f32,
+    pub duration: Duration,
+    elapsed: Duration,
+}
+
+impl Tween {
+    pub fn new(from: f32, to: f32, duration: Duration) -> Self {
+        Self {
+            from,
+            to,
+            duration,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// Advance the tween, returning the new value.
+    /// With `reduced_motion` set the tween completes immediately.
+    pub fn step(&mut self, dt: Duration, reduced_motion: bool) -> f32 {
+        if reduced_motion {
+            self.elapsed = self.duration;
+        } else {
+            self.elapsed = (self.elapsed + dt).min(self.duration);
+        }
+        self.value()
+    }
+
+    pub fn value(&self) -> f32 {
+        if self.duration.is_zero() {
+            return self.to;
+        }
+        let t = self.elapsed.as_secs_f32() / self.duration.as_secs_f32();
+        self.from + (self.to - self.from) * t
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+}
+
+#[derive(Clone)]
+pub struct SlideInProps {
+    pub distance: f32,
+    pub duration: Duration,
+}
+
+/// A panel that slides in from the side
+pub struct SlideInPanel {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: SlideInProps,
+    // Mirrors the platform's prefers-reduced-motion setting
+    reduced_motion: Signal<bool>,
+    tween: Tween,
+}
+
+impl Component for SlideInPanel {
+    type Props = SlideInProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let reduced_motion = create_signal(&scope, false);
+        let tween = Tween::new(props.distance, 0.0, props.duration);
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            props,
+            reduced_motion,
+            tween,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut panel = Node::default();
+        panel.add_attribute("class".to_string(), "panel".to_string());
+        panel.add_attribute(
+            "transform".to_string(),
+            format!("translateX({:.1}px)", self.tween.value()),
+        );
+        Ok(vec![panel])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl SlideInPanel {
+    /// Update the reduced-motion preference (e.g. when the OS setting changes)
+    pub fn set_reduced_motion(&self, enabled: bool) {
+        self.reduced_motion.set(enabled).unwrap_or_else(|e| {
+            eprintln!("Failed to update reduced motion: {}", e);
+        });
+    }
+
+    /// Restart the slide-in animation
+    pub fn play(&mut self) {
+        self.tween = Tween::new(self.props.distance, 0.0, self.props.duration);
+    }
+
+    /// Advance the animation by one frame
+    pub fn tick(&mut self, dt: Duration) -> f32 {
+        let reduced = *self.reduced_motion.get();
+        self.tween.step(dt, reduced)
+    }
+
+    pub fn is_animating(&self) -> bool {
+        !self.tween.is_finished()
+    }
+}
+
+fn run_animation(panel: &mut SlideInPanel) -> Result<usize, ComponentError> {
+    let frame = Duration::from_millis(16);
+    let mut frames = 0;
+
+    panel.play();
+    while panel.is_animating() {
+        let offset = panel.tick(frame);
+        panel.render()?;
+        frames += 1;
+        if frames % 5 == 0 || !panel.is_animating() {
+            println!("  frame {:2}: offset {:.1}px", frames, offset);
+        }
+    }
+
+    Ok(frames)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reduced_motion_reaches_end_in_one_step() {
+        let mut tween = Tween::new(300.0, 0.0, Duration::from_millis(250));
+
+        let value = tween.step(Duration::from_millis(16), true);
+
+        assert_eq!(value, 0.0);
+        assert!(tween.is_finished());
+    }
+
+    #[test]
+    fn test_normal_motion_interpolates() {
+        let mut tween = Tween::new(100.0, 0.0, Duration::from_millis(100));
+
+        let value = tween.step(Duration::from_millis(50), false);
+
+        assert!((value - 50.0).abs() < 0.001);
+        assert!(!tween.is_finished());
+    }
+
+    #[test]
+    fn test_panel_follows_reduced_motion_signal() {
+        let mut panel = SlideInPanel::create(
+            SlideInProps {
+                distance: 200.0,
+                duration: Duration::from_millis(300),
+            },
+            Context::new(),
+        );
+        panel.set_reduced_motion(true);
+
+        panel.play();
+        panel.tick(Duration::from_millis(16));
+
+        assert!(!panel.is_animating());
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Reduced Motion Example\n");
+
+    let mut panel = SlideInPanel::create(
+        SlideInProps {
+            distance: 240.0,
+            duration: Duration::from_millis(200),
+        },
+        Context::new(),
+    );
+
+    println!("Full motion:");
+    let frames = run_animation(&mut panel)?;
+    println!("Finished in {} frames", frames);
+
+    println!("\nReduced motion:");
+    panel.set_reduced_motion(true);
+    let frames = run_animation(&mut panel)?;
+    println!("Finished in {} frame(s)", frames);
+
+    println!("\nReduced Motion example completed!");
+    Ok(())
+}
@@ -0,0 +1,285 @@
+//! Example demonstrating right-to-left (RTL) layout mirroring
+//! Toolbar items are aligned to the start or end edge rather than to the left or
+//! right, so a single `Signal<Direction>` decides which physical side each one lands
+//! on. Switching to RTL mirrors the row and flips directional icons (back and forward
+//! arrows) while leaving symmetric ones, like search, alone.
+//! To run: cargo run --example rtl_layout
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    Ltr,
+    Rtl,
+}
+
+impl Direction {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Direction::Ltr => "ltr",
+            Direction::Rtl => "rtl",
+        }
+    }
+}
+
+/// A logical edge; which physical side it is depends on the direction
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Edge {
+    Start,
+    End,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Icon {
+    Back,
+    Forward,
+    Search,
+}
+
+impl Icon {
+    /// The glyph to draw, mirrored for RTL where the icon points along the text
+    pub fn glyph(self, direction: Direction) -> &'static str {
+        match (self, direction) {
+            (Icon::Back, Direction::Ltr) | (Icon::Forward, Direction::Rtl) => "arrow-left",
+            (Icon::Back, Direction::Rtl) | (Icon::Forward, Direction::Ltr) => "arrow-right",
+            (Icon::Search, _) => "magnifier",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ToolbarItem {
+    pub label: String,
+    pub icon: Icon,
+    pub width: f32,
+    pub align: Edge,
+}
+
+impl ToolbarItem {
+    pub fn new(label: &str, icon: Icon, width: f32, align: Edge) -> Self {
+        Self {
+            label: label.to_string(),
+            icon,
+            width,
+            align,
+        }
+    }
+}
+
+/// An item's horizontal span, measured from the left edge
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Span {
+    pub x: f32,
+    pub width: f32,
+}
+
+/// Pack start items from the start edge and end items from the end edge, then
+/// mirror every span for RTL so the start edge becomes the right-hand side
+pub fn layout_row(items: &[ToolbarItem], width: f32, gap: f32, direction: Direction) -> Vec<Span> {
+    let mut start = 0.0;
+    let mut end = width;
+    items
+        .iter()
+        .map(|item| {
+            // Offset from the start edge, as if the row were LTR
+            let x = match item.align {
+                Edge::Start => {
+                    let x = start;
+                    start += item.width + gap;
+                    x
+                }
+                Edge::End => {
+                    end -= item.width;
+                    let x = end;
+                    end -= gap;
+                    x
+                }
+            };
+            let x = match direction {
+                Direction::Ltr => x,
+                Direction::Rtl => width - x - item.width,
+            };
+            Span {
+                x,
+                width: item.width,
+            }
+        })
+        .collect()
+}
+
+#[derive(Clone)]
+pub struct ToolbarProps {
+    pub items: Vec<ToolbarItem>,
+    pub width: f32,
+    pub gap: f32,
+    pub direction: Direction,
+}
+
+/// A toolbar that lays itself out for the current text direction
+pub struct Toolbar {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    items: Vec<ToolbarItem>,
+    width: f32,
+    gap: f32,
+    direction: Signal<Direction>,
+}
+
+impl Component for Toolbar {
+    type Props = ToolbarProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let direction = create_signal(&scope, props.direction);
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            items: props.items,
+            width: props.width,
+            gap: props.gap,
+            direction,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.items = props.items;
+        self.width = props.width;
+        self.gap = props.gap;
+        self.direction
+            .set(props.direction)
+            .map_err(|e| ComponentError::UpdateError(format!("Failed to set direction: {}", e)))
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let direction = self.direction();
+        let mut toolbar = Node::default();
+        toolbar.add_attribute("role".to_string(), "toolbar".to_string());
+        toolbar.add_attribute("dir".to_string(), direction.as_str().to_string());
+
+        for (item, span) in self.items.iter().zip(self.spans()) {
+            let mut button = Node::default();
+            button.add_attribute("role".to_string(), "button".to_string());
+            button.add_attribute("aria-label".to_string(), item.label.clone());
+            button.add_attribute("icon".to_string(), item.icon.glyph(direction).to_string());
+            button.add_attribute("x".to_string(), span.x.to_string());
+            button.add_attribute("width".to_string(), span.width.to_string());
+            toolbar.add_child(button);
+        }
+
+        Ok(vec![toolbar])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl Toolbar {
+    pub fn direction(&self) -> Direction {
+        *self.direction.get()
+    }
+
+    pub fn set_direction(&self, direction: Direction) {
+        self.direction
+            .set(direction)
+            .unwrap_or_else(|e| eprintln!("Failed to set direction: {}", e));
+    }
+
+    /// Each item's span for the current direction
+    pub fn spans(&self) -> Vec<Span> {
+        layout_row(&self.items, self.width, self.gap, self.direction())
+    }
+}
+
+fn browser_toolbar(direction: Direction) -> Toolbar {
+    Toolbar::create(
+        ToolbarProps {
+            items: vec![
+                ToolbarItem::new("Back", Icon::Back, 32.0, Edge::Start),
+                ToolbarItem::new("Forward", Icon::Forward, 32.0, Edge::Start),
+                ToolbarItem::new("Search", Icon::Search, 120.0, Edge::End),
+            ],
+            width: 400.0,
+            gap: 8.0,
+            direction,
+        },
+        Context::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_start_item_moves_to_right_edge_in_rtl() {
+        let toolbar = browser_toolbar(Direction::Ltr);
+        let back = toolbar.spans()[0];
+        assert!(back.x.abs() < f32::EPSILON);
+
+        toolbar.set_direction(Direction::Rtl);
+
+        let back = toolbar.spans()[0];
+        assert!((back.x + back.width - 400.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_rtl_mirrors_every_span() {
+        let toolbar = browser_toolbar(Direction::Ltr);
+        let ltr = toolbar.spans();
+
+        toolbar.set_direction(Direction::Rtl);
+
+        for (ltr, rtl) in ltr.iter().zip(toolbar.spans()) {
+            assert!((rtl.x - (400.0 - ltr.x - ltr.width)).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_only_directional_icons_flip() {
+        assert_eq!(Icon::Back.glyph(Direction::Rtl), "arrow-right");
+        assert_eq!(Icon::Forward.glyph(Direction::Rtl), "arrow-left");
+        assert_eq!(
+            Icon::Search.glyph(Direction::Rtl),
+            Icon::Search.glyph(Direction::Ltr)
+        );
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("RTL Layout Example\n");
+
+    let toolbar = browser_toolbar(Direction::Ltr);
+    for direction in [Direction::Ltr, Direction::Rtl] {
+        toolbar.set_direction(direction);
+        toolbar.render()?;
+        println!("{}:", direction.as_str().to_uppercase());
+        for (item, span) in toolbar.items.iter().zip(toolbar.spans()) {
+            println!(
+                "  {:<8} x {:>5.1}..{:<5.1} {}",
+                item.label,
+                span.x,
+                span.x + span.width,
+                item.icon.glyph(direction)
+            );
+        }
+        println!();
+    }
+
+    println!("RTL Layout example completed!");
+    Ok(())
+}
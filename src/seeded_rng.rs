@@ -0,0 +1,247 @@
+//! Example demonstrating a seedable random number generator
+//! Components that need randomness take an `Rng` instead of reaching for a global
+//! source. `SeededRng` is a small xorshift generator, so the same seed always yields
+//! the same sequence: the particle emitter below sprays identical particles on every
+//! run with a given seed, which makes its output something a test can assert on.
+//! To run: cargo run --example seeded_rng
+
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+/// A source of random numbers
+pub trait Rng: Send {
+    fn next_u64(&mut self) -> u64;
+
+    /// Uniform in `[0, 1)`
+    fn next_f32(&mut self) -> f32 {
+        // The top 24 bits fill an f32 mantissa exactly
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// Uniform in `[low, high)`
+    fn range(&mut self, low: f32, high: f32) -> f32 {
+        low + (high - low) * self.next_f32()
+    }
+}
+
+/// xorshift64*: tiny, fast and fully determined by its seed
+pub struct SeededRng {
+    state: u64,
+}
+
+impl SeededRng {
+    pub fn new(seed: u64) -> Self {
+        // Zero is a fixed point of xorshift, so nudge it
+        Self {
+            state: if seed == 0 { 0x9E37_79B9_7F4A_7C15 } else { seed },
+        }
+    }
+
+    /// Seeded from the system time, for when reproducibility doesn't matter
+    pub fn from_time() -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_nanos() as u64)
+            .unwrap_or(0);
+        Self::new(nanos)
+    }
+}
+
+impl Rng for SeededRng {
+    fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+}
+
+pub type SharedRng = Arc<Mutex<dyn Rng>>;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Particle {
+    pub x: f32,
+    pub y: f32,
+    pub vx: f32,
+    pub vy: f32,
+}
+
+#[derive(Clone)]
+pub struct EmitterProps {
+    pub origin: (f32, f32),
+    pub rng: SharedRng,
+}
+
+/// Emits particles with random velocities from a fixed origin
+pub struct ParticleEmitter {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: EmitterProps,
+    particles: Signal<Vec<Particle>>,
+}
+
+impl Component for ParticleEmitter {
+    type Props = EmitterProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            particles: create_signal(&scope, Vec::new()),
+            scope,
+            props,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let nodes = self
+            .particles
+            .get()
+            .iter()
+            .map(|particle| {
+                let mut dot = Node::default();
+                dot.add_attribute("cx".to_string(), format!("{:.1}", particle.x));
+                dot.add_attribute("cy".to_string(), format!("{:.1}", particle.y));
+                dot
+            })
+            .collect();
+        Ok(nodes)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl ParticleEmitter {
+    pub fn emit(&self, count: usize) {
+        let Ok(mut rng) = self.props.rng.lock() else {
+            return;
+        };
+        let (x, y) = self.props.origin;
+        let burst: Vec<Particle> = (0..count)
+            .map(|_| Particle {
+                x,
+                y,
+                vx: rng.range(-50.0, 50.0),
+                vy: rng.range(-120.0, -40.0),
+            })
+            .collect();
+        drop(rng);
+
+        self.particles
+            .update(|particles| particles.extend(burst))
+            .unwrap_or_else(|e| eprintln!("Failed to emit particles: {}", e));
+    }
+
+    /// Move every particle forward by `dt` seconds under gravity
+    pub fn step(&self, dt: f32) {
+        self.particles
+            .update(|particles| {
+                for particle in particles.iter_mut() {
+                    particle.vy += 98.0 * dt;
+                    particle.x += particle.vx * dt;
+                    particle.y += particle.vy * dt;
+                }
+            })
+            .unwrap_or_else(|e| eprintln!("Failed to step particles: {}", e));
+    }
+
+    pub fn particles(&self) -> Vec<Particle> {
+        self.particles.get().clone()
+    }
+}
+
+fn emitter(rng: SeededRng) -> ParticleEmitter {
+    ParticleEmitter::create(
+        EmitterProps {
+            origin: (100.0, 200.0),
+            rng: Arc::new(Mutex::new(rng)),
+        },
+        Context::new(),
+    )
+}
+
+/// Emit a burst and let it fly for half a second
+fn simulate(emitter: &ParticleEmitter) -> Vec<Particle> {
+    emitter.emit(5);
+    for _ in 0..30 {
+        emitter.step(1.0 / 60.0);
+    }
+    emitter.particles()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_gives_identical_particles() {
+        let first = simulate(&emitter(SeededRng::new(42)));
+        let second = simulate(&emitter(SeededRng::new(42)));
+
+        assert_eq!(first.len(), 5);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_different_seeds_diverge() {
+        let first = simulate(&emitter(SeededRng::new(1)));
+        let second = simulate(&emitter(SeededRng::new(2)));
+
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_range_stays_in_bounds() {
+        let mut rng = SeededRng::new(7);
+        for _ in 0..1_000 {
+            let value = rng.range(-1.0, 1.0);
+            assert!((-1.0..1.0).contains(&value));
+        }
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Seeded RNG Example\n");
+
+    for run in 1..=2 {
+        let emitter = emitter(SeededRng::new(2024));
+        let particles = simulate(&emitter);
+        emitter.render()?;
+        println!("Seed 2024, run {}:", run);
+        for particle in particles {
+            println!("  ({:>6.1}, {:>6.1})", particle.x, particle.y);
+        }
+    }
+
+    let emitter = emitter(SeededRng::from_time());
+    println!("\nTime-seeded run:");
+    for particle in simulate(&emitter) {
+        println!("  ({:>6.1}, {:>6.1})", particle.x, particle.y);
+    }
+
+    println!("\nSeeded RNG example completed!");
+    Ok(())
+}
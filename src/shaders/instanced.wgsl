@@ -0,0 +1,50 @@
+// Many copies of one mesh, each placed by a per-instance model matrix
+
+struct Uniforms {
+    view_proj: mat4x4<f32>,
+    // Unused; instances carry their own model matrices
+    model: mat4x4<f32>,
+};
+
+@group(0) @binding(0)
+var<uniform> uniforms: Uniforms;
+
+struct VertexInput {
+    @location(0) position: vec3<f32>,
+    @location(1) normal: vec3<f32>,
+    @location(2) uv: vec2<f32>,
+};
+
+// A mat4x4 can't be a vertex attribute, so it arrives as four columns
+struct InstanceInput {
+    @location(5) model_0: vec4<f32>,
+    @location(6) model_1: vec4<f32>,
+    @location(7) model_2: vec4<f32>,
+    @location(8) model_3: vec4<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) normal: vec3<f32>,
+    @location(1) height: f32,
+};
+
+@vertex
+fn vs_main(in: VertexInput, instance: InstanceInput) -> VertexOutput {
+    let model = mat4x4<f32>(instance.model_0, instance.model_1, instance.model_2, instance.model_3);
+    var out: VertexOutput;
+    out.clip_position = uniforms.view_proj * model * vec4<f32>(in.position, 1.0);
+    out.normal = (model * vec4<f32>(in.normal, 0.0)).xyz;
+    out.height = instance.model_3.y;
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let light = normalize(vec3<f32>(0.4, 1.0, 0.6));
+    let diffuse = max(dot(normalize(in.normal), light), 0.0);
+    // Troughs blue, crests orange
+    let t = clamp(in.height * 0.5 + 0.5, 0.0, 1.0);
+    let base = mix(vec3<f32>(0.2, 0.4, 0.9), vec3<f32>(1.0, 0.6, 0.2), t);
+    return vec4<f32>(base * (0.2 + 0.8 * diffuse), 1.0);
+}
@@ -0,0 +1,37 @@
+// Flat-coloured meshes lit by a fixed directional light
+
+struct Uniforms {
+    view_proj: mat4x4<f32>,
+    model: mat4x4<f32>,
+};
+
+@group(0) @binding(0)
+var<uniform> uniforms: Uniforms;
+
+struct VertexInput {
+    @location(0) position: vec3<f32>,
+    @location(1) normal: vec3<f32>,
+    @location(2) uv: vec2<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) normal: vec3<f32>,
+};
+
+@vertex
+fn vs_main(in: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+    out.clip_position = uniforms.view_proj * uniforms.model * vec4<f32>(in.position, 1.0);
+    // Fine for the rotations and uniform scales the examples use
+    out.normal = (uniforms.model * vec4<f32>(in.normal, 0.0)).xyz;
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let light = normalize(vec3<f32>(0.4, 1.0, 0.6));
+    let diffuse = max(dot(normalize(in.normal), light), 0.0);
+    let base = vec3<f32>(0.55, 0.62, 0.78);
+    return vec4<f32>(base * (0.2 + 0.8 * diffuse), 1.0);
+}
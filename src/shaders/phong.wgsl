@@ -0,0 +1,55 @@
+// Phong shading: ambient + diffuse + specular from one directional light
+
+struct Uniforms {
+    view_proj: mat4x4<f32>,
+    model: mat4x4<f32>,
+    // xyz used; vec4 keeps the Rust and WGSL layouts identical
+    camera_position: vec4<f32>,
+    // The way the light travels, towards the scene
+    light_direction: vec4<f32>,
+    light_color: vec4<f32>,
+};
+
+@group(0) @binding(0)
+var<uniform> uniforms: Uniforms;
+
+struct VertexInput {
+    @location(0) position: vec3<f32>,
+    @location(1) normal: vec3<f32>,
+    @location(2) uv: vec2<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) world_position: vec3<f32>,
+    @location(1) normal: vec3<f32>,
+};
+
+@vertex
+fn vs_main(in: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+    let world = uniforms.model * vec4<f32>(in.position, 1.0);
+    out.clip_position = uniforms.view_proj * world;
+    out.world_position = world.xyz;
+    // Fine for the rotations and uniform scales the examples use
+    out.normal = (uniforms.model * vec4<f32>(in.normal, 0.0)).xyz;
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let base = vec3<f32>(0.55, 0.62, 0.78);
+    let light_color = uniforms.light_color.rgb;
+    let shininess = 32.0;
+
+    let normal = normalize(in.normal);
+    let to_light = normalize(-uniforms.light_direction.xyz);
+    let to_camera = normalize(uniforms.camera_position.xyz - in.world_position);
+
+    let ambient = 0.1 * light_color;
+    let diffuse = max(dot(normal, to_light), 0.0) * light_color;
+    let reflected = reflect(-to_light, normal);
+    let specular = pow(max(dot(to_camera, reflected), 0.0), shininess) * light_color;
+
+    return vec4<f32>((ambient + diffuse) * base + specular * 0.5, 1.0);
+}
@@ -0,0 +1,265 @@
+//! Example demonstrating a reactive shopping cart
+//! Cart items live in a signal; subtotal, tax and total are computed values derived from
+//! it, so adding, removing or changing quantities keeps the totals up to date.
+//! To run: cargo run --example shopping_cart
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_computed, create_signal, ReactiveComputed, ReactiveScope, Signal};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CartItem {
+    pub sku: String,
+    pub name: String,
+    // Prices are kept in cents to avoid rounding errors
+    pub unit_price: u64,
+    pub quantity: u32,
+}
+
+#[derive(Clone)]
+pub struct CartProps {
+    // Tax rate in basis points (825 = 8.25%)
+    pub tax_rate_bps: u64,
+}
+
+/// A cart whose totals are derived from its items
+pub struct ShoppingCart {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: CartProps,
+    items: Signal<Vec<CartItem>>,
+    subtotal: ReactiveComputed<u64, Box<dyn FnMut() -> u64>>,
+    tax: ReactiveComputed<u64, Box<dyn FnMut() -> u64>>,
+    total: ReactiveComputed<u64, Box<dyn FnMut() -> u64>>,
+}
+
+impl Component for ShoppingCart {
+    type Props = CartProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let items = create_signal(&scope, Vec::<CartItem>::new());
+
+        let items_for_subtotal = items.value.clone();
+        let subtotal = create_computed(&scope, Box::new(move || {
+            items_for_subtotal
+                .borrow()
+                .iter()
+                .map(|item| item.unit_price * item.quantity as u64)
+                .sum()
+        }) as Box<dyn FnMut() -> u64>);
+
+        let subtotal_for_tax = subtotal.value.clone();
+        let tax_rate_bps = props.tax_rate_bps;
+        let tax = create_computed(&scope, Box::new(move || {
+            let subtotal = subtotal_for_tax.borrow().unwrap_or(0);
+            // Round half up to the nearest cent
+            (subtotal * tax_rate_bps + 5_000) / 10_000
+        }) as Box<dyn FnMut() -> u64>);
+
+        let subtotal_for_total = subtotal.value.clone();
+        let tax_for_total = tax.value.clone();
+        let total = create_computed(&scope, Box::new(move || {
+            subtotal_for_total.borrow().unwrap_or(0) + tax_for_total.borrow().unwrap_or(0)
+        }) as Box<dyn FnMut() -> u64>);
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            props,
+            items,
+            subtotal,
+            tax,
+            total,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut cart = Node::default();
+        cart.add_attribute("class".to_string(), "cart".to_string());
+
+        for item in self.items.get().iter() {
+            let mut row = Node::default();
+            row.add_attribute("data-sku".to_string(), item.sku.clone());
+            row.add_child(Node::text(&format!(
+                "{} x{} {}",
+                item.name,
+                item.quantity,
+                format_cents(item.unit_price * item.quantity as u64)
+            )));
+            cart.add_child(row);
+        }
+
+        for (label, value) in [
+            ("Subtotal", self.subtotal()),
+            ("Tax", self.tax()),
+            ("Total", self.total()),
+        ] {
+            cart.add_child(Node::text(&format!("{}: {}", label, format_cents(value))));
+        }
+
+        Ok(vec![cart])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl ShoppingCart {
+    /// Add an item, or increase its quantity if it is already in the cart
+    pub fn add(&self, item: CartItem) {
+        self.items
+            .update(|items| match items.iter_mut().find(|i| i.sku == item.sku) {
+                Some(existing) => existing.quantity += item.quantity,
+                None => items.push(item),
+            })
+            .unwrap_or_else(|e| eprintln!("Failed to add item: {}", e));
+    }
+
+    pub fn remove(&self, sku: &str) {
+        self.items
+            .update(|items| items.retain(|item| item.sku != sku))
+            .unwrap_or_else(|e| eprintln!("Failed to remove item: {}", e));
+    }
+
+    /// Set an item's quantity; a quantity of zero removes it
+    pub fn set_quantity(&self, sku: &str, quantity: u32) {
+        if quantity == 0 {
+            self.remove(sku);
+            return;
+        }
+        self.items
+            .update(|items| {
+                if let Some(item) = items.iter_mut().find(|item| item.sku == sku) {
+                    item.quantity = quantity;
+                }
+            })
+            .unwrap_or_else(|e| eprintln!("Failed to update quantity: {}", e));
+    }
+
+    pub fn subtotal(&self) -> u64 {
+        self.subtotal.get().map(|value| *value).unwrap_or(0)
+    }
+
+    pub fn tax(&self) -> u64 {
+        self.tax.get().map(|value| *value).unwrap_or(0)
+    }
+
+    pub fn total(&self) -> u64 {
+        self.total.get().map(|value| *value).unwrap_or(0)
+    }
+
+    pub fn tax_rate_bps(&self) -> u64 {
+        self.props.tax_rate_bps
+    }
+}
+
+fn format_cents(cents: u64) -> String {
+    format!("${}.{:02}", cents / 100, cents % 100)
+}
+
+fn item(sku: &str, name: &str, unit_price: u64, quantity: u32) -> CartItem {
+    CartItem {
+        sku: sku.to_string(),
+        name: name.to_string(),
+        unit_price,
+        quantity,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cart() -> ShoppingCart {
+        ShoppingCart::create(CartProps { tax_rate_bps: 1_000 }, Context::new())
+    }
+
+    #[test]
+    fn test_total_includes_tax() {
+        let cart = cart();
+
+        cart.add(item("mug", "Mug", 1_250, 2));
+        cart.add(item("tea", "Tea", 899, 3));
+
+        // 25.00 + 26.97 = 51.97, plus 10% tax (5.20)
+        assert_eq!(cart.subtotal(), 5_197);
+        assert_eq!(cart.tax(), 520);
+        assert_eq!(cart.total(), 5_717);
+    }
+
+    #[test]
+    fn test_removing_last_item_zeroes_total() {
+        let cart = cart();
+        cart.add(item("mug", "Mug", 1_250, 1));
+
+        cart.remove("mug");
+
+        assert_eq!(cart.total(), 0);
+    }
+
+    #[test]
+    fn test_zero_quantity_removes_item() {
+        let cart = cart();
+        cart.add(item("mug", "Mug", 1_250, 1));
+        cart.add(item("tea", "Tea", 899, 1));
+
+        cart.set_quantity("mug", 0);
+
+        assert_eq!(cart.subtotal(), 899);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Shopping Cart Example\n");
+
+    let cart = ShoppingCart::create(CartProps { tax_rate_bps: 825 }, Context::new());
+    println!("Tax rate: {}bps", cart.tax_rate_bps());
+
+    let print_totals = |cart: &ShoppingCart| {
+        println!(
+            "  subtotal {} + tax {} = {}",
+            format_cents(cart.subtotal()),
+            format_cents(cart.tax()),
+            format_cents(cart.total())
+        );
+    };
+
+    println!("\nAdding a keyboard...");
+    cart.add(item("kb", "Keyboard", 7_999, 1));
+    print_totals(&cart);
+
+    println!("Adding two mice...");
+    cart.add(item("mouse", "Mouse", 2_499, 2));
+    print_totals(&cart);
+
+    println!("Changing mice to three...");
+    cart.set_quantity("mouse", 3);
+    cart.render()?;
+    print_totals(&cart);
+
+    println!("Removing everything...");
+    cart.remove("kb");
+    cart.remove("mouse");
+    print_totals(&cart);
+
+    println!("\nShopping Cart example completed!");
+    Ok(())
+}
@@ -0,0 +1,279 @@
+//! Example demonstrating a snapshot-diff debugger for re-renders
+//! `SnapshotDebugger` renders components on the caller's behalf, snapshots the
+//! attributes and children of each node tree it gets back, and compares the snapshot
+//! with the one from that component's previous render. The printed diff names the
+//! component, the node's path and each attribute that changed; a re-render that
+//! changed nothing at all is flagged, since that's usually the one worth chasing.
+//! To run: cargo run --example snapshot_debugger
+
+use std::collections::{BTreeMap, HashMap};
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+/// What a node looked like when it was rendered
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Snapshot {
+    // Sorted, so diffs list attributes in a stable order
+    pub attributes: BTreeMap<String, String>,
+    pub children: Vec<Snapshot>,
+}
+
+impl Snapshot {
+    pub fn of(node: &Node) -> Self {
+        Self {
+            attributes: node
+                .attributes()
+                .iter()
+                .map(|(name, value)| (name.clone(), value.clone()))
+                .collect(),
+            children: node.children().iter().map(Snapshot::of).collect(),
+        }
+    }
+}
+
+/// Describe how `after` differs from `before`, one line per change; `path` is the
+/// node's position as child indices from the root, e.g. `0/2`
+pub fn diff(path: &str, before: &Snapshot, after: &Snapshot) -> Vec<String> {
+    let mut changes = Vec::new();
+
+    for (name, old) in &before.attributes {
+        match after.attributes.get(name) {
+            Some(new) if new != old => {
+                changes.push(format!("{} {}: {:?} -> {:?}", path, name, old, new));
+            }
+            Some(_) => {}
+            None => changes.push(format!("{} {}: {:?} removed", path, name, old)),
+        }
+    }
+    for (name, new) in &after.attributes {
+        if !before.attributes.contains_key(name) {
+            changes.push(format!("{} {}: added {:?}", path, name, new));
+        }
+    }
+
+    if before.children.len() != after.children.len() {
+        changes.push(format!(
+            "{} children: {} -> {}",
+            path,
+            before.children.len(),
+            after.children.len()
+        ));
+    }
+    for (index, (old, new)) in before.children.iter().zip(&after.children).enumerate() {
+        changes.extend(diff(&format!("{}/{}", path, index), old, new));
+    }
+
+    changes
+}
+
+/// Renders components and reports what changed since each one's last render
+#[derive(Default)]
+pub struct SnapshotDebugger {
+    previous: HashMap<ComponentId, Vec<Snapshot>>,
+    last_diff: Vec<String>,
+}
+
+impl SnapshotDebugger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn render<C: Component>(&mut self, component: &C) -> Result<Vec<Node>, ComponentError> {
+        let nodes = component.render()?;
+        let name = component_name::<C>();
+        let snapshots: Vec<Snapshot> = nodes.iter().map(Snapshot::of).collect();
+
+        self.last_diff = match self.previous.get(&component.component_id()) {
+            None => Vec::new(),
+            Some(previous) if *previous == snapshots => {
+                vec![format!("{}: re-rendered with no changes", name)]
+            }
+            Some(previous) => {
+                let mut changes = Vec::new();
+                if previous.len() != snapshots.len() {
+                    changes.push(format!("roots: {} -> {}", previous.len(), snapshots.len()));
+                }
+                for (index, (old, new)) in previous.iter().zip(&snapshots).enumerate() {
+                    changes.extend(diff(&index.to_string(), old, new));
+                }
+                changes
+                    .into_iter()
+                    .map(|change| format!("{}: {}", name, change))
+                    .collect()
+            }
+        };
+        for line in &self.last_diff {
+            println!("[snapshot] {}", line);
+        }
+
+        self.previous.insert(component.component_id(), snapshots);
+        Ok(nodes)
+    }
+
+    /// The changes found by the most recent render
+    pub fn last_diff(&self) -> &[String] {
+        &self.last_diff
+    }
+}
+
+/// The type's name without its module path
+fn component_name<C>() -> &'static str {
+    let full = std::any::type_name::<C>();
+    full.rsplit("::").next().unwrap_or(full)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Status {
+    Online,
+    Away,
+}
+
+#[derive(Clone)]
+pub struct StatusBadgeProps {
+    pub user: String,
+}
+
+/// A user's name with a coloured presence indicator
+pub struct StatusBadge {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: StatusBadgeProps,
+    status: Signal<Status>,
+}
+
+impl Component for StatusBadge {
+    type Props = StatusBadgeProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            status: create_signal(&scope, Status::Online),
+            scope,
+            props,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let status = format!("{:?}", *self.status.get()).to_lowercase();
+
+        let mut dot = Node::default();
+        dot.add_attribute("class".to_string(), format!("dot {}", status));
+
+        let mut badge = Node::default();
+        badge.add_attribute("data-status".to_string(), status);
+        badge.add_attribute("aria-label".to_string(), self.props.user.clone());
+        badge.add_child(dot);
+        badge.add_child(Node::text(&self.props.user));
+        Ok(vec![badge])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl StatusBadge {
+    pub fn set_status(&self, status: Status) {
+        self.status
+            .set(status)
+            .unwrap_or_else(|e| eprintln!("Failed to set status: {}", e));
+    }
+}
+
+fn badge() -> StatusBadge {
+    StatusBadge::create(
+        StatusBadgeProps {
+            user: "ada".to_string(),
+        },
+        Context::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_names_changed_attribute_and_component() {
+        let mut debugger = SnapshotDebugger::new();
+        let badge = badge();
+        debugger.render(&badge).unwrap();
+
+        badge.set_status(Status::Away);
+        debugger.render(&badge).unwrap();
+
+        let diff = debugger.last_diff();
+        assert!(diff.iter().any(|line| line.starts_with("StatusBadge:")
+            && line.contains("data-status: \"online\" -> \"away\"")));
+        assert!(diff.iter().all(|line| !line.contains("aria-label")));
+    }
+
+    #[test]
+    fn test_unchanged_rerender_is_flagged() {
+        let mut debugger = SnapshotDebugger::new();
+        let badge = badge();
+        debugger.render(&badge).unwrap();
+        assert!(debugger.last_diff().is_empty());
+
+        debugger.render(&badge).unwrap();
+
+        assert_eq!(
+            debugger.last_diff(),
+            ["StatusBadge: re-rendered with no changes"]
+        );
+    }
+
+    #[test]
+    fn test_nested_changes_report_their_path() {
+        let before = Snapshot {
+            children: vec![Snapshot::default()],
+            ..Snapshot::default()
+        };
+        let mut after = before.clone();
+        after.children[0]
+            .attributes
+            .insert("hidden".to_string(), "true".to_string());
+
+        assert_eq!(diff("0", &before, &after), ["0/0 hidden: added \"true\""]);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Snapshot Debugger Example\n");
+
+    let mut debugger = SnapshotDebugger::new();
+    let badge = badge();
+
+    println!("First render:");
+    debugger.render(&badge)?;
+
+    println!("\nAfter going away:");
+    badge.set_status(Status::Away);
+    debugger.render(&badge)?;
+
+    println!("\nRendering again without a change:");
+    debugger.render(&badge)?;
+
+    println!("\nSnapshot Debugger example completed!");
+    Ok(())
+}
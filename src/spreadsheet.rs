@@ -0,0 +1,564 @@
+//! Example demonstrating a reactive spreadsheet with formula cells
+//! Cells hold numbers, text or formulas like `=A1+B2*2`. Each formula records the cells
+//! it reads, and changing a cell recomputes exactly the cells that depend on it,
+//! directly or transitively. Circular references are detected and flagged as errors.
+//! To run: cargo run --example spreadsheet
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+/// A cell address such as `B3`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CellRef {
+    pub col: u8,
+    pub row: u32,
+}
+
+impl CellRef {
+    pub fn parse(name: &str) -> Option<Self> {
+        let mut chars = name.chars();
+        let letter = chars.next()?.to_ascii_uppercase();
+        if !letter.is_ascii_uppercase() {
+            return None;
+        }
+        let row: u32 = chars.as_str().parse().ok()?;
+        (row > 0).then_some(CellRef {
+            col: letter as u8 - b'A',
+            row,
+        })
+    }
+}
+
+impl fmt::Display for CellRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", (b'A' + self.col) as char, self.row)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CellError {
+    Circular,
+    Parse(String),
+    DivideByZero,
+    NotANumber(CellRef),
+}
+
+impl fmt::Display for CellError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CellError::Circular => write!(f, "#CIRCULAR"),
+            CellError::Parse(message) => write!(f, "#PARSE({})", message),
+            CellError::DivideByZero => write!(f, "#DIV/0"),
+            CellError::NotANumber(cell) => write!(f, "#VALUE({})", cell),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CellValue {
+    Empty,
+    Number(f64),
+    Text(String),
+    Error(CellError),
+}
+
+impl fmt::Display for CellValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CellValue::Empty => Ok(()),
+            CellValue::Number(value) => write!(f, "{}", value),
+            CellValue::Text(text) => write!(f, "{}", text),
+            CellValue::Error(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    Number(f64),
+    Ref(CellRef),
+    Neg(Box<Expr>),
+    Binary(Box<Expr>, char, Box<Expr>),
+}
+
+impl Expr {
+    fn references(&self, out: &mut HashSet<CellRef>) {
+        match self {
+            Expr::Number(_) => {}
+            Expr::Ref(cell) => {
+                out.insert(*cell);
+            }
+            Expr::Neg(inner) => inner.references(out),
+            Expr::Binary(left, _, right) => {
+                left.references(out);
+                right.references(out);
+            }
+        }
+    }
+}
+
+/// Recursive-descent parser for `+ - * /`, parentheses, numbers and cell references
+struct Parser<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn parse(formula: &'a str) -> Result<Expr, CellError> {
+        let mut parser = Parser {
+            input: formula.as_bytes(),
+            pos: 0,
+        };
+        let expr = parser.expr()?;
+        parser.skip_whitespace();
+        match parser.peek() {
+            None => Ok(expr),
+            Some(c) => Err(CellError::Parse(format!("unexpected '{}'", c as char))),
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().map_or(false, |c| c.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn expr(&mut self) -> Result<Expr, CellError> {
+        let mut left = self.term()?;
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some(op @ (b'+' | b'-')) => {
+                    self.pos += 1;
+                    let right = self.term()?;
+                    left = Expr::Binary(Box::new(left), op as char, Box::new(right));
+                }
+                _ => return Ok(left),
+            }
+        }
+    }
+
+    fn term(&mut self) -> Result<Expr, CellError> {
+        let mut left = self.factor()?;
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some(op @ (b'*' | b'/')) => {
+                    self.pos += 1;
+                    let right = self.factor()?;
+                    left = Expr::Binary(Box::new(left), op as char, Box::new(right));
+                }
+                _ => return Ok(left),
+            }
+        }
+    }
+
+    fn factor(&mut self) -> Result<Expr, CellError> {
+        self.skip_whitespace();
+        let start = self.pos;
+        match self.peek() {
+            Some(b'-') => {
+                self.pos += 1;
+                Ok(Expr::Neg(Box::new(self.factor()?)))
+            }
+            Some(b'(') => {
+                self.pos += 1;
+                let inner = self.expr()?;
+                self.skip_whitespace();
+                if self.peek() != Some(b')') {
+                    return Err(CellError::Parse("missing ')'".to_string()));
+                }
+                self.pos += 1;
+                Ok(inner)
+            }
+            Some(c) if c.is_ascii_digit() || c == b'.' => {
+                while self.peek().map_or(false, |c| c.is_ascii_digit() || c == b'.') {
+                    self.pos += 1;
+                }
+                let text = std::str::from_utf8(&self.input[start..self.pos]).unwrap();
+                text.parse()
+                    .map(Expr::Number)
+                    .map_err(|_| CellError::Parse(format!("bad number '{}'", text)))
+            }
+            Some(c) if c.is_ascii_alphabetic() => {
+                while self.peek().map_or(false, |c| c.is_ascii_alphanumeric()) {
+                    self.pos += 1;
+                }
+                let text = std::str::from_utf8(&self.input[start..self.pos]).unwrap();
+                CellRef::parse(text)
+                    .map(Expr::Ref)
+                    .ok_or_else(|| CellError::Parse(format!("bad reference '{}'", text)))
+            }
+            Some(c) => Err(CellError::Parse(format!("unexpected '{}'", c as char))),
+            None => Err(CellError::Parse("unexpected end of formula".to_string())),
+        }
+    }
+}
+
+fn eval_expr(expr: &Expr, lookup: &mut dyn FnMut(CellRef) -> CellValue) -> Result<f64, CellError> {
+    match expr {
+        Expr::Number(value) => Ok(*value),
+        Expr::Ref(cell) => match lookup(*cell) {
+            CellValue::Empty => Ok(0.0),
+            CellValue::Number(value) => Ok(value),
+            CellValue::Text(_) => Err(CellError::NotANumber(*cell)),
+            CellValue::Error(error) => Err(error),
+        },
+        Expr::Neg(inner) => Ok(-eval_expr(inner, lookup)?),
+        Expr::Binary(left, op, right) => {
+            let left = eval_expr(left, lookup)?;
+            let right = eval_expr(right, lookup)?;
+            match op {
+                '+' => Ok(left + right),
+                '-' => Ok(left - right),
+                '*' => Ok(left * right),
+                _ if right == 0.0 => Err(CellError::DivideByZero),
+                _ => Ok(left / right),
+            }
+        }
+    }
+}
+
+// Evaluation state for a cell during a recompute pass
+enum Visit {
+    InProgress,
+    Done,
+}
+
+#[derive(Clone)]
+pub struct SpreadsheetProps {
+    pub rows: u32,
+    pub cols: u8,
+}
+
+/// A grid of cells whose formulas recompute when their inputs change
+pub struct Spreadsheet {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: SpreadsheetProps,
+    // What the user typed into each cell
+    inputs: HashMap<CellRef, String>,
+    formulas: HashMap<CellRef, Result<Expr, CellError>>,
+    // Reverse edges: for each cell, the formulas that read it
+    dependents: HashMap<CellRef, HashSet<CellRef>>,
+    values: Signal<HashMap<CellRef, CellValue>>,
+    // Cells evaluated by the most recent edit
+    last_recomputed: Vec<CellRef>,
+}
+
+impl Component for Spreadsheet {
+    type Props = SpreadsheetProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let values = create_signal(&scope, HashMap::new());
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            props,
+            inputs: HashMap::new(),
+            formulas: HashMap::new(),
+            dependents: HashMap::new(),
+            values,
+            last_recomputed: Vec::new(),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut grid = Node::default();
+        grid.add_attribute("role".to_string(), "grid".to_string());
+
+        for row in 1..=self.props.rows {
+            let mut row_node = Node::default();
+            row_node.add_attribute("role".to_string(), "row".to_string());
+            for col in 0..self.props.cols {
+                let cell = CellRef { col, row };
+                let value = self.value(cell);
+                let mut cell_node = Node::default();
+                cell_node.add_attribute("role".to_string(), "gridcell".to_string());
+                cell_node.add_attribute("aria-label".to_string(), cell.to_string());
+                if let CellValue::Error(_) = value {
+                    cell_node.add_attribute("aria-invalid".to_string(), "true".to_string());
+                }
+                cell_node.add_child(Node::text(&value.to_string()));
+                row_node.add_child(cell_node);
+            }
+            grid.add_child(row_node);
+        }
+
+        Ok(vec![grid])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl Spreadsheet {
+    /// Set a cell from user input: a number, text, or a formula starting with `=`
+    pub fn set(&mut self, name: &str, input: &str) {
+        let cell = match CellRef::parse(name) {
+            Some(cell) => cell,
+            None => {
+                eprintln!("Invalid cell reference: {}", name);
+                return;
+            }
+        };
+
+        // Drop the edges from the cell's previous formula
+        if let Some(Ok(old)) = self.formulas.remove(&cell) {
+            let mut refs = HashSet::new();
+            old.references(&mut refs);
+            for source in refs {
+                if let Some(dependents) = self.dependents.get_mut(&source) {
+                    dependents.remove(&cell);
+                }
+            }
+        }
+
+        if let Some(formula) = input.strip_prefix('=') {
+            let parsed = Parser::parse(formula);
+            if let Ok(expr) = &parsed {
+                let mut refs = HashSet::new();
+                expr.references(&mut refs);
+                for source in refs {
+                    self.dependents.entry(source).or_default().insert(cell);
+                }
+            }
+            self.formulas.insert(cell, parsed);
+        }
+        self.inputs.insert(cell, input.to_string());
+
+        self.recompute_from(cell);
+    }
+
+    // Re-evaluate `changed` and everything downstream of it
+    fn recompute_from(&mut self, changed: CellRef) {
+        let mut affected = HashSet::new();
+        let mut queue = VecDeque::from([changed]);
+        while let Some(cell) = queue.pop_front() {
+            if affected.insert(cell) {
+                if let Some(dependents) = self.dependents.get(&cell) {
+                    queue.extend(dependents.iter().copied());
+                }
+            }
+        }
+
+        let mut values = self.values.get().clone();
+        let mut visits = HashMap::new();
+        let mut order = Vec::new();
+        let mut cells: Vec<CellRef> = affected.iter().copied().collect();
+        cells.sort();
+        for cell in cells {
+            self.evaluate(cell, &affected, &mut visits, &mut values, &mut order);
+        }
+
+        self.last_recomputed = order;
+        self.values
+            .set(values)
+            .unwrap_or_else(|e| eprintln!("Failed to update cell values: {}", e));
+    }
+
+    fn evaluate(
+        &self,
+        cell: CellRef,
+        affected: &HashSet<CellRef>,
+        visits: &mut HashMap<CellRef, Visit>,
+        values: &mut HashMap<CellRef, CellValue>,
+        order: &mut Vec<CellRef>,
+    ) -> CellValue {
+        // Cells outside the affected set keep their current value
+        if !affected.contains(&cell) {
+            return values.get(&cell).cloned().unwrap_or(CellValue::Empty);
+        }
+        match visits.get(&cell) {
+            Some(Visit::Done) => return values[&cell].clone(),
+            // Reaching a cell that is still being evaluated means we went round a loop
+            Some(Visit::InProgress) => return CellValue::Error(CellError::Circular),
+            None => {}
+        }
+        visits.insert(cell, Visit::InProgress);
+
+        let value = match self.formulas.get(&cell) {
+            Some(Ok(expr)) => {
+                let mut lookup = |r: CellRef| self.evaluate(r, affected, visits, values, order);
+                match eval_expr(expr, &mut lookup) {
+                    Ok(number) => CellValue::Number(number),
+                    Err(error) => CellValue::Error(error),
+                }
+            }
+            Some(Err(error)) => CellValue::Error(error.clone()),
+            None => match self.inputs.get(&cell).map(|input| input.trim()) {
+                None | Some("") => CellValue::Empty,
+                Some(input) => input
+                    .parse()
+                    .map(CellValue::Number)
+                    .unwrap_or_else(|_| CellValue::Text(input.to_string())),
+            },
+        };
+
+        visits.insert(cell, Visit::Done);
+        values.insert(cell, value.clone());
+        order.push(cell);
+        value
+    }
+
+    /// What was typed into a cell, before evaluation
+    pub fn input(&self, cell: CellRef) -> String {
+        self.inputs.get(&cell).cloned().unwrap_or_default()
+    }
+
+    pub fn value(&self, cell: CellRef) -> CellValue {
+        self.values
+            .get()
+            .get(&cell)
+            .cloned()
+            .unwrap_or(CellValue::Empty)
+    }
+
+    /// Value of a cell by name, e.g. `"B1"`
+    pub fn get(&self, name: &str) -> CellValue {
+        CellRef::parse(name).map_or(CellValue::Empty, |cell| self.value(cell))
+    }
+
+    pub fn last_recomputed(&self) -> &[CellRef] {
+        &self.last_recomputed
+    }
+}
+
+fn print_cells(sheet: &Spreadsheet, names: &[&str]) {
+    let cells: Vec<String> = names
+        .iter()
+        .map(|name| format!("{}={}", name, sheet.get(name)))
+        .collect();
+    println!("  {}", cells.join("  "));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sheet() -> Spreadsheet {
+        Spreadsheet::create(SpreadsheetProps { rows: 5, cols: 3 }, Context::new())
+    }
+
+    #[test]
+    fn test_dependent_cell_updates_when_input_changes() {
+        let mut sheet = sheet();
+        sheet.set("B1", "=A1*2");
+
+        sheet.set("A1", "21");
+
+        assert_eq!(sheet.get("B1"), CellValue::Number(42.0));
+    }
+
+    #[test]
+    fn test_circular_reference_is_flagged() {
+        let mut sheet = sheet();
+        sheet.set("A1", "=B1+1");
+        sheet.set("B1", "=A1*2");
+
+        assert_eq!(sheet.get("A1"), CellValue::Error(CellError::Circular));
+        assert_eq!(sheet.get("B1"), CellValue::Error(CellError::Circular));
+
+        // Breaking the loop recovers both cells
+        sheet.set("A1", "3");
+        assert_eq!(sheet.get("B1"), CellValue::Number(6.0));
+    }
+
+    #[test]
+    fn test_only_dependents_are_recomputed() {
+        let mut sheet = sheet();
+        sheet.set("A1", "1");
+        sheet.set("A2", "=A1+1");
+        sheet.set("C1", "=5*5");
+
+        sheet.set("A1", "10");
+
+        let mut recomputed = sheet.last_recomputed().to_vec();
+        recomputed.sort();
+        assert_eq!(
+            recomputed,
+            vec![CellRef::parse("A1").unwrap(), CellRef::parse("A2").unwrap()]
+        );
+    }
+
+    #[test]
+    fn test_formula_errors() {
+        let mut sheet = sheet();
+        sheet.set("A1", "=1/0");
+        sheet.set("A2", "hello");
+        sheet.set("A3", "=A2+1");
+        sheet.set("A4", "=(1+");
+
+        assert_eq!(sheet.get("A1"), CellValue::Error(CellError::DivideByZero));
+        assert_eq!(
+            sheet.get("A3"),
+            CellValue::Error(CellError::NotANumber(CellRef::parse("A2").unwrap()))
+        );
+        assert!(matches!(sheet.get("A4"), CellValue::Error(CellError::Parse(_))));
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Spreadsheet Example\n");
+
+    let mut sheet = Spreadsheet::create(SpreadsheetProps { rows: 3, cols: 3 }, Context::new());
+
+    sheet.set("A1", "10");
+    sheet.set("B1", "=A1*2");
+    sheet.set("C1", "=B1+A1");
+    sheet.set("A2", "=(C1-5)/5");
+    println!("Formula chain A1 -> B1 -> C1 -> A2:");
+    print_cells(&sheet, &["A1", "B1", "C1", "A2"]);
+
+    println!("\nChanging A1 to 25...");
+    sheet.set("A1", "25");
+    print_cells(&sheet, &["A1", "B1", "C1", "A2"]);
+    let recomputed: Vec<String> = sheet
+        .last_recomputed()
+        .iter()
+        .map(|cell| cell.to_string())
+        .collect();
+    println!("  recomputed: {}", recomputed.join(", "));
+
+    println!("\nPointing A1 at C1 creates a loop...");
+    sheet.set("A1", "=C1");
+    print_cells(&sheet, &["A1", "B1", "C1", "A2"]);
+
+    println!("\nLabels and bad formulas...");
+    sheet.set("B2", "total");
+    sheet.set("C2", "=B2*2");
+    sheet.set("B3", "=A3/0");
+    sheet.set("C3", "=2*(A1");
+    sheet.render()?;
+    print_cells(&sheet, &["B2", "C2", "B3", "C3"]);
+
+    println!("\nSpreadsheet example completed!");
+    Ok(())
+}
@@ -0,0 +1,318 @@
+//! Example demonstrating copying and pasting a range of spreadsheet cells
+//! A rectangular selection is copied to an internal clipboard as rows of values. Pasting
+//! writes each value at the same offset from the target anchor as it had from the top
+//! left of the copied range, clipping anything that would fall outside the grid.
+//! To run: cargo run --example spreadsheet_clipboard
+
+use std::collections::HashMap;
+use std::fmt;
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+// Shared with the spreadsheet example for its cell addresses
+#[allow(dead_code)]
+#[path = "spreadsheet.rs"]
+mod spreadsheet;
+
+use spreadsheet::CellRef;
+
+/// A rectangular block of cells, stored with `start` at the top left
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CellRange {
+    pub start: CellRef,
+    pub end: CellRef,
+}
+
+impl CellRange {
+    /// Range spanning two corners given in any order
+    pub fn new(a: CellRef, b: CellRef) -> Self {
+        Self {
+            start: CellRef {
+                col: a.col.min(b.col),
+                row: a.row.min(b.row),
+            },
+            end: CellRef {
+                col: a.col.max(b.col),
+                row: a.row.max(b.row),
+            },
+        }
+    }
+
+    /// Parse `A1:B2`
+    pub fn parse(range: &str) -> Option<Self> {
+        let (a, b) = range.split_once(':')?;
+        Some(Self::new(CellRef::parse(a)?, CellRef::parse(b)?))
+    }
+}
+
+impl fmt::Display for CellRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.start, self.end)
+    }
+}
+
+#[derive(Clone)]
+pub struct ClipboardSheetProps {
+    pub rows: u32,
+    pub cols: u8,
+}
+
+/// A grid of cells with a range selection and an internal clipboard
+pub struct ClipboardSheet {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: ClipboardSheetProps,
+    cells: Signal<HashMap<CellRef, String>>,
+    selection: Signal<Option<CellRange>>,
+    // Copied values, row by row; empty cells are kept as empty strings
+    clipboard: Signal<Vec<Vec<String>>>,
+}
+
+impl Component for ClipboardSheet {
+    type Props = ClipboardSheetProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            cells: create_signal(&scope, HashMap::new()),
+            selection: create_signal(&scope, None),
+            clipboard: create_signal(&scope, Vec::new()),
+            scope,
+            props,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let selection = *self.selection.get();
+        let cells = self.cells.get();
+
+        let mut grid = Node::default();
+        grid.add_attribute("role".to_string(), "grid".to_string());
+        grid.add_attribute("aria-multiselectable".to_string(), "true".to_string());
+
+        for row in 1..=self.props.rows {
+            let mut row_node = Node::default();
+            row_node.add_attribute("role".to_string(), "row".to_string());
+            for col in 0..self.props.cols {
+                let cell = CellRef { col, row };
+                let selected = selection.is_some_and(|range| {
+                    (range.start.col..=range.end.col).contains(&col)
+                        && (range.start.row..=range.end.row).contains(&row)
+                });
+
+                let mut cell_node = Node::default();
+                cell_node.add_attribute("role".to_string(), "gridcell".to_string());
+                cell_node.add_attribute("id".to_string(), cell.to_string());
+                cell_node.add_attribute("aria-selected".to_string(), selected.to_string());
+                cell_node.add_child(Node::text(cells.get(&cell).map_or("", |s| s.as_str())));
+                row_node.add_child(cell_node);
+            }
+            grid.add_child(row_node);
+        }
+
+        Ok(vec![grid])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl ClipboardSheet {
+    pub fn set(&self, cell: CellRef, text: &str) {
+        self.cells
+            .update(|cells| {
+                if text.is_empty() {
+                    cells.remove(&cell);
+                } else {
+                    cells.insert(cell, text.to_string());
+                }
+            })
+            .unwrap_or_else(|e| eprintln!("Failed to set cell: {}", e));
+    }
+
+    pub fn text(&self, cell: CellRef) -> String {
+        self.cells.get().get(&cell).cloned().unwrap_or_default()
+    }
+
+    pub fn select(&self, range: CellRange) {
+        self.selection
+            .set(Some(range))
+            .unwrap_or_else(|e| eprintln!("Failed to select range: {}", e));
+    }
+
+    /// Copy the selected range's values; returns false with nothing selected
+    pub fn copy(&self) -> bool {
+        let Some(range) = *self.selection.get() else {
+            return false;
+        };
+        let values: Vec<Vec<String>> = (range.start.row..=range.end.row)
+            .map(|row| {
+                (range.start.col..=range.end.col)
+                    .map(|col| self.text(CellRef { col, row }))
+                    .collect()
+            })
+            .collect();
+
+        self.clipboard
+            .set(values)
+            .unwrap_or_else(|e| eprintln!("Failed to copy: {}", e));
+        true
+    }
+
+    /// Paste the clipboard with its top-left value at `anchor`
+    pub fn paste(&self, anchor: CellRef) {
+        let clipboard = self.clipboard.get().clone();
+        let (rows, cols) = (self.props.rows, self.props.cols as u32);
+        // Nothing fits in an empty grid
+        if rows == 0 || cols == 0 {
+            return;
+        }
+
+        self.cells
+            .update(|cells| {
+                for (row_offset, values) in clipboard.iter().enumerate() {
+                    for (col_offset, value) in values.iter().enumerate() {
+                        let row = anchor.row + row_offset as u32;
+                        let col = anchor.col as u32 + col_offset as u32;
+                        if row > rows || col >= cols {
+                            continue;
+                        }
+                        let cell = CellRef {
+                            col: col as u8,
+                            row,
+                        };
+                        if value.is_empty() {
+                            cells.remove(&cell);
+                        } else {
+                            cells.insert(cell, value.clone());
+                        }
+                    }
+                }
+            })
+            .unwrap_or_else(|e| eprintln!("Failed to paste: {}", e));
+
+        // The pasted block becomes the new selection
+        let height = clipboard.len() as u32;
+        let width = clipboard.first().map_or(0, |row| row.len()) as u32;
+        if height > 0 && width > 0 {
+            let end = CellRef {
+                col: (anchor.col as u32 + width - 1).min(cols - 1) as u8,
+                row: (anchor.row + height - 1).min(rows),
+            };
+            self.select(CellRange::new(anchor, end));
+        }
+    }
+
+    pub fn selection(&self) -> Option<CellRange> {
+        *self.selection.get()
+    }
+}
+
+fn cell(name: &str) -> CellRef {
+    CellRef::parse(name).expect("valid cell name")
+}
+
+fn sheet() -> ClipboardSheet {
+    let sheet = ClipboardSheet::create(ClipboardSheetProps { rows: 5, cols: 5 }, Context::new());
+    for (name, text) in [("A1", "Q1"), ("B1", "Q2"), ("A2", "120"), ("B2", "145")] {
+        sheet.set(cell(name), text);
+    }
+    sheet
+}
+
+fn print_sheet(sheet: &ClipboardSheet) {
+    for row in 1..=sheet.props.rows {
+        let values: Vec<String> = (0..sheet.props.cols)
+            .map(|col| format!("{:<5}", sheet.text(CellRef { col, row })))
+            .collect();
+        println!("  {} | {}", row, values.join(" "));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paste_places_values_at_offsets() {
+        let sheet = sheet();
+        sheet.select(CellRange::parse("A1:B2").unwrap());
+        assert!(sheet.copy());
+
+        sheet.paste(cell("C3"));
+
+        assert_eq!(sheet.text(cell("C3")), sheet.text(cell("A1")));
+        assert_eq!(sheet.text(cell("D3")), sheet.text(cell("B1")));
+        assert_eq!(sheet.text(cell("C4")), sheet.text(cell("A2")));
+        assert_eq!(sheet.text(cell("D4")), sheet.text(cell("B2")));
+        assert_eq!(sheet.selection(), CellRange::parse("C3:D4"));
+    }
+
+    #[test]
+    fn test_paste_near_edge_is_clipped() {
+        let sheet = sheet();
+        sheet.select(CellRange::parse("B2:A1").unwrap());
+        sheet.copy();
+
+        sheet.paste(cell("E5"));
+
+        assert_eq!(sheet.text(cell("E5")), "Q1");
+        assert_eq!(sheet.selection(), CellRange::parse("E5:E5"));
+    }
+
+    #[test]
+    fn test_paste_into_empty_grid_does_nothing() {
+        let sheet =
+            ClipboardSheet::create(ClipboardSheetProps { rows: 0, cols: 0 }, Context::new());
+        sheet.set(cell("A1"), "Q1");
+        sheet.select(CellRange::parse("A1:A1").unwrap());
+        sheet.copy();
+
+        sheet.paste(cell("B2"));
+
+        assert_eq!(sheet.text(cell("B2")), "");
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Spreadsheet Clipboard Example\n");
+
+    let sheet = sheet();
+    println!("Before:");
+    print_sheet(&sheet);
+
+    let range = CellRange::parse("A1:B2").expect("valid range");
+    sheet.select(range);
+    sheet.copy();
+    println!("\nCopied {}, pasting at C3...", range);
+    sheet.paste(cell("C3"));
+    sheet.render()?;
+    print_sheet(&sheet);
+    if let Some(selection) = sheet.selection() {
+        println!("  selection is now {}", selection);
+    }
+
+    println!("\nSpreadsheet Clipboard example completed!");
+    Ok(())
+}
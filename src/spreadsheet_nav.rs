@@ -0,0 +1,348 @@
+//! Example demonstrating keyboard navigation in a spreadsheet grid
+//! Arrow keys move the active cell, Tab moves right and wraps to the start of the next
+//! row, and typing a character starts editing the active cell. Enter or Tab commits the
+//! edit and Escape discards it. Edits are committed to the `Spreadsheet` from the
+//! spreadsheet example, shared through a `#[path]` module, so a committed formula is
+//! evaluated and the grid shows its value.
+//! To run: cargo run --example spreadsheet_nav
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+use winit::event::VirtualKeyCode;
+
+// Shared with the spreadsheet example; not every item is used here
+#[allow(dead_code)]
+#[path = "spreadsheet.rs"]
+mod spreadsheet;
+
+use spreadsheet::{CellRef, CellValue, Spreadsheet, SpreadsheetProps};
+
+/// What keystrokes currently do
+#[derive(Debug, Clone, PartialEq)]
+pub enum Mode {
+    Navigating,
+    // Text typed so far, not yet written to the cell
+    Editing(String),
+}
+
+#[derive(Clone)]
+pub struct SheetNavProps {
+    pub rows: u32,
+    pub cols: u8,
+}
+
+/// A grid with an active cell driven from the keyboard
+pub struct SheetNav {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: SheetNavProps,
+    sheet: Spreadsheet,
+    active: Signal<CellRef>,
+    mode: Signal<Mode>,
+}
+
+impl Component for SheetNav {
+    type Props = SheetNavProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+
+        let sheet = Spreadsheet::create(
+            SpreadsheetProps {
+                rows: props.rows,
+                cols: props.cols,
+            },
+            context.clone(),
+        );
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            sheet,
+            active: create_signal(&scope, CellRef { col: 0, row: 1 }),
+            mode: create_signal(&scope, Mode::Navigating),
+            scope,
+            props,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.sheet.update(SpreadsheetProps {
+            rows: props.rows,
+            cols: props.cols,
+        })?;
+        self.props = props;
+        // The grid may have shrunk around the active cell
+        let active = self.active();
+        self.move_to(active.col as i64, active.row as i64);
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let active = self.active();
+        let mode = self.mode.get().clone();
+
+        let mut grid = Node::default();
+        grid.add_attribute("role".to_string(), "grid".to_string());
+        grid.add_attribute("aria-activedescendant".to_string(), active.to_string());
+
+        for row in 1..=self.props.rows {
+            let mut row_node = Node::default();
+            row_node.add_attribute("role".to_string(), "row".to_string());
+            for col in 0..self.props.cols {
+                let cell = CellRef { col, row };
+                let mut cell_node = Node::default();
+                cell_node.add_attribute("role".to_string(), "gridcell".to_string());
+                cell_node.add_attribute("id".to_string(), cell.to_string());
+
+                let text = match &mode {
+                    Mode::Editing(buffer) if cell == active => {
+                        cell_node.add_attribute("contenteditable".to_string(), "true".to_string());
+                        buffer.clone()
+                    }
+                    _ => self.sheet.value(cell).to_string(),
+                };
+                if cell == active {
+                    cell_node.add_attribute("aria-selected".to_string(), "true".to_string());
+                }
+                cell_node.add_child(Node::text(&text));
+                row_node.add_child(cell_node);
+            }
+            grid.add_child(row_node);
+        }
+
+        Ok(vec![grid])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl SheetNav {
+    /// Handle a navigation or editing key; returns whether it was used
+    pub fn handle_key(&mut self, key: VirtualKeyCode) -> bool {
+        let active = self.active();
+        let (col, row) = (active.col as i64, active.row as i64);
+
+        match key {
+            VirtualKeyCode::Up => self.commit_and_move(col, row - 1),
+            VirtualKeyCode::Down => self.commit_and_move(col, row + 1),
+            VirtualKeyCode::Left => self.commit_and_move(col - 1, row),
+            VirtualKeyCode::Right => self.commit_and_move(col + 1, row),
+            VirtualKeyCode::Tab => {
+                // Past the last column, continue at the start of the next row
+                if col + 1 < self.props.cols as i64 {
+                    self.commit_and_move(col + 1, row)
+                } else if row < self.props.rows as i64 {
+                    self.commit_and_move(0, row + 1)
+                } else {
+                    self.commit_and_move(col, row)
+                }
+            }
+            VirtualKeyCode::Return | VirtualKeyCode::NumpadEnter => {
+                if self.is_editing() {
+                    self.commit_and_move(col, row + 1)
+                } else {
+                    // Enter on a cell starts editing its current contents
+                    self.set_mode(Mode::Editing(self.text(active)));
+                }
+            }
+            VirtualKeyCode::Escape if self.is_editing() => self.set_mode(Mode::Navigating),
+            VirtualKeyCode::Back if self.is_editing() => {
+                self.mode
+                    .update(|mode| {
+                        if let Mode::Editing(buffer) = mode {
+                            buffer.pop();
+                        }
+                    })
+                    .unwrap_or_else(|e| eprintln!("Failed to edit cell: {}", e));
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Handle a typed character, starting an edit if none is in progress
+    pub fn type_char(&self, ch: char) {
+        if ch.is_control() {
+            return;
+        }
+        let mode = self.mode.get().clone();
+        let next = match mode {
+            Mode::Editing(mut buffer) => {
+                buffer.push(ch);
+                buffer
+            }
+            // Typing over a cell replaces its contents, as in most spreadsheets
+            Mode::Navigating => ch.to_string(),
+        };
+        self.set_mode(Mode::Editing(next));
+    }
+
+    fn commit_and_move(&mut self, col: i64, row: i64) {
+        let mode = self.mode.get().clone();
+        if let Mode::Editing(buffer) = mode {
+            self.sheet.set(&self.active().to_string(), &buffer);
+            self.set_mode(Mode::Navigating);
+        }
+        self.move_to(col, row);
+    }
+
+    // Move the active cell, clamped to the grid
+    fn move_to(&self, col: i64, row: i64) {
+        let max_col = self.props.cols.saturating_sub(1) as i64;
+        let max_row = self.props.rows.max(1) as i64;
+        let cell = CellRef {
+            col: col.clamp(0, max_col) as u8,
+            row: row.clamp(1, max_row) as u32,
+        };
+        self.active
+            .set(cell)
+            .unwrap_or_else(|e| eprintln!("Failed to move active cell: {}", e));
+    }
+
+    fn set_mode(&self, mode: Mode) {
+        self.mode
+            .set(mode)
+            .unwrap_or_else(|e| eprintln!("Failed to set mode: {}", e));
+    }
+
+    pub fn active(&self) -> CellRef {
+        *self.active.get()
+    }
+
+    pub fn is_editing(&self) -> bool {
+        matches!(*self.mode.get(), Mode::Editing(_))
+    }
+
+    /// What was typed into a cell
+    pub fn text(&self, cell: CellRef) -> String {
+        self.sheet.input(cell)
+    }
+
+    /// The cell's evaluated value
+    pub fn value(&self, cell: CellRef) -> CellValue {
+        self.sheet.value(cell)
+    }
+}
+
+fn sheet() -> SheetNav {
+    SheetNav::create(SheetNavProps { rows: 3, cols: 3 }, Context::new())
+}
+
+fn type_text(sheet: &SheetNav, text: &str) {
+    for ch in text.chars() {
+        sheet.type_char(ch);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_down_then_right_from_a1_lands_on_b2() {
+        let mut sheet = sheet();
+
+        sheet.handle_key(VirtualKeyCode::Down);
+        sheet.handle_key(VirtualKeyCode::Right);
+
+        assert_eq!(sheet.active(), CellRef { col: 1, row: 2 });
+        assert_eq!(sheet.active().to_string(), "B2");
+    }
+
+    #[test]
+    fn test_tab_wraps_to_next_row() {
+        let mut sheet = sheet();
+        for _ in 0..3 {
+            sheet.handle_key(VirtualKeyCode::Tab);
+        }
+
+        assert_eq!(sheet.active().to_string(), "A2");
+    }
+
+    #[test]
+    fn test_typing_edits_and_escape_discards() {
+        let mut sheet = sheet();
+        type_text(&sheet, "42");
+        assert!(sheet.is_editing());
+
+        sheet.handle_key(VirtualKeyCode::Tab);
+        type_text(&sheet, "oops");
+        sheet.handle_key(VirtualKeyCode::Escape);
+
+        assert_eq!(sheet.text(CellRef { col: 0, row: 1 }), "42");
+        assert_eq!(sheet.text(CellRef { col: 1, row: 1 }), "");
+        assert!(!sheet.is_editing());
+    }
+
+    #[test]
+    fn test_committed_formula_is_evaluated() {
+        let mut sheet = sheet();
+        type_text(&sheet, "21");
+        sheet.handle_key(VirtualKeyCode::Tab);
+
+        type_text(&sheet, "=A1*2");
+        sheet.handle_key(VirtualKeyCode::Return);
+
+        let b1 = CellRef { col: 1, row: 1 };
+        assert_eq!(sheet.text(b1), "=A1*2");
+        assert_eq!(sheet.value(b1), CellValue::Number(42.0));
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Spreadsheet Navigation Example\n");
+
+    let mut sheet = sheet();
+    println!("Active cell: {}", sheet.active());
+
+    let keys = [
+        VirtualKeyCode::Right,
+        VirtualKeyCode::Down,
+        VirtualKeyCode::Down,
+        VirtualKeyCode::Down,
+        VirtualKeyCode::Left,
+        VirtualKeyCode::Up,
+    ];
+    for key in keys {
+        sheet.handle_key(key);
+        println!("  {:?} -> {}", key, sheet.active());
+    }
+
+    println!("\nFilling the first row with Tab...");
+    sheet.handle_key(VirtualKeyCode::Up);
+    for value in ["Pens", "3", "1.5", "=B1*C1"] {
+        type_text(&sheet, value);
+        sheet.handle_key(VirtualKeyCode::Tab);
+    }
+    sheet.render()?;
+    for row in 1..=2 {
+        let values: Vec<String> = (0..3)
+            .map(|col| sheet.value(CellRef { col, row }).to_string())
+            .collect();
+        println!("  row {}: {:?}", row, values);
+    }
+    println!("  active cell: {}", sheet.active());
+
+    println!("\nEditing then pressing Escape...");
+    type_text(&sheet, "draft");
+    println!("  editing: {}", sheet.is_editing());
+    sheet.handle_key(VirtualKeyCode::Escape);
+    println!("  after Escape, {} holds {:?}", sheet.active(), sheet.text(sheet.active()));
+
+    println!("\nSpreadsheet Navigation example completed!");
+    Ok(())
+}
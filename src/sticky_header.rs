@@ -0,0 +1,213 @@
+//! Example demonstrating sticky section headers driven by a scroll offset signal
+//! The header of the section currently under the top edge stays pinned. As the next
+//! section's header scrolls up it pushes the pinned header out, and at the boundary the
+//! pinned header swaps to the next section.
+//! To run: cargo run --example sticky_header
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+#[derive(Debug, Clone)]
+pub struct Section {
+    pub title: String,
+    // Height of the section's content, excluding its header
+    pub content_height: f32,
+}
+
+/// The pinned header and how far it is pushed up by the next one
+#[derive(Debug, Clone, PartialEq)]
+pub struct PinnedHeader {
+    pub section: usize,
+    pub title: String,
+    // Negative when the next header is pushing this one out of view
+    pub offset: f32,
+}
+
+#[derive(Clone)]
+pub struct StickyListProps {
+    pub sections: Vec<Section>,
+    pub header_height: f32,
+}
+
+/// A scrolling list whose section headers stick to the top
+pub struct StickyList {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: StickyListProps,
+    scroll_offset: Signal<f32>,
+}
+
+impl Component for StickyList {
+    type Props = StickyListProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let scroll_offset = create_signal(&scope, 0.0);
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            props,
+            scroll_offset,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut list = Node::default();
+        list.add_attribute("class".to_string(), "sticky-list".to_string());
+        list.add_attribute("scroll-top".to_string(), self.scroll_offset.get().to_string());
+
+        if let Some(pinned) = self.pinned_header() {
+            let mut header = Node::default();
+            header.add_attribute("class".to_string(), "sticky-header".to_string());
+            header.add_attribute("position".to_string(), "sticky".to_string());
+            header.add_attribute("data-section".to_string(), pinned.section.to_string());
+            header.add_attribute("translate-y".to_string(), pinned.offset.to_string());
+            header.add_child(Node::text(&pinned.title));
+            list.add_child(header);
+        }
+
+        for (section, top) in self.props.sections.iter().zip(self.section_tops()) {
+            let mut node = Node::default();
+            node.add_attribute("class".to_string(), "section".to_string());
+            node.add_attribute("top".to_string(), top.to_string());
+            node.add_child(Node::text(&section.title));
+            list.add_child(node);
+        }
+
+        Ok(vec![list])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl StickyList {
+    pub fn scroll_to(&self, offset: f32) {
+        self.scroll_offset.set(offset.max(0.0)).unwrap_or_else(|e| {
+            eprintln!("Failed to scroll: {}", e);
+        });
+    }
+
+    // Top edge of each section (its header) in content coordinates
+    fn section_tops(&self) -> Vec<f32> {
+        let mut top = 0.0;
+        self.props
+            .sections
+            .iter()
+            .map(|section| {
+                let current = top;
+                top += self.props.header_height + section.content_height;
+                current
+            })
+            .collect()
+    }
+
+    /// The header pinned at the current scroll offset
+    pub fn pinned_header(&self) -> Option<PinnedHeader> {
+        let offset = *self.scroll_offset.get();
+        let tops = self.section_tops();
+
+        // The last section whose top has reached the top edge
+        let index = tops.iter().rposition(|&top| top <= offset)?;
+
+        // Once the next header touches the pinned one, it starts pushing it up
+        let push = match tops.get(index + 1) {
+            Some(&next_top) => (next_top - offset - self.props.header_height).min(0.0),
+            None => 0.0,
+        };
+
+        Some(PinnedHeader {
+            section: index,
+            title: self.props.sections[index].title.clone(),
+            offset: push,
+        })
+    }
+}
+
+fn contacts() -> StickyList {
+    let sections = ["A", "B", "C"]
+        .iter()
+        .map(|letter| Section {
+            title: letter.to_string(),
+            content_height: 200.0,
+        })
+        .collect();
+
+    StickyList::create(
+        StickyListProps {
+            sections,
+            header_height: 40.0,
+        },
+        Context::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_header_swaps_at_section_boundary() {
+        let list = contacts();
+
+        // Section B starts at 40 + 200 = 240
+        list.scroll_to(239.0);
+        assert_eq!(list.pinned_header().unwrap().title, "A");
+
+        list.scroll_to(240.0);
+        let pinned = list.pinned_header().unwrap();
+        assert_eq!(pinned.title, "B");
+        assert_eq!(pinned.offset, 0.0);
+    }
+
+    #[test]
+    fn test_next_header_pushes_pinned_one_up() {
+        let list = contacts();
+
+        // B's header is 10px below the top, overlapping A's 40px header by 30px
+        list.scroll_to(230.0);
+
+        let pinned = list.pinned_header().unwrap();
+        assert_eq!(pinned.title, "A");
+        assert_eq!(pinned.offset, -30.0);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Sticky Header Example\n");
+
+    let list = contacts();
+
+    for offset in [0.0, 150.0, 220.0, 240.0, 400.0, 470.0, 600.0] {
+        list.scroll_to(offset);
+        list.render()?;
+        if let Some(pinned) = list.pinned_header() {
+            println!(
+                "scroll {:5.0}: pinned '{}' (offset {})",
+                offset, pinned.title, pinned.offset
+            );
+        }
+    }
+
+    println!("\nSticky Header example completed!");
+    Ok(())
+}
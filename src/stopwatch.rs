@@ -0,0 +1,298 @@
+//! Example demonstrating a reactive stopwatch with lap times
+//! Elapsed time lives in a `Signal<Duration>` that ticks while running, and laps are
+//! recorded into a `Signal<Vec<Duration>>`. Time comes from a `Clock` so tests can
+//! drive a mock clock instead of sleeping.
+//! To run: cargo run --example stopwatch
+
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+/// Source of the current time
+pub trait Clock: Send + Sync {
+    fn now(&self) -> Instant;
+}
+
+/// The real clock
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+#[derive(Clone)]
+pub struct StopwatchProps {
+    pub clock: Arc<dyn Clock>,
+}
+
+/// A stopwatch with start/stop/lap/reset
+pub struct Stopwatch {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: StopwatchProps,
+    elapsed: Signal<Duration>,
+    laps: Signal<Vec<Duration>>,
+    // When the current run started and how much time had accumulated before it
+    started_at: Option<Instant>,
+    accumulated: Duration,
+}
+
+impl Component for Stopwatch {
+    type Props = StopwatchProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let elapsed = create_signal(&scope, Duration::ZERO);
+        let laps = create_signal(&scope, Vec::new());
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            props,
+            elapsed,
+            laps,
+            started_at: None,
+            accumulated: Duration::ZERO,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut display = Node::default();
+        display.add_attribute("role".to_string(), "timer".to_string());
+        display.add_child(Node::text(&format_duration(self.elapsed())));
+
+        let mut laps = Node::default();
+        laps.add_attribute("role".to_string(), "list".to_string());
+        for (index, lap) in self.laps().iter().enumerate() {
+            laps.add_child(Node::text(&format!(
+                "Lap {}: {}",
+                index + 1,
+                format_duration(*lap)
+            )));
+        }
+
+        Ok(vec![display, laps])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl Stopwatch {
+    pub fn is_running(&self) -> bool {
+        self.started_at.is_some()
+    }
+
+    pub fn start(&mut self) {
+        if !self.is_running() {
+            self.started_at = Some(self.props.clock.now());
+        }
+    }
+
+    pub fn stop(&mut self) {
+        self.tick();
+        if let Some(started_at) = self.started_at.take() {
+            self.accumulated += self.props.clock.now() - started_at;
+        }
+    }
+
+    /// Refresh the elapsed signal from the clock; call once per frame while running
+    pub fn tick(&self) {
+        if let Some(started_at) = self.started_at {
+            let elapsed = self.accumulated + (self.props.clock.now() - started_at);
+            self.elapsed
+                .set(elapsed)
+                .unwrap_or_else(|e| eprintln!("Failed to update elapsed time: {}", e));
+        }
+    }
+
+    /// Record a lap: the time since the previous lap, or since the start
+    pub fn lap(&self) {
+        // Laps only make sense while the stopwatch is running
+        if !self.is_running() {
+            return;
+        }
+        self.tick();
+
+        let elapsed = self.elapsed();
+        self.laps
+            .update(|laps| {
+                let previous: Duration = laps.iter().sum();
+                laps.push(elapsed - previous);
+            })
+            .unwrap_or_else(|e| eprintln!("Failed to record lap: {}", e));
+    }
+
+    pub fn reset(&mut self) {
+        self.started_at = None;
+        self.accumulated = Duration::ZERO;
+        self.elapsed
+            .set(Duration::ZERO)
+            .unwrap_or_else(|e| eprintln!("Failed to reset elapsed time: {}", e));
+        self.laps
+            .set(Vec::new())
+            .unwrap_or_else(|e| eprintln!("Failed to clear laps: {}", e));
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        *self.elapsed.get()
+    }
+
+    pub fn laps(&self) -> Vec<Duration> {
+        self.laps.get().clone()
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    let millis = duration.as_millis();
+    format!(
+        "{:02}:{:02}.{:03}",
+        millis / 60_000,
+        millis / 1_000 % 60,
+        millis % 1_000
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    // A clock that only moves when told to
+    struct MockClock {
+        now: Mutex<Instant>,
+    }
+
+    impl MockClock {
+        fn new() -> Self {
+            Self {
+                now: Mutex::new(Instant::now()),
+            }
+        }
+
+        fn advance(&self, by: Duration) {
+            *self.now.lock().unwrap() += by;
+        }
+    }
+
+    impl Clock for MockClock {
+        fn now(&self) -> Instant {
+            *self.now.lock().unwrap()
+        }
+    }
+
+    fn stopwatch_with_mock() -> (Stopwatch, Arc<MockClock>) {
+        let clock = Arc::new(MockClock::new());
+        let stopwatch = Stopwatch::create(
+            StopwatchProps {
+                clock: clock.clone(),
+            },
+            Context::new(),
+        );
+        (stopwatch, clock)
+    }
+
+    #[test]
+    fn test_lap_records_time_since_previous_lap() {
+        let (mut stopwatch, clock) = stopwatch_with_mock();
+
+        stopwatch.start();
+        clock.advance(Duration::from_millis(1_500));
+        stopwatch.lap();
+        clock.advance(Duration::from_millis(700));
+        stopwatch.lap();
+
+        assert_eq!(
+            stopwatch.laps(),
+            vec![Duration::from_millis(1_500), Duration::from_millis(700)]
+        );
+        assert_eq!(stopwatch.elapsed(), Duration::from_millis(2_200));
+    }
+
+    #[test]
+    fn test_lap_while_stopped_is_ignored() {
+        let (mut stopwatch, clock) = stopwatch_with_mock();
+        stopwatch.start();
+        clock.advance(Duration::from_secs(1));
+        stopwatch.stop();
+
+        stopwatch.lap();
+
+        assert!(stopwatch.laps().is_empty());
+    }
+
+    #[test]
+    fn test_stopped_time_is_not_counted() {
+        let (mut stopwatch, clock) = stopwatch_with_mock();
+        stopwatch.start();
+        clock.advance(Duration::from_secs(1));
+        stopwatch.stop();
+        clock.advance(Duration::from_secs(5));
+        stopwatch.start();
+        clock.advance(Duration::from_secs(2));
+        stopwatch.tick();
+
+        assert_eq!(stopwatch.elapsed(), Duration::from_secs(3));
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Stopwatch Example\n");
+
+    let mut stopwatch = Stopwatch::create(
+        StopwatchProps {
+            clock: Arc::new(SystemClock),
+        },
+        Context::new(),
+    );
+
+    stopwatch.lap();
+    println!("Lap while stopped ignored: {} laps", stopwatch.laps().len());
+
+    stopwatch.start();
+    for _ in 0..2 {
+        for _ in 0..3 {
+            thread::sleep(Duration::from_millis(40));
+            stopwatch.tick();
+            println!("  {}", format_duration(stopwatch.elapsed()));
+        }
+        stopwatch.lap();
+        println!("Lap recorded");
+    }
+    stopwatch.stop();
+    stopwatch.render()?;
+
+    for (index, lap) in stopwatch.laps().iter().enumerate() {
+        println!("Lap {}: {}", index + 1, format_duration(*lap));
+    }
+    println!("Total: {}", format_duration(stopwatch.elapsed()));
+
+    stopwatch.reset();
+    println!("\nAfter reset: {}", format_duration(stopwatch.elapsed()));
+
+    println!("\nStopwatch example completed!");
+    Ok(())
+}
@@ -0,0 +1,248 @@
+//! Example demonstrating a strict mode that double-invokes to surface side effects
+//! Like React's StrictMode, `StrictMode` in development creates each component twice,
+//! throwing the first away, and runs each effect's setup, cleanup and setup again.
+//! Pure code can't tell the difference. Impure code can, and so can strict mode: it
+//! snapshots the state a side effect would touch around each step and flags anything
+//! left changed. With strict mode off everything runs once, unchecked.
+//! To run: cargo run --example strict_mode
+
+use std::cell::{Cell, RefCell};
+use std::fmt::Debug;
+use std::rc::Rc;
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+
+/// Teardown returned by an effect's setup
+pub type Cleanup = Box<dyn FnOnce()>;
+
+/// Double-invokes creation and effects when enabled, recording what looks impure
+pub struct StrictMode {
+    enabled: bool,
+    violations: RefCell<Vec<String>>,
+}
+
+impl StrictMode {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            violations: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Create a component; in strict mode a throwaway instance is created first, and
+    /// any change it makes to `observe`'s snapshot is flagged
+    pub fn create<C, T>(&self, name: &str, props: C::Props, observe: impl Fn() -> T) -> C
+    where
+        C: Component,
+        C::Props: Clone,
+        T: PartialEq + Debug,
+    {
+        if self.enabled {
+            let before = observe();
+            drop(C::create(props.clone(), Context::new()));
+            let after = observe();
+            if after != before {
+                self.flag(format!(
+                    "{}: creating it changed {:?} to {:?}; create should be pure",
+                    name, before, after
+                ));
+            }
+        }
+        C::create(props, Context::new())
+    }
+
+    /// Run an effect; in strict mode it's set up, cleaned up and set up again, and
+    /// a cleanup that doesn't restore `observe`'s snapshot is flagged
+    pub fn run_effect<T>(
+        &self,
+        name: &str,
+        mut setup: impl FnMut() -> Cleanup,
+        observe: impl Fn() -> T,
+    ) -> Cleanup
+    where
+        T: PartialEq + Debug,
+    {
+        if self.enabled {
+            let before = observe();
+            setup()();
+            let after_cleanup = observe();
+            if after_cleanup != before {
+                self.flag(format!(
+                    "{}: after setup and cleanup {:?} became {:?}; cleanup should undo setup",
+                    name, before, after_cleanup
+                ));
+            }
+        }
+        setup()
+    }
+
+    pub fn violations(&self) -> Vec<String> {
+        self.violations.borrow().clone()
+    }
+
+    fn flag(&self, violation: String) {
+        eprintln!("[strict mode] {}", violation);
+        self.violations.borrow_mut().push(violation);
+    }
+}
+
+/// Channels with live subscribers, standing in for any external resource
+pub type Subscriptions = Rc<RefCell<Vec<String>>>;
+
+/// Subscribe on setup and unsubscribe on cleanup: survives double-invocation
+fn subscribe(subscriptions: &Subscriptions, channel: &str) -> impl FnMut() -> Cleanup {
+    let (subscriptions, channel) = (subscriptions.clone(), channel.to_string());
+    move || {
+        subscriptions.borrow_mut().push(channel.clone());
+        let (subscriptions, channel) = (subscriptions.clone(), channel.clone());
+        Box::new(move || {
+            let mut subscriptions = subscriptions.borrow_mut();
+            if let Some(index) = subscriptions.iter().position(|c| *c == channel) {
+                subscriptions.remove(index);
+            }
+        }) as Cleanup
+    }
+}
+
+/// Subscribe but forget to clean up: leaks a subscription per invocation
+fn subscribe_leaky(subscriptions: &Subscriptions, channel: &str) -> impl FnMut() -> Cleanup {
+    let (subscriptions, channel) = (subscriptions.clone(), channel.to_string());
+    move || {
+        subscriptions.borrow_mut().push(channel.clone());
+        Box::new(|| {}) as Cleanup
+    }
+}
+
+#[derive(Clone)]
+pub struct BadgeProps {
+    pub label: String,
+    // Bumped on creation, which makes creation impure
+    pub created: Rc<Cell<usize>>,
+}
+
+/// A badge that (wrongly) counts its instances as a side effect of creation
+pub struct Badge {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    props: BadgeProps,
+}
+
+impl Component for Badge {
+    type Props = BadgeProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        props.created.set(props.created.get() + 1);
+        Self {
+            id: ComponentId::new(),
+            context,
+            props,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        Ok(vec![Node::text(&self.props.label)])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strict_mode_flags_side_effect_counter() {
+        let strict = StrictMode::new(true);
+        let count = Rc::new(Cell::new(0));
+
+        let counter = count.clone();
+        let _cleanup = strict.run_effect(
+            "counter",
+            move || {
+                counter.set(counter.get() + 1);
+                Box::new(|| {}) as Cleanup
+            },
+            || count.get(),
+        );
+
+        assert_ne!(count.get(), 1);
+        assert_eq!(strict.violations().len(), 1);
+    }
+
+    #[test]
+    fn test_balanced_effect_passes() {
+        let strict = StrictMode::new(true);
+        let subscriptions = Subscriptions::default();
+
+        let cleanup = strict.run_effect(
+            "prices",
+            subscribe(&subscriptions, "prices"),
+            || subscriptions.borrow().len(),
+        );
+
+        assert!(strict.violations().is_empty());
+        assert_eq!(subscriptions.borrow().len(), 1);
+        cleanup();
+        assert!(subscriptions.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_disabled_runs_once_unchecked() {
+        let strict = StrictMode::new(false);
+        let subscriptions = Subscriptions::default();
+
+        let _cleanup = strict.run_effect(
+            "news",
+            subscribe_leaky(&subscriptions, "news"),
+            || subscriptions.borrow().len(),
+        );
+
+        assert_eq!(subscriptions.borrow().len(), 1);
+        assert!(strict.violations().is_empty());
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Strict Mode Example\n");
+
+    let strict = StrictMode::new(true);
+    let subscriptions = Subscriptions::default();
+    let created = Rc::new(Cell::new(0));
+
+    let badge: Badge = strict.create(
+        "Badge",
+        BadgeProps {
+            label: "New".to_string(),
+            created: created.clone(),
+        },
+        || created.get(),
+    );
+    badge.render()?;
+
+    let observe = || subscriptions.borrow().len();
+    let prices = strict.run_effect("prices", subscribe(&subscriptions, "prices"), observe);
+    let _news = strict.run_effect("news", subscribe_leaky(&subscriptions, "news"), observe);
+
+    println!("\nSubscriptions: {:?}", subscriptions.borrow());
+    println!("Violations found: {}", strict.violations().len());
+    prices();
+
+    println!("\nStrict Mode example completed!");
+    Ok(())
+}
@@ -0,0 +1,262 @@
+//! Example demonstrating swipe-to-dismiss list items
+//! Swiping an item horizontally past a threshold slides it out and then removes it from
+//! the list signal. Shorter swipes snap the item back into place.
+//! To run: cargo run --example swipe_dismiss
+
+use std::time::Duration;
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item {
+    pub id: u32,
+    pub text: String,
+}
+
+/// Swipe state of the item being interacted with
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SwipeState {
+    Dragging { offset: f32 },
+    // Sliding out towards `direction`; removed once progress reaches 1.0
+    Dismissing { direction: f32, progress: f32 },
+}
+
+#[derive(Clone)]
+pub struct SwipeListProps {
+    pub item_width: f32,
+    // Fraction of the item width that must be swiped to dismiss
+    pub threshold: f32,
+    pub animation: Duration,
+}
+
+/// A list whose items can be swiped away
+pub struct SwipeList {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: SwipeListProps,
+    items: Signal<Vec<Item>>,
+    // Swipe state for the item currently being interacted with
+    active: Option<(u32, SwipeState)>,
+}
+
+impl Component for SwipeList {
+    type Props = SwipeListProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let items = create_signal(&scope, Vec::new());
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            props,
+            items,
+            active: None,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut list = Node::default();
+        list.add_attribute("role".to_string(), "list".to_string());
+
+        for item in self.items.get().iter() {
+            let mut row = Node::default();
+            row.add_attribute("role".to_string(), "listitem".to_string());
+            row.add_attribute("translate-x".to_string(), self.offset_of(item.id).to_string());
+            row.add_child(Node::text(&item.text));
+            list.add_child(row);
+        }
+
+        Ok(vec![list])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl SwipeList {
+    pub fn set_items(&self, items: Vec<Item>) {
+        self.items
+            .set(items)
+            .unwrap_or_else(|e| eprintln!("Failed to set items: {}", e));
+    }
+
+    pub fn item_ids(&self) -> Vec<u32> {
+        self.items.get().iter().map(|item| item.id).collect()
+    }
+
+    /// Drag the item with `id` horizontally
+    pub fn swipe(&mut self, id: u32, dx: f32) {
+        // Only one item can be swiped at a time, and not while one is animating out
+        let offset = match self.active {
+            None => 0.0,
+            Some((active, SwipeState::Dragging { offset })) if active == id => offset,
+            Some(_) => return,
+        };
+        self.active = Some((id, SwipeState::Dragging { offset: offset + dx }));
+    }
+
+    /// Release the swiped item: dismiss it past the threshold, otherwise snap back
+    pub fn release(&mut self) {
+        if let Some((id, SwipeState::Dragging { offset })) = self.active {
+            if offset.abs() >= self.props.threshold * self.props.item_width {
+                println!("Item {} swiped past threshold, dismissing", id);
+                self.active = Some((
+                    id,
+                    SwipeState::Dismissing {
+                        direction: offset.signum(),
+                        progress: offset.abs() / self.props.item_width,
+                    },
+                ));
+            } else {
+                println!("Item {} snapped back", id);
+                self.active = None;
+            }
+        }
+    }
+
+    /// Advance the dismiss animation; the item is removed once it has slid out
+    pub fn tick(&mut self, dt: Duration) {
+        if let Some((id, SwipeState::Dismissing { direction, progress })) = self.active {
+            let step = dt.as_secs_f32() / self.props.animation.as_secs_f32().max(f32::EPSILON);
+            let progress = (progress + step).min(1.0);
+
+            if progress >= 1.0 {
+                self.items
+                    .update(|items| items.retain(|item| item.id != id))
+                    .unwrap_or_else(|e| eprintln!("Failed to remove item: {}", e));
+                self.active = None;
+                println!("Item {} removed", id);
+            } else {
+                self.active = Some((id, SwipeState::Dismissing { direction, progress }));
+            }
+        }
+    }
+
+    pub fn is_animating(&self) -> bool {
+        matches!(self.active, Some((_, SwipeState::Dismissing { .. })))
+    }
+
+    fn offset_of(&self, id: u32) -> f32 {
+        match self.active {
+            Some((active, state)) if active == id => match state {
+                SwipeState::Dragging { offset } => offset,
+                SwipeState::Dismissing {
+                    direction,
+                    progress,
+                } => direction * progress * self.props.item_width,
+            },
+            _ => 0.0,
+        }
+    }
+}
+
+fn inbox() -> SwipeList {
+    let list = SwipeList::create(
+        SwipeListProps {
+            item_width: 320.0,
+            threshold: 0.5,
+            animation: Duration::from_millis(200),
+        },
+        Context::new(),
+    );
+    list.set_items(vec![
+        Item {
+            id: 1,
+            text: "Meeting notes".to_string(),
+        },
+        Item {
+            id: 2,
+            text: "Lunch?".to_string(),
+        },
+        Item {
+            id: 3,
+            text: "Build failed".to_string(),
+        },
+    ]);
+    list
+}
+
+fn finish_animation(list: &mut SwipeList) {
+    while list.is_animating() {
+        list.tick(Duration::from_millis(16));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_swipe_past_threshold_removes_item() {
+        let mut list = inbox();
+
+        list.swipe(2, -100.0);
+        list.swipe(2, -100.0);
+        list.release();
+        finish_animation(&mut list);
+
+        assert_eq!(list.item_ids(), vec![1, 3]);
+    }
+
+    #[test]
+    fn test_short_swipe_snaps_back() {
+        let mut list = inbox();
+
+        list.swipe(2, 80.0);
+        list.release();
+        finish_animation(&mut list);
+
+        assert_eq!(list.item_ids(), vec![1, 2, 3]);
+        assert_eq!(list.offset_of(2), 0.0);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Swipe to Dismiss Example\n");
+
+    let mut list = inbox();
+    println!("Items: {:?}", list.item_ids());
+
+    println!("\nShort swipe on item 1...");
+    list.swipe(1, 60.0);
+    list.release();
+
+    println!("\nLong swipe on item 2...");
+    for _ in 0..4 {
+        list.swipe(2, -50.0);
+        list.render()?;
+    }
+    list.release();
+
+    let mut frames = 0;
+    while list.is_animating() {
+        list.tick(Duration::from_millis(16));
+        list.render()?;
+        frames += 1;
+    }
+    println!("Dismiss animation took {} frames", frames);
+    println!("Items: {:?}", list.item_ids());
+
+    println!("\nSwipe to Dismiss example completed!");
+    Ok(())
+}
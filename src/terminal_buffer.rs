@@ -0,0 +1,302 @@
+//! Example demonstrating a reactive terminal emulator buffer
+//! Output is written into a scrollback `Signal<Vec<Line>>`. Carriage returns move the
+//! cursor back so later text overwrites the line, a subset of ANSI escape sequences
+//! handles colors and cursor movement, and old lines are dropped past the scrollback cap.
+//! To run: cargo run --example terminal_buffer
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Style {
+    // ANSI color index 0-7; None is the default foreground
+    pub fg: Option<u8>,
+    pub bold: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cell {
+    pub ch: char,
+    pub style: Style,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Line {
+    pub cells: Vec<Cell>,
+}
+
+impl Line {
+    pub fn text(&self) -> String {
+        self.cells.iter().map(|cell| cell.ch).collect()
+    }
+
+    fn put(&mut self, col: usize, cell: Cell) {
+        if col >= self.cells.len() {
+            let blank = Cell {
+                ch: ' ',
+                style: Style::default(),
+            };
+            self.cells.resize(col + 1, blank);
+        }
+        self.cells[col] = cell;
+    }
+}
+
+// Where the escape sequence parser is between writes
+#[derive(Debug, Clone, PartialEq)]
+enum ParseState {
+    Text,
+    Escape,
+    // Inside `ESC [`, collecting parameter bytes
+    Csi(String),
+}
+
+#[derive(Clone)]
+pub struct TerminalProps {
+    pub scrollback: usize,
+}
+
+/// A terminal output buffer with scrollback
+pub struct Terminal {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: TerminalProps,
+    lines: Signal<Vec<Line>>,
+    // The cursor is always on the last line
+    cursor_col: usize,
+    style: Style,
+    state: ParseState,
+}
+
+impl Component for Terminal {
+    type Props = TerminalProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let lines = create_signal(&scope, vec![Line::default()]);
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            props,
+            lines,
+            cursor_col: 0,
+            style: Style::default(),
+            state: ParseState::Text,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut screen = Node::default();
+        screen.add_attribute("role".to_string(), "log".to_string());
+
+        for line in self.lines.get().iter() {
+            let mut row = Node::default();
+            // Group runs of equally styled cells into spans
+            let mut start = 0;
+            while start < line.cells.len() {
+                let style = line.cells[start].style;
+                let end = line.cells[start..]
+                    .iter()
+                    .position(|cell| cell.style != style)
+                    .map_or(line.cells.len(), |offset| start + offset);
+
+                let text: String = line.cells[start..end].iter().map(|c| c.ch).collect();
+                let mut span = Node::default();
+                if let Some(fg) = style.fg {
+                    span.add_attribute("class".to_string(), format!("fg-{}", fg));
+                }
+                if style.bold {
+                    span.add_attribute("font-weight".to_string(), "bold".to_string());
+                }
+                span.add_child(Node::text(&text));
+                row.add_child(span);
+                start = end;
+            }
+            screen.add_child(row);
+        }
+
+        Ok(vec![screen])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl Terminal {
+    /// Feed program output into the buffer
+    pub fn write(&mut self, output: &str) {
+        let mut lines = self.lines.get().clone();
+
+        for ch in output.chars() {
+            self.state = match std::mem::replace(&mut self.state, ParseState::Text) {
+                ParseState::Text => match ch {
+                    '\x1b' => ParseState::Escape,
+                    '\n' => {
+                        lines.push(Line::default());
+                        self.cursor_col = 0;
+                        ParseState::Text
+                    }
+                    '\r' => {
+                        self.cursor_col = 0;
+                        ParseState::Text
+                    }
+                    _ => {
+                        let cell = Cell {
+                            ch,
+                            style: self.style,
+                        };
+                        lines.last_mut().unwrap().put(self.cursor_col, cell);
+                        self.cursor_col += 1;
+                        ParseState::Text
+                    }
+                },
+                ParseState::Escape if ch == '[' => ParseState::Csi(String::new()),
+                // Unsupported escape; drop it
+                ParseState::Escape => ParseState::Text,
+                ParseState::Csi(mut params) => {
+                    if ch.is_ascii_digit() || ch == ';' {
+                        params.push(ch);
+                        ParseState::Csi(params)
+                    } else {
+                        self.apply_csi(&params, ch, lines.last_mut().unwrap());
+                        ParseState::Text
+                    }
+                }
+            };
+        }
+
+        // Drop the oldest lines beyond the scrollback cap
+        let excess = lines.len().saturating_sub(self.props.scrollback.max(1));
+        lines.drain(..excess);
+
+        self.lines
+            .set(lines)
+            .unwrap_or_else(|e| eprintln!("Failed to update terminal lines: {}", e));
+    }
+
+    fn apply_csi(&mut self, params: &str, command: char, line: &mut Line) {
+        let numbers: Vec<usize> = params
+            .split(';')
+            .map(|param| param.parse().unwrap_or(0))
+            .collect();
+        // Cursor movements treat a missing or zero count as 1
+        let count = numbers.first().copied().unwrap_or(0).max(1);
+
+        match command {
+            'm' => {
+                for code in numbers {
+                    match code {
+                        0 => self.style = Style::default(),
+                        1 => self.style.bold = true,
+                        30..=37 => self.style.fg = Some((code - 30) as u8),
+                        39 => self.style.fg = None,
+                        _ => {}
+                    }
+                }
+            }
+            'C' => self.cursor_col += count,
+            'D' => self.cursor_col = self.cursor_col.saturating_sub(count),
+            // Columns are 1-based in escape sequences
+            'G' => self.cursor_col = count - 1,
+            'K' => line.cells.truncate(self.cursor_col),
+            _ => {}
+        }
+    }
+
+    pub fn lines(&self) -> Vec<String> {
+        self.lines.get().iter().map(Line::text).collect()
+    }
+
+    pub fn line(&self, index: usize) -> Option<Line> {
+        self.lines.get().get(index).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn terminal(scrollback: usize) -> Terminal {
+        Terminal::create(TerminalProps { scrollback }, Context::new())
+    }
+
+    #[test]
+    fn test_carriage_return_overwrites_line() {
+        let mut term = terminal(100);
+
+        term.write("Downloading  10%\rDownloading 100%\n");
+        term.write("abcdef\rXY");
+
+        assert_eq!(term.lines(), vec!["Downloading 100%", "XYcdef"]);
+    }
+
+    #[test]
+    fn test_sgr_and_erase_line() {
+        let mut term = terminal(100);
+
+        term.write("\x1b[1;32mok\x1b[0m done");
+        term.write("\x1b[3G\x1b[K!");
+
+        let line = term.line(0).unwrap();
+        assert_eq!(line.text(), "ok!");
+        assert_eq!(line.cells[0].style, Style { fg: Some(2), bold: true });
+        assert_eq!(line.cells[2].style, Style::default());
+    }
+
+    #[test]
+    fn test_scrollback_is_capped() {
+        let mut term = terminal(3);
+
+        term.write("1\n2\n3\n4\n5");
+
+        assert_eq!(term.lines(), vec!["3", "4", "5"]);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Terminal Buffer Example\n");
+
+    let mut term = Terminal::create(TerminalProps { scrollback: 5 }, Context::new());
+
+    term.write("$ cargo build\n");
+    for percent in [0, 25, 50, 75, 100] {
+        // Progress bars redraw the same line with a carriage return
+        term.write(&format!("\r\x1b[36mBuilding\x1b[39m [{:>3}%]", percent));
+    }
+    term.write("\n\x1b[1;32m   Finished\x1b[0m release target\n");
+    term.write("\x1b[31merror\x1b[0m: disk almost full\n");
+    term.write("$ ");
+    term.render()?;
+
+    for (index, line) in term.lines().iter().enumerate() {
+        println!("  {:>2} | {}", index, line);
+    }
+
+    let styled = term.line(2).unwrap();
+    println!("\nStyle of 'Finished': {:?}", styled.cells[3].style);
+
+    term.write("\n\n\n");
+    println!("After more output, oldest line: {:?}", term.lines()[0]);
+
+    println!("\nTerminal Buffer example completed!");
+    Ok(())
+}
@@ -0,0 +1,182 @@
+//! Example demonstrating structured tracing of the component lifecycle
+//! Each lifecycle method of the counter below runs inside a `tracing` span carrying the
+//! component id and count, so a subscriber sees mount → render → update → unmount as
+//! structured, timed records instead of loose `println!`s. The default filter is `debug`;
+//! set `RUST_LOG=trace` to also see each render.
+//! To run: cargo run --example tracing_integration
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+use tracing::{debug, info, info_span, trace};
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::EnvFilter;
+
+#[derive(Clone)]
+pub struct CounterProps {
+    pub initial: i32,
+}
+
+/// A counter whose lifecycle is traced
+pub struct Counter {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: CounterProps,
+    count: Signal<i32>,
+}
+
+impl Component for Counter {
+    type Props = CounterProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let count = create_signal(&scope, props.initial);
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            props,
+            count,
+        }
+    }
+
+    fn mount(&mut self) -> Result<(), ComponentError> {
+        let _span = info_span!("mount", component = ?self.id, count = self.count()).entered();
+        info!("counter mounted");
+        Ok(())
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        let _span = info_span!("update", component = ?self.id, initial = props.initial).entered();
+
+        if self.props.initial != props.initial {
+            debug!(from = self.props.initial, to = props.initial, "initial value changed");
+            self.count.set(props.initial).map_err(|e| {
+                ComponentError::UpdateError(format!("Failed to reset count: {}", e))
+            })?;
+        }
+        self.props = props;
+        Ok(())
+    }
+
+    fn unmount(&mut self) -> Result<(), ComponentError> {
+        let _span = info_span!("unmount", component = ?self.id, count = self.count()).entered();
+        info!("counter unmounted");
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let _span = info_span!("render", component = ?self.id, count = self.count()).entered();
+
+        let mut counter = Node::default();
+        counter.add_attribute("role".to_string(), "status".to_string());
+        counter.add_child(Node::text(&format!("Count: {}", self.count())));
+        trace!(children = counter.children().len(), "rendered");
+        Ok(vec![counter])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl Counter {
+    pub fn increment(&self) {
+        let count = self.count() + 1;
+        debug!(component = ?self.id, count, "increment");
+        self.count
+            .set(count)
+            .unwrap_or_else(|e| eprintln!("Failed to increment count: {}", e));
+    }
+
+    pub fn count(&self) -> i32 {
+        *self.count.get()
+    }
+}
+
+/// Walk a counter through its whole lifecycle
+fn run_lifecycle() -> Result<(), ComponentError> {
+    let mut counter = Counter::create(CounterProps { initial: 0 }, Context::new());
+    counter.mount()?;
+    counter.render()?;
+
+    counter.increment();
+    counter.update(CounterProps { initial: 10 })?;
+    counter.render()?;
+
+    counter.unmount()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use tracing::span::{Attributes, Id};
+    use tracing::Subscriber;
+    use tracing_subscriber::layer::{Context as LayerContext, Layer, SubscriberExt};
+    use tracing_subscriber::Registry;
+
+    /// Records the name of every span as it's created
+    #[derive(Clone, Default)]
+    struct SpanRecorder {
+        names: Arc<Mutex<Vec<&'static str>>>,
+    }
+
+    impl<S: Subscriber> Layer<S> for SpanRecorder {
+        fn on_new_span(&self, attrs: &Attributes<'_>, _id: &Id, _ctx: LayerContext<'_, S>) {
+            self.names.lock().unwrap().push(attrs.metadata().name());
+        }
+    }
+
+    fn record(f: impl FnOnce()) -> Vec<&'static str> {
+        let recorder = SpanRecorder::default();
+        let subscriber = Registry::default().with(recorder.clone());
+        tracing::subscriber::with_default(subscriber, f);
+        let mut names = recorder.names.lock().unwrap();
+        std::mem::take(&mut *names)
+    }
+
+    #[test]
+    fn test_lifecycle_spans_in_order() {
+        let spans = record(|| run_lifecycle().unwrap());
+
+        assert_eq!(spans, vec!["mount", "render", "update", "render", "unmount"]);
+    }
+
+    #[test]
+    fn test_update_still_traced_when_props_unchanged() {
+        let spans = record(|| {
+            let mut counter = Counter::create(CounterProps { initial: 3 }, Context::new());
+            counter.update(CounterProps { initial: 3 }).unwrap();
+        });
+
+        assert_eq!(spans, vec!["update"]);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("debug")),
+        )
+        // Log each span as it closes, with how long it was open
+        .with_span_events(FmtSpan::CLOSE)
+        .with_target(false)
+        .init();
+
+    println!("Tracing Integration Example\n");
+    run_lifecycle()?;
+    println!("\nTracing Integration example completed!");
+    Ok(())
+}
@@ -0,0 +1,166 @@
+//! Example demonstrating two-way binding between a text field and a signal
+//! The field's contents live in a `Signal<String>`. Typing writes into the signal, and
+//! `render` reads it back into the input's `value` attribute, so code that sets the
+//! signal directly and the user typing always show the same text.
+//! To run: cargo run --example two_way_binding
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+#[derive(Clone)]
+pub struct TextFieldProps {
+    pub label: String,
+    pub initial: String,
+}
+
+/// A controlled text field backed by a signal
+pub struct TextField {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: TextFieldProps,
+    value: Signal<String>,
+}
+
+impl Component for TextField {
+    type Props = TextFieldProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let value = create_signal(&scope, props.initial.clone());
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            props,
+            value,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        // The signal owns the value once created, so only the label follows props
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut input = Node::default();
+        input.add_attribute("role".to_string(), "textbox".to_string());
+        input.add_attribute("aria-label".to_string(), self.props.label.clone());
+        input.add_attribute("value".to_string(), self.value.get().clone());
+        Ok(vec![input])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl TextField {
+    /// Replace the field's contents
+    pub fn set_value(&self, value: &str) {
+        self.value
+            .set(value.to_string())
+            .unwrap_or_else(|e| eprintln!("Failed to set value: {}", e));
+    }
+
+    /// Simulate a key press that inserts `c` at the end
+    pub fn type_char(&self, c: char) {
+        self.value
+            .update(|value| value.push(c))
+            .unwrap_or_else(|e| eprintln!("Failed to type character: {}", e));
+    }
+
+    pub fn backspace(&self) {
+        self.value
+            .update(|value| {
+                value.pop();
+            })
+            .unwrap_or_else(|e| eprintln!("Failed to delete character: {}", e));
+    }
+
+    pub fn value(&self) -> String {
+        self.value.get().clone()
+    }
+}
+
+/// The `value` attribute of the rendered input
+fn rendered_value(field: &TextField) -> Result<Option<String>, ComponentError> {
+    let nodes = field.render()?;
+    Ok(nodes.first().and_then(|input| input.attributes().get("value").cloned()))
+}
+
+fn text_field() -> TextField {
+    TextField::create(
+        TextFieldProps {
+            label: "Username".to_string(),
+            initial: String::new(),
+        },
+        Context::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rendered_value_follows_edits() {
+        let field = text_field();
+
+        for c in "orbitt".chars() {
+            field.type_char(c);
+        }
+        field.backspace();
+        field.type_char('s');
+
+        assert_eq!(field.value(), "orbits");
+        assert_eq!(rendered_value(&field).unwrap(), Some(field.value()));
+    }
+
+    #[test]
+    fn test_set_value_replaces_typed_text() {
+        let field = text_field();
+        field.type_char('x');
+
+        field.set_value("admin");
+
+        assert_eq!(rendered_value(&field).unwrap().as_deref(), Some("admin"));
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Two-Way Binding Example\n");
+
+    let field = text_field();
+
+    println!("Typing \"orbit\":");
+    for c in "orbit".chars() {
+        field.type_char(c);
+        println!(
+            "  signal = {:?}, rendered value = {:?}",
+            field.value(),
+            rendered_value(&field)?
+        );
+    }
+
+    field.backspace();
+    println!("\nAfter backspace: rendered value = {:?}", rendered_value(&field)?);
+
+    field.set_value("orbitrs");
+    println!("After set_value: rendered value = {:?}", rendered_value(&field)?);
+
+    println!("\nTwo-Way Binding example completed!");
+    Ok(())
+}
@@ -0,0 +1,288 @@
+//! Example demonstrating undo history with transactions
+//! `ProfileEditor` records a snapshot before each edit so it can be undone. Edits made
+//! inside `transaction(|| { ... })` share a single snapshot taken when the transaction
+//! starts, so however many fields it touches, one undo reverts them all. Transactions
+//! nest by joining the outermost one, and a transaction that changes nothing adds no step.
+//! To run: cargo run --example undo_transactions
+
+use std::cell::{Cell, RefCell};
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Profile {
+    pub name: String,
+    pub email: String,
+    pub bio: String,
+}
+
+#[derive(Clone)]
+pub struct ProfileEditorProps {
+    pub initial: Profile,
+}
+
+/// An editable profile form with undo and redo
+pub struct ProfileEditor {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    name: Signal<String>,
+    email: Signal<String>,
+    bio: Signal<String>,
+    // Profiles before each undoable step, oldest first
+    history: RefCell<Vec<Profile>>,
+    // Profiles undone since the last edit, most recent last
+    redo_stack: RefCell<Vec<Profile>>,
+    // How many transactions are open; edits inside one aren't recorded separately
+    depth: Cell<usize>,
+}
+
+impl Component for ProfileEditor {
+    type Props = ProfileEditorProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let Profile { name, email, bio } = props.initial;
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            name: create_signal(&scope, name),
+            email: create_signal(&scope, email),
+            bio: create_signal(&scope, bio),
+            scope,
+            history: RefCell::new(Vec::new()),
+            redo_stack: RefCell::new(Vec::new()),
+            depth: Cell::new(0),
+        }
+    }
+
+    fn update(&mut self, _props: Self::Props) -> Result<(), ComponentError> {
+        // The initial profile only seeds the signals; later edits go through the setters
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let profile = self.profile();
+        let mut form = Node::default();
+        form.add_attribute("role".to_string(), "form".to_string());
+        for (field, value) in [
+            ("name", profile.name),
+            ("email", profile.email),
+            ("bio", profile.bio),
+        ] {
+            let mut input = Node::default();
+            input.add_attribute("name".to_string(), field.to_string());
+            input.add_attribute("value".to_string(), value);
+            form.add_child(input);
+        }
+        Ok(vec![form])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl ProfileEditor {
+    pub fn profile(&self) -> Profile {
+        Profile {
+            name: self.name.get().clone(),
+            email: self.email.get().clone(),
+            bio: self.bio.get().clone(),
+        }
+    }
+
+    fn restore(&self, profile: Profile) {
+        for (signal, value) in [
+            (&self.name, profile.name),
+            (&self.email, profile.email),
+            (&self.bio, profile.bio),
+        ] {
+            signal
+                .set(value)
+                .unwrap_or_else(|e| eprintln!("Failed to restore profile: {}", e));
+        }
+    }
+
+    // Remember the current profile before an edit, unless a transaction will
+    fn record(&self) {
+        if self.depth.get() > 0 {
+            return;
+        }
+        self.history.borrow_mut().push(self.profile());
+        // A new edit makes the undone profiles unreachable
+        self.redo_stack.borrow_mut().clear();
+    }
+
+    fn edit(&self, signal: &Signal<String>, value: &str) {
+        self.record();
+        signal
+            .set(value.to_string())
+            .unwrap_or_else(|e| eprintln!("Failed to edit profile: {}", e));
+    }
+
+    pub fn set_name(&self, name: &str) {
+        self.edit(&self.name, name);
+    }
+
+    pub fn set_email(&self, email: &str) {
+        self.edit(&self.email, email);
+    }
+
+    pub fn set_bio(&self, bio: &str) {
+        self.edit(&self.bio, bio);
+    }
+
+    /// Run `edits` as a single undo step
+    pub fn transaction(&self, edits: impl FnOnce()) {
+        let before = self.profile();
+        self.depth.set(self.depth.get() + 1);
+        edits();
+        self.depth.set(self.depth.get() - 1);
+
+        // Only the outermost transaction records, and only if something changed
+        if self.depth.get() == 0 && self.profile() != before {
+            self.history.borrow_mut().push(before);
+            self.redo_stack.borrow_mut().clear();
+        }
+    }
+
+    /// Revert the last step; returns false when there is nothing to undo
+    pub fn undo(&self) -> bool {
+        let Some(previous) = self.history.borrow_mut().pop() else {
+            return false;
+        };
+        self.redo_stack.borrow_mut().push(self.profile());
+        self.restore(previous);
+        true
+    }
+
+    /// Reapply the last undone step; returns false when there is nothing to redo
+    pub fn redo(&self) -> bool {
+        let Some(next) = self.redo_stack.borrow_mut().pop() else {
+            return false;
+        };
+        self.history.borrow_mut().push(self.profile());
+        self.restore(next);
+        true
+    }
+
+    pub fn undo_steps(&self) -> usize {
+        self.history.borrow().len()
+    }
+}
+
+fn editor() -> ProfileEditor {
+    ProfileEditor::create(
+        ProfileEditorProps {
+            initial: Profile {
+                name: "Ada".to_string(),
+                email: "ada@example.com".to_string(),
+                bio: "Mathematician".to_string(),
+            },
+        },
+        Context::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transaction_undoes_in_one_step() {
+        let editor = editor();
+        let original = editor.profile();
+
+        editor.transaction(|| {
+            editor.set_name("Grace");
+            editor.set_email("grace@example.com");
+            editor.set_bio("Rear admiral");
+        });
+        assert_eq!(editor.undo_steps(), 1);
+
+        assert!(editor.undo());
+        assert_eq!(editor.profile(), original);
+        assert!(!editor.undo());
+    }
+
+    #[test]
+    fn test_edits_outside_a_transaction_undo_separately() {
+        let editor = editor();
+
+        editor.set_name("Grace");
+        editor.set_bio("Rear admiral");
+        editor.undo();
+
+        assert_eq!(editor.profile().name, "Grace");
+        assert_eq!(editor.profile().bio, "Mathematician");
+    }
+
+    #[test]
+    fn test_nested_and_empty_transactions() {
+        let editor = editor();
+
+        editor.transaction(|| {
+            editor.set_name("Grace");
+            editor.transaction(|| editor.set_email("grace@example.com"));
+        });
+        editor.transaction(|| {});
+        assert_eq!(editor.undo_steps(), 1);
+
+        editor.undo();
+        assert!(editor.redo());
+        assert_eq!(editor.profile().email, "grace@example.com");
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Undo Transactions Example\n");
+
+    let editor = editor();
+    println!("Start:            {:?}", editor.profile());
+
+    editor.set_name("Ada Lovelace");
+    println!("Single edit:      {:?}", editor.profile());
+
+    editor.transaction(|| {
+        editor.set_name("Grace Hopper");
+        editor.set_email("grace@example.com");
+        editor.set_bio("Rear admiral");
+    });
+    println!("Transaction:      {:?}", editor.profile());
+    println!("Undo steps:       {}", editor.undo_steps());
+
+    editor.undo();
+    println!("Undo transaction: {:?}", editor.profile());
+
+    editor.undo();
+    println!("Undo single edit: {:?}", editor.profile());
+
+    editor.redo();
+    println!("Redo:             {:?}", editor.profile());
+
+    println!("\nRendered fields:");
+    for input in editor.render()?[0].children() {
+        let attributes = input.attributes();
+        println!(
+            "  {} = {}",
+            attributes.get("name").cloned().unwrap_or_default(),
+            attributes.get("value").cloned().unwrap_or_default()
+        );
+    }
+
+    println!("\nUndo Transactions example completed!");
+    Ok(())
+}
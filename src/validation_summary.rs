@@ -0,0 +1,344 @@
+//! Example demonstrating a validation summary derived from field values
+//! Each field has rules in a small validation schema. A computed value runs the schema
+//! over the current values and collects every error into one summary list, and a second
+//! computed turns that list into the `is_valid` flag that gates submission.
+//! To run: cargo run --example validation_summary
+
+use std::collections::HashMap;
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_computed, create_signal, ReactiveComputed, ReactiveScope, Signal};
+
+/// A check applied to one field's value
+#[derive(Debug, Clone, PartialEq)]
+pub enum Rule {
+    Required,
+    MinLength(usize),
+    Email,
+}
+
+impl Rule {
+    // The error message if `value` breaks this rule
+    fn check(&self, label: &str, value: &str) -> Option<String> {
+        let value = value.trim();
+        match self {
+            Rule::Required if value.is_empty() => Some(format!("{} is required", label)),
+            // Length and format only apply once something has been entered
+            Rule::MinLength(min) if !value.is_empty() && value.chars().count() < *min => {
+                Some(format!("{} must be at least {} characters", label, min))
+            }
+            Rule::Email if !value.is_empty() && !is_email(value) => {
+                Some(format!("{} must be an email address", label))
+            }
+            _ => None,
+        }
+    }
+}
+
+fn is_email(value: &str) -> bool {
+    match value.split_once('@') {
+        Some((user, domain)) => !user.is_empty() && domain.contains('.'),
+        None => false,
+    }
+}
+
+/// The rules for one field
+#[derive(Debug, Clone)]
+pub struct FieldSchema {
+    pub name: String,
+    pub label: String,
+    pub rules: Vec<Rule>,
+}
+
+/// One entry in the summary
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+/// Run every rule in the schema, in field order; the first broken rule per field wins
+pub fn validate(schema: &[FieldSchema], values: &HashMap<String, String>) -> Vec<FieldError> {
+    schema
+        .iter()
+        .filter_map(|field| {
+            let value = values.get(&field.name).map_or("", |v| v.as_str());
+            field
+                .rules
+                .iter()
+                .find_map(|rule| rule.check(&field.label, value))
+                .map(|message| FieldError {
+                    field: field.name.clone(),
+                    message,
+                })
+        })
+        .collect()
+}
+
+#[derive(Clone)]
+pub struct SignupFormProps {
+    pub schema: Vec<FieldSchema>,
+}
+
+/// A form whose error summary and validity are derived from its values
+pub struct SignupForm {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    schema: Signal<Vec<FieldSchema>>,
+    values: Signal<HashMap<String, String>>,
+    summary: ReactiveComputed<Vec<FieldError>, Box<dyn FnMut() -> Vec<FieldError>>>,
+    is_valid: ReactiveComputed<bool, Box<dyn FnMut() -> bool>>,
+    submissions: Signal<usize>,
+}
+
+impl Component for SignupForm {
+    type Props = SignupFormProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let schema = create_signal(&scope, props.schema);
+        let values = create_signal(&scope, HashMap::new());
+
+        // Reads the schema signal so validation follows schema updates
+        let schema_clone = schema.value.clone();
+        let values_clone = values.value.clone();
+        let summary = create_computed(
+            &scope,
+            Box::new(move || validate(&schema_clone.borrow(), &values_clone.borrow()))
+                as Box<dyn FnMut() -> Vec<FieldError>>,
+        );
+
+        let summary_clone = summary.value.clone();
+        let is_valid = create_computed(
+            &scope,
+            Box::new(move || {
+                summary_clone
+                    .borrow()
+                    .as_ref()
+                    .is_some_and(|errors| errors.is_empty())
+            }) as Box<dyn FnMut() -> bool>,
+        );
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            submissions: create_signal(&scope, 0),
+            scope,
+            schema,
+            values,
+            summary,
+            is_valid,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.schema
+            .set(props.schema)
+            .map_err(|e| ComponentError::UpdateError(format!("Failed to update schema: {}", e)))
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut form = Node::default();
+        form.add_attribute("role".to_string(), "form".to_string());
+
+        let summary = self.summary();
+        if !summary.is_empty() {
+            let mut alert = Node::default();
+            alert.add_attribute("role".to_string(), "alert".to_string());
+            alert.add_child(Node::text(&format!(
+                "{} problem(s) with this form",
+                summary.len()
+            )));
+            for error in &summary {
+                let mut item = Node::default();
+                // Each entry links to the field it describes
+                item.add_attribute("href".to_string(), format!("#{}", error.field));
+                item.add_child(Node::text(&error.message));
+                alert.add_child(item);
+            }
+            form.add_child(alert);
+        }
+
+        for field in self.schema.get().iter() {
+            let mut input = Node::default();
+            input.add_attribute("id".to_string(), field.name.clone());
+            input.add_attribute("aria-label".to_string(), field.label.clone());
+            input.add_attribute("value".to_string(), self.value(&field.name));
+            let invalid = summary.iter().any(|error| error.field == field.name);
+            input.add_attribute("aria-invalid".to_string(), invalid.to_string());
+            form.add_child(input);
+        }
+
+        let mut submit = Node::default();
+        submit.add_attribute("type".to_string(), "submit".to_string());
+        submit.add_attribute("disabled".to_string(), (!self.is_valid()).to_string());
+        form.add_child(submit);
+
+        Ok(vec![form])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl SignupForm {
+    pub fn set_value(&self, field: &str, value: &str) {
+        self.values
+            .update(|values| {
+                values.insert(field.to_string(), value.to_string());
+            })
+            .unwrap_or_else(|e| eprintln!("Failed to set {}: {}", field, e));
+    }
+
+    pub fn value(&self, field: &str) -> String {
+        self.values.get().get(field).cloned().unwrap_or_default()
+    }
+
+    pub fn summary(&self) -> Vec<FieldError> {
+        self.summary.get().map(|s| s.clone()).unwrap_or_default()
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.is_valid.get().map(|v| *v).unwrap_or(false)
+    }
+
+    /// Submit the form; refused while the summary has errors
+    pub fn submit(&self) -> bool {
+        if !self.is_valid() {
+            return false;
+        }
+        self.submissions
+            .update(|count| *count += 1)
+            .unwrap_or_else(|e| eprintln!("Failed to record submission: {}", e));
+        true
+    }
+
+    pub fn submissions(&self) -> usize {
+        *self.submissions.get()
+    }
+}
+
+fn signup_form() -> SignupForm {
+    let field = |name: &str, label: &str, rules: Vec<Rule>| FieldSchema {
+        name: name.to_string(),
+        label: label.to_string(),
+        rules,
+    };
+    SignupForm::create(
+        SignupFormProps {
+            schema: vec![
+                field("username", "Username", vec![Rule::Required, Rule::MinLength(3)]),
+                field("email", "Email", vec![Rule::Required, Rule::Email]),
+                field("bio", "Bio", vec![Rule::MinLength(10)]),
+            ],
+        },
+        Context::new(),
+    )
+}
+
+fn print_summary(form: &SignupForm) {
+    let summary = form.summary();
+    if summary.is_empty() {
+        println!("  no problems");
+    }
+    for error in summary {
+        println!("  - {}", error.message);
+    }
+    println!("  is_valid: {}", form.is_valid());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary_lists_every_invalid_field() {
+        let form = signup_form();
+        form.set_value("username", "al");
+        form.set_value("email", "not-an-email");
+
+        let fields: Vec<String> = form.summary().into_iter().map(|e| e.field).collect();
+        assert_eq!(fields, vec!["username", "email"]);
+        assert!(!form.is_valid());
+        assert!(!form.submit());
+
+        form.set_value("username", "alice");
+        form.set_value("email", "alice@example.com");
+
+        assert!(form.summary().is_empty());
+        assert!(form.is_valid());
+        assert!(form.submit());
+    }
+
+    #[test]
+    fn test_optional_field_is_only_checked_when_filled() {
+        let form = signup_form();
+        form.set_value("username", "alice");
+        form.set_value("email", "alice@example.com");
+        assert!(form.is_valid());
+
+        form.set_value("bio", "Hi");
+
+        assert_eq!(form.summary()[0].message, "Bio must be at least 10 characters");
+    }
+
+    #[test]
+    fn test_update_validates_against_new_schema() {
+        let mut form = signup_form();
+        form.set_value("username", "alice");
+        form.set_value("email", "alice@example.com");
+        assert!(form.is_valid());
+
+        form.update(SignupFormProps {
+            schema: vec![FieldSchema {
+                name: "phone".to_string(),
+                label: "Phone".to_string(),
+                rules: vec![Rule::Required],
+            }],
+        })
+        .unwrap();
+
+        let fields: Vec<String> = form.summary().into_iter().map(|e| e.field).collect();
+        assert_eq!(fields, vec!["phone"]);
+        assert!(!form.is_valid());
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Validation Summary Example\n");
+
+    let form = signup_form();
+    println!("Empty form:");
+    print_summary(&form);
+
+    let edits = [
+        ("username", "al"),
+        ("email", "al@example"),
+        ("username", "alice"),
+        ("email", "alice@example.com"),
+    ];
+    for (field, value) in edits {
+        form.set_value(field, value);
+        form.render()?;
+        println!("\nAfter setting {} = {:?}:", field, value);
+        print_summary(&form);
+    }
+
+    println!("\nSubmitted: {}", form.submit());
+    println!("Submissions so far: {}", form.submissions());
+
+    println!("\nValidation Summary example completed!");
+    Ok(())
+}
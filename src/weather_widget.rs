@@ -0,0 +1,215 @@
+//! Example demonstrating derived units in a weather widget
+//! The temperature is stored once in Celsius; Fahrenheit is a computed value derived
+//! from it. A separate unit signal picks which one is displayed, so toggling the unit
+//! never touches the temperature or its conversion.
+//! To run: cargo run --example weather_widget
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_computed, create_signal, ReactiveComputed, ReactiveScope, Signal};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Unit {
+    Celsius,
+    Fahrenheit,
+}
+
+impl Unit {
+    fn symbol(self) -> &'static str {
+        match self {
+            Unit::Celsius => "°C",
+            Unit::Fahrenheit => "°F",
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct WeatherWidgetProps {
+    pub city: String,
+}
+
+/// Shows the current temperature in the selected unit
+pub struct WeatherWidget {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: WeatherWidgetProps,
+    celsius: Signal<f32>,
+    fahrenheit: ReactiveComputed<f32, Box<dyn FnMut() -> f32>>,
+    unit: Signal<Unit>,
+    // How many times the Fahrenheit conversion has run
+    conversions: Rc<Cell<usize>>,
+}
+
+impl Component for WeatherWidget {
+    type Props = WeatherWidgetProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+        let celsius = create_signal(&scope, 0.0_f32);
+        let unit = create_signal(&scope, Unit::Celsius);
+
+        let conversions = Rc::new(Cell::new(0));
+        let conversions_for_computed = conversions.clone();
+        let celsius_for_computed = celsius.value.clone();
+        let fahrenheit = create_computed(&scope, Box::new(move || {
+            conversions_for_computed.set(conversions_for_computed.get() + 1);
+            *celsius_for_computed.borrow() * 9.0 / 5.0 + 32.0
+        }) as Box<dyn FnMut() -> f32>);
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            scope,
+            props,
+            celsius,
+            fahrenheit,
+            unit,
+            conversions,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut widget = Node::default();
+        widget.add_attribute("class".to_string(), "weather".to_string());
+        widget.add_child(Node::text(&self.props.city));
+        widget.add_child(Node::text(&self.display()));
+
+        let mut toggle = Node::default();
+        toggle.add_attribute("role".to_string(), "switch".to_string());
+        toggle.add_attribute(
+            "aria-checked".to_string(),
+            (*self.unit.get() == Unit::Fahrenheit).to_string(),
+        );
+        widget.add_child(toggle);
+
+        Ok(vec![widget])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl WeatherWidget {
+    pub fn set_celsius(&self, celsius: f32) {
+        self.celsius
+            .set(celsius)
+            .unwrap_or_else(|e| eprintln!("Failed to set temperature: {}", e));
+    }
+
+    pub fn set_unit(&self, unit: Unit) {
+        self.unit
+            .set(unit)
+            .unwrap_or_else(|e| eprintln!("Failed to set unit: {}", e));
+    }
+
+    pub fn toggle_unit(&self) {
+        let next = match *self.unit.get() {
+            Unit::Celsius => Unit::Fahrenheit,
+            Unit::Fahrenheit => Unit::Celsius,
+        };
+        self.set_unit(next);
+    }
+
+    pub fn fahrenheit(&self) -> f32 {
+        self.fahrenheit.get().map(|value| *value).unwrap_or(f32::NAN)
+    }
+
+    /// The temperature formatted in the selected unit
+    pub fn display(&self) -> String {
+        let unit = *self.unit.get();
+        let value = match unit {
+            Unit::Celsius => *self.celsius.get(),
+            Unit::Fahrenheit => self.fahrenheit(),
+        };
+        format!("{:.1}{}", value, unit.symbol())
+    }
+
+    pub fn conversions(&self) -> usize {
+        self.conversions.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn widget() -> WeatherWidget {
+        WeatherWidget::create(
+            WeatherWidgetProps {
+                city: "Oslo".to_string(),
+            },
+            Context::new(),
+        )
+    }
+
+    #[test]
+    fn test_fahrenheit_is_derived_from_celsius() {
+        let widget = widget();
+
+        widget.set_celsius(100.0);
+
+        assert_eq!(widget.fahrenheit(), 212.0);
+    }
+
+    #[test]
+    fn test_unit_change_does_not_recompute_conversion() {
+        let widget = widget();
+        widget.set_celsius(20.0);
+        widget.set_unit(Unit::Fahrenheit);
+        assert_eq!(widget.display(), "68.0°F");
+        let conversions = widget.conversions();
+
+        widget.set_unit(Unit::Celsius);
+        widget.set_unit(Unit::Fahrenheit);
+
+        assert_eq!(widget.display(), "68.0°F");
+        assert_eq!(widget.conversions(), conversions);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Weather Widget Example\n");
+
+    let widget = WeatherWidget::create(
+        WeatherWidgetProps {
+            city: "Lisbon".to_string(),
+        },
+        Context::new(),
+    );
+
+    widget.set_celsius(23.5);
+    widget.render()?;
+    println!("Lisbon: {}", widget.display());
+
+    for _ in 0..3 {
+        widget.toggle_unit();
+        widget.render()?;
+        println!("Toggled unit -> {}", widget.display());
+    }
+
+    widget.set_celsius(-4.0);
+    println!("\nCold snap -> {}", widget.display());
+    println!("Fahrenheit conversions run: {}", widget.conversions());
+
+    println!("\nWeather Widget example completed!");
+    Ok(())
+}
@@ -0,0 +1,244 @@
+//! Example demonstrating instanced rendering with WGPU
+//! Ten thousand cubes are drawn with a single instanced draw call: the cube mesh is
+//! uploaded once and each instance's model matrix comes from an instance buffer. The
+//! buffer is rewritten every frame to ripple the grid in a wave, and the average
+//! frame time is printed so throughput can be compared across machines.
+//! To run: cargo run --release --example wgpu_instancing
+
+use std::mem;
+use std::time::{Duration, Instant};
+
+use bytemuck::{Pod, Zeroable};
+use cgmath::{Matrix4, Vector3};
+use winit::{
+    dpi::LogicalSize,
+    event::{Event, WindowEvent},
+    event_loop::{ControlFlow, EventLoop},
+    window::WindowBuilder,
+};
+
+// Shared with the other wgpu_* examples; not every item is used by each of them
+#[allow(dead_code)]
+mod camera;
+#[allow(dead_code)]
+mod gpu;
+#[allow(dead_code)]
+mod mesh;
+
+use camera::Camera;
+use gpu::{Gpu, MeshBuffers, UniformBinding, Uniforms};
+use mesh::{MeshPrimitives, Vertex};
+
+/// Cubes along each side of the grid
+const GRID_SIDE: usize = 100;
+const INSTANCE_COUNT: usize = GRID_SIDE * GRID_SIDE;
+const SPACING: f32 = 0.6;
+const CUBE_SIZE: f32 = 0.4;
+
+/// One instance's model matrix, as the vertex shader reads it
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+pub struct InstanceRaw {
+    pub model: [[f32; 4]; 4],
+}
+
+impl InstanceRaw {
+    // After the mesh's locations 0-2, leaving room for more vertex attributes
+    const ATTRIBUTES: [wgpu::VertexAttribute; 4] = wgpu::vertex_attr_array![
+        5 => Float32x4, 6 => Float32x4, 7 => Float32x4, 8 => Float32x4
+    ];
+
+    pub fn layout() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            array_stride: mem::size_of::<InstanceRaw>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Instance,
+            attributes: &Self::ATTRIBUTES,
+        }
+    }
+}
+
+/// Bytes needed to hold `count` instances
+pub fn instance_buffer_size(count: usize) -> wgpu::BufferAddress {
+    (count * mem::size_of::<InstanceRaw>()) as wgpu::BufferAddress
+}
+
+/// A square grid of cubes rippling outwards from its centre
+pub struct WaveGrid {
+    side: usize,
+    instances: Vec<InstanceRaw>,
+}
+
+impl WaveGrid {
+    pub fn new(side: usize) -> Self {
+        let mut grid = Self {
+            side,
+            instances: vec![InstanceRaw::zeroed(); side * side],
+        };
+        grid.update(0.0);
+        grid
+    }
+
+    /// Recompute every instance for time `t` in seconds
+    pub fn update(&mut self, t: f32) {
+        let half = (self.side as f32 - 1.0) * SPACING / 2.0;
+        for (index, instance) in self.instances.iter_mut().enumerate() {
+            let x = (index % self.side) as f32 * SPACING - half;
+            let z = (index / self.side) as f32 * SPACING - half;
+            let distance = (x * x + z * z).sqrt();
+            let y = (distance * 0.5 - t * 2.0).sin() * 0.8;
+            instance.model = (Matrix4::from_translation(Vector3::new(x, y, z))
+                * Matrix4::from_scale(CUBE_SIZE))
+            .into();
+        }
+    }
+
+    pub fn instances(&self) -> &[InstanceRaw] {
+        &self.instances
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_instance_buffer_fits_configured_count() {
+        let grid = WaveGrid::new(GRID_SIDE);
+
+        assert_eq!(grid.instances().len(), INSTANCE_COUNT);
+        assert_eq!(instance_buffer_size(INSTANCE_COUNT), 10_000 * 64);
+        assert_eq!(
+            bytemuck::cast_slice::<_, u8>(grid.instances()).len() as wgpu::BufferAddress,
+            instance_buffer_size(INSTANCE_COUNT)
+        );
+    }
+
+    #[test]
+    fn test_wave_moves_over_time() {
+        let mut grid = WaveGrid::new(4);
+        let before = grid.instances()[0].model[3][1];
+
+        grid.update(0.5);
+
+        assert!((grid.instances()[0].model[3][1] - before).abs() > 1e-3);
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("WGPU Instancing Example");
+    println!("Drawing {} cubes in one instanced draw call", INSTANCE_COUNT);
+
+    let event_loop = EventLoop::new();
+    let window = WindowBuilder::new()
+        .with_title("Orbit WGPU Instancing")
+        .with_inner_size(LogicalSize::new(800.0, 600.0))
+        .build(&event_loop)?;
+    let mut gpu = Gpu::new(&window)?;
+
+    let shader = gpu
+        .device
+        .create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("instanced shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shaders/instanced.wgsl").into()),
+        });
+    let cube = MeshBuffers::new(&gpu.device, &MeshPrimitives::cube(1.0));
+
+    let mut grid = WaveGrid::new(GRID_SIDE);
+    let instance_buffer = gpu.device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("instances"),
+        size: instance_buffer_size(INSTANCE_COUNT),
+        usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    });
+
+    let mut camera = Camera::new(
+        [0.0, 25.0, 45.0],
+        gpu.config.width as f32 / gpu.config.height as f32,
+    );
+    camera.pitch = -0.5;
+
+    let uniforms = UniformBinding::new(
+        &gpu.device,
+        &Uniforms {
+            view_proj: camera.view_proj().into(),
+            model: Matrix4::from_scale(1.0).into(),
+        },
+    );
+    let pipeline = gpu::mesh_pipeline(
+        &gpu.device,
+        gpu.config.format,
+        &shader,
+        &[&uniforms.layout],
+        &[Vertex::layout(), InstanceRaw::layout()],
+        wgpu::PolygonMode::Fill,
+    );
+
+    let started = Instant::now();
+    let mut frames = 0;
+    let mut window_start = Instant::now();
+
+    event_loop.run(move |event, _, control_flow| {
+        *control_flow = ControlFlow::Poll;
+
+        match event {
+            Event::WindowEvent {
+                event: WindowEvent::CloseRequested,
+                window_id,
+            } if window_id == window.id() => *control_flow = ControlFlow::Exit,
+            Event::WindowEvent {
+                event: WindowEvent::Resized(size),
+                window_id,
+            } if window_id == window.id() => gpu.fit_window(size, &mut camera),
+            Event::MainEventsCleared => window.request_redraw(),
+            Event::RedrawRequested(window_id) if window_id == window.id() => {
+                grid.update(started.elapsed().as_secs_f32());
+                gpu.queue.write_buffer(&instance_buffer, 0, bytemuck::cast_slice(grid.instances()));
+                uniforms.write(
+                    &gpu.queue,
+                    &Uniforms {
+                        view_proj: camera.view_proj().into(),
+                        model: Matrix4::from_scale(1.0).into(),
+                    },
+                );
+
+                let mut frame = match gpu.begin_frame() {
+                    Ok(frame) => frame,
+                    // The surface went stale (e.g. mid-resize); reconfigure and try next frame
+                    Err(wgpu::SurfaceError::Lost | wgpu::SurfaceError::Outdated) => {
+                        gpu.resize(gpu.config.width, gpu.config.height);
+                        return;
+                    }
+                    Err(wgpu::SurfaceError::OutOfMemory) => {
+                        eprintln!("Out of GPU memory");
+                        *control_flow = ControlFlow::Exit;
+                        return;
+                    }
+                    Err(wgpu::SurfaceError::Timeout) => return,
+                };
+                {
+                    let mut pass = frame.pass(&gpu.depth_view, wgpu::Color::BLACK);
+                    pass.set_pipeline(&pipeline);
+                    pass.set_bind_group(0, &uniforms.bind_group, &[]);
+                    pass.set_vertex_buffer(1, instance_buffer.slice(..));
+                    cube.draw_instanced(&mut pass, INSTANCE_COUNT as u32);
+                }
+                gpu.end_frame(frame);
+
+                frames += 1;
+                let elapsed = window_start.elapsed();
+                if elapsed >= Duration::from_secs(1) {
+                    let frame_ms = elapsed.as_secs_f64() * 1000.0 / frames as f64;
+                    println!(
+                        "{} instances: {:.2} ms/frame ({:.0} FPS)",
+                        INSTANCE_COUNT,
+                        frame_ms,
+                        1000.0 / frame_ms
+                    );
+                    frames = 0;
+                    window_start = Instant::now();
+                }
+            }
+            _ => {}
+        }
+    })
+}
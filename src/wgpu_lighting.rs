@@ -0,0 +1,285 @@
+//! Example demonstrating Phong lighting in the WGPU renderer
+//! A sphere and a floor are shaded with ambient, diffuse and specular terms from a
+//! single directional light. The scene component circles the light around the sphere
+//! and shifts its colour every frame, so the highlight can be seen sliding across it.
+//! Pass `--frames N` to exit after N frames.
+//! To run: cargo run --example wgpu_lighting [-- --frames 100]
+
+use std::f32::consts::TAU;
+use std::time::Instant;
+
+use bytemuck::{Pod, Zeroable};
+use cgmath::Matrix4;
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use winit::{
+    dpi::LogicalSize,
+    event::{Event, WindowEvent},
+    event_loop::{ControlFlow, EventLoop},
+    window::WindowBuilder,
+};
+
+// Shared with the other wgpu_* examples; not every item is used by each of them
+#[allow(dead_code)]
+mod camera;
+#[allow(dead_code)]
+mod gpu;
+#[allow(dead_code)]
+mod mesh;
+
+use camera::Camera;
+use gpu::{Gpu, MeshBuffers, UniformBinding};
+use mesh::MeshPrimitives;
+
+const PHONG_SHADER: &str = include_str!("shaders/phong.wgsl");
+
+/// Seconds for the light to circle the scene once
+const LIGHT_PERIOD: f32 = 6.0;
+
+/// A light shining in one direction everywhere, like the sun
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DirectionalLight {
+    // The way the light travels; kept normalized
+    pub direction: [f32; 3],
+    pub color: [f32; 3],
+}
+
+/// Matches `Uniforms` in `shaders/phong.wgsl`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+pub struct LightingUniforms {
+    pub view_proj: [[f32; 4]; 4],
+    pub model: [[f32; 4]; 4],
+    pub camera_position: [f32; 4],
+    pub light_direction: [f32; 4],
+    pub light_color: [f32; 4],
+}
+
+impl LightingUniforms {
+    pub fn new(camera: &Camera, model: Matrix4<f32>, light: &DirectionalLight) -> Self {
+        let [x, y, z] = camera.position;
+        let [dx, dy, dz] = light.direction;
+        let [r, g, b] = light.color;
+        Self {
+            view_proj: camera.view_proj().into(),
+            model: model.into(),
+            camera_position: [x, y, z, 1.0],
+            light_direction: [dx, dy, dz, 0.0],
+            light_color: [r, g, b, 1.0],
+        }
+    }
+}
+
+/// A lit scene whose light moves over time
+pub struct LightingScene {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    pub light: DirectionalLight,
+    pub camera: Camera,
+    // Seconds of animation so far
+    elapsed: f32,
+    last_update: Instant,
+}
+
+impl Component for LightingScene {
+    type Props = ();
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(_props: Self::Props, context: Context) -> Self {
+        let mut camera = Camera::new([0.0, 1.5, 5.0], 16.0 / 9.0);
+        camera.pitch = -0.3;
+
+        let mut scene = Self {
+            id: ComponentId::new(),
+            context,
+            light: DirectionalLight {
+                direction: [0.0, -1.0, 0.0],
+                color: [1.0, 1.0, 1.0],
+            },
+            camera,
+            elapsed: 0.0,
+            last_update: Instant::now(),
+        };
+        scene.advance(0.0);
+        scene
+    }
+
+    fn update(&mut self, _props: Self::Props) -> Result<(), ComponentError> {
+        let now = Instant::now();
+        let dt = now.duration_since(self.last_update).as_secs_f32();
+        self.last_update = now;
+        self.advance(dt);
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        // The meshes are drawn directly with WGPU; there's no node tree for this scene
+        Ok(Vec::new())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl LightingScene {
+    /// Move the light on by `dt` seconds
+    pub fn advance(&mut self, dt: f32) {
+        self.elapsed += dt;
+        let angle = self.elapsed / LIGHT_PERIOD * TAU;
+
+        // Circle overhead, tilted down at the scene
+        let (x, y, z) = (angle.cos(), -1.0, angle.sin());
+        let length = (x * x + y * y + z * z).sqrt();
+        self.light.direction = [x / length, y / length, z / length];
+
+        // Drift between a cool white and a warm orange
+        let warmth = 0.5 - 0.5 * angle.cos();
+        self.light.color = [1.0, 1.0 - 0.25 * warmth, 1.0 - 0.55 * warmth];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_phong_shader_compiles() {
+        let module = naga::front::wgsl::parse_str(PHONG_SHADER).expect("shader failed to parse");
+        naga::valid::Validator::new(
+            naga::valid::ValidationFlags::all(),
+            naga::valid::Capabilities::empty(),
+        )
+        .validate(&module)
+        .expect("shader failed to validate");
+    }
+
+    #[test]
+    fn test_light_rotates_and_stays_normalized() {
+        let mut scene = LightingScene::create((), Context::new());
+        let start = scene.light;
+
+        scene.advance(LIGHT_PERIOD / 4.0);
+
+        assert_ne!(scene.light.direction, start.direction);
+        let [x, y, z] = scene.light.direction;
+        assert!(((x * x + y * y + z * z).sqrt() - 1.0).abs() < 1e-5);
+        // Always shining downwards onto the floor
+        assert!(y < 0.0);
+    }
+
+    #[test]
+    fn test_uniforms_keep_16_byte_alignment() {
+        assert_eq!(std::mem::size_of::<LightingUniforms>() % 16, 0);
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("WGPU Lighting Example");
+
+    let args: Vec<String> = std::env::args().collect();
+    let frame_limit: Option<u64> = match args.iter().position(|arg| arg == "--frames") {
+        Some(index) => Some(args.get(index + 1).ok_or("--frames needs a count")?.parse()?),
+        None => None,
+    };
+
+    let event_loop = EventLoop::new();
+    let window = WindowBuilder::new()
+        .with_title("Orbit WGPU Lighting")
+        .with_inner_size(LogicalSize::new(800.0, 600.0))
+        .build(&event_loop)?;
+    let mut gpu = Gpu::new(&window)?;
+
+    let shader = gpu
+        .device
+        .create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("phong shader"),
+            source: wgpu::ShaderSource::Wgsl(PHONG_SHADER.into()),
+        });
+
+    let mut scene = LightingScene::create((), Context::new());
+    scene.camera.aspect = gpu.config.width as f32 / gpu.config.height as f32;
+
+    // The sphere shows off the highlight; the floor shows the diffuse falloff
+    let floor = Matrix4::from_translation([0.0, -1.2, 0.0].into());
+    let objects = [
+        (MeshPrimitives::sphere(1.0, 48, 32), Matrix4::from_scale(1.0)),
+        (MeshPrimitives::plane(8.0), floor),
+    ]
+    .map(|(mesh, model)| {
+        let uniforms = LightingUniforms::new(&scene.camera, model, &scene.light);
+        (
+            MeshBuffers::new(&gpu.device, &mesh),
+            UniformBinding::new(&gpu.device, &uniforms),
+            model,
+        )
+    });
+    let pipeline = gpu.mesh_pipeline(&shader, &[&objects[0].1.layout], wgpu::PolygonMode::Fill);
+
+    let mut frame: u64 = 0;
+
+    event_loop.run(move |event, _, control_flow| {
+        *control_flow = ControlFlow::Poll;
+
+        match event {
+            Event::WindowEvent {
+                event: WindowEvent::CloseRequested,
+                window_id,
+            } if window_id == window.id() => *control_flow = ControlFlow::Exit,
+            Event::WindowEvent {
+                event: WindowEvent::Resized(size),
+                window_id,
+            } if window_id == window.id() => gpu.fit_window(size, &mut scene.camera),
+            Event::MainEventsCleared => window.request_redraw(),
+            Event::RedrawRequested(window_id) if window_id == window.id() => {
+                if let Err(e) = scene.update(()) {
+                    eprintln!("Failed to update scene: {}", e);
+                    *control_flow = ControlFlow::Exit;
+                    return;
+                }
+                for (_, uniforms, model) in &objects {
+                    let values = LightingUniforms::new(&scene.camera, *model, &scene.light);
+                    uniforms.write(&gpu.queue, &values);
+                }
+
+                let mut output = match gpu.begin_frame() {
+                    Ok(output) => output,
+                    // The surface went stale (e.g. mid-resize); reconfigure and try next frame
+                    Err(wgpu::SurfaceError::Lost | wgpu::SurfaceError::Outdated) => {
+                        gpu.resize(gpu.config.width, gpu.config.height);
+                        return;
+                    }
+                    Err(wgpu::SurfaceError::OutOfMemory) => {
+                        eprintln!("Out of GPU memory");
+                        *control_flow = ControlFlow::Exit;
+                        return;
+                    }
+                    Err(wgpu::SurfaceError::Timeout) => return,
+                };
+                {
+                    let mut pass = output.pass(&gpu.depth_view, wgpu::Color::BLACK);
+                    pass.set_pipeline(&pipeline);
+                    for (buffers, uniforms, _) in &objects {
+                        pass.set_bind_group(0, &uniforms.bind_group, &[]);
+                        buffers.draw(&mut pass);
+                    }
+                }
+                gpu.end_frame(output);
+
+                frame += 1;
+                if frame_limit == Some(frame) {
+                    println!("Rendered {} frames", frame);
+                    *control_flow = ControlFlow::Exit;
+                }
+            }
+            _ => {}
+        }
+    })
+}
@@ -0,0 +1,335 @@
+//! Example demonstrating loading a Wavefront OBJ file and drawing it with WGPU
+//! The parser reads positions (`v`), normals (`vn`) and faces (`f`), splitting
+//! polygons into triangles. Faces without normals get a flat normal from their
+//! winding, and a malformed face is reported with its line number. Pass a path to
+//! load your own file; otherwise the bundled `cube.obj` is shown, spinning.
+//! To run: cargo run --example wgpu_obj_loader [path/to/model.obj]
+
+use std::fmt;
+use std::fs;
+use std::time::Instant;
+
+use cgmath::{InnerSpace, Matrix4, Rad, Vector3};
+use winit::{
+    dpi::LogicalSize,
+    event::{Event, WindowEvent},
+    event_loop::{ControlFlow, EventLoop},
+    window::WindowBuilder,
+};
+
+// Shared with the other wgpu_* examples; not every item is used by each of them
+#[allow(dead_code)]
+mod camera;
+#[allow(dead_code)]
+mod gpu;
+#[allow(dead_code)]
+mod mesh;
+
+use camera::Camera;
+use gpu::{Gpu, MeshBuffers, UniformBinding, Uniforms};
+use mesh::{Mesh, Vertex};
+
+const BUNDLED_CUBE: &str = include_str!("cube.obj");
+
+/// Why an OBJ file couldn't be read
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ObjError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ObjError {}
+
+/// One corner of a face: indices into the model's positions and normals
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Corner {
+    pub position: usize,
+    pub normal: Option<usize>,
+}
+
+/// The geometry of an OBJ file, before it's turned into GPU vertices
+#[derive(Debug, Default)]
+pub struct ObjModel {
+    pub positions: Vec<[f32; 3]>,
+    pub normals: Vec<[f32; 3]>,
+    pub triangles: Vec<[Corner; 3]>,
+}
+
+pub fn parse_obj(source: &str) -> Result<ObjModel, ObjError> {
+    let mut model = ObjModel::default();
+
+    for (index, line) in source.lines().enumerate() {
+        let line_number = index + 1;
+        let error = |message: String| ObjError {
+            line: line_number,
+            message,
+        };
+
+        let mut parts = line.split_whitespace();
+        match parts.next() {
+            Some("v") => model.positions.push(parse_vector(parts).map_err(error)?),
+            Some("vn") => model.normals.push(parse_vector(parts).map_err(error)?),
+            Some("f") => {
+                let corners = parts
+                    .map(|corner| parse_corner(corner, &model))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(error)?;
+                if corners.len() < 3 {
+                    return Err(error(format!(
+                        "face needs at least 3 vertices, found {}",
+                        corners.len()
+                    )));
+                }
+                // Fan out from the first corner; fine for the convex faces exporters write
+                for pair in corners[1..].windows(2) {
+                    model.triangles.push([corners[0], pair[0], pair[1]]);
+                }
+            }
+            // Comments, texture coordinates, groups, materials and smoothing are ignored
+            _ => {}
+        }
+    }
+
+    Ok(model)
+}
+
+fn parse_vector<'a>(mut parts: impl Iterator<Item = &'a str>) -> Result<[f32; 3], String> {
+    let mut vector = [0.0; 3];
+    for component in &mut vector {
+        let text = parts.next().ok_or("expected 3 coordinates")?;
+        *component = text
+            .parse()
+            .map_err(|_| format!("invalid coordinate '{}'", text))?;
+    }
+    Ok(vector)
+}
+
+/// Parse `v`, `v/vt`, `v//vn` or `v/vt/vn`
+fn parse_corner(text: &str, model: &ObjModel) -> Result<Corner, String> {
+    let mut fields = text.split('/');
+    let position = resolve_index(fields.next().unwrap_or(""), model.positions.len(), "vertex")?;
+    let _texture = fields.next();
+    let normal = match fields.next() {
+        Some(field) if !field.is_empty() => {
+            Some(resolve_index(field, model.normals.len(), "normal")?)
+        }
+        _ => None,
+    };
+    Ok(Corner { position, normal })
+}
+
+/// OBJ indices start at 1, and negative ones count back from the latest element
+fn resolve_index(text: &str, count: usize, kind: &str) -> Result<usize, String> {
+    let index: i64 = text
+        .parse()
+        .map_err(|_| format!("invalid {} index '{}'", kind, text))?;
+    let resolved = if index < 0 {
+        count as i64 + index
+    } else {
+        index - 1
+    };
+    if resolved < 0 || resolved >= count as i64 {
+        return Err(format!(
+            "{} index {} is out of range ({} defined so far)",
+            kind, index, count
+        ));
+    }
+    Ok(resolved as usize)
+}
+
+impl ObjModel {
+    /// Expand into GPU vertices, computing a flat normal for faces without normals
+    pub fn to_mesh(&self) -> Mesh {
+        let mut mesh = Mesh::default();
+        for triangle in &self.triangles {
+            let corners = triangle.map(|corner| Vector3::from(self.positions[corner.position]));
+            let flat = (corners[1] - corners[0])
+                .cross(corners[2] - corners[0])
+                .normalize();
+
+            for (corner, position) in triangle.iter().zip(corners) {
+                let normal = match corner.normal {
+                    Some(normal) => self.normals[normal],
+                    None => flat.into(),
+                };
+                mesh.indices.push(mesh.vertices.len() as u32);
+                mesh.vertices.push(Vertex {
+                    position: position.into(),
+                    normal,
+                    uv: [0.0, 0.0],
+                });
+            }
+        }
+        mesh
+    }
+
+    /// Scale and centre the model so it fits in a sphere of `radius` at the origin
+    pub fn fit(&self, radius: f32) -> Matrix4<f32> {
+        if self.positions.is_empty() {
+            return Matrix4::from_scale(1.0);
+        }
+        let mut min = [f32::MAX; 3];
+        let mut max = [f32::MIN; 3];
+        for position in &self.positions {
+            for ((lo, hi), value) in min.iter_mut().zip(&mut max).zip(position) {
+                *lo = lo.min(*value);
+                *hi = hi.max(*value);
+            }
+        }
+        let center = (Vector3::from(min) + Vector3::from(max)) / 2.0;
+        let extent = (Vector3::from(max) - center).magnitude().max(f32::EPSILON);
+        Matrix4::from_scale(radius / extent) * Matrix4::from_translation(-center)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundled_cube_has_8_vertices_and_12_triangles() {
+        let cube = parse_obj(BUNDLED_CUBE).unwrap();
+
+        assert_eq!(cube.positions.len(), 8);
+        assert_eq!(cube.triangles.len(), 12);
+        assert_eq!(cube.to_mesh().triangle_count(), 12);
+    }
+
+    #[test]
+    fn test_flat_normals_when_file_has_none() {
+        let source = "v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n";
+
+        let mesh = parse_obj(source).unwrap().to_mesh();
+
+        assert!(mesh.vertices.iter().all(|v| v.normal == [0.0, 0.0, 1.0]));
+    }
+
+    #[test]
+    fn test_file_normals_are_used() {
+        let source = "v 0 0 0\nv 1 0 0\nv 0 1 0\nvn 0 0 -1\nf 1//1 2//1 3//1\n";
+
+        let mesh = parse_obj(source).unwrap().to_mesh();
+
+        assert_eq!(mesh.vertices[0].normal, [0.0, 0.0, -1.0]);
+    }
+
+    #[test]
+    fn test_malformed_faces_report_their_line() {
+        let too_short = parse_obj("v 0 0 0\nv 1 0 0\nf 1 2\n").unwrap_err();
+        assert_eq!(too_short.line, 3);
+
+        let out_of_range = parse_obj("v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 9\n").unwrap_err();
+        assert_eq!(out_of_range.line, 4);
+        assert!(out_of_range.message.contains("out of range"));
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("WGPU OBJ Loader Example");
+
+    let (name, source) = match std::env::args().nth(1) {
+        Some(path) => {
+            let source = fs::read_to_string(&path)?;
+            (path, source)
+        }
+        None => ("bundled cube.obj".to_string(), BUNDLED_CUBE.to_string()),
+    };
+    let model = parse_obj(&source).map_err(|e| format!("{}: {}", name, e))?;
+    let mesh = model.to_mesh();
+    println!(
+        "Loaded {}: {} vertices, {} normals, {} triangles",
+        name,
+        model.positions.len(),
+        model.normals.len(),
+        mesh.triangle_count()
+    );
+
+    let event_loop = EventLoop::new();
+    let window = WindowBuilder::new()
+        .with_title("Orbit WGPU OBJ Loader")
+        .with_inner_size(LogicalSize::new(800.0, 600.0))
+        .build(&event_loop)?;
+    let mut gpu = Gpu::new(&window)?;
+
+    let shader = gpu
+        .device
+        .create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("mesh shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shaders/mesh.wgsl").into()),
+        });
+    let buffers = MeshBuffers::new(&gpu.device, &mesh);
+    let fit = model.fit(1.5);
+
+    let mut camera = Camera::new(
+        [0.0, 1.5, 5.0],
+        gpu.config.width as f32 / gpu.config.height as f32,
+    );
+    camera.pitch = -0.3;
+
+    let uniforms = UniformBinding::new(
+        &gpu.device,
+        &Uniforms {
+            view_proj: camera.view_proj().into(),
+            model: fit.into(),
+        },
+    );
+    let pipeline = gpu.mesh_pipeline(&shader, &[&uniforms.layout], wgpu::PolygonMode::Fill);
+    let started = Instant::now();
+
+    event_loop.run(move |event, _, control_flow| {
+        *control_flow = ControlFlow::Poll;
+
+        match event {
+            Event::WindowEvent {
+                event: WindowEvent::CloseRequested,
+                window_id,
+            } if window_id == window.id() => *control_flow = ControlFlow::Exit,
+            Event::WindowEvent {
+                event: WindowEvent::Resized(size),
+                window_id,
+            } if window_id == window.id() => gpu.fit_window(size, &mut camera),
+            Event::MainEventsCleared => window.request_redraw(),
+            Event::RedrawRequested(window_id) if window_id == window.id() => {
+                let t = started.elapsed().as_secs_f32();
+                let spin =
+                    Matrix4::from_angle_y(Rad(t * 0.8)) * Matrix4::from_angle_x(Rad(t * 0.3));
+                uniforms.write(
+                    &gpu.queue,
+                    &Uniforms {
+                        view_proj: camera.view_proj().into(),
+                        model: (spin * fit).into(),
+                    },
+                );
+
+                let mut frame = match gpu.begin_frame() {
+                    Ok(frame) => frame,
+                    // The surface went stale (e.g. mid-resize); reconfigure and try next frame
+                    Err(wgpu::SurfaceError::Lost | wgpu::SurfaceError::Outdated) => {
+                        gpu.resize(gpu.config.width, gpu.config.height);
+                        return;
+                    }
+                    Err(wgpu::SurfaceError::OutOfMemory) => {
+                        eprintln!("Out of GPU memory");
+                        *control_flow = ControlFlow::Exit;
+                        return;
+                    }
+                    Err(wgpu::SurfaceError::Timeout) => return,
+                };
+                {
+                    let mut pass = frame.pass(&gpu.depth_view, wgpu::Color::BLACK);
+                    pass.set_pipeline(&pipeline);
+                    pass.set_bind_group(0, &uniforms.bind_group, &[]);
+                    buffers.draw(&mut pass);
+                }
+                gpu.end_frame(frame);
+            }
+            _ => {}
+        }
+    })
+}
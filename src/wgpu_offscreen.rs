@@ -0,0 +1,333 @@
+//! Example demonstrating offscreen rendering to a PNG with WGPU
+//! The spinning-cube scene from `wgpu_renderer` is drawn into a texture instead of a
+//! window, copied back to CPU memory and written to `output.png`, so CI and headless
+//! machines can check rendering without a display. The scene is posed at a fixed
+//! rotation, which keeps the image the same from run to run.
+//! To run: cargo run --example wgpu_offscreen [-- WIDTH HEIGHT]
+
+use std::fmt;
+use std::iter;
+use std::num::NonZeroU32;
+use std::sync::mpsc;
+
+use cgmath::{Matrix4, Rad};
+use orbit::component::{Component, ComponentError, Context, Node};
+
+// Shared with the other wgpu_* examples; not every item is used by each of them
+#[allow(dead_code)]
+mod camera;
+#[allow(dead_code)]
+mod gpu;
+#[allow(dead_code)]
+mod mesh;
+
+use camera::{aspect_ratio, Camera};
+use gpu::{GpuError, MeshBuffers, UniformBinding, Uniforms};
+use mesh::MeshPrimitives;
+
+const FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8UnormSrgb;
+const BYTES_PER_PIXEL: u32 = 4;
+
+#[derive(Debug)]
+pub enum OffscreenError {
+    Gpu(GpuError),
+    Readback(wgpu::BufferAsyncError),
+    Encode(png::EncodingError),
+}
+
+impl fmt::Display for OffscreenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OffscreenError::Gpu(e) => write!(f, "{}", e),
+            OffscreenError::Readback(e) => write!(f, "failed to read the frame back: {}", e),
+            OffscreenError::Encode(e) => write!(f, "failed to encode PNG: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for OffscreenError {}
+
+impl From<GpuError> for OffscreenError {
+    fn from(e: GpuError) -> Self {
+        OffscreenError::Gpu(e)
+    }
+}
+
+impl From<png::EncodingError> for OffscreenError {
+    fn from(e: png::EncodingError) -> Self {
+        OffscreenError::Encode(e)
+    }
+}
+
+#[derive(Clone)]
+pub struct Scene3DProps {
+    // Radians about the Y axis
+    pub rotation: f32,
+}
+
+/// The `wgpu_renderer` scene: a cube seen from slightly above
+pub struct Scene3D {
+    #[allow(dead_code)]
+    context: Context,
+    rotation: f32,
+    camera: Camera,
+}
+
+impl Component for Scene3D {
+    type Props = Scene3DProps;
+
+    fn component_id(&self) -> orbit::component::ComponentId {
+        orbit::component::ComponentId::new()
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let mut camera = Camera::new([0.0, 1.0, 5.0], 16.0 / 9.0);
+        camera.pitch = -0.2;
+        Self {
+            context,
+            rotation: props.rotation,
+            camera,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.rotation = props.rotation;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        // The cube itself is drawn straight to the GPU by `render_png`
+        Ok(vec![])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl Scene3D {
+    /// Camera and cube transforms for the mesh shader
+    pub fn uniforms(&self) -> Uniforms {
+        Uniforms {
+            view_proj: self.camera.view_proj().into(),
+            model: Matrix4::from_angle_y(Rad(self.rotation)).into(),
+        }
+    }
+}
+
+/// Bytes per row in a texture-to-buffer copy, which wgpu requires to be a multiple
+/// of `COPY_BYTES_PER_ROW_ALIGNMENT` (256)
+pub fn padded_bytes_per_row(width: u32) -> u32 {
+    let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+    (width * BYTES_PER_PIXEL).div_ceil(align) * align
+}
+
+/// Drop the padding from the end of each copied row, leaving tightly packed RGBA
+pub fn strip_row_padding(padded: &[u8], width: u32, height: u32) -> Vec<u8> {
+    let row = (width * BYTES_PER_PIXEL) as usize;
+    padded
+        .chunks(padded_bytes_per_row(width) as usize)
+        .take(height as usize)
+        .flat_map(|padded_row| &padded_row[..row])
+        .copied()
+        .collect()
+}
+
+pub fn encode_png(rgba: &[u8], width: u32, height: u32) -> Result<Vec<u8>, png::EncodingError> {
+    let mut bytes = Vec::new();
+    let mut encoder = png::Encoder::new(&mut bytes, width, height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(rgba)?;
+    writer.finish()?;
+    Ok(bytes)
+}
+
+/// A device with no window or surface
+async fn request_device() -> Result<(wgpu::Device, wgpu::Queue), GpuError> {
+    let instance = wgpu::Instance::new(wgpu::Backends::all());
+    let adapter = instance
+        .request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::default(),
+            compatible_surface: None,
+            force_fallback_adapter: false,
+        })
+        .await
+        .ok_or(GpuError::NoAdapter)?;
+    adapter
+        .request_device(
+            &wgpu::DeviceDescriptor {
+                label: Some("offscreen device"),
+                features: wgpu::Features::empty(),
+                limits: wgpu::Limits::default(),
+            },
+            None,
+        )
+        .await
+        .map_err(GpuError::Device)
+}
+
+/// Render `scene` at `width`x`height` and return it encoded as a PNG
+pub fn render_png(
+    scene: &mut Scene3D,
+    width: u32,
+    height: u32,
+) -> Result<Vec<u8>, OffscreenError> {
+    let (device, queue) = pollster::block_on(request_device())?;
+    if let Some(aspect) = aspect_ratio(width, height) {
+        scene.camera.aspect = aspect;
+    }
+
+    let size = wgpu::Extent3d {
+        width,
+        height,
+        depth_or_array_layers: 1,
+    };
+    let target = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("offscreen target"),
+        size,
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: FORMAT,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+    });
+    let view = target.create_view(&wgpu::TextureViewDescriptor::default());
+    let depth_view = gpu::create_depth_view(&device, width, height);
+
+    let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: Some("mesh shader"),
+        source: wgpu::ShaderSource::Wgsl(include_str!("shaders/mesh.wgsl").into()),
+    });
+    let cube = MeshBuffers::new(&device, &MeshPrimitives::cube(1.5));
+    let uniforms = UniformBinding::new(&device, &scene.uniforms());
+    let pipeline = gpu::mesh_pipeline(
+        &device,
+        FORMAT,
+        &shader,
+        &[&uniforms.layout],
+        &[mesh::Vertex::layout()],
+        wgpu::PolygonMode::Fill,
+    );
+
+    let bytes_per_row = padded_bytes_per_row(width);
+    let readback = device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("readback"),
+        size: (bytes_per_row * height) as wgpu::BufferAddress,
+        usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+        mapped_at_creation: false,
+    });
+
+    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+        label: Some("offscreen encoder"),
+    });
+    {
+        let mut pass = gpu::begin_pass(&mut encoder, &view, &depth_view, wgpu::Color::BLACK);
+        pass.set_pipeline(&pipeline);
+        pass.set_bind_group(0, &uniforms.bind_group, &[]);
+        cube.draw(&mut pass);
+    }
+    encoder.copy_texture_to_buffer(
+        wgpu::ImageCopyTexture {
+            texture: &target,
+            mip_level: 0,
+            origin: wgpu::Origin3d::ZERO,
+            aspect: wgpu::TextureAspect::All,
+        },
+        wgpu::ImageCopyBuffer {
+            buffer: &readback,
+            layout: wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: NonZeroU32::new(bytes_per_row),
+                rows_per_image: NonZeroU32::new(height),
+            },
+        },
+        size,
+    );
+    queue.submit(iter::once(encoder.finish()));
+
+    // Mapping completes during `poll`, which blocks until the GPU is done
+    let slice = readback.slice(..);
+    let (sender, receiver) = mpsc::channel();
+    slice.map_async(wgpu::MapMode::Read, move |result| {
+        let _ = sender.send(result);
+    });
+    device.poll(wgpu::Maintain::Wait);
+    receiver
+        .recv()
+        .expect("map_async callback ran during poll")
+        .map_err(OffscreenError::Readback)?;
+
+    let rgba = strip_row_padding(&slice.get_mapped_range(), width, height);
+    readback.unmap();
+
+    Ok(encode_png(&rgba, width, height)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rows_are_padded_to_copy_alignment() {
+        assert_eq!(padded_bytes_per_row(64), 256);
+        assert_eq!(padded_bytes_per_row(65), 512);
+
+        // Two 3-pixel rows, each padded out to 256 bytes
+        let mut padded = vec![0xAA; 2 * 256];
+        padded[..12].fill(1);
+        padded[256..268].fill(2);
+        let rgba = strip_row_padding(&padded, 3, 2);
+
+        assert_eq!(rgba.len(), 2 * 12);
+        assert!(rgba[..12].iter().all(|&b| b == 1));
+        assert!(rgba[12..].iter().all(|&b| b == 2));
+    }
+
+    #[test]
+    fn test_png_has_requested_dimensions() {
+        // An odd width, so the copy needs row padding
+        let mut scene = Scene3D::create(Scene3DProps { rotation: 0.6 }, Context::new());
+        let bytes = match render_png(&mut scene, 100, 75) {
+            Ok(bytes) => bytes,
+            Err(OffscreenError::Gpu(GpuError::NoAdapter)) => {
+                eprintln!("No graphics adapter; skipping offscreen render");
+                return;
+            }
+            Err(e) => panic!("offscreen render failed: {}", e),
+        };
+
+        assert!(!bytes.is_empty());
+        let reader = png::Decoder::new(bytes.as_slice()).read_info().unwrap();
+        assert_eq!((reader.info().width, reader.info().height), (100, 75));
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("WGPU Offscreen Example");
+
+    let mut args = std::env::args().skip(1).map(|arg| arg.parse::<u32>());
+    let width = args.next().transpose()?.unwrap_or(800);
+    let height = args.next().transpose()?.unwrap_or(600);
+    if aspect_ratio(width, height).is_none() {
+        return Err("width and height must both be non-zero".into());
+    }
+
+    let mut scene = Scene3D::create(Scene3DProps { rotation: 0.6 }, Context::new());
+    scene.render()?;
+    let bytes = render_png(&mut scene, width, height)?;
+    std::fs::write("output.png", &bytes)?;
+
+    println!(
+        "Wrote output.png ({}x{}, {} bytes)",
+        width,
+        height,
+        bytes.len()
+    );
+    Ok(())
+}
@@ -0,0 +1,218 @@
+//! Example demonstrating every `MeshPrimitives` shape side by side
+//! A visual catalog and smoke test for the primitives API: each shape is built,
+//! uploaded and drawn in a row, turning slowly so every side shows. The gallery also
+//! lists shapes `MeshPrimitives` doesn't provide yet (a cylinder and a torus); those
+//! are skipped with a warning, as is any shape that comes back without triangles.
+//! To run: cargo run --example wgpu_primitives_gallery
+
+use std::time::Instant;
+
+use cgmath::{Matrix4, Rad, Vector3};
+use winit::{
+    dpi::LogicalSize,
+    event::{Event, WindowEvent},
+    event_loop::{ControlFlow, EventLoop},
+    window::WindowBuilder,
+};
+
+// Shared with the other wgpu_* examples; not every item is used by each of them
+#[allow(dead_code)]
+mod camera;
+#[allow(dead_code)]
+mod gpu;
+#[allow(dead_code)]
+mod mesh;
+
+use camera::{aspect_ratio, Camera};
+use gpu::{Gpu, MeshBuffers, UniformBinding, Uniforms};
+use mesh::{Mesh, MeshPrimitives};
+
+/// Distance between neighbouring shapes
+const SPACING: f32 = 2.5;
+
+/// Builds a shape's mesh, or `None` when `MeshPrimitives` doesn't provide it
+pub type Builder = fn() -> Option<Mesh>;
+
+/// Each shape in the gallery, and how to build it if `MeshPrimitives` can
+pub const GALLERY: [(&str, Builder); 5] = [
+    ("cube", || Some(MeshPrimitives::cube(1.4))),
+    ("sphere", || Some(MeshPrimitives::sphere(0.8, 32, 16))),
+    ("plane", || Some(MeshPrimitives::plane(1.6))),
+    ("cylinder", || None),
+    ("torus", || None),
+];
+
+/// Build every shape that's available, warning about and skipping the rest
+pub fn available_primitives() -> Vec<(&'static str, Mesh)> {
+    GALLERY
+        .iter()
+        .filter_map(|&(name, build)| match build() {
+            Some(mesh) if mesh.triangle_count() > 0 => Some((name, mesh)),
+            Some(_) => {
+                eprintln!("Warning: skipping {}, which has no triangles", name);
+                None
+            }
+            None => {
+                eprintln!(
+                    "Warning: skipping {}, which MeshPrimitives doesn't provide",
+                    name
+                );
+                None
+            }
+        })
+        .collect()
+}
+
+/// The model matrix for the `index`th of `count` shapes, centred on the origin
+pub fn placement(index: usize, count: usize, t: f32) -> Matrix4<f32> {
+    let x = (index as f32 - (count as f32 - 1.0) / 2.0) * SPACING;
+    // Tipped towards the camera so the flat plane isn't seen edge-on
+    Matrix4::from_translation(Vector3::new(x, 0.0, 0.0))
+        * Matrix4::from_angle_y(Rad(t * 0.5))
+        * Matrix4::from_angle_x(Rad(0.5))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_each_primitive_has_vertices() {
+        let primitives = available_primitives();
+
+        assert_eq!(primitives.len(), 3);
+        for (name, mesh) in &primitives {
+            let vertex_bytes: &[u8] = bytemuck::cast_slice(&mesh.vertices);
+            assert!(
+                !vertex_bytes.is_empty(),
+                "{} has an empty vertex buffer",
+                name
+            );
+            assert!(
+                mesh.indices
+                    .iter()
+                    .all(|&i| (i as usize) < mesh.vertices.len()),
+                "{} indexes past its vertices",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_row_is_centred() {
+        let first = placement(0, 3, 0.0).w;
+        let last = placement(2, 3, 0.0).w;
+
+        assert!((first.x + last.x).abs() < 1e-5);
+        assert!((last.x - first.x - 2.0 * SPACING).abs() < 1e-5);
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("WGPU Primitives Gallery Example");
+
+    let primitives = available_primitives();
+    for (name, mesh) in &primitives {
+        println!(
+            "  {:<8} {:>5} vertices, {:>5} triangles",
+            name,
+            mesh.vertices.len(),
+            mesh.triangle_count()
+        );
+    }
+
+    let event_loop = EventLoop::new();
+    let window = WindowBuilder::new()
+        .with_title("Orbit WGPU Primitives Gallery")
+        .with_inner_size(LogicalSize::new(1000.0, 450.0))
+        .build(&event_loop)?;
+    let mut gpu = Gpu::new(&window)?;
+
+    let shader = gpu
+        .device
+        .create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("mesh shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shaders/mesh.wgsl").into()),
+        });
+
+    let mut camera = Camera::new([0.0, 1.5, 7.0], 1.0);
+    camera.pitch = -0.2;
+    if let Some(aspect) = aspect_ratio(gpu.config.width, gpu.config.height) {
+        camera.aspect = aspect;
+    }
+
+    // Every shape needs its own model matrix, so each gets its own uniforms
+    let count = primitives.len();
+    let shapes: Vec<(MeshBuffers, UniformBinding)> = primitives
+        .iter()
+        .enumerate()
+        .map(|(index, (_, mesh))| {
+            let uniforms = Uniforms {
+                view_proj: camera.view_proj().into(),
+                model: placement(index, count, 0.0).into(),
+            };
+            (
+                MeshBuffers::new(&gpu.device, mesh),
+                UniformBinding::new(&gpu.device, &uniforms),
+            )
+        })
+        .collect();
+    let Some((_, first)) = shapes.first() else {
+        return Err("MeshPrimitives provided none of the gallery's shapes".into());
+    };
+    let pipeline = gpu.mesh_pipeline(&shader, &[&first.layout], wgpu::PolygonMode::Fill);
+    let started = Instant::now();
+
+    event_loop.run(move |event, _, control_flow| {
+        *control_flow = ControlFlow::Poll;
+
+        match event {
+            Event::WindowEvent {
+                event: WindowEvent::CloseRequested,
+                window_id,
+            } if window_id == window.id() => *control_flow = ControlFlow::Exit,
+            Event::WindowEvent {
+                event: WindowEvent::Resized(size),
+                window_id,
+            } if window_id == window.id() => gpu.fit_window(size, &mut camera),
+            Event::MainEventsCleared => window.request_redraw(),
+            Event::RedrawRequested(window_id) if window_id == window.id() => {
+                let t = started.elapsed().as_secs_f32();
+                for (index, (_, uniforms)) in shapes.iter().enumerate() {
+                    uniforms.write(
+                        &gpu.queue,
+                        &Uniforms {
+                            view_proj: camera.view_proj().into(),
+                            model: placement(index, count, t).into(),
+                        },
+                    );
+                }
+
+                let mut frame = match gpu.begin_frame() {
+                    Ok(frame) => frame,
+                    // The surface went stale (e.g. mid-resize); reconfigure and try next frame
+                    Err(wgpu::SurfaceError::Lost | wgpu::SurfaceError::Outdated) => {
+                        gpu.resize(gpu.config.width, gpu.config.height);
+                        return;
+                    }
+                    Err(wgpu::SurfaceError::OutOfMemory) => {
+                        eprintln!("Out of GPU memory");
+                        *control_flow = ControlFlow::Exit;
+                        return;
+                    }
+                    Err(wgpu::SurfaceError::Timeout) => return,
+                };
+                {
+                    let mut pass = frame.pass(&gpu.depth_view, wgpu::Color::BLACK);
+                    pass.set_pipeline(&pipeline);
+                    for (buffers, uniforms) in &shapes {
+                        pass.set_bind_group(0, &uniforms.bind_group, &[]);
+                        buffers.draw(&mut pass);
+                    }
+                }
+                gpu.end_frame(frame);
+            }
+            _ => {}
+        }
+    })
+}
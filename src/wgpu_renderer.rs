@@ -1,11 +1,106 @@
 //! Example demonstrating the WGPU renderer with 3D content
+//! Opens a window and drives the scene from winit's event loop: every redraw updates
+//! the scene with the real time since the last frame, hands its nodes to the renderer
+//! and draws its spinning cube to the window's surface. Resizing reconfigures the
+//! surface and the camera's aspect ratio; a minimized window skips both until it's
+//! restored. FPS, averaged over the last second, is printed once a second. WASD moves
+//! the camera and the arrow keys turn it; O switches to orbiting the cube instead,
+//! where left-drag rotates and the scroll wheel zooms. Closing the window exits.
+//! To run: cargo run --example wgpu_renderer
 
+use std::collections::VecDeque;
 use std::time::{Duration, Instant};
 
+use cgmath::{Matrix4, Rad};
 use orbit::{
     component::{Component, ComponentError, Context, Node},
-    renderer::{create_renderer, RendererType},
+    renderer::{create_renderer, Renderer, RendererType},
 };
+use winit::{
+    dpi::LogicalSize,
+    event::{
+        ElementState, Event, KeyboardInput, MouseButton, MouseScrollDelta, VirtualKeyCode,
+        WindowEvent,
+    },
+    event_loop::{ControlFlow, EventLoop},
+    window::WindowBuilder,
+};
+
+// Shared with the other wgpu_* examples; not every item is used by each of them
+#[allow(dead_code)]
+mod camera;
+#[allow(dead_code)]
+mod gpu;
+#[allow(dead_code)]
+mod mesh;
+
+use camera::{aspect_ratio, Camera, CameraController, OrbitController};
+use gpu::{Gpu, MeshBuffers, UniformBinding, Uniforms};
+use mesh::MeshPrimitives;
+
+/// How far back `FpsCounter` averages, and how often it reports
+const FPS_WINDOW: Duration = Duration::from_secs(1);
+
+/// Frames per second over a rolling window of recent frame times
+pub struct FpsCounter {
+    // Ring buffer of frame durations, oldest first, covering at most `FPS_WINDOW`
+    frames: VecDeque<Duration>,
+    total: Duration,
+    last_frame: Option<Instant>,
+    last_report: Option<Instant>,
+}
+
+impl FpsCounter {
+    pub fn new() -> Self {
+        Self {
+            frames: VecDeque::with_capacity(240),
+            total: Duration::ZERO,
+            last_frame: None,
+            last_report: None,
+        }
+    }
+
+    /// Record a frame finishing at `now`; returns the FPS once per window
+    pub fn tick(&mut self, now: Instant) -> Option<f32> {
+        // The first frame has nothing to measure from, so it only starts the clock
+        let Some(last_frame) = self.last_frame.replace(now) else {
+            self.last_report = Some(now);
+            return None;
+        };
+        self.push(now - last_frame);
+
+        let last_report = self.last_report.get_or_insert(now);
+        if now - *last_report < FPS_WINDOW {
+            return None;
+        }
+        *last_report = now;
+        self.fps()
+    }
+
+    /// Add one frame's duration, dropping frames older than the window
+    pub fn push(&mut self, frame: Duration) {
+        self.frames.push_back(frame);
+        self.total += frame;
+        while self.total > FPS_WINDOW && self.frames.len() > 1 {
+            if let Some(oldest) = self.frames.pop_front() {
+                self.total -= oldest;
+            }
+        }
+    }
+
+    pub fn fps(&self) -> Option<f32> {
+        if self.total.is_zero() {
+            return None;
+        }
+        Some(self.frames.len() as f32 / self.total.as_secs_f32())
+    }
+}
+
+impl Default for FpsCounter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 /// A simple 3D scene component
 pub struct Scene3D {
@@ -13,6 +108,11 @@ pub struct Scene3D {
     context: Context,
     rotation: f32,
     last_update: Instant,
+    camera: Camera,
+    controller: CameraController,
+    orbit: OrbitController,
+    // Whether `orbit` drives the camera rather than `controller`
+    orbiting: bool,
 }
 
 impl Component for Scene3D {
@@ -27,6 +127,10 @@ impl Component for Scene3D {
             context,
             rotation: 0.0,
             last_update: Instant::now(),
+            camera: Camera::new([0.0, 1.0, 5.0], 16.0 / 9.0),
+            controller: CameraController::new(2.0, 1.5),
+            orbit: OrbitController::new(5.0, 0.005),
+            orbiting: false,
         }
     }
 
@@ -37,6 +141,11 @@ impl Component for Scene3D {
         self.last_update = now;
 
         self.rotation += dt * 0.5; // Rotate 0.5 radians per second
+        if self.orbiting {
+            self.orbit.update_camera(&mut self.camera);
+        } else {
+            self.controller.update_camera(&mut self.camera, dt);
+        }
 
         Ok(())
     }
@@ -63,13 +172,153 @@ impl Component for Scene3D {
     }
 }
 
+impl Scene3D {
+    /// Camera and cube transforms for the mesh shader
+    pub fn uniforms(&self) -> Uniforms {
+        Uniforms {
+            view_proj: self.camera.view_proj().into(),
+            model: Matrix4::from_angle_y(Rad(self.rotation)).into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::camera::spherical_to_cartesian;
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    fn origin_camera() -> Camera {
+        Camera::new([0.0, 0.0, 0.0], 1.0)
+    }
+
+    #[test]
+    fn test_holding_w_moves_forward() {
+        let mut controller = CameraController::new(2.0, 1.5);
+        let mut camera = origin_camera();
+
+        assert!(controller.process_keyboard(VirtualKeyCode::W, ElementState::Pressed));
+        controller.update_camera(&mut camera, 0.5);
+
+        assert!((camera.position[2] + 1.0).abs() < 1e-5);
+        assert!(camera.position[0].abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_clear_stops_drift() {
+        let mut controller = CameraController::new(2.0, 1.5);
+        let mut camera = origin_camera();
+        controller.process_keyboard(VirtualKeyCode::D, ElementState::Pressed);
+        controller.process_keyboard(VirtualKeyCode::Left, ElementState::Pressed);
+
+        // Focus lost before the keys were released
+        controller.clear();
+        controller.update_camera(&mut camera, 1.0);
+
+        assert_eq!(camera, origin_camera());
+    }
+
+    #[test]
+    fn test_pitch_is_clamped() {
+        let mut controller = CameraController::new(2.0, 1.5);
+        let mut camera = origin_camera();
+        controller.process_keyboard(VirtualKeyCode::Up, ElementState::Pressed);
+
+        controller.update_camera(&mut camera, 10.0);
+
+        assert!(camera.pitch < FRAC_PI_2);
+    }
+
+    #[test]
+    fn test_fps_from_synthetic_frames() {
+        let mut counter = FpsCounter::new();
+        for _ in 0..120 {
+            counter.push(Duration::from_micros(16_667));
+        }
+
+        // Only the last second's worth of the two seconds pushed is averaged
+        let fps = counter.fps().unwrap();
+        assert!((fps - 60.0).abs() < 0.5, "fps was {}", fps);
+    }
+
+    #[test]
+    fn test_first_frame_has_no_fps() {
+        let mut counter = FpsCounter::new();
+        let start = Instant::now();
+
+        assert_eq!(counter.tick(start), None);
+        assert_eq!(counter.fps(), None);
+
+        // Reported once a full window has passed
+        let mut now = start;
+        let mut reports = Vec::new();
+        for _ in 0..100 {
+            now += Duration::from_millis(10);
+            reports.extend(counter.tick(now));
+        }
+        assert_eq!(reports.len(), 1);
+        assert!((reports[0] - 100.0).abs() < 0.5);
+    }
+
+    fn assert_near(actual: [f32; 3], expected: [f32; 3]) {
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-4, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_spherical_to_cartesian() {
+        assert_near(spherical_to_cartesian(0.0, 0.0, 5.0), [0.0, 0.0, 5.0]);
+        assert_near(spherical_to_cartesian(FRAC_PI_2, 0.0, 2.0), [2.0, 0.0, 0.0]);
+        assert_near(spherical_to_cartesian(0.0, FRAC_PI_2, 3.0), [0.0, 3.0, 0.0]);
+        let half = 2.0_f32.sqrt() / 2.0;
+        assert_near(
+            spherical_to_cartesian(FRAC_PI_4, FRAC_PI_4, 2.0),
+            [1.0, 2.0 * half, 1.0],
+        );
+    }
+
+    #[test]
+    fn test_orbit_faces_origin_with_clamped_pitch() {
+        let mut orbit = OrbitController::new(5.0, 0.01);
+        let mut camera = origin_camera();
+        orbit.set_dragging(true);
+        orbit.process_cursor(0.0, 0.0);
+
+        // Far enough down to flip over the pole without the clamp
+        orbit.process_cursor(120.0, 1000.0);
+        orbit.update_camera(&mut camera);
+
+        assert!(orbit.pitch < FRAC_PI_2);
+        let forward = camera.forward();
+        let towards_origin = camera.position.map(|p| -p / orbit.distance);
+        assert_near(forward, towards_origin);
+    }
+
+    #[test]
+    fn test_aspect_ratio() {
+        assert_eq!(aspect_ratio(600, 600), Some(1.0));
+        let ultrawide = aspect_ratio(3440, 1440).unwrap();
+        assert!((ultrawide - 21.5 / 9.0).abs() < 1e-3);
+        // Minimized windows report a zero size
+        assert_eq!(aspect_ratio(800, 0), None);
+    }
+}
+
 /// Main function
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("WGPU Renderer Example");
+    println!("Controls: W/S move forward/back, A/D strafe, arrow keys look around");
+    println!("          O toggles orbit mode: left-drag rotates, scroll zooms");
+
+    let event_loop = EventLoop::new();
+    let window = WindowBuilder::new()
+        .with_title("Orbit WGPU Renderer")
+        .with_inner_size(LogicalSize::new(800.0, 600.0))
+        .build(&event_loop)?;
 
     // Create a WGPU renderer
-    // In a real application, we would use this renderer to render the scene
-    let _renderer = create_renderer(RendererType::Wgpu)?;
+    let mut renderer = create_renderer(RendererType::Wgpu)?;
 
     // Create a context
     let context = Context::new();
@@ -77,28 +326,164 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Create a 3D scene
     let mut scene = Scene3D::create((), context);
 
-    // Main loop
-    // Note: We removed the unused last_update variable
-
-    for i in 0..100 {
-        // Update scene
-        scene.update(())?;
+    // The window's own surface, for drawing the scene's cube
+    let mut gpu = Gpu::new(&window)?;
+    if let Some(aspect) = aspect_ratio(gpu.config.width, gpu.config.height) {
+        scene.camera.aspect = aspect;
+    }
+    let shader = gpu
+        .device
+        .create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("mesh shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shaders/mesh.wgsl").into()),
+        });
+    let cube = MeshBuffers::new(&gpu.device, &MeshPrimitives::cube(1.5));
+    let uniforms = UniformBinding::new(&gpu.device, &scene.uniforms());
+    let pipeline = gpu.mesh_pipeline(&shader, &[&uniforms.layout], wgpu::PolygonMode::Fill);
 
-        // Render scene - this would render nodes in a real application
-        // We'll just print the rotation value here
-        let _nodes = scene.render()?;
+    let mut frame: u64 = 0;
+    let mut minimized = false;
+    let mut fps_counter = FpsCounter::new();
 
-        println!("Current rotation: {:.2}", scene.rotation);
+    // `run` never returns; the process exits when the loop does
+    event_loop.run(move |event, _, control_flow| {
+        *control_flow = ControlFlow::Poll;
 
-        // Sleep to simulate frame timing
-        std::thread::sleep(Duration::from_millis(16));
+        match event {
+            Event::WindowEvent {
+                event: WindowEvent::CloseRequested,
+                window_id,
+            } if window_id == window.id() => {
+                println!("Window closed after {} frames", frame);
+                *control_flow = ControlFlow::Exit;
+            }
+            Event::WindowEvent {
+                event:
+                    WindowEvent::KeyboardInput {
+                        input:
+                            KeyboardInput {
+                                virtual_keycode: Some(key),
+                                state,
+                                ..
+                            },
+                        ..
+                    },
+                window_id,
+            } if window_id == window.id() => {
+                if key == VirtualKeyCode::O && state == ElementState::Pressed {
+                    scene.orbiting = !scene.orbiting;
+                    scene.controller.clear();
+                    println!("Orbit mode {}", if scene.orbiting { "on" } else { "off" });
+                } else if !scene.orbiting {
+                    scene.controller.process_keyboard(key, state);
+                }
+            }
+            Event::WindowEvent {
+                event:
+                    WindowEvent::MouseInput {
+                        button: MouseButton::Left,
+                        state,
+                        ..
+                    },
+                window_id,
+            } if window_id == window.id() => {
+                scene
+                    .orbit
+                    .set_dragging(scene.orbiting && state == ElementState::Pressed);
+            }
+            Event::WindowEvent {
+                event: WindowEvent::CursorMoved { position, .. },
+                window_id,
+            } if window_id == window.id() => scene.orbit.process_cursor(position.x, position.y),
+            Event::WindowEvent {
+                event: WindowEvent::MouseWheel { delta, .. },
+                window_id,
+            } if window_id == window.id() && scene.orbiting => {
+                let lines = match delta {
+                    MouseScrollDelta::LineDelta(_, y) => y,
+                    // Touchpads scroll in pixels; call a line about 40 of them
+                    MouseScrollDelta::PixelDelta(offset) => offset.y as f32 / 40.0,
+                };
+                scene.orbit.process_scroll(lines);
+            }
+            // Releases that happen while unfocused never arrive, so drop held keys now
+            Event::WindowEvent {
+                event: WindowEvent::Focused(false),
+                window_id,
+            } if window_id == window.id() => {
+                scene.controller.clear();
+                scene.orbit.set_dragging(false);
+            }
+            Event::WindowEvent {
+                event: WindowEvent::Resized(size),
+                window_id,
+            } if window_id == window.id() => match aspect_ratio(size.width, size.height) {
+                Some(aspect) => {
+                    gpu.resize(size.width, size.height);
+                    scene.camera.aspect = aspect;
+                    minimized = false;
+                    println!("Resized to {}x{}", size.width, size.height);
+                    window.request_redraw();
+                }
+                // A zero-sized surface can't be configured; keep the old one until restored
+                None => minimized = true,
+            },
+            // Keep animating: ask for a new frame whenever the queue is drained
+            Event::MainEventsCleared => window.request_redraw(),
+            Event::RedrawRequested(window_id) if window_id == window.id() => {
+                // Advances the rotation by the real time since the last frame
+                if let Err(e) = scene.update(()) {
+                    eprintln!("Failed to update scene: {}", e);
+                    *control_flow = ControlFlow::Exit;
+                    return;
+                }
 
-        if i % 10 == 0 {
-            println!("Frame {}", i);
-        }
-    }
+                match scene.render() {
+                    Ok(nodes) => {
+                        for node in &nodes {
+                            if let Err(e) = renderer.render(node) {
+                                eprintln!("Failed to render frame: {}", e);
+                            }
+                        }
+                    }
+                    Err(e) => eprintln!("Failed to render scene: {}", e),
+                }
 
-    println!("Example completed");
+                if !minimized {
+                    uniforms.write(&gpu.queue, &scene.uniforms());
+                    match gpu.begin_frame() {
+                        Ok(mut output) => {
+                            {
+                                let mut pass = output.pass(&gpu.depth_view, wgpu::Color::BLACK);
+                                pass.set_pipeline(&pipeline);
+                                pass.set_bind_group(0, &uniforms.bind_group, &[]);
+                                cube.draw(&mut pass);
+                            }
+                            gpu.end_frame(output);
+                        }
+                        // The surface went stale; reconfigure and draw next frame
+                        Err(wgpu::SurfaceError::Lost | wgpu::SurfaceError::Outdated) => {
+                            gpu.resize(gpu.config.width, gpu.config.height);
+                        }
+                        Err(wgpu::SurfaceError::OutOfMemory) => {
+                            eprintln!("Out of GPU memory");
+                            *control_flow = ControlFlow::Exit;
+                            return;
+                        }
+                        Err(wgpu::SurfaceError::Timeout) => {}
+                    }
+                }
 
-    Ok(())
+                if let Some(fps) = fps_counter.tick(Instant::now()) {
+                    let [x, y, z] = scene.camera.position;
+                    println!(
+                        "{:.1} FPS: rotation {:.2}, camera at ({:.2}, {:.2}, {:.2})",
+                        fps, scene.rotation, x, y, z
+                    );
+                }
+                frame += 1;
+            }
+            _ => {}
+        }
+    })
 }
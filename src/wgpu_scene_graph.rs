@@ -0,0 +1,316 @@
+//! Example demonstrating a scene graph of meshes drawn with WGPU
+//! Every `SceneNode` has a local transform and children; its world transform is its
+//! parent's world transform times its own, so spinning a node carries everything
+//! beneath it along. Here a sun's orbit pivots swing planets around it, and a moon
+//! rides along with its planet.
+//! To run: cargo run --example wgpu_scene_graph
+
+use std::time::Instant;
+
+use cgmath::{Matrix4, One, Quaternion, Rad, Rotation3, Vector3};
+use winit::{
+    dpi::LogicalSize,
+    event::{Event, WindowEvent},
+    event_loop::{ControlFlow, EventLoop},
+    window::WindowBuilder,
+};
+
+// Shared with the other wgpu_* examples; not every item is used by each of them
+#[allow(dead_code)]
+mod camera;
+#[allow(dead_code)]
+mod gpu;
+#[allow(dead_code)]
+mod mesh;
+
+use camera::Camera;
+use gpu::{Gpu, MeshBuffers, UniformBinding, Uniforms};
+use mesh::{Mesh, MeshPrimitives};
+
+/// Placement of a node relative to its parent, applied as scale, then rotation,
+/// then translation
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transform {
+    pub translation: Vector3<f32>,
+    pub rotation: Quaternion<f32>,
+    pub scale: Vector3<f32>,
+}
+
+impl Transform {
+    pub fn identity() -> Self {
+        Self {
+            translation: Vector3::new(0.0, 0.0, 0.0),
+            rotation: Quaternion::one(),
+            scale: Vector3::new(1.0, 1.0, 1.0),
+        }
+    }
+
+    pub fn matrix(&self) -> Matrix4<f32> {
+        Matrix4::from_translation(self.translation)
+            * Matrix4::from(self.rotation)
+            * Matrix4::from_nonuniform_scale(self.scale.x, self.scale.y, self.scale.z)
+    }
+}
+
+/// A node in the scene; pivots that only group or move their children have no mesh
+pub struct SceneNode {
+    pub name: String,
+    pub mesh: Option<Mesh>,
+    pub transform: Transform,
+    // Radians per second about the node's own Y axis
+    pub spin: f32,
+    pub children: Vec<SceneNode>,
+}
+
+impl SceneNode {
+    pub fn new(name: &str, mesh: Option<Mesh>) -> Self {
+        Self {
+            name: name.to_string(),
+            mesh,
+            transform: Transform::identity(),
+            spin: 0.0,
+            children: Vec::new(),
+        }
+    }
+
+    pub fn at(mut self, x: f32, y: f32, z: f32) -> Self {
+        self.transform.translation = Vector3::new(x, y, z);
+        self
+    }
+
+    pub fn scaled(mut self, scale: f32) -> Self {
+        self.transform.scale = Vector3::new(scale, scale, scale);
+        self
+    }
+
+    pub fn spinning(mut self, spin: f32) -> Self {
+        self.spin = spin;
+        self
+    }
+
+    pub fn with_child(mut self, child: SceneNode) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Advance every node's spin by `dt` seconds
+    pub fn animate(&mut self, dt: f32) {
+        let turn = Quaternion::from_angle_y(Rad(self.spin * dt));
+        self.transform.rotation = turn * self.transform.rotation;
+        for child in &mut self.children {
+            child.animate(dt);
+        }
+    }
+
+    /// Visit this node and its descendants depth-first with their world transforms
+    pub fn visit<'a>(
+        &'a self,
+        parent: Matrix4<f32>,
+        f: &mut impl FnMut(&'a SceneNode, Matrix4<f32>),
+    ) {
+        let world = parent * self.transform.matrix();
+        f(self, world);
+        for child in &self.children {
+            child.visit(world, f);
+        }
+    }
+
+    /// World transforms of the whole tree, in the same order as `visit`
+    pub fn world_transforms(&self) -> Vec<(&SceneNode, Matrix4<f32>)> {
+        let mut transforms = Vec::new();
+        self.visit(Matrix4::one(), &mut |node, world| transforms.push((node, world)));
+        transforms
+    }
+
+    /// Where the named node's origin ends up in the world
+    pub fn world_position(&self, name: &str) -> Option<Vector3<f32>> {
+        self.world_transforms()
+            .into_iter()
+            .find(|(node, _)| node.name == name)
+            .map(|(_, world)| world.w.truncate())
+    }
+}
+
+/// A sun with two planets on pivots turning at their own orbital speeds, one with a moon
+fn solar_system() -> SceneNode {
+    let sphere = || Some(MeshPrimitives::sphere(1.0, 24, 16));
+
+    let earth = SceneNode::new("earth", sphere())
+        .at(5.0, 0.0, 0.0)
+        .scaled(0.5)
+        .spinning(1.5)
+        .with_child(SceneNode::new("moon", sphere()).at(2.5, 0.0, 0.0).scaled(0.4));
+    let mars = SceneNode::new("mars", sphere())
+        .at(8.0, 0.0, 0.0)
+        .scaled(0.35)
+        .spinning(1.2);
+
+    SceneNode::new("sun", None)
+        .with_child(SceneNode::new("sun body", sphere()).scaled(1.5).spinning(0.2))
+        .with_child(SceneNode::new("earth orbit", None).spinning(0.5).with_child(earth))
+        .with_child(SceneNode::new("mars orbit", None).spinning(0.3).with_child(mars))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::FRAC_PI_2;
+
+    fn assert_near(actual: Vector3<f32>, expected: Vector3<f32>) {
+        let error = actual - expected;
+        assert!(
+            error.x.abs() < 1e-4 && error.y.abs() < 1e-4 && error.z.abs() < 1e-4,
+            "{:?} != {:?}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_child_world_position_follows_parent() {
+        let tree = SceneNode::new("parent", None)
+            .at(1.0, 2.0, 3.0)
+            .scaled(2.0)
+            .with_child(SceneNode::new("child", None).at(1.0, 0.0, 0.0));
+
+        // The parent's scale stretches the child's offset before the parent's translation
+        assert_near(tree.world_position("child").unwrap(), Vector3::new(3.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn test_parent_rotation_carries_children() {
+        let mut tree = SceneNode::new("pivot", None)
+            .spinning(FRAC_PI_2)
+            .with_child(SceneNode::new("planet", None).at(4.0, 0.0, 0.0));
+
+        tree.animate(1.0);
+
+        // A quarter turn about +Y takes +X to -Z
+        assert_near(tree.world_position("planet").unwrap(), Vector3::new(0.0, 0.0, -4.0));
+    }
+
+    #[test]
+    fn test_moon_orbits_its_planet() {
+        let system = solar_system();
+
+        assert_near(system.world_position("earth").unwrap(), Vector3::new(5.0, 0.0, 0.0));
+        // 2.5 units from the earth, shrunk by the earth's 0.5 scale
+        assert_near(system.world_position("moon").unwrap(), Vector3::new(6.25, 0.0, 0.0));
+    }
+}
+
+/// One drawable node's buffers on the GPU
+struct DrawItem {
+    buffers: MeshBuffers,
+    uniforms: UniformBinding,
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("WGPU Scene Graph Example");
+
+    let mut scene = solar_system();
+    for (node, world) in scene.world_transforms() {
+        let position = world.w.truncate();
+        println!(
+            "  {:<12} at ({:.2}, {:.2}, {:.2})",
+            node.name, position.x, position.y, position.z
+        );
+    }
+
+    let event_loop = EventLoop::new();
+    let window = WindowBuilder::new()
+        .with_title("Orbit WGPU Scene Graph")
+        .with_inner_size(LogicalSize::new(800.0, 600.0))
+        .build(&event_loop)?;
+    let mut gpu = Gpu::new(&window)?;
+
+    let shader = gpu
+        .device
+        .create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("mesh shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shaders/mesh.wgsl").into()),
+        });
+
+    let mut camera = Camera::new(
+        [0.0, 6.0, 16.0],
+        gpu.config.width as f32 / gpu.config.height as f32,
+    );
+    camera.pitch = -0.35;
+
+    // The tree's shape never changes, so the drawables line up with `world_transforms`
+    let mut items = Vec::new();
+    for (node, world) in scene.world_transforms() {
+        if let Some(mesh) = &node.mesh {
+            let uniforms = Uniforms {
+                view_proj: camera.view_proj().into(),
+                model: world.into(),
+            };
+            items.push(DrawItem {
+                buffers: MeshBuffers::new(&gpu.device, mesh),
+                uniforms: UniformBinding::new(&gpu.device, &uniforms),
+            });
+        }
+    }
+    let layout = &items[0].uniforms.layout;
+    let pipeline = gpu.mesh_pipeline(&shader, &[layout], wgpu::PolygonMode::Fill);
+    let mut last_frame = Instant::now();
+
+    event_loop.run(move |event, _, control_flow| {
+        *control_flow = ControlFlow::Poll;
+
+        match event {
+            Event::WindowEvent {
+                event: WindowEvent::CloseRequested,
+                window_id,
+            } if window_id == window.id() => *control_flow = ControlFlow::Exit,
+            Event::WindowEvent {
+                event: WindowEvent::Resized(size),
+                window_id,
+            } if window_id == window.id() => gpu.fit_window(size, &mut camera),
+            Event::MainEventsCleared => window.request_redraw(),
+            Event::RedrawRequested(window_id) if window_id == window.id() => {
+                let now = Instant::now();
+                scene.animate((now - last_frame).as_secs_f32());
+                last_frame = now;
+
+                let view_proj: [[f32; 4]; 4] = camera.view_proj().into();
+                let drawn = scene
+                    .world_transforms()
+                    .into_iter()
+                    .filter(|(node, _)| node.mesh.is_some());
+                for (item, (_, world)) in items.iter().zip(drawn) {
+                    let uniforms = Uniforms {
+                        view_proj,
+                        model: world.into(),
+                    };
+                    item.uniforms.write(&gpu.queue, &uniforms);
+                }
+
+                let mut frame = match gpu.begin_frame() {
+                    Ok(frame) => frame,
+                    // The surface went stale (e.g. mid-resize); reconfigure and try next frame
+                    Err(wgpu::SurfaceError::Lost | wgpu::SurfaceError::Outdated) => {
+                        gpu.resize(gpu.config.width, gpu.config.height);
+                        return;
+                    }
+                    Err(wgpu::SurfaceError::OutOfMemory) => {
+                        eprintln!("Out of GPU memory");
+                        *control_flow = ControlFlow::Exit;
+                        return;
+                    }
+                    Err(wgpu::SurfaceError::Timeout) => return,
+                };
+                {
+                    let mut pass = frame.pass(&gpu.depth_view, wgpu::Color::BLACK);
+                    pass.set_pipeline(&pipeline);
+                    for item in &items {
+                        pass.set_bind_group(0, &item.uniforms.bind_group, &[]);
+                        item.buffers.draw(&mut pass);
+                    }
+                }
+                gpu.end_frame(frame);
+            }
+            _ => {}
+        }
+    })
+}
@@ -0,0 +1,322 @@
+//! Example demonstrating a textured, spinning cube in the WGPU renderer
+//! The bundled checkerboard PNG is decoded to RGBA, uploaded as a GPU texture and
+//! sampled in the fragment shader using the cube mesh's UV coordinates. If the image
+//! can't be decoded a solid magenta texture is used instead, so a bad asset is
+//! obvious on screen rather than fatal.
+//! To run: cargo run --example wgpu_textured_cube
+
+use std::fmt;
+use std::num::NonZeroU32;
+use std::time::Instant;
+
+use cgmath::{Matrix4, Rad};
+use winit::{
+    dpi::LogicalSize,
+    event::{Event, WindowEvent},
+    event_loop::{ControlFlow, EventLoop},
+    window::WindowBuilder,
+};
+
+// Shared with the other wgpu_* examples; not every item is used by each of them
+#[allow(dead_code)]
+mod camera;
+#[allow(dead_code)]
+mod gpu;
+#[allow(dead_code)]
+mod mesh;
+
+use camera::Camera;
+use gpu::{Gpu, MeshBuffers, UniformBinding, Uniforms};
+use mesh::MeshPrimitives;
+
+const CHECKERBOARD_PNG: &[u8] = include_bytes!("checkerboard.png");
+
+const MAGENTA: [u8; 4] = [255, 0, 255, 255];
+
+/// An 8-bit RGBA image
+#[derive(Debug, Clone, PartialEq)]
+pub struct Image {
+    pub width: u32,
+    pub height: u32,
+    pub rgba: Vec<u8>,
+}
+
+#[derive(Debug)]
+pub enum ImageError {
+    Decode(png::DecodingError),
+    UnsupportedColor(png::ColorType),
+}
+
+impl fmt::Display for ImageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImageError::Decode(e) => write!(f, "failed to decode PNG: {}", e),
+            ImageError::UnsupportedColor(color) => {
+                write!(f, "unsupported PNG color type {:?}", color)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ImageError {}
+
+impl From<png::DecodingError> for ImageError {
+    fn from(e: png::DecodingError) -> Self {
+        ImageError::Decode(e)
+    }
+}
+
+impl Image {
+    pub fn decode_png(bytes: &[u8]) -> Result<Self, ImageError> {
+        let mut decoder = png::Decoder::new(bytes);
+        // Palettes become RGB and 16-bit channels become 8-bit
+        decoder.set_transformations(png::Transformations::EXPAND | png::Transformations::STRIP_16);
+        let mut reader = decoder.read_info()?;
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels)?;
+        pixels.truncate(info.buffer_size());
+
+        let rgba = match info.color_type {
+            png::ColorType::Rgba => pixels,
+            png::ColorType::Rgb => pixels
+                .chunks_exact(3)
+                .flat_map(|p| [p[0], p[1], p[2], 255])
+                .collect(),
+            png::ColorType::GrayscaleAlpha => pixels
+                .chunks_exact(2)
+                .flat_map(|p| [p[0], p[0], p[0], p[1]])
+                .collect(),
+            png::ColorType::Grayscale => pixels.iter().flat_map(|&v| [v, v, v, 255]).collect(),
+            other => return Err(ImageError::UnsupportedColor(other)),
+        };
+
+        Ok(Self {
+            width: info.width,
+            height: info.height,
+            rgba,
+        })
+    }
+
+    pub fn solid(color: [u8; 4], width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            rgba: color.repeat((width * height) as usize),
+        }
+    }
+
+    /// Decode `bytes`, or fall back to solid magenta so a broken asset stands out
+    pub fn load_or_magenta(bytes: &[u8]) -> Self {
+        Self::decode_png(bytes).unwrap_or_else(|e| {
+            eprintln!("Using a magenta placeholder texture: {}", e);
+            Self::solid(MAGENTA, 1, 1)
+        })
+    }
+}
+
+/// An image on the GPU, bound with its sampler for the fragment shader
+pub struct Texture {
+    pub layout: wgpu::BindGroupLayout,
+    pub bind_group: wgpu::BindGroup,
+}
+
+impl Texture {
+    pub fn new(device: &wgpu::Device, queue: &wgpu::Queue, image: &Image) -> Self {
+        let size = wgpu::Extent3d {
+            width: image.width,
+            height: image.height,
+            depth_or_array_layers: 1,
+        };
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("diffuse texture"),
+            size,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8UnormSrgb,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+        });
+        queue.write_texture(
+            wgpu::ImageCopyTexture {
+                texture: &texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            &image.rgba,
+            wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: NonZeroU32::new(4 * image.width),
+                rows_per_image: NonZeroU32::new(image.height),
+            },
+            size,
+        );
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        // Nearest filtering keeps the checker edges crisp
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("diffuse sampler"),
+            address_mode_u: wgpu::AddressMode::Repeat,
+            address_mode_v: wgpu::AddressMode::Repeat,
+            mag_filter: wgpu::FilterMode::Nearest,
+            min_filter: wgpu::FilterMode::Nearest,
+            ..Default::default()
+        });
+
+        let layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("texture layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        });
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("texture bind group"),
+            layout: &layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(&sampler),
+                },
+            ],
+        });
+
+        Self { layout, bind_group }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundled_checkerboard_decodes() {
+        let image = Image::decode_png(CHECKERBOARD_PNG).unwrap();
+
+        assert_eq!((image.width, image.height), (64, 64));
+        assert_eq!(image.rgba.len(), 64 * 64 * 4);
+        // Opposite tiles differ
+        assert_ne!(image.rgba[..4], image.rgba[8 * 4..8 * 4 + 4]);
+    }
+
+    #[test]
+    fn test_undecodable_bytes_fall_back_to_magenta() {
+        let image = Image::load_or_magenta(b"definitely not a png");
+
+        assert_eq!(image, Image::solid(MAGENTA, 1, 1));
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("WGPU Textured Cube Example");
+
+    let image = Image::load_or_magenta(CHECKERBOARD_PNG);
+    println!("Texture: {}x{}", image.width, image.height);
+
+    let event_loop = EventLoop::new();
+    let window = WindowBuilder::new()
+        .with_title("Orbit WGPU Textured Cube")
+        .with_inner_size(LogicalSize::new(800.0, 600.0))
+        .build(&event_loop)?;
+    let mut gpu = Gpu::new(&window)?;
+
+    let shader = gpu
+        .device
+        .create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("textured shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shaders/textured.wgsl").into()),
+        });
+    let texture = Texture::new(&gpu.device, &gpu.queue, &image);
+    let cube = MeshBuffers::new(&gpu.device, &MeshPrimitives::cube(2.0));
+
+    let mut camera = Camera::new(
+        [0.0, 1.5, 5.0],
+        gpu.config.width as f32 / gpu.config.height as f32,
+    );
+    camera.pitch = -0.3;
+
+    let uniforms = UniformBinding::new(
+        &gpu.device,
+        &Uniforms {
+            view_proj: camera.view_proj().into(),
+            model: Matrix4::from_scale(1.0).into(),
+        },
+    );
+    let pipeline = gpu.mesh_pipeline(
+        &shader,
+        &[&uniforms.layout, &texture.layout],
+        wgpu::PolygonMode::Fill,
+    );
+    let started = Instant::now();
+
+    event_loop.run(move |event, _, control_flow| {
+        *control_flow = ControlFlow::Poll;
+
+        match event {
+            Event::WindowEvent {
+                event: WindowEvent::CloseRequested,
+                window_id,
+            } if window_id == window.id() => *control_flow = ControlFlow::Exit,
+            Event::WindowEvent {
+                event: WindowEvent::Resized(size),
+                window_id,
+            } if window_id == window.id() => gpu.fit_window(size, &mut camera),
+            Event::MainEventsCleared => window.request_redraw(),
+            Event::RedrawRequested(window_id) if window_id == window.id() => {
+                // Turning about two axes at different rates brings every face round
+                let t = started.elapsed().as_secs_f32();
+                let spin =
+                    Matrix4::from_angle_y(Rad(t * 0.9)) * Matrix4::from_angle_x(Rad(t * 0.6));
+                uniforms.write(
+                    &gpu.queue,
+                    &Uniforms {
+                        view_proj: camera.view_proj().into(),
+                        model: spin.into(),
+                    },
+                );
+
+                let mut frame = match gpu.begin_frame() {
+                    Ok(frame) => frame,
+                    // The surface went stale (e.g. mid-resize); reconfigure and try next frame
+                    Err(wgpu::SurfaceError::Lost | wgpu::SurfaceError::Outdated) => {
+                        gpu.resize(gpu.config.width, gpu.config.height);
+                        return;
+                    }
+                    Err(wgpu::SurfaceError::OutOfMemory) => {
+                        eprintln!("Out of GPU memory");
+                        *control_flow = ControlFlow::Exit;
+                        return;
+                    }
+                    Err(wgpu::SurfaceError::Timeout) => return,
+                };
+                {
+                    let mut pass = frame.pass(&gpu.depth_view, wgpu::Color::BLACK);
+                    pass.set_pipeline(&pipeline);
+                    pass.set_bind_group(0, &uniforms.bind_group, &[]);
+                    pass.set_bind_group(1, &texture.bind_group, &[]);
+                    cube.draw(&mut pass);
+                }
+                gpu.end_frame(frame);
+            }
+            _ => {}
+        }
+    })
+}
@@ -0,0 +1,238 @@
+//! Example demonstrating a wireframe toggle in the WGPU renderer
+//! A sphere is drawn with one of two pipelines, identical but for the polygon mode:
+//! filled triangles, or just their edges. Pressing W switches between them. Line mode
+//! needs the `POLYGON_MODE_LINE` feature, which not every adapter has; without it only
+//! the filled pipeline is built and W says so instead of switching.
+//! To run: cargo run --example wgpu_wireframe
+
+use std::time::Instant;
+
+use cgmath::{Matrix4, Rad};
+use winit::{
+    dpi::LogicalSize,
+    event::{ElementState, Event, KeyboardInput, VirtualKeyCode, WindowEvent},
+    event_loop::{ControlFlow, EventLoop},
+    window::WindowBuilder,
+};
+
+// Shared with the other wgpu_* examples; not every item is used by each of them
+#[allow(dead_code)]
+mod camera;
+#[allow(dead_code)]
+mod gpu;
+#[allow(dead_code)]
+mod mesh;
+
+use camera::{aspect_ratio, Camera};
+use gpu::{Gpu, MeshBuffers, UniformBinding, Uniforms};
+use mesh::{MeshPrimitives, Vertex};
+
+/// The filled pipeline, and the wireframe one when the device supports it
+pub struct Pipelines {
+    fill: wgpu::RenderPipeline,
+    line: Option<wgpu::RenderPipeline>,
+}
+
+impl Pipelines {
+    pub fn new(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        shader: &wgpu::ShaderModule,
+        bind_group_layouts: &[&wgpu::BindGroupLayout],
+    ) -> Self {
+        let build = |polygon_mode| {
+            gpu::mesh_pipeline(
+                device,
+                format,
+                shader,
+                bind_group_layouts,
+                &[Vertex::layout()],
+                polygon_mode,
+            )
+        };
+        let supports_line = device
+            .features()
+            .contains(wgpu::Features::POLYGON_MODE_LINE);
+
+        Self {
+            fill: build(wgpu::PolygonMode::Fill),
+            line: supports_line.then(|| build(wgpu::PolygonMode::Line)),
+        }
+    }
+
+    pub fn supports_wireframe(&self) -> bool {
+        self.line.is_some()
+    }
+
+    /// The wireframe pipeline if asked for and available, otherwise the filled one
+    pub fn get(&self, wireframe: bool) -> &wgpu::RenderPipeline {
+        match (&self.line, wireframe) {
+            (Some(line), true) => line,
+            _ => &self.fill,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytemuck::Zeroable;
+
+    /// A windowless device with line mode if the adapter has it
+    fn headless_device() -> Option<(wgpu::Device, wgpu::Queue)> {
+        let instance = wgpu::Instance::new(wgpu::Backends::all());
+        let adapter = pollster::block_on(instance.request_adapter(&Default::default()))?;
+        let features = adapter.features() & wgpu::Features::POLYGON_MODE_LINE;
+        pollster::block_on(adapter.request_device(
+            &wgpu::DeviceDescriptor {
+                label: Some("test device"),
+                features,
+                limits: wgpu::Limits::default(),
+            },
+            None,
+        ))
+        .ok()
+    }
+
+    #[test]
+    fn test_both_pipelines_build() {
+        let Some((device, _queue)) = headless_device() else {
+            eprintln!("No graphics adapter; skipping pipeline creation");
+            return;
+        };
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("mesh shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shaders/mesh.wgsl").into()),
+        });
+        let uniforms = UniformBinding::new(&device, &Uniforms::zeroed());
+
+        device.push_error_scope(wgpu::ErrorFilter::Validation);
+        let pipelines = Pipelines::new(
+            &device,
+            wgpu::TextureFormat::Bgra8UnormSrgb,
+            &shader,
+            &[&uniforms.layout],
+        );
+        let error = pollster::block_on(device.pop_error_scope());
+
+        assert!(error.is_none(), "pipeline validation failed: {:?}", error);
+        assert_eq!(
+            pipelines.supports_wireframe(),
+            device
+                .features()
+                .contains(wgpu::Features::POLYGON_MODE_LINE)
+        );
+        // Asking for wireframe never fails, with or without support
+        pipelines.get(true);
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("WGPU Wireframe Example");
+    println!("Press W to toggle wireframe");
+
+    let event_loop = EventLoop::new();
+    let window = WindowBuilder::new()
+        .with_title("Orbit WGPU Wireframe")
+        .with_inner_size(LogicalSize::new(800.0, 600.0))
+        .build(&event_loop)?;
+    let mut gpu = Gpu::with_features(&window, wgpu::Features::POLYGON_MODE_LINE)?;
+
+    let shader = gpu
+        .device
+        .create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("mesh shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shaders/mesh.wgsl").into()),
+        });
+    let sphere = MeshBuffers::new(&gpu.device, &MeshPrimitives::sphere(1.2, 24, 16));
+
+    let mut camera = Camera::new([0.0, 0.0, 4.0], 1.0);
+    if let Some(aspect) = aspect_ratio(gpu.config.width, gpu.config.height) {
+        camera.aspect = aspect;
+    }
+    let uniforms = UniformBinding::new(
+        &gpu.device,
+        &Uniforms {
+            view_proj: camera.view_proj().into(),
+            model: Matrix4::from_scale(1.0).into(),
+        },
+    );
+    let pipelines = Pipelines::new(&gpu.device, gpu.config.format, &shader, &[&uniforms.layout]);
+    if pipelines.supports_wireframe() {
+        println!("Adapter supports line polygon mode");
+    } else {
+        println!("Adapter doesn't support line polygon mode; wireframe is unavailable");
+    }
+
+    let mut wireframe = false;
+    let started = Instant::now();
+
+    event_loop.run(move |event, _, control_flow| {
+        *control_flow = ControlFlow::Poll;
+
+        match event {
+            Event::WindowEvent {
+                event: WindowEvent::CloseRequested,
+                window_id,
+            } if window_id == window.id() => *control_flow = ControlFlow::Exit,
+            Event::WindowEvent {
+                event:
+                    WindowEvent::KeyboardInput {
+                        input:
+                            KeyboardInput {
+                                virtual_keycode: Some(VirtualKeyCode::W),
+                                state: ElementState::Pressed,
+                                ..
+                            },
+                        ..
+                    },
+                window_id,
+            } if window_id == window.id() => {
+                if pipelines.supports_wireframe() {
+                    wireframe = !wireframe;
+                    println!("Wireframe {}", if wireframe { "on" } else { "off" });
+                } else {
+                    println!("Wireframe isn't supported on this adapter; staying filled");
+                }
+            }
+            Event::WindowEvent {
+                event: WindowEvent::Resized(size),
+                window_id,
+            } if window_id == window.id() => gpu.fit_window(size, &mut camera),
+            Event::MainEventsCleared => window.request_redraw(),
+            Event::RedrawRequested(window_id) if window_id == window.id() => {
+                let spin = Matrix4::from_angle_y(Rad(started.elapsed().as_secs_f32() * 0.4));
+                uniforms.write(
+                    &gpu.queue,
+                    &Uniforms {
+                        view_proj: camera.view_proj().into(),
+                        model: spin.into(),
+                    },
+                );
+
+                let mut frame = match gpu.begin_frame() {
+                    Ok(frame) => frame,
+                    // The surface went stale (e.g. mid-resize); reconfigure and try next frame
+                    Err(wgpu::SurfaceError::Lost | wgpu::SurfaceError::Outdated) => {
+                        gpu.resize(gpu.config.width, gpu.config.height);
+                        return;
+                    }
+                    Err(wgpu::SurfaceError::OutOfMemory) => {
+                        eprintln!("Out of GPU memory");
+                        *control_flow = ControlFlow::Exit;
+                        return;
+                    }
+                    Err(wgpu::SurfaceError::Timeout) => return,
+                };
+                {
+                    let mut pass = frame.pass(&gpu.depth_view, wgpu::Color::BLACK);
+                    pass.set_pipeline(pipelines.get(wireframe));
+                    pass.set_bind_group(0, &uniforms.bind_group, &[]);
+                    sphere.draw(&mut pass);
+                }
+                gpu.end_frame(frame);
+            }
+            _ => {}
+        }
+    })
+}
@@ -0,0 +1,289 @@
+//! Example demonstrating why-did-you-render style re-render tracking
+//! Components list the props and signal values their output depends on through
+//! `Inspect`. `WhyDidYouRender` wraps one, compares that list with the one from the
+//! previous render, and logs exactly which entries changed. `rerender` only renders
+//! when something did, so a no-op update stays silent, while a forced `render` with
+//! nothing changed is logged as wasted.
+//! To run: cargo run --example why_did_you_render
+
+use std::cell::RefCell;
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+use orbit::state::{create_signal, ReactiveScope, Signal};
+
+/// Exposes what a component's output depends on, as `(name, value)` pairs
+pub trait Inspect {
+    fn inspect(&self) -> Vec<(&'static str, String)>;
+}
+
+/// One input that differs from the previous render
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    pub name: &'static str,
+    pub before: String,
+    pub after: String,
+}
+
+/// Why a component rendered; no changes means the render was wasted
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderLog {
+    pub component: &'static str,
+    pub changes: Vec<Change>,
+}
+
+impl RenderLog {
+    pub fn describe(&self) -> String {
+        if self.changes.is_empty() {
+            return format!("{} re-rendered, but nothing changed", self.component);
+        }
+        let reasons: Vec<String> = self
+            .changes
+            .iter()
+            .map(|c| format!("{} {} -> {}", c.name, c.before, c.after))
+            .collect();
+        format!("{} re-rendered: {}", self.component, reasons.join(", "))
+    }
+}
+
+/// Logs the cause of each re-render of the wrapped component
+pub struct WhyDidYouRender<C: Component + Inspect> {
+    inner: C,
+    name: &'static str,
+    // Inputs as of the last render
+    rendered: RefCell<Vec<(&'static str, String)>>,
+    log: RefCell<Vec<RenderLog>>,
+}
+
+impl<C: Component + Inspect> Component for WhyDidYouRender<C> {
+    type Props = C::Props;
+
+    fn component_id(&self) -> ComponentId {
+        self.inner.component_id()
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let inner = C::create(props, context);
+        let full = std::any::type_name::<C>();
+        Self {
+            rendered: RefCell::new(inner.inspect()),
+            inner,
+            name: full.rsplit("::").next().unwrap_or(full),
+            log: RefCell::new(Vec::new()),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.inner.update(props)
+    }
+
+    /// Render unconditionally, logging what changed (possibly nothing)
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let changes = self.changes();
+        let log = RenderLog {
+            component: self.name,
+            changes,
+        };
+        println!("[why-did-you-render] {}", log.describe());
+        self.log.borrow_mut().push(log);
+        self.rendered.replace(self.inner.inspect());
+        self.inner.render()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl<C: Component + Inspect> WhyDidYouRender<C> {
+    /// Inputs that differ from the last render
+    pub fn changes(&self) -> Vec<Change> {
+        let rendered = self.rendered.borrow();
+        self.inner
+            .inspect()
+            .into_iter()
+            .zip(rendered.iter())
+            .filter(|((_, after), (_, before))| after != before)
+            .map(|((name, after), (_, before))| Change {
+                name,
+                before: before.clone(),
+                after,
+            })
+            .collect()
+    }
+
+    /// Render only if an input changed since the last render
+    pub fn rerender(&self) -> Result<Option<Vec<Node>>, ComponentError> {
+        if self.changes().is_empty() {
+            return Ok(None);
+        }
+        self.render().map(Some)
+    }
+
+    pub fn log(&self) -> Vec<RenderLog> {
+        self.log.borrow().clone()
+    }
+
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+}
+
+#[derive(Clone)]
+pub struct GreetingProps {
+    pub name: String,
+    pub greeting: String,
+}
+
+/// Greets a user and counts their visits
+pub struct Greeting {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    #[allow(dead_code)]
+    scope: ReactiveScope,
+    props: GreetingProps,
+    visits: Signal<u32>,
+}
+
+impl Component for Greeting {
+    type Props = GreetingProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let scope = ReactiveScope::new();
+
+        Self {
+            id: ComponentId::new(),
+            context,
+            visits: create_signal(&scope, 1),
+            scope,
+            props,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        Ok(vec![Node::text(&format!(
+            "{}, {}! (visit {})",
+            self.props.greeting,
+            self.props.name,
+            *self.visits.get()
+        ))])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl Inspect for Greeting {
+    fn inspect(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("props.name", format!("{:?}", self.props.name)),
+            ("props.greeting", format!("{:?}", self.props.greeting)),
+            ("signal visits", self.visits.get().to_string()),
+        ]
+    }
+}
+
+impl Greeting {
+    pub fn visit(&self) {
+        self.visits
+            .set(*self.visits.get() + 1)
+            .unwrap_or_else(|e| eprintln!("Failed to count visit: {}", e));
+    }
+}
+
+fn props(name: &str) -> GreetingProps {
+    GreetingProps {
+        name: name.to_string(),
+        greeting: "Hello".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_attributed_to_changed_prop() {
+        let mut greeting = WhyDidYouRender::<Greeting>::create(props("Ada"), Context::new());
+
+        greeting.update(props("Grace")).unwrap();
+        assert!(greeting.rerender().unwrap().is_some());
+
+        let log = greeting.log();
+        assert_eq!(log.len(), 1);
+        let names: Vec<&str> = log[0].changes.iter().map(|c| c.name).collect();
+        assert_eq!(names, ["props.name"]);
+        assert_eq!(log[0].component, "Greeting");
+    }
+
+    #[test]
+    fn test_noop_update_does_not_render() {
+        let mut greeting = WhyDidYouRender::<Greeting>::create(props("Ada"), Context::new());
+
+        greeting.update(props("Ada")).unwrap();
+
+        assert!(greeting.rerender().unwrap().is_none());
+        assert!(greeting.log().is_empty());
+    }
+
+    #[test]
+    fn test_signal_change_is_attributed() {
+        let greeting = WhyDidYouRender::<Greeting>::create(props("Ada"), Context::new());
+
+        greeting.inner().visit();
+        greeting.rerender().unwrap();
+
+        assert_eq!(
+            greeting.log()[0].changes,
+            [Change {
+                name: "signal visits",
+                before: "1".to_string(),
+                after: "2".to_string(),
+            }]
+        );
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("Why Did You Render Example\n");
+
+    let mut greeting = WhyDidYouRender::<Greeting>::create(props("Ada"), Context::new());
+
+    println!("Updating name:");
+    greeting.update(props("Grace"))?;
+    greeting.rerender()?;
+
+    println!("\nUpdating with identical props:");
+    greeting.update(props("Grace"))?;
+    if greeting.rerender()?.is_none() {
+        println!("(skipped; nothing changed)");
+    }
+
+    println!("\nCounting a visit:");
+    greeting.inner().visit();
+    greeting.rerender()?;
+
+    println!("\nForcing a render anyway:");
+    greeting.render()?;
+
+    println!("\nWhy Did You Render example completed!");
+    Ok(())
+}
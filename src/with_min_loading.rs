@@ -0,0 +1,232 @@
+//! Example demonstrating a higher-order component that enforces a minimum loading time
+//! When data arrives very quickly, flashing a spinner for a few milliseconds looks like
+//! flicker. `WithMinLoading` keeps the spinner up for at least `min_duration`.
+//! To run: cargo run --example with_min_loading
+
+use std::time::{Duration, Instant};
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+
+/// Props for the loading wrapper
+#[derive(Clone)]
+pub struct MinLoadingProps<P> {
+    pub inner: P,
+    pub min_duration: Duration,
+}
+
+/// Higher-order component that withholds its content until both the data has
+/// resolved and the spinner has been visible for `min_duration`
+pub struct WithMinLoading<C: Component> {
+    id: ComponentId,
+    inner: C,
+    min_duration: Duration,
+    // When the current load started
+    loading_since: Option<Instant>,
+    // Whether the data for the current load has arrived
+    resolved: bool,
+}
+
+impl<C: Component> Component for WithMinLoading<C> {
+    type Props = MinLoadingProps<C::Props>;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            id: ComponentId::new(),
+            inner: C::create(props.inner, context),
+            min_duration: props.min_duration,
+            loading_since: None,
+            resolved: false,
+        }
+    }
+
+    fn mount(&mut self) -> Result<(), ComponentError> {
+        self.inner.mount()
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.min_duration = props.min_duration;
+        self.inner.update(props.inner)
+    }
+
+    fn unmount(&mut self) -> Result<(), ComponentError> {
+        self.inner.unmount()
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        self.render_at(Instant::now())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl<C: Component> WithMinLoading<C> {
+    /// Begin a new load at `now`
+    pub fn start_loading(&mut self, now: Instant) {
+        self.loading_since = Some(now);
+        self.resolved = false;
+    }
+
+    /// Mark the current load as finished
+    pub fn resolve(&mut self) {
+        self.resolved = true;
+    }
+
+    /// Whether the wrapped content should be shown at `now`
+    pub fn shows_content_at(&self, now: Instant) -> bool {
+        match self.loading_since {
+            // Nothing is loading, so the content is always visible
+            None => true,
+            Some(since) => {
+                self.resolved && now.saturating_duration_since(since) >= self.min_duration
+            }
+        }
+    }
+
+    /// Render either the spinner or the wrapped content as of `now`
+    pub fn render_at(&self, now: Instant) -> Result<Vec<Node>, ComponentError> {
+        if self.shows_content_at(now) {
+            return self.inner.render();
+        }
+
+        let mut spinner = Node::default();
+        spinner.add_attribute("class".to_string(), "spinner".to_string());
+        spinner.add_attribute("aria-busy".to_string(), "true".to_string());
+        Ok(vec![spinner])
+    }
+}
+
+// Props for a simple content panel
+#[derive(Clone)]
+pub struct PanelProps {
+    pub text: String,
+}
+
+/// The content shown once loading completes
+pub struct Panel {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    props: PanelProps,
+}
+
+impl Component for Panel {
+    type Props = PanelProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            id: ComponentId::new(),
+            context,
+            props,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        Ok(vec![Node::text(&self.props.text)])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn panel(min_duration: Duration) -> WithMinLoading<Panel> {
+        WithMinLoading::<Panel>::create(
+            MinLoadingProps {
+                inner: PanelProps {
+                    text: "Loaded".to_string(),
+                },
+                min_duration,
+            },
+            Context::new(),
+        )
+    }
+
+    #[test]
+    fn test_instant_resolve_withholds_content_until_min_duration() {
+        let mut wrapped = panel(Duration::from_millis(300));
+        let start = Instant::now();
+
+        wrapped.start_loading(start);
+        wrapped.resolve();
+
+        assert!(!wrapped.shows_content_at(start));
+        assert!(!wrapped.shows_content_at(start + Duration::from_millis(299)));
+        assert!(wrapped.shows_content_at(start + Duration::from_millis(300)));
+    }
+
+    #[test]
+    fn test_unresolved_keeps_spinner_after_min_duration() {
+        let mut wrapped = panel(Duration::from_millis(100));
+        let start = Instant::now();
+
+        wrapped.start_loading(start);
+
+        assert!(!wrapped.shows_content_at(start + Duration::from_secs(5)));
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("WithMinLoading HOC Example\n");
+
+    let mut wrapped = WithMinLoading::<Panel>::create(
+        MinLoadingProps {
+            inner: PanelProps {
+                text: "Your dashboard is ready".to_string(),
+            },
+            min_duration: Duration::from_millis(200),
+        },
+        Context::new(),
+    );
+    wrapped.mount()?;
+
+    let start = Instant::now();
+    wrapped.start_loading(start);
+
+    // The data arrives almost immediately...
+    std::thread::sleep(Duration::from_millis(5));
+    wrapped.resolve();
+    println!("Data resolved after {:?}", start.elapsed());
+
+    // ...but the spinner stays up until the minimum duration has passed
+    while !wrapped.shows_content_at(Instant::now()) {
+        println!("  showing spinner ({:?} elapsed)", start.elapsed());
+        std::thread::sleep(Duration::from_millis(50));
+    }
+
+    let nodes = wrapped.render()?;
+    println!(
+        "Content shown after {:?} ({} node(s))",
+        start.elapsed(),
+        nodes.len()
+    );
+
+    println!("\nWithMinLoading example completed!");
+    Ok(())
+}
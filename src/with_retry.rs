@@ -0,0 +1,259 @@
+//! Example demonstrating a higher-order component that retries on error
+//! `WithRetry` wraps any component and re-runs a failing `render`/`update` up to
+//! `max_retries` times before surfacing the error to its parent.
+//! To run: cargo run --example with_retry
+
+use std::cell::Cell;
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+
+/// Props for the retry wrapper: the wrapped component's props plus the retry limit
+#[derive(Clone)]
+pub struct RetryProps<P> {
+    pub inner: P,
+    pub max_retries: u32,
+}
+
+/// Higher-order component that retries the wrapped component's render and update
+pub struct WithRetry<C: Component> {
+    id: ComponentId,
+    inner: C,
+    max_retries: u32,
+    // Total number of retries performed, for diagnostics
+    retries: Cell<u32>,
+}
+
+impl<C: Component> Component for WithRetry<C>
+where
+    C::Props: Clone,
+{
+    type Props = RetryProps<C::Props>;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            id: ComponentId::new(),
+            inner: C::create(props.inner, context),
+            max_retries: props.max_retries,
+            retries: Cell::new(0),
+        }
+    }
+
+    fn initialize(&mut self) -> Result<(), ComponentError> {
+        self.inner.initialize()
+    }
+
+    fn mount(&mut self) -> Result<(), ComponentError> {
+        self.inner.mount()
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.max_retries = props.max_retries;
+
+        let mut attempt = 0;
+        loop {
+            match self.inner.update(props.inner.clone()) {
+                Ok(()) => return Ok(()),
+                Err(e) if attempt < self.max_retries => {
+                    attempt += 1;
+                    self.retries.set(self.retries.get() + 1);
+                    println!("WithRetry: update failed ({}), retry {}", e, attempt);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    fn unmount(&mut self) -> Result<(), ComponentError> {
+        self.inner.unmount()
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut attempt = 0;
+        loop {
+            match self.inner.render() {
+                Ok(nodes) => return Ok(nodes),
+                Err(e) if attempt < self.max_retries => {
+                    attempt += 1;
+                    self.retries.set(self.retries.get() + 1);
+                    println!("WithRetry: render failed ({}), retry {}", e, attempt);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl<C: Component> WithRetry<C> {
+    /// Number of retries performed so far
+    pub fn retry_count(&self) -> u32 {
+        self.retries.get()
+    }
+
+    /// Access the wrapped component
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+}
+
+// Props for a component that fails a fixed number of times before succeeding
+#[derive(Clone)]
+pub struct FlakyProps {
+    pub label: String,
+    pub failures: u32,
+}
+
+/// A component whose render fails until it has been attempted `failures` times
+pub struct FlakyWidget {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    props: FlakyProps,
+    attempts: Cell<u32>,
+}
+
+impl Component for FlakyWidget {
+    type Props = FlakyProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            id: ComponentId::new(),
+            context,
+            props,
+            attempts: Cell::new(0),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let attempt = self.attempts.get() + 1;
+        self.attempts.set(attempt);
+
+        if attempt <= self.props.failures {
+            return Err(ComponentError::RenderError(format!(
+                "'{}' failed on attempt {}",
+                self.props.label, attempt
+            )));
+        }
+
+        println!("FlakyWidget rendered on attempt {}", attempt);
+        Ok(vec![Node::text(&self.props.label)])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl FlakyWidget {
+    /// Number of times render has been called
+    pub fn attempts(&self) -> u32 {
+        self.attempts.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flaky(failures: u32, max_retries: u32) -> WithRetry<FlakyWidget> {
+        WithRetry::<FlakyWidget>::create(
+            RetryProps {
+                inner: FlakyProps {
+                    label: "Data".to_string(),
+                    failures,
+                },
+                max_retries,
+            },
+            Context::new(),
+        )
+    }
+
+    #[test]
+    fn test_retries_then_renders() {
+        let wrapped = flaky(1, 3);
+
+        let nodes = wrapped.render();
+
+        assert!(nodes.is_ok());
+        assert_eq!(nodes.unwrap().len(), 1);
+        assert_eq!(wrapped.retry_count(), 1);
+        assert_eq!(wrapped.inner().attempts(), 2);
+    }
+
+    #[test]
+    fn test_surfaces_error_after_max_retries() {
+        let wrapped = flaky(5, 2);
+
+        assert!(wrapped.render().is_err());
+        assert_eq!(wrapped.retry_count(), 2);
+        assert_eq!(wrapped.inner().attempts(), 3);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("WithRetry HOC Example\n");
+
+    // A widget that fails once and then succeeds
+    let wrapped = WithRetry::<FlakyWidget>::create(
+        RetryProps {
+            inner: FlakyProps {
+                label: "Weather data".to_string(),
+                failures: 1,
+            },
+            max_retries: 3,
+        },
+        Context::new(),
+    );
+
+    let nodes = wrapped.render()?;
+    println!(
+        "Rendered {} node(s) after {} retry(ies) ({} render attempts)",
+        nodes.len(),
+        wrapped.retry_count(),
+        wrapped.inner().attempts()
+    );
+
+    // A widget that keeps failing surfaces the error once retries run out
+    let broken = WithRetry::<FlakyWidget>::create(
+        RetryProps {
+            inner: FlakyProps {
+                label: "Broken feed".to_string(),
+                failures: 10,
+            },
+            max_retries: 2,
+        },
+        Context::new(),
+    );
+
+    match broken.render() {
+        Ok(_) => println!("Unexpected success"),
+        Err(e) => println!("Gave up after {} retries: {}", broken.retry_count(), e),
+    }
+
+    println!("\nWithRetry example completed!");
+    Ok(())
+}
@@ -0,0 +1,285 @@
+//! Example demonstrating a higher-order component that injects the current theme as props
+//! Instead of every component reading the theme itself, `WithTheme` reads it once and
+//! hands theme-derived values to the wrapped component through its props.
+//! To run: cargo run --example with_theme
+
+use std::sync::{Arc, RwLock};
+
+use orbit::component::{Component, ComponentError, ComponentId, Context, Node};
+
+/// Theme values shared with the component tree
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    pub name: String,
+    pub primary: String,
+    pub on_primary: String,
+}
+
+impl Theme {
+    pub fn light() -> Self {
+        Self {
+            name: "light".to_string(),
+            primary: "#0070f3".to_string(),
+            on_primary: "#ffffff".to_string(),
+        }
+    }
+
+    pub fn dark() -> Self {
+        Self {
+            name: "dark".to_string(),
+            primary: "#79b8ff".to_string(),
+            on_primary: "#0d1117".to_string(),
+        }
+    }
+}
+
+/// Provider handle for the current theme.
+/// Clones share one theme, so a `set` is seen by every component holding the handle.
+#[derive(Clone)]
+pub struct ThemeContext {
+    theme: Arc<RwLock<Theme>>,
+}
+
+impl ThemeContext {
+    pub fn provide(theme: Theme) -> Self {
+        Self {
+            theme: Arc::new(RwLock::new(theme)),
+        }
+    }
+
+    pub fn current(&self) -> Theme {
+        match self.theme.read() {
+            Ok(theme) => theme.clone(),
+            Err(_) => Theme::light(),
+        }
+    }
+
+    pub fn set(&self, theme: Theme) {
+        if let Ok(mut current) = self.theme.write() {
+            *current = theme;
+        }
+    }
+}
+
+/// Props that can receive values derived from the theme
+pub trait ThemeAware {
+    fn apply_theme(&mut self, theme: &Theme);
+}
+
+/// Props for the theme-injecting wrapper
+#[derive(Clone)]
+pub struct WithThemeProps<P> {
+    pub inner: P,
+    pub theme: ThemeContext,
+}
+
+/// Higher-order component that injects theme-derived props into the wrapped component
+pub struct WithTheme<C: Component> {
+    id: ComponentId,
+    inner: C,
+    theme: ThemeContext,
+}
+
+impl<C: Component> Component for WithTheme<C>
+where
+    C::Props: ThemeAware,
+{
+    type Props = WithThemeProps<C::Props>;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let mut inner_props = props.inner;
+        inner_props.apply_theme(&props.theme.current());
+
+        Self {
+            id: ComponentId::new(),
+            inner: C::create(inner_props, context),
+            theme: props.theme,
+        }
+    }
+
+    fn mount(&mut self) -> Result<(), ComponentError> {
+        self.inner.mount()
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        // Re-inject on every update so theme changes reach the wrapped component
+        let mut inner_props = props.inner;
+        inner_props.apply_theme(&props.theme.current());
+        self.theme = props.theme;
+        self.inner.update(inner_props)
+    }
+
+    fn unmount(&mut self) -> Result<(), ComponentError> {
+        self.inner.unmount()
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        self.inner.render()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl<C: Component> WithTheme<C> {
+    /// Access the wrapped component
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+}
+
+// Button props: the colors are filled in by the theme HOC
+#[derive(Clone)]
+pub struct ButtonProps {
+    pub label: String,
+    pub background: String,
+    pub color: String,
+}
+
+impl ButtonProps {
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: label.to_string(),
+            background: String::new(),
+            color: String::new(),
+        }
+    }
+}
+
+impl ThemeAware for ButtonProps {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.background = theme.primary.clone();
+        self.color = theme.on_primary.clone();
+    }
+}
+
+/// A button that knows nothing about themes, only about its props
+pub struct Button {
+    id: ComponentId,
+    #[allow(dead_code)]
+    context: Context,
+    props: ButtonProps,
+}
+
+impl Component for Button {
+    type Props = ButtonProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            id: ComponentId::new(),
+            context,
+            props,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.props = props;
+        Ok(())
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut node = Node::default();
+        node.add_attribute("label".to_string(), self.props.label.clone());
+        node.add_attribute(
+            "style".to_string(),
+            format!(
+                "background: {}; color: {}",
+                self.props.background, self.props.color
+            ),
+        );
+        Ok(vec![node])
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl Button {
+    /// The background color the button renders with
+    pub fn background(&self) -> &str {
+        &self.props.background
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrapped_button_uses_provider_theme() {
+        let theme = ThemeContext::provide(Theme::dark());
+        let button = WithTheme::<Button>::create(
+            WithThemeProps {
+                inner: ButtonProps::new("Save"),
+                theme: theme.clone(),
+            },
+            Context::new(),
+        );
+
+        assert_eq!(button.inner().background(), Theme::dark().primary);
+    }
+
+    #[test]
+    fn test_update_picks_up_theme_change() {
+        let theme = ThemeContext::provide(Theme::light());
+        let props = WithThemeProps {
+            inner: ButtonProps::new("Save"),
+            theme: theme.clone(),
+        };
+        let mut button = WithTheme::<Button>::create(props.clone(), Context::new());
+
+        theme.set(Theme::dark());
+        button.update(props).unwrap();
+
+        assert_eq!(button.inner().background(), Theme::dark().primary);
+    }
+}
+
+fn main() -> Result<(), ComponentError> {
+    println!("WithTheme HOC Example\n");
+
+    let theme = ThemeContext::provide(Theme::light());
+    let props = WithThemeProps {
+        inner: ButtonProps::new("Continue"),
+        theme: theme.clone(),
+    };
+
+    let mut button = WithTheme::<Button>::create(props.clone(), Context::new());
+    button.render()?;
+    println!(
+        "Theme '{}' -> button background {}",
+        theme.current().name,
+        button.inner().background()
+    );
+
+    println!("\nSwitching to dark theme...");
+    theme.set(Theme::dark());
+    button.update(props)?;
+    button.render()?;
+    println!(
+        "Theme '{}' -> button background {}",
+        theme.current().name,
+        button.inner().background()
+    );
+
+    println!("\nWithTheme example completed!");
+    Ok(())
+}